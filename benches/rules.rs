@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Applies rules to a large procedure with many edits, which is where
+//! applying the edits one after another used to take quadratic time.
+
+use bencher::{benchmark_group, benchmark_main, Bencher};
+use poc_plpgsql_analyzer::{
+    apply_rules_filtered, DboAnalyzeContext, DboAnalyzeOptions, DboType, KeywordCase, RuleFilter,
+};
+
+/// Returns a procedure of `pairs` pairs of lowercase statements. Each
+/// pair has seven keywords and a `FROM dual` to remove, such that the edits
+/// change the length of the source.
+fn large_procedure(pairs: usize) -> String {
+    let mut sql =
+        String::from("create or replace procedure archive_orders is\n    l_now date;\nbegin\n");
+    for i in 0..pairs {
+        sql.push_str(&format!(
+            "    select sysdate into l_now from dual;\n    \
+             insert into orders_archive (id, archived) select id, l_now from orders where id = {i};\n"
+        ));
+    }
+    sql.push_str("end archive_orders;\n");
+    sql
+}
+
+fn apply_rules(bench: &mut Bencher) {
+    let sql = large_procedure(20_000);
+    let ctx = DboAnalyzeContext::default().with_options(DboAnalyzeOptions {
+        keyword_case: Some(KeywordCase::Upper),
+        ..Default::default()
+    });
+    let filter = RuleFilter {
+        ids: vec!["dual".to_string(), "keyword_case".to_string()],
+        ..Default::default()
+    };
+
+    bench.bytes = sql.len() as u64;
+    bench.iter(|| apply_rules_filtered(DboType::Procedure, &sql, &filter, &ctx).unwrap());
+}
+
+benchmark_group!(benches, apply_rules);
+benchmark_main!(benches);
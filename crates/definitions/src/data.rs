@@ -9,8 +9,32 @@ use crate::token::{Tokens, T};
 
 pub const TOKENS: Tokens<'_> = Tokens {
     trivia: &[
-        T!("inline_comment", "inline_comment", "inline_comment", "--.*"),
-        T!("whitespace", "whitespace", "whitespace", "[ \t\n\r]+"),
+        T!(
+            "block_comment",
+            "block_comment",
+            "comment",
+            r"/\*[^*]*\*+([^/*][^*]*\*+)*/"
+        ),
+        T!(
+            "hint",
+            "hint",
+            "hint",
+            r"/\*\+[^*]*\*+([^/*][^*]*\*+)*/",
+            10
+        ),
+        T!(
+            "inline_comment",
+            "inline_comment",
+            "inline_comment",
+            "--[^\r\n]*"
+        ),
+        // Includes the byte order mark, which editors hide at the start of a file
+        T!(
+            "whitespace",
+            "whitespace",
+            "whitespace",
+            "[ \t\n\r\u{feff}]+"
+        ),
     ],
     punctuation: &[
         T!("$$", "dollar_quote", "dollar_quote"),
@@ -22,6 +46,7 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("..", "double_dot", "range"),
         T!("||", "double_pipe", "concat"),
         T!("=", "equals", "comparison_op"),
+        T!("=>", "fat_arrow", "fat_arrow"),
         T!("!", "exclam", "exclam"),
         T!("(", "l_paren", "l_paren"),
         T!("-", "minus", "arithmetic_op"),
@@ -53,9 +78,15 @@ pub const TOKENS: Tokens<'_> = Tokens {
             "quoted_literal",
             "quoted_literal",
             "quoted_literal",
-            "'[^']*'"
+            "'(?:[^']|'')*'"
         ),
         T!("bind_var", "bind_var", "bind_var", r"(?i):[a-z][a-z0-9_]*"),
+        T!(
+            "substitution_var",
+            "substitution_var",
+            "substitution_var",
+            r"(?i)&&?[a-z0-9_][a-z0-9_$#]*\.?"
+        ),
         T!(
             "loop_label",
             "loop_label",
@@ -140,6 +171,7 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("cross"),
         T!("crossedition"),
         T!("cube"),
+        T!("current"),
         T!("current_user"),
         T!("cursor"),
         T!("cycle"),
@@ -163,6 +195,7 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("disable"),
         T!("disallow"),
         T!("disassociate"),
+        T!("distinct"),
         T!("double"),
         T!("drop"),
         T!("duration"),
@@ -177,6 +210,7 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("env"),
         T!("exception"),
         T!("exceptions"),
+        T!("exclude"),
         T!("execute"),
         T!("exists"),
         T!("exit"),
@@ -184,12 +218,14 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("extended"),
         T!("external"),
         T!("fact"),
+        T!("fetch"),
         T!("filter"),
         T!("final"),
         T!("first"),
         T!("float"),
         T!("follows"),
         T!("for"),
+        T!("forall"),
         T!("force"),
         T!("foreign"),
         T!("forward"),
@@ -210,6 +246,7 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("immediate"),
         T!("immutable"),
         T!("in"),
+        T!("include"),
         T!("increment"),
         T!("index"),
         T!("indicator"),
@@ -232,10 +269,12 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("language"),
         T!("large"),
         T!("last"),
+        T!("lateral"),
         T!("left"),
         T!("length"),
         T!("library"),
         T!("like", "like", "comparison_op"),
+        T!("limit"),
         T!("lobs"),
         T!("local"),
         T!("logoff"),
@@ -243,6 +282,7 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("long"),
         T!("loop"),
         T!("map"),
+        T!("materialized"),
         T!("maxlen"),
         T!("measures"),
         T!("maxvalue"),
@@ -250,6 +290,7 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("metadata"),
         T!("minvalue"),
         T!("mle"),
+        T!("model"),
         T!("module"),
         T!("month"),
         T!("mutable"),
@@ -305,6 +346,7 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("partition"),
         T!("persistable"),
         T!("pipelined"),
+        T!("pivot"),
         T!("plpgsql"),
         T!("pls_integer"),
         T!("pluggable"),
@@ -339,11 +381,14 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("revoke"),
         T!("rnds"),
         T!("rnps"),
+        T!("rollback"),
         T!("rollup"),
         T!("right"),
         T!("row"),
         T!("rowid"),
         T!("rowtype"),
+        T!("save"),
+        T!("savepoint"),
         T!("scale"),
         T!("schema"),
         T!("scope"),
@@ -362,6 +407,8 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("siblings"),
         T!("signature"),
         T!("smallint"),
+        T!("sqlcode"),
+        T!("sqlerrm"),
         T!("start"),
         T!("starts"),
         T!("startup"),
@@ -372,6 +419,7 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("struct"),
         T!("subtype"),
         T!("suspend"),
+        T!("symmetric"),
         T!("table"),
         T!("tables"),
         T!("tdo"),
@@ -385,6 +433,7 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("type"),
         T!("under"),
         T!("unique"),
+        T!("unpivot"),
         T!("unplug"),
         T!("update"),
         T!("urowid"),
@@ -409,6 +458,7 @@ pub const TOKENS: Tokens<'_> = Tokens {
         T!("wnps"),
         T!("work"),
         T!("write"),
+        T!("xml"),
         T!("xmlschema"),
         T!("xmltype"),
         T!("year"),
@@ -434,13 +484,20 @@ pub const SYNTAX_NODES: &'_ [SyntaxNode<'_>] = &[
     S!("block_statement", "A node that marks an individual statement inside a block"),
     S!("bulk_into_clause", "A node containing a BULK COLLECT INTO clause"),
     S!("calc_meas_clause", "A node containing a calc meas clause"),
+    S!("call_spec", "A node containing an external call specification"),
+    S!("case_block", "A node containing a PL/SQL CASE statement, choosing between statements instead of values"),
     S!("case_stmt", "A node containing a CASE statement"),
+    S!("collection_type_def", "A node containing an associative array, nested table or varray type definition"),
     S!("colon", "A colon token"),
+    S!("column_alias_list", "A node containing the column aliases of a FROM list item, e.g. `x (a, b)`"),
     S!("column_expr", "A single column expression, as part of an SELECT clause"),
     S!("comma", "A single comma"),
     S!("inline_comment", "Inline comment starting with `--`"),
+    S!("inline_view", "A node containing a subquery in a FROM clause, optionally LATERAL"),
+    S!("close_stmt", "A node that contains a full CLOSE statement"),
     S!("commit_stmt", "A node containing a full commit statement"),
-    S!("comment", "Inline comment starting with `--`"),
+    S!("comment", "Block comment enclosed in `/*` and `*/`"),
+    S!("comment_stmt", "A node containing a full COMMENT ON statement"),
     S!("comparisson_expression", "A node containing a comparisson expression"),
     S!("comparison_op", "Represents an arithmetic SQL comparison operator (=, <>, <, >, <=, >=) or other types of comparison operators of SQL (ilike, like)"),
     S!("concat", "A concatination operator `||`"),
@@ -452,6 +509,7 @@ pub const SYNTAX_NODES: &'_ [SyntaxNode<'_>] = &[
     S!("constructor_declaration", "A node containing a constructor_declaration"),
     S!("cross_join_clause", "A node that contains a full CROSS JOIN clause"),
     S!("cross_outer_apply_clause", "A node that contains a full cross outer apply clause"),
+    S!("cursor_attribute", "A node containing a cursor attribute, e.g. `SQL%ROWCOUNT`"),
     S!("cursor_parameter_declaration", "A node containing a cursor parameter declaration"),
     S!("cursor_parameter_declarations", "A node containing cursor parameter declarations"),
     S!("cursor_stmt", "A node that marks a full cursor statement"),
@@ -467,17 +525,24 @@ pub const SYNTAX_NODES: &'_ [SyntaxNode<'_>] = &[
     S!("else_expression", "A node containing an else expression"),
     S!("error", "An error token with a cause"),
     S!("exclam", "An exclamation mark `!`"),   
+    S!("exception_handler", "A single `WHEN .. THEN` handler inside an exception section"),
+    S!("exception_section", "A node that contains the EXCEPTION section of a block"),
     S!("execute_immediate_stmt", "A node that contains a full EXECUTE IMMEDIATE statement"),
     S!("exit_stmt", "A node that contains a full EXIT statement"),
     S!("expression", "Holds a generic SQL logic/arithmetic expression"),
+    S!("fat_arrow", "The association operator `=>` of named notation"),
+    S!("fetch_stmt", "A node that contains a full FETCH statement"),
+    S!("field_access", "A node containing an attribute access on a call result, e.g. `v.method(x).attr`"),
     S!("filter_clause", "A node that contains a full filter clause"),
     S!("filter_clauses", "A node that contains a full filter clauses"),
     S!("for_loop", "A node containing a FOR LOOP"),
+    S!("forall_stmt", "A node containing a FORALL statement"),
     S!("func_decl_in_type", "A node containing a func_decl_in_type"),
     S!("function", "A node that marks a full CREATE [..] FUNCTION block"),
     S!("function_header", "A node that marks a FUNCTION header with params and return type"),    
     S!("function_invocation", "An invocation of a function, from the identifier and the opening bracket to the closing bracket"),
     S!("hier_ids", "A node containing hier_ids"),
+    S!("hint", "An optimizer hint, e.g. `/*+ materialize */`"),
     S!("function_spec", "A node containing a function_spec"),
     S!("group_by_clause", "A node containing a group by clause"),
     S!("grouping_expression_list", "A node containing a grouping expression list"),
@@ -486,6 +551,7 @@ pub const SYNTAX_NODES: &'_ [SyntaxNode<'_>] = &[
     S!("hierarchies_clause", "A node that marks a hierarchies clause"),
     S!("ident", "An identifier, either quoted or unquoted"),
     S!("ident_group", "An identifier group, consisting of multiple idents"),
+    S!("ignored_clause", "A physical property without meaning in PostgreSQL, e.g. `PARALLEL 4` or `NOLOGGING`, skipped as a whole"),
     S!("iteration_control", "A node containing an iteration control block"),
     S!("insert_stmt", "A node that marks a full INSERT statement"),
     S!("integer", "Any integer, positive and negative"),
@@ -499,7 +565,9 @@ pub const SYNTAX_NODES: &'_ [SyntaxNode<'_>] = &[
     S!("logic_op", "Represents a logical SQL operator (AND, OR, NOT)"),
     S!("loop", "A node that contains a Basic, For, or While LOOP"),
     S!("l_paren", "Left Paren"),
+    S!("materialized_view", "A node that marks a full CREATE MATERIALIZED VIEW statement"),
     S!("map_order_func_declaration", "A node containing a map_order_func_declaration"),
+    S!("method_invocation", "A node containing a method call, e.g. `tab.EXISTS(i)` or `v.method(x).other(y)`"),
     S!("minus", "A minus `-`"),
     S!("nested_table_type_spec", "A node containing a full nested_table_type_spec"),
     S!("natural_join_clause", "A node containing an NATURAL JOIN clause"),
@@ -507,10 +575,11 @@ pub const SYNTAX_NODES: &'_ [SyntaxNode<'_>] = &[
     S!("object_base_type_def", "A node containing a full object_base_type_def"),
     S!("object_subtype_def", "A node containing a full object_subtyep_def"),
     S!("object_type_def", "A node containing a full object_type_def"),
+    S!("open_stmt", "A node that contains a full OPEN statement"),
     S!("or", "Logical operator OR"),
     S!("order_by_clause", "A node containing a full order by clause"),
     S!("outer_join_clause", "A node containing a full OUTER JOIN clause"),
-    S!("package", "A node that marks a full CREATE PACKAGE BODY block"),
+    S!("package", "A node that marks a full CREATE PACKAGE or CREATE PACKAGE BODY block"),
     S!("param", "A single Param node, consisting of name & type"),
     S!("param_list", "A node that consists of multiple parameters"),
     S!("parallel_enable_clause", "A node containing a parallel enable clause"),
@@ -519,6 +588,7 @@ pub const SYNTAX_NODES: &'_ [SyntaxNode<'_>] = &[
     S!("plsql_type_source", "A node containing a plsql type source for UDTs"),
     S!("plsql_body_type_source", "A node containing a plsql type"),
     S!("plus", "A plus `+`"),
+    S!("pragma_stmt", "A node containing a PRAGMA, e.g. PRAGMA INLINE(p, 'YES')"),
     S!("prior", "The PL/SQL unary prior operator"),
     S!("proc_decl_in_type", "A node containing a proc_decl_in_type"),
     S!("procedure", "A node that marks a full CREATE [..] PROCEDURE block"),
@@ -526,13 +596,19 @@ pub const SYNTAX_NODES: &'_ [SyntaxNode<'_>] = &[
     S!("procedure_spec", "A node that contains a full procedure_spec"),
     S!("quoted_literal", "A single quoted literal"),
     S!("range", "Two dots"),
+    S!("record_row", "A record providing a whole row in `INSERT .. VALUES` or `UPDATE .. SET ROW =`"),
+    S!("references_clause", "A node containing the `REFERENCES` clause of a foreign key constraint"),
+    S!("refresh_clause", "A node containing the REFRESH clause of a materialized view"),
     S!("result_cache_clause", "A node containing a result_cache clause"),
     S!("return_into_clause", "A node containing a return into clause"),
+    S!("returning_clause", "A node containing the `RETURNING .. INTO` clause of a DML statement"),
     S!("raise_stmt", "A node that contains the whole RAISE statement for exceptions"),
+    S!("rollback_stmt", "A node containing a full rollback statement"),
     S!("rollup_cube_clause", "A node containing a rollup_cube_clause"),
     S!("root", "The root node element"),
     S!("rowtype_clause", "A node containing a rowtype definition for cursors"),
     S!("r_paren", "Right Paren"),
+    S!("savepoint_stmt", "A node containing a SAVEPOINT statement"),
     S!("search_clause", "A node containing a search clause"),
     S!("searched_case_expression", "A node containing a searched case expression"),
     S!("select_clause", "A node that contains the whole SELECT clause of a query"),
@@ -550,14 +626,21 @@ pub const SYNTAX_NODES: &'_ [SyntaxNode<'_>] = &[
     S!("subquery_factoring_clause", "A node containing a full subquery factoring clause"),
     S!("streaming_clause", "A node containing a streaming clause"),
     S!("subprog_decl_in_type", "A node containing a subprog_decl_in_type"),
+    S!("substitution_var", "A SQL*Plus substitution variable including the `.` ending it, e.g. `&schema.` or `&&run_id`"),
+    S!("table", "A node that marks a full CREATE TABLE .. AS SELECT statement"),
+    S!("table_collection_expr", "A node containing a `TABLE(..)` collection expression in a FROM clause"),
     S!("text", "A text slice node"),
+    S!("trailing_content", "Tokens following a complete object, e.g. a SQL*Plus `/`"),
     S!("trigger","A node that marks a full CREATE [..] TRIGGER block"),
     S!("trigger_header","A node that marks a TRIGGER header"),
     S!("type_attribute", "A `%TYPE` attribute"),
     S!("type_name", "A type name"),
+    S!("typed_literal", "A literal prefixed with its type, e.g. `DATE '2020-01-01'`"),
     S!("udt_definition_stmt", "A node containing a UDT-Definitions"),
+    S!("unsupported_clause", "A recognized clause without PostgreSQL counterpart, e.g. `PIVOT`, skipped as a whole"),
     S!("update_stmt", "A node that marks a full UPDATE statement"),
     S!("using_clause", "A node containing a using clause"),
+    S!("using_index_clause", "A node containing the `USING INDEX` clause of a constraint"),
     S!("values_clause", "A node containing a values clause"),
     S!("varray_type_spec","A node containing a full varray_type_spec"),
     S!("variable_decl", "A node that marks a variable declaration as part of a function or procedure"),
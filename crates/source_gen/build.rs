@@ -191,7 +191,13 @@ mod lexer {
                 }
 
                 pub fn is_ident(self) -> bool {
-                    matches!(self, Self::UnquotedIdent | Self::QuotedIdent | Self::BindVar)
+                    matches!(
+                        self,
+                        Self::UnquotedIdent
+                            | Self::QuotedIdent
+                            | Self::BindVar
+                            | Self::SubstitutionVar
+                    )
                         || !(self.is_trivia()
                             || self.is_punct()
                             || self.is_literal()
@@ -6,9 +6,13 @@
 
 #[derive(logos :: Logos, Debug, Copy, Clone, PartialEq, Eq)]
 pub enum TokenKind {
-    #[regex("--.*")]
+    #[regex("/\\*[^*]*\\*+([^/*][^*]*\\*+)*/")]
+    BlockComment,
+    #[regex("/\\*\\+[^*]*\\*+([^/*][^*]*\\*+)*/", priority = 10)]
+    Hint,
+    #[regex("--[^\r\n]*")]
     InlineComment,
-    #[regex("[ \t\n\r]+")]
+    #[regex("[ \t\n\r\u{feff}]+")]
     Whitespace,
     #[token("$$", ignore(case))]
     DollarQuote,
@@ -28,6 +32,8 @@ pub enum TokenKind {
     DoublePipe,
     #[token("=", ignore(case))]
     Equals,
+    #[token("=>", ignore(case))]
+    FatArrow,
     #[token("!", ignore(case))]
     Exclam,
     #[token("(", ignore(case))]
@@ -54,10 +60,12 @@ pub enum TokenKind {
     UnquotedIdent,
     #[regex("\"(?:[^\"]|\"\")+\"")]
     QuotedIdent,
-    #[regex("'[^']*'")]
+    #[regex("'(?:[^']|'')*'")]
     QuotedLiteral,
     #[regex("(?i):[a-z][a-z0-9_]*")]
     BindVar,
+    #[regex("(?i)&&?[a-z0-9_][a-z0-9_$#]*\\.?")]
+    SubstitutionVar,
     #[regex("(?i)<<[a-z_][a-z0-9_$#]*>>", priority = 1)]
     LoopLabel,
     #[regex("[0-9]*[[:space:]]?\\.\\.[[:space:]]?[0-9]*", priority = 5)]
@@ -198,6 +206,8 @@ pub enum TokenKind {
     CrosseditionKw,
     #[token("cube", ignore(case))]
     CubeKw,
+    #[token("current", ignore(case))]
+    CurrentKw,
     #[token("current_user", ignore(case))]
     CurrentUserKw,
     #[token("cursor", ignore(case))]
@@ -244,6 +254,8 @@ pub enum TokenKind {
     DisallowKw,
     #[token("disassociate", ignore(case))]
     DisassociateKw,
+    #[token("distinct", ignore(case))]
+    DistinctKw,
     #[token("double", ignore(case))]
     DoubleKw,
     #[token("drop", ignore(case))]
@@ -272,6 +284,8 @@ pub enum TokenKind {
     ExceptionKw,
     #[token("exceptions", ignore(case))]
     ExceptionsKw,
+    #[token("exclude", ignore(case))]
+    ExcludeKw,
     #[token("execute", ignore(case))]
     ExecuteKw,
     #[token("exists", ignore(case))]
@@ -286,6 +300,8 @@ pub enum TokenKind {
     ExternalKw,
     #[token("fact", ignore(case))]
     FactKw,
+    #[token("fetch", ignore(case))]
+    FetchKw,
     #[token("filter", ignore(case))]
     FilterKw,
     #[token("final", ignore(case))]
@@ -298,6 +314,8 @@ pub enum TokenKind {
     FollowsKw,
     #[token("for", ignore(case))]
     ForKw,
+    #[token("forall", ignore(case))]
+    ForallKw,
     #[token("force", ignore(case))]
     ForceKw,
     #[token("foreign", ignore(case))]
@@ -338,6 +356,8 @@ pub enum TokenKind {
     ImmutableKw,
     #[token("in", ignore(case))]
     InKw,
+    #[token("include", ignore(case))]
+    IncludeKw,
     #[token("increment", ignore(case))]
     IncrementKw,
     #[token("index", ignore(case))]
@@ -382,6 +402,8 @@ pub enum TokenKind {
     LargeKw,
     #[token("last", ignore(case))]
     LastKw,
+    #[token("lateral", ignore(case))]
+    LateralKw,
     #[token("left", ignore(case))]
     LeftKw,
     #[token("length", ignore(case))]
@@ -390,6 +412,8 @@ pub enum TokenKind {
     LibraryKw,
     #[token("like", ignore(case))]
     LikeKw,
+    #[token("limit", ignore(case))]
+    LimitKw,
     #[token("lobs", ignore(case))]
     LobsKw,
     #[token("local", ignore(case))]
@@ -404,6 +428,8 @@ pub enum TokenKind {
     LoopKw,
     #[token("map", ignore(case))]
     MapKw,
+    #[token("materialized", ignore(case))]
+    MaterializedKw,
     #[token("maxlen", ignore(case))]
     MaxlenKw,
     #[token("measures", ignore(case))]
@@ -418,6 +444,8 @@ pub enum TokenKind {
     MinvalueKw,
     #[token("mle", ignore(case))]
     MleKw,
+    #[token("model", ignore(case))]
+    ModelKw,
     #[token("module", ignore(case))]
     ModuleKw,
     #[token("month", ignore(case))]
@@ -528,6 +556,8 @@ pub enum TokenKind {
     PersistableKw,
     #[token("pipelined", ignore(case))]
     PipelinedKw,
+    #[token("pivot", ignore(case))]
+    PivotKw,
     #[token("plpgsql", ignore(case))]
     PlpgsqlKw,
     #[token("pls_integer", ignore(case))]
@@ -596,6 +626,8 @@ pub enum TokenKind {
     RndsKw,
     #[token("rnps", ignore(case))]
     RnpsKw,
+    #[token("rollback", ignore(case))]
+    RollbackKw,
     #[token("rollup", ignore(case))]
     RollupKw,
     #[token("right", ignore(case))]
@@ -606,6 +638,10 @@ pub enum TokenKind {
     RowidKw,
     #[token("rowtype", ignore(case))]
     RowtypeKw,
+    #[token("save", ignore(case))]
+    SaveKw,
+    #[token("savepoint", ignore(case))]
+    SavepointKw,
     #[token("scale", ignore(case))]
     ScaleKw,
     #[token("schema", ignore(case))]
@@ -642,6 +678,10 @@ pub enum TokenKind {
     SignatureKw,
     #[token("smallint", ignore(case))]
     SmallintKw,
+    #[token("sqlcode", ignore(case))]
+    SqlcodeKw,
+    #[token("sqlerrm", ignore(case))]
+    SqlerrmKw,
     #[token("start", ignore(case))]
     StartKw,
     #[token("starts", ignore(case))]
@@ -662,6 +702,8 @@ pub enum TokenKind {
     SubtypeKw,
     #[token("suspend", ignore(case))]
     SuspendKw,
+    #[token("symmetric", ignore(case))]
+    SymmetricKw,
     #[token("table", ignore(case))]
     TableKw,
     #[token("tables", ignore(case))]
@@ -688,6 +730,8 @@ pub enum TokenKind {
     UnderKw,
     #[token("unique", ignore(case))]
     UniqueKw,
+    #[token("unpivot", ignore(case))]
+    UnpivotKw,
     #[token("unplug", ignore(case))]
     UnplugKw,
     #[token("update", ignore(case))]
@@ -736,6 +780,8 @@ pub enum TokenKind {
     WorkKw,
     #[token("write", ignore(case))]
     WriteKw,
+    #[token("xml", ignore(case))]
+    XmlKw,
     #[token("xmlschema", ignore(case))]
     XmlschemaKw,
     #[token("xmltype", ignore(case))]
@@ -750,7 +796,10 @@ pub enum TokenKind {
 }
 impl TokenKind {
     pub fn is_trivia(self) -> bool {
-        matches!(self, Self::InlineComment | Self::Whitespace)
+        matches!(
+            self,
+            Self::BlockComment | Self::Hint | Self::InlineComment | Self::Whitespace
+        )
     }
     pub fn is_punct(self) -> bool {
         matches!(
@@ -764,6 +813,7 @@ impl TokenKind {
                 | Self::DoubleDot
                 | Self::DoublePipe
                 | Self::Equals
+                | Self::FatArrow
                 | Self::Exclam
                 | Self::LParen
                 | Self::Minus
@@ -784,6 +834,7 @@ impl TokenKind {
                 | Self::QuotedIdent
                 | Self::QuotedLiteral
                 | Self::BindVar
+                | Self::SubstitutionVar
                 | Self::LoopLabel
                 | Self::IterRange
         )
@@ -791,7 +842,7 @@ impl TokenKind {
     pub fn is_ident(self) -> bool {
         matches!(
             self,
-            Self::UnquotedIdent | Self::QuotedIdent | Self::BindVar
+            Self::UnquotedIdent | Self::QuotedIdent | Self::BindVar | Self::SubstitutionVar
         ) || !(self.is_trivia()
             || self.is_punct()
             || self.is_literal()
@@ -804,4 +855,4 @@ impl std::fmt::Display for TokenKind {
     }
 }
 #[macro_export]
-macro_rules ! T { [inline_comment] => { TokenKind :: InlineComment } ; [whitespace] => { TokenKind :: Whitespace } ; ["$$"] => { TokenKind :: DollarQuote } ; [:=] => { TokenKind :: Assign } ; [*] => { TokenKind :: Asterisk } ; [,] => { TokenKind :: Comma } ; [comparison] => { TokenKind :: Comparison } ; [.] => { TokenKind :: Dot } ; [..] => { TokenKind :: DoubleDot } ; [||] => { TokenKind :: DoublePipe } ; [=] => { TokenKind :: Equals } ; [!] => { TokenKind :: Exclam } ; ["("] => { TokenKind :: LParen } ; [-] => { TokenKind :: Minus } ; [(+)] => { TokenKind :: OracleJoin } ; [%] => { TokenKind :: Percentage } ; [+] => { TokenKind :: Plus } ; [")"] => { TokenKind :: RParen } ; [;] => { TokenKind :: Semicolon } ; [/] => { TokenKind :: Slash } ; [int_literal] => { TokenKind :: Integer } ; [decimal_literal] => { TokenKind :: Decimal } ; [unquoted_ident] => { TokenKind :: UnquotedIdent } ; [quoted_ident] => { TokenKind :: QuotedIdent } ; [quoted_literal] => { TokenKind :: QuotedLiteral } ; [bind_var] => { TokenKind :: BindVar } ; [loop_label] => { TokenKind :: LoopLabel } ; [iter_range] => { TokenKind :: IterRange } ; [accessible] => { TokenKind :: AccessibleKw } ; [add] => { TokenKind :: AddKw } ; [after] => { TokenKind :: AfterKw } ; [agent] => { TokenKind :: AgentKw } ; [aggregate] => { TokenKind :: AggregateKw } ; [all] => { TokenKind :: AllKw } ; [allow] => { TokenKind :: AllowKw } ; [alter] => { TokenKind :: AlterKw } ; [analytic] => { TokenKind :: AnalyticKw } ; [analyze] => { TokenKind :: AnalyzeKw } ; [and] => { TokenKind :: AndKw } ; [annotations] => { TokenKind :: AnnotationsKw } ; [any] => { TokenKind :: AnyKw } ; [anyschema] => { TokenKind :: AnyschemaKw } ; [apply] => { TokenKind :: ApplyKw } ; [array] => { TokenKind :: ArrayKw } ; [as] => { TokenKind :: AsKw } ; [asc] => { TokenKind :: AscKw } ; [associate] => { TokenKind :: AssociateKw } ; [audit] => { TokenKind :: AuditKw } ; [authid] => { TokenKind :: AuthidKw } ; [batch] => { TokenKind :: BatchKw } ; [before] => { TokenKind :: BeforeKw } ; [begin] => { TokenKind :: BeginKw } ; [bequeath] => { TokenKind :: BequeathKw } ; [between] => { TokenKind :: BetweenKw } ; [bfile] => { TokenKind :: BfileKw } ; [binary] => { TokenKind :: BinaryKw } ; [binary_double] => { TokenKind :: BinaryDoubleKw } ; [binary_float] => { TokenKind :: BinaryFloatKw } ; [binary_integer] => { TokenKind :: BinaryIntegerKw } ; [blob] => { TokenKind :: BlobKw } ; [body] => { TokenKind :: BodyKw } ; [breadth] => { TokenKind :: BreadthKw } ; [bulk] => { TokenKind :: BulkKw } ; [by] => { TokenKind :: ByKw } ; [byte] => { TokenKind :: ByteKw } ; [cache] => { TokenKind :: CacheKw } ; [call] => { TokenKind :: CallKw } ; [cascade] => { TokenKind :: CascadeKw } ; [case] => { TokenKind :: CaseKw } ; [c] => { TokenKind :: CKw } ; [char] => { TokenKind :: CharKw } ; [character] => { TokenKind :: CharacterKw } ; [charsetform] => { TokenKind :: CharsetformKw } ; [charsetid] => { TokenKind :: CharsetidKw } ; [check] => { TokenKind :: CheckKw } ; [clob] => { TokenKind :: ClobKw } ; [clone] => { TokenKind :: CloneKw } ; [cluster] => { TokenKind :: ClusterKw } ; [collation] => { TokenKind :: CollationKw } ; [collect] => { TokenKind :: CollectKw } ; [comment] => { TokenKind :: CommentKw } ; [commit] => { TokenKind :: CommitKw } ; [connect] => { TokenKind :: ConnectKw } ; [connect_by_root] => { TokenKind :: ConnectByRootKw } ; [constant] => { TokenKind :: ConstantKw } ; [constraint] => { TokenKind :: ConstraintKw } ; [constructor] => { TokenKind :: ConstructorKw } ; [container] => { TokenKind :: ContainerKw } ; [container_map] => { TokenKind :: ContainerMapKw } ; [containers_default] => { TokenKind :: ContainersDefaultKw } ; [continue] => { TokenKind :: ContinueKw } ; [context] => { TokenKind :: ContextKw } ; [create] => { TokenKind :: CreateKw } ; [cross] => { TokenKind :: CrossKw } ; [crossedition] => { TokenKind :: CrosseditionKw } ; [cube] => { TokenKind :: CubeKw } ; [current_user] => { TokenKind :: CurrentUserKw } ; [cursor] => { TokenKind :: CursorKw } ; [cycle] => { TokenKind :: CycleKw } ; [data] => { TokenKind :: DataKw } ; [database] => { TokenKind :: DatabaseKw } ; [date] => { TokenKind :: DateKw } ; [day] => { TokenKind :: DayKw } ; [db_role_change] => { TokenKind :: DbRoleChangeKw } ; [ddl] => { TokenKind :: DdlKw } ; [dec] => { TokenKind :: DecKw } ; [decimal] => { TokenKind :: DecimalKw } ; [declare] => { TokenKind :: DeclareKw } ; [default] => { TokenKind :: DefaultKw } ; [deferrable] => { TokenKind :: DeferrableKw } ; [deferred] => { TokenKind :: DeferredKw } ; [definer] => { TokenKind :: DefinerKw } ; [delete] => { TokenKind :: DeleteKw } ; [depth] => { TokenKind :: DepthKw } ; [desc] => { TokenKind :: DescKw } ; [deterministic] => { TokenKind :: DeterministicKw } ; [disable] => { TokenKind :: DisableKw } ; [disallow] => { TokenKind :: DisallowKw } ; [disassociate] => { TokenKind :: DisassociateKw } ; [double] => { TokenKind :: DoubleKw } ; [drop] => { TokenKind :: DropKw } ; [duration] => { TokenKind :: DurationKw } ; [each] => { TokenKind :: EachKw } ; [editionable] => { TokenKind :: EditionableKw } ; [editioning] => { TokenKind :: EditioningKw } ; [element] => { TokenKind :: ElementKw } ; [else] => { TokenKind :: ElseKw } ; [elsif] => { TokenKind :: ElsifKw } ; [enable] => { TokenKind :: EnableKw } ; [end] => { TokenKind :: EndKw } ; [env] => { TokenKind :: EnvKw } ; [exception] => { TokenKind :: ExceptionKw } ; [exceptions] => { TokenKind :: ExceptionsKw } ; [execute] => { TokenKind :: ExecuteKw } ; [exists] => { TokenKind :: ExistsKw } ; [exit] => { TokenKind :: ExitKw } ; [extend] => { TokenKind :: ExtendKw } ; [extended] => { TokenKind :: ExtendedKw } ; [external] => { TokenKind :: ExternalKw } ; [fact] => { TokenKind :: FactKw } ; [filter] => { TokenKind :: FilterKw } ; [final] => { TokenKind :: FinalKw } ; [first] => { TokenKind :: FirstKw } ; [float] => { TokenKind :: FloatKw } ; [follows] => { TokenKind :: FollowsKw } ; [for] => { TokenKind :: ForKw } ; [force] => { TokenKind :: ForceKw } ; [foreign] => { TokenKind :: ForeignKw } ; [forward] => { TokenKind :: ForwardKw } ; [from] => { TokenKind :: FromKw } ; [full] => { TokenKind :: FullKw } ; [function] => { TokenKind :: FunctionKw } ; [global] => { TokenKind :: GlobalKw } ; [grant] => { TokenKind :: GrantKw } ; [hierarchies] => { TokenKind :: HierarchiesKw } ; [group] => { TokenKind :: GroupKw } ; [grouping] => { TokenKind :: GroupingKw } ; [hash] => { TokenKind :: HashKw } ; [having] => { TokenKind :: HavingKw } ; [id] => { TokenKind :: IdKw } ; [identifier] => { TokenKind :: IdentifierKw } ; [if] => { TokenKind :: IfKw } ; [ilike] => { TokenKind :: IlikeKw } ; [immediate] => { TokenKind :: ImmediateKw } ; [immutable] => { TokenKind :: ImmutableKw } ; [in] => { TokenKind :: InKw } ; [increment] => { TokenKind :: IncrementKw } ; [index] => { TokenKind :: IndexKw } ; [indicator] => { TokenKind :: IndicatorKw } ; [indices] => { TokenKind :: IndicesKw } ; [initially] => { TokenKind :: InitiallyKw } ; [inner] => { TokenKind :: InnerKw } ; [insert] => { TokenKind :: InsertKw } ; [instantiable] => { TokenKind :: InstantiableKw } ; [instead] => { TokenKind :: InsteadKw } ; [int] => { TokenKind :: IntKw } ; [integer] => { TokenKind :: IntegerKw } ; [interval] => { TokenKind :: IntervalKw } ; [into] => { TokenKind :: IntoKw } ; [invisible] => { TokenKind :: InvisibleKw } ; [is] => { TokenKind :: IsKw } ; [java] => { TokenKind :: JavaKw } ; [keep] => { TokenKind :: KeepKw } ; [join] => { TokenKind :: JoinKw } ; [key] => { TokenKind :: KeyKw } ; [language] => { TokenKind :: LanguageKw } ; [large] => { TokenKind :: LargeKw } ; [last] => { TokenKind :: LastKw } ; [left] => { TokenKind :: LeftKw } ; [length] => { TokenKind :: LengthKw } ; [library] => { TokenKind :: LibraryKw } ; [like] => { TokenKind :: LikeKw } ; [lobs] => { TokenKind :: LobsKw } ; [local] => { TokenKind :: LocalKw } ; [logoff] => { TokenKind :: LogoffKw } ; [logon] => { TokenKind :: LogonKw } ; [long] => { TokenKind :: LongKw } ; [loop] => { TokenKind :: LoopKw } ; [map] => { TokenKind :: MapKw } ; [maxlen] => { TokenKind :: MaxlenKw } ; [measures] => { TokenKind :: MeasuresKw } ; [maxvalue] => { TokenKind :: MaxvalueKw } ; [member] => { TokenKind :: MemberKw } ; [metadata] => { TokenKind :: MetadataKw } ; [minvalue] => { TokenKind :: MinvalueKw } ; [mle] => { TokenKind :: MleKw } ; [module] => { TokenKind :: ModuleKw } ; [month] => { TokenKind :: MonthKw } ; [mutable] => { TokenKind :: MutableKw } ; [name] => { TokenKind :: NameKw } ; [national] => { TokenKind :: NationalKw } ; [natural] => { TokenKind :: NaturalKw } ; [nchar] => { TokenKind :: NcharKw } ; [nclob] => { TokenKind :: NclobKw } ; [new] => { TokenKind :: NewKw } ; [no] => { TokenKind :: NoKw } ; [noaudit] => { TokenKind :: NoauditKw } ; [nocache] => { TokenKind :: NocacheKw } ; [nocopy] => { TokenKind :: NocopyKw } ; [nocycle] => { TokenKind :: NocycleKw } ; [noextend] => { TokenKind :: NoextendKw } ; [nokeep] => { TokenKind :: NokeepKw } ; [nomaxvalue] => { TokenKind :: NomaxvalueKw } ; [nominvalue] => { TokenKind :: NominvalueKw } ; [none] => { TokenKind :: NoneKw } ; [noneditionable] => { TokenKind :: NoneditionableKw } ; [nonschema] => { TokenKind :: NonschemaKw } ; [noorder] => { TokenKind :: NoorderKw } ; [noprecheck] => { TokenKind :: NoprecheckKw } ; [norely] => { TokenKind :: NorelyKw } ; [noscale] => { TokenKind :: NoscaleKw } ; [noshard] => { TokenKind :: NoshardKw } ; [not] => { TokenKind :: NotKw } ; [novalidate] => { TokenKind :: NovalidateKw } ; [nowait] => { TokenKind :: NowaitKw } ; [null] => { TokenKind :: NullKw } ; [nulls] => { TokenKind :: NullsKw } ; [number] => { TokenKind :: NumberKw } ; [numeric] => { TokenKind :: NumericKw } ; [nvarchar2] => { TokenKind :: Nvarchar2Kw } ; [object] => { TokenKind :: ObjectKw } ; [of] => { TokenKind :: OfKw } ; [oid] => { TokenKind :: OidKw } ; [old] => { TokenKind :: OldKw } ; [on] => { TokenKind :: OnKw } ; [only] => { TokenKind :: OnlyKw } ; [option] => { TokenKind :: OptionKw } ; [or] => { TokenKind :: OrKw } ; [order] => { TokenKind :: OrderKw } ; [others] => { TokenKind :: OthersKw } ; [out] => { TokenKind :: OutKw } ; [overriding] => { TokenKind :: OverridingKw } ; [outer] => { TokenKind :: OuterKw } ; [package] => { TokenKind :: PackageKw } ; [parallel_enable] => { TokenKind :: ParallelEnableKw } ; [parameters] => { TokenKind :: ParametersKw } ; [parent] => { TokenKind :: ParentKw } ; [pairs] => { TokenKind :: PairsKw } ; [partition] => { TokenKind :: PartitionKw } ; [persistable] => { TokenKind :: PersistableKw } ; [pipelined] => { TokenKind :: PipelinedKw } ; [plpgsql] => { TokenKind :: PlpgsqlKw } ; [pls_integer] => { TokenKind :: PlsIntegerKw } ; [pluggable] => { TokenKind :: PluggableKw } ; [pragma] => { TokenKind :: PragmaKw } ; [precedes] => { TokenKind :: PrecedesKw } ; [precheck] => { TokenKind :: PrecheckKw } ; [precision] => { TokenKind :: PrecisionKw } ; [prior] => { TokenKind :: PriorKw } ; [primary] => { TokenKind :: PrimaryKw } ; [procedure] => { TokenKind :: ProcedureKw } ; [range] => { TokenKind :: RangeKw } ; [raise] => { TokenKind :: RaiseKw } ; [raw] => { TokenKind :: RawKw } ; [read] => { TokenKind :: ReadKw } ; [real] => { TokenKind :: RealKw } ; [record] => { TokenKind :: RecordKw } ; [ref] => { TokenKind :: RefKw } ; [reference] => { TokenKind :: ReferenceKw } ; [references] => { TokenKind :: ReferencesKw } ; [referencing] => { TokenKind :: ReferencingKw } ; [relies_on] => { TokenKind :: ReliesOnKw } ; [rely] => { TokenKind :: RelyKw } ; [rename] => { TokenKind :: RenameKw } ; [repeat] => { TokenKind :: RepeatKw } ; [replace] => { TokenKind :: ReplaceKw } ; [result] => { TokenKind :: ResultKw } ; [result_cache] => { TokenKind :: ResultCacheKw } ; [restricted_references] => { TokenKind :: RestrictedReferencesKw } ; [return] => { TokenKind :: ReturnKw } ; [returning] => { TokenKind :: ReturningKw } ; [reverse] => { TokenKind :: ReverseKw } ; [revoke] => { TokenKind :: RevokeKw } ; [rnds] => { TokenKind :: RndsKw } ; [rnps] => { TokenKind :: RnpsKw } ; [rollup] => { TokenKind :: RollupKw } ; [right] => { TokenKind :: RightKw } ; [row] => { TokenKind :: RowKw } ; [rowid] => { TokenKind :: RowidKw } ; [rowtype] => { TokenKind :: RowtypeKw } ; [scale] => { TokenKind :: ScaleKw } ; [schema] => { TokenKind :: SchemaKw } ; [scope] => { TokenKind :: ScopeKw } ; [search] => { TokenKind :: SearchKw } ; [second] => { TokenKind :: SecondKw } ; [select] => { TokenKind :: SelectKw } ; [self] => { TokenKind :: SelfKw } ; [sequence] => { TokenKind :: SequenceKw } ; [servererror] => { TokenKind :: ServererrorKw } ; [session] => { TokenKind :: SessionKw } ; [set] => { TokenKind :: SetKw } ; [sets] => { TokenKind :: SetsKw } ; [shard] => { TokenKind :: ShardKw } ; [sharing] => { TokenKind :: SharingKw } ; [shutdown] => { TokenKind :: ShutdownKw } ; [siblings] => { TokenKind :: SiblingsKw } ; [signature] => { TokenKind :: SignatureKw } ; [smallint] => { TokenKind :: SmallintKw } ; [start] => { TokenKind :: StartKw } ; [starts] => { TokenKind :: StartsKw } ; [startup] => { TokenKind :: StartupKw } ; [static] => { TokenKind :: StaticKw } ; [statistics] => { TokenKind :: StatisticsKw } ; [store] => { TokenKind :: StoreKw } ; [string] => { TokenKind :: StringKw } ; [struct] => { TokenKind :: StructKw } ; [subtype] => { TokenKind :: SubtypeKw } ; [suspend] => { TokenKind :: SuspendKw } ; [table] => { TokenKind :: TableKw } ; [tables] => { TokenKind :: TablesKw } ; [tdo] => { TokenKind :: TdoKw } ; [then] => { TokenKind :: ThenKw } ; [time] => { TokenKind :: TimeKw } ; [timestamp] => { TokenKind :: TimestampKw } ; [to] => { TokenKind :: ToKw } ; [trigger] => { TokenKind :: TriggerKw } ; [truncate] => { TokenKind :: TruncateKw } ; [trust] => { TokenKind :: TrustKw } ; [type] => { TokenKind :: TypeKw } ; [under] => { TokenKind :: UnderKw } ; [unique] => { TokenKind :: UniqueKw } ; [unplug] => { TokenKind :: UnplugKw } ; [update] => { TokenKind :: UpdateKw } ; [urowid] => { TokenKind :: UrowidKw } ; [using] => { TokenKind :: UsingKw } ; [using_nls_comp] => { TokenKind :: UsingNlsCompKw } ; [validate] => { TokenKind :: ValidateKw } ; [value] => { TokenKind :: ValueKw } ; [values] => { TokenKind :: ValuesKw } ; [varchar] => { TokenKind :: VarcharKw } ; [varchar2] => { TokenKind :: Varchar2Kw } ; [varray] => { TokenKind :: VarrayKw } ; [varrays] => { TokenKind :: VarraysKw } ; [varying] => { TokenKind :: VaryingKw } ; [view] => { TokenKind :: ViewKw } ; [visible] => { TokenKind :: VisibleKw } ; [wait] => { TokenKind :: WaitKw } ; [when] => { TokenKind :: WhenKw } ; [where] => { TokenKind :: WhereKw } ; [while] => { TokenKind :: WhileKw } ; [with] => { TokenKind :: WithKw } ; [wnds] => { TokenKind :: WndsKw } ; [wnps] => { TokenKind :: WnpsKw } ; [work] => { TokenKind :: WorkKw } ; [write] => { TokenKind :: WriteKw } ; [xmlschema] => { TokenKind :: XmlschemaKw } ; [xmltype] => { TokenKind :: XmltypeKw } ; [year] => { TokenKind :: YearKw } ; [zone] => { TokenKind :: ZoneKw } ; [EOF] => { TokenKind :: Eof } ; }
+macro_rules ! T { [block_comment] => { TokenKind :: BlockComment } ; [hint] => { TokenKind :: Hint } ; [inline_comment] => { TokenKind :: InlineComment } ; [whitespace] => { TokenKind :: Whitespace } ; ["$$"] => { TokenKind :: DollarQuote } ; [:=] => { TokenKind :: Assign } ; [*] => { TokenKind :: Asterisk } ; [,] => { TokenKind :: Comma } ; [comparison] => { TokenKind :: Comparison } ; [.] => { TokenKind :: Dot } ; [..] => { TokenKind :: DoubleDot } ; [||] => { TokenKind :: DoublePipe } ; [=] => { TokenKind :: Equals } ; [=>] => { TokenKind :: FatArrow } ; [!] => { TokenKind :: Exclam } ; ["("] => { TokenKind :: LParen } ; [-] => { TokenKind :: Minus } ; [(+)] => { TokenKind :: OracleJoin } ; [%] => { TokenKind :: Percentage } ; [+] => { TokenKind :: Plus } ; [")"] => { TokenKind :: RParen } ; [;] => { TokenKind :: Semicolon } ; [/] => { TokenKind :: Slash } ; [int_literal] => { TokenKind :: Integer } ; [decimal_literal] => { TokenKind :: Decimal } ; [unquoted_ident] => { TokenKind :: UnquotedIdent } ; [quoted_ident] => { TokenKind :: QuotedIdent } ; [quoted_literal] => { TokenKind :: QuotedLiteral } ; [bind_var] => { TokenKind :: BindVar } ; [substitution_var] => { TokenKind :: SubstitutionVar } ; [loop_label] => { TokenKind :: LoopLabel } ; [iter_range] => { TokenKind :: IterRange } ; [accessible] => { TokenKind :: AccessibleKw } ; [add] => { TokenKind :: AddKw } ; [after] => { TokenKind :: AfterKw } ; [agent] => { TokenKind :: AgentKw } ; [aggregate] => { TokenKind :: AggregateKw } ; [all] => { TokenKind :: AllKw } ; [allow] => { TokenKind :: AllowKw } ; [alter] => { TokenKind :: AlterKw } ; [analytic] => { TokenKind :: AnalyticKw } ; [analyze] => { TokenKind :: AnalyzeKw } ; [and] => { TokenKind :: AndKw } ; [annotations] => { TokenKind :: AnnotationsKw } ; [any] => { TokenKind :: AnyKw } ; [anyschema] => { TokenKind :: AnyschemaKw } ; [apply] => { TokenKind :: ApplyKw } ; [array] => { TokenKind :: ArrayKw } ; [as] => { TokenKind :: AsKw } ; [asc] => { TokenKind :: AscKw } ; [associate] => { TokenKind :: AssociateKw } ; [audit] => { TokenKind :: AuditKw } ; [authid] => { TokenKind :: AuthidKw } ; [batch] => { TokenKind :: BatchKw } ; [before] => { TokenKind :: BeforeKw } ; [begin] => { TokenKind :: BeginKw } ; [bequeath] => { TokenKind :: BequeathKw } ; [between] => { TokenKind :: BetweenKw } ; [bfile] => { TokenKind :: BfileKw } ; [binary] => { TokenKind :: BinaryKw } ; [binary_double] => { TokenKind :: BinaryDoubleKw } ; [binary_float] => { TokenKind :: BinaryFloatKw } ; [binary_integer] => { TokenKind :: BinaryIntegerKw } ; [blob] => { TokenKind :: BlobKw } ; [body] => { TokenKind :: BodyKw } ; [breadth] => { TokenKind :: BreadthKw } ; [bulk] => { TokenKind :: BulkKw } ; [by] => { TokenKind :: ByKw } ; [byte] => { TokenKind :: ByteKw } ; [cache] => { TokenKind :: CacheKw } ; [call] => { TokenKind :: CallKw } ; [cascade] => { TokenKind :: CascadeKw } ; [case] => { TokenKind :: CaseKw } ; [c] => { TokenKind :: CKw } ; [char] => { TokenKind :: CharKw } ; [character] => { TokenKind :: CharacterKw } ; [charsetform] => { TokenKind :: CharsetformKw } ; [charsetid] => { TokenKind :: CharsetidKw } ; [check] => { TokenKind :: CheckKw } ; [clob] => { TokenKind :: ClobKw } ; [clone] => { TokenKind :: CloneKw } ; [cluster] => { TokenKind :: ClusterKw } ; [collation] => { TokenKind :: CollationKw } ; [collect] => { TokenKind :: CollectKw } ; [comment] => { TokenKind :: CommentKw } ; [commit] => { TokenKind :: CommitKw } ; [connect] => { TokenKind :: ConnectKw } ; [connect_by_root] => { TokenKind :: ConnectByRootKw } ; [constant] => { TokenKind :: ConstantKw } ; [constraint] => { TokenKind :: ConstraintKw } ; [constructor] => { TokenKind :: ConstructorKw } ; [container] => { TokenKind :: ContainerKw } ; [container_map] => { TokenKind :: ContainerMapKw } ; [containers_default] => { TokenKind :: ContainersDefaultKw } ; [continue] => { TokenKind :: ContinueKw } ; [context] => { TokenKind :: ContextKw } ; [create] => { TokenKind :: CreateKw } ; [cross] => { TokenKind :: CrossKw } ; [crossedition] => { TokenKind :: CrosseditionKw } ; [cube] => { TokenKind :: CubeKw } ; [current] => { TokenKind :: CurrentKw } ; [current_user] => { TokenKind :: CurrentUserKw } ; [cursor] => { TokenKind :: CursorKw } ; [cycle] => { TokenKind :: CycleKw } ; [data] => { TokenKind :: DataKw } ; [database] => { TokenKind :: DatabaseKw } ; [date] => { TokenKind :: DateKw } ; [day] => { TokenKind :: DayKw } ; [db_role_change] => { TokenKind :: DbRoleChangeKw } ; [ddl] => { TokenKind :: DdlKw } ; [dec] => { TokenKind :: DecKw } ; [decimal] => { TokenKind :: DecimalKw } ; [declare] => { TokenKind :: DeclareKw } ; [default] => { TokenKind :: DefaultKw } ; [deferrable] => { TokenKind :: DeferrableKw } ; [deferred] => { TokenKind :: DeferredKw } ; [definer] => { TokenKind :: DefinerKw } ; [delete] => { TokenKind :: DeleteKw } ; [depth] => { TokenKind :: DepthKw } ; [desc] => { TokenKind :: DescKw } ; [deterministic] => { TokenKind :: DeterministicKw } ; [disable] => { TokenKind :: DisableKw } ; [disallow] => { TokenKind :: DisallowKw } ; [disassociate] => { TokenKind :: DisassociateKw } ; [distinct] => { TokenKind :: DistinctKw } ; [double] => { TokenKind :: DoubleKw } ; [drop] => { TokenKind :: DropKw } ; [duration] => { TokenKind :: DurationKw } ; [each] => { TokenKind :: EachKw } ; [editionable] => { TokenKind :: EditionableKw } ; [editioning] => { TokenKind :: EditioningKw } ; [element] => { TokenKind :: ElementKw } ; [else] => { TokenKind :: ElseKw } ; [elsif] => { TokenKind :: ElsifKw } ; [enable] => { TokenKind :: EnableKw } ; [end] => { TokenKind :: EndKw } ; [env] => { TokenKind :: EnvKw } ; [exception] => { TokenKind :: ExceptionKw } ; [exceptions] => { TokenKind :: ExceptionsKw } ; [exclude] => { TokenKind :: ExcludeKw } ; [execute] => { TokenKind :: ExecuteKw } ; [exists] => { TokenKind :: ExistsKw } ; [exit] => { TokenKind :: ExitKw } ; [extend] => { TokenKind :: ExtendKw } ; [extended] => { TokenKind :: ExtendedKw } ; [external] => { TokenKind :: ExternalKw } ; [fact] => { TokenKind :: FactKw } ; [fetch] => { TokenKind :: FetchKw } ; [filter] => { TokenKind :: FilterKw } ; [final] => { TokenKind :: FinalKw } ; [first] => { TokenKind :: FirstKw } ; [float] => { TokenKind :: FloatKw } ; [follows] => { TokenKind :: FollowsKw } ; [for] => { TokenKind :: ForKw } ; [forall] => { TokenKind :: ForallKw } ; [force] => { TokenKind :: ForceKw } ; [foreign] => { TokenKind :: ForeignKw } ; [forward] => { TokenKind :: ForwardKw } ; [from] => { TokenKind :: FromKw } ; [full] => { TokenKind :: FullKw } ; [function] => { TokenKind :: FunctionKw } ; [global] => { TokenKind :: GlobalKw } ; [grant] => { TokenKind :: GrantKw } ; [hierarchies] => { TokenKind :: HierarchiesKw } ; [group] => { TokenKind :: GroupKw } ; [grouping] => { TokenKind :: GroupingKw } ; [hash] => { TokenKind :: HashKw } ; [having] => { TokenKind :: HavingKw } ; [id] => { TokenKind :: IdKw } ; [identifier] => { TokenKind :: IdentifierKw } ; [if] => { TokenKind :: IfKw } ; [ilike] => { TokenKind :: IlikeKw } ; [immediate] => { TokenKind :: ImmediateKw } ; [immutable] => { TokenKind :: ImmutableKw } ; [in] => { TokenKind :: InKw } ; [include] => { TokenKind :: IncludeKw } ; [increment] => { TokenKind :: IncrementKw } ; [index] => { TokenKind :: IndexKw } ; [indicator] => { TokenKind :: IndicatorKw } ; [indices] => { TokenKind :: IndicesKw } ; [initially] => { TokenKind :: InitiallyKw } ; [inner] => { TokenKind :: InnerKw } ; [insert] => { TokenKind :: InsertKw } ; [instantiable] => { TokenKind :: InstantiableKw } ; [instead] => { TokenKind :: InsteadKw } ; [int] => { TokenKind :: IntKw } ; [integer] => { TokenKind :: IntegerKw } ; [interval] => { TokenKind :: IntervalKw } ; [into] => { TokenKind :: IntoKw } ; [invisible] => { TokenKind :: InvisibleKw } ; [is] => { TokenKind :: IsKw } ; [java] => { TokenKind :: JavaKw } ; [keep] => { TokenKind :: KeepKw } ; [join] => { TokenKind :: JoinKw } ; [key] => { TokenKind :: KeyKw } ; [language] => { TokenKind :: LanguageKw } ; [large] => { TokenKind :: LargeKw } ; [last] => { TokenKind :: LastKw } ; [lateral] => { TokenKind :: LateralKw } ; [left] => { TokenKind :: LeftKw } ; [length] => { TokenKind :: LengthKw } ; [library] => { TokenKind :: LibraryKw } ; [like] => { TokenKind :: LikeKw } ; [limit] => { TokenKind :: LimitKw } ; [lobs] => { TokenKind :: LobsKw } ; [local] => { TokenKind :: LocalKw } ; [logoff] => { TokenKind :: LogoffKw } ; [logon] => { TokenKind :: LogonKw } ; [long] => { TokenKind :: LongKw } ; [loop] => { TokenKind :: LoopKw } ; [map] => { TokenKind :: MapKw } ; [materialized] => { TokenKind :: MaterializedKw } ; [maxlen] => { TokenKind :: MaxlenKw } ; [measures] => { TokenKind :: MeasuresKw } ; [maxvalue] => { TokenKind :: MaxvalueKw } ; [member] => { TokenKind :: MemberKw } ; [metadata] => { TokenKind :: MetadataKw } ; [minvalue] => { TokenKind :: MinvalueKw } ; [mle] => { TokenKind :: MleKw } ; [model] => { TokenKind :: ModelKw } ; [module] => { TokenKind :: ModuleKw } ; [month] => { TokenKind :: MonthKw } ; [mutable] => { TokenKind :: MutableKw } ; [name] => { TokenKind :: NameKw } ; [national] => { TokenKind :: NationalKw } ; [natural] => { TokenKind :: NaturalKw } ; [nchar] => { TokenKind :: NcharKw } ; [nclob] => { TokenKind :: NclobKw } ; [new] => { TokenKind :: NewKw } ; [no] => { TokenKind :: NoKw } ; [noaudit] => { TokenKind :: NoauditKw } ; [nocache] => { TokenKind :: NocacheKw } ; [nocopy] => { TokenKind :: NocopyKw } ; [nocycle] => { TokenKind :: NocycleKw } ; [noextend] => { TokenKind :: NoextendKw } ; [nokeep] => { TokenKind :: NokeepKw } ; [nomaxvalue] => { TokenKind :: NomaxvalueKw } ; [nominvalue] => { TokenKind :: NominvalueKw } ; [none] => { TokenKind :: NoneKw } ; [noneditionable] => { TokenKind :: NoneditionableKw } ; [nonschema] => { TokenKind :: NonschemaKw } ; [noorder] => { TokenKind :: NoorderKw } ; [noprecheck] => { TokenKind :: NoprecheckKw } ; [norely] => { TokenKind :: NorelyKw } ; [noscale] => { TokenKind :: NoscaleKw } ; [noshard] => { TokenKind :: NoshardKw } ; [not] => { TokenKind :: NotKw } ; [novalidate] => { TokenKind :: NovalidateKw } ; [nowait] => { TokenKind :: NowaitKw } ; [null] => { TokenKind :: NullKw } ; [nulls] => { TokenKind :: NullsKw } ; [number] => { TokenKind :: NumberKw } ; [numeric] => { TokenKind :: NumericKw } ; [nvarchar2] => { TokenKind :: Nvarchar2Kw } ; [object] => { TokenKind :: ObjectKw } ; [of] => { TokenKind :: OfKw } ; [oid] => { TokenKind :: OidKw } ; [old] => { TokenKind :: OldKw } ; [on] => { TokenKind :: OnKw } ; [only] => { TokenKind :: OnlyKw } ; [option] => { TokenKind :: OptionKw } ; [or] => { TokenKind :: OrKw } ; [order] => { TokenKind :: OrderKw } ; [others] => { TokenKind :: OthersKw } ; [out] => { TokenKind :: OutKw } ; [overriding] => { TokenKind :: OverridingKw } ; [outer] => { TokenKind :: OuterKw } ; [package] => { TokenKind :: PackageKw } ; [parallel_enable] => { TokenKind :: ParallelEnableKw } ; [parameters] => { TokenKind :: ParametersKw } ; [parent] => { TokenKind :: ParentKw } ; [pairs] => { TokenKind :: PairsKw } ; [partition] => { TokenKind :: PartitionKw } ; [persistable] => { TokenKind :: PersistableKw } ; [pipelined] => { TokenKind :: PipelinedKw } ; [pivot] => { TokenKind :: PivotKw } ; [plpgsql] => { TokenKind :: PlpgsqlKw } ; [pls_integer] => { TokenKind :: PlsIntegerKw } ; [pluggable] => { TokenKind :: PluggableKw } ; [pragma] => { TokenKind :: PragmaKw } ; [precedes] => { TokenKind :: PrecedesKw } ; [precheck] => { TokenKind :: PrecheckKw } ; [precision] => { TokenKind :: PrecisionKw } ; [prior] => { TokenKind :: PriorKw } ; [primary] => { TokenKind :: PrimaryKw } ; [procedure] => { TokenKind :: ProcedureKw } ; [range] => { TokenKind :: RangeKw } ; [raise] => { TokenKind :: RaiseKw } ; [raw] => { TokenKind :: RawKw } ; [read] => { TokenKind :: ReadKw } ; [real] => { TokenKind :: RealKw } ; [record] => { TokenKind :: RecordKw } ; [ref] => { TokenKind :: RefKw } ; [reference] => { TokenKind :: ReferenceKw } ; [references] => { TokenKind :: ReferencesKw } ; [referencing] => { TokenKind :: ReferencingKw } ; [relies_on] => { TokenKind :: ReliesOnKw } ; [rely] => { TokenKind :: RelyKw } ; [rename] => { TokenKind :: RenameKw } ; [repeat] => { TokenKind :: RepeatKw } ; [replace] => { TokenKind :: ReplaceKw } ; [result] => { TokenKind :: ResultKw } ; [result_cache] => { TokenKind :: ResultCacheKw } ; [restricted_references] => { TokenKind :: RestrictedReferencesKw } ; [return] => { TokenKind :: ReturnKw } ; [returning] => { TokenKind :: ReturningKw } ; [reverse] => { TokenKind :: ReverseKw } ; [revoke] => { TokenKind :: RevokeKw } ; [rnds] => { TokenKind :: RndsKw } ; [rnps] => { TokenKind :: RnpsKw } ; [rollback] => { TokenKind :: RollbackKw } ; [rollup] => { TokenKind :: RollupKw } ; [right] => { TokenKind :: RightKw } ; [row] => { TokenKind :: RowKw } ; [rowid] => { TokenKind :: RowidKw } ; [rowtype] => { TokenKind :: RowtypeKw } ; [save] => { TokenKind :: SaveKw } ; [savepoint] => { TokenKind :: SavepointKw } ; [scale] => { TokenKind :: ScaleKw } ; [schema] => { TokenKind :: SchemaKw } ; [scope] => { TokenKind :: ScopeKw } ; [search] => { TokenKind :: SearchKw } ; [second] => { TokenKind :: SecondKw } ; [select] => { TokenKind :: SelectKw } ; [self] => { TokenKind :: SelfKw } ; [sequence] => { TokenKind :: SequenceKw } ; [servererror] => { TokenKind :: ServererrorKw } ; [session] => { TokenKind :: SessionKw } ; [set] => { TokenKind :: SetKw } ; [sets] => { TokenKind :: SetsKw } ; [shard] => { TokenKind :: ShardKw } ; [sharing] => { TokenKind :: SharingKw } ; [shutdown] => { TokenKind :: ShutdownKw } ; [siblings] => { TokenKind :: SiblingsKw } ; [signature] => { TokenKind :: SignatureKw } ; [smallint] => { TokenKind :: SmallintKw } ; [sqlcode] => { TokenKind :: SqlcodeKw } ; [sqlerrm] => { TokenKind :: SqlerrmKw } ; [start] => { TokenKind :: StartKw } ; [starts] => { TokenKind :: StartsKw } ; [startup] => { TokenKind :: StartupKw } ; [static] => { TokenKind :: StaticKw } ; [statistics] => { TokenKind :: StatisticsKw } ; [store] => { TokenKind :: StoreKw } ; [string] => { TokenKind :: StringKw } ; [struct] => { TokenKind :: StructKw } ; [subtype] => { TokenKind :: SubtypeKw } ; [suspend] => { TokenKind :: SuspendKw } ; [symmetric] => { TokenKind :: SymmetricKw } ; [table] => { TokenKind :: TableKw } ; [tables] => { TokenKind :: TablesKw } ; [tdo] => { TokenKind :: TdoKw } ; [then] => { TokenKind :: ThenKw } ; [time] => { TokenKind :: TimeKw } ; [timestamp] => { TokenKind :: TimestampKw } ; [to] => { TokenKind :: ToKw } ; [trigger] => { TokenKind :: TriggerKw } ; [truncate] => { TokenKind :: TruncateKw } ; [trust] => { TokenKind :: TrustKw } ; [type] => { TokenKind :: TypeKw } ; [under] => { TokenKind :: UnderKw } ; [unique] => { TokenKind :: UniqueKw } ; [unpivot] => { TokenKind :: UnpivotKw } ; [unplug] => { TokenKind :: UnplugKw } ; [update] => { TokenKind :: UpdateKw } ; [urowid] => { TokenKind :: UrowidKw } ; [using] => { TokenKind :: UsingKw } ; [using_nls_comp] => { TokenKind :: UsingNlsCompKw } ; [validate] => { TokenKind :: ValidateKw } ; [value] => { TokenKind :: ValueKw } ; [values] => { TokenKind :: ValuesKw } ; [varchar] => { TokenKind :: VarcharKw } ; [varchar2] => { TokenKind :: Varchar2Kw } ; [varray] => { TokenKind :: VarrayKw } ; [varrays] => { TokenKind :: VarraysKw } ; [varying] => { TokenKind :: VaryingKw } ; [view] => { TokenKind :: ViewKw } ; [visible] => { TokenKind :: VisibleKw } ; [wait] => { TokenKind :: WaitKw } ; [when] => { TokenKind :: WhenKw } ; [where] => { TokenKind :: WhereKw } ; [while] => { TokenKind :: WhileKw } ; [with] => { TokenKind :: WithKw } ; [wnds] => { TokenKind :: WndsKw } ; [wnps] => { TokenKind :: WnpsKw } ; [work] => { TokenKind :: WorkKw } ; [write] => { TokenKind :: WriteKw } ; [xml] => { TokenKind :: XmlKw } ; [xmlschema] => { TokenKind :: XmlschemaKw } ; [xmltype] => { TokenKind :: XmltypeKw } ; [year] => { TokenKind :: YearKw } ; [zone] => { TokenKind :: ZoneKw } ; [EOF] => { TokenKind :: Eof } ; }
@@ -17,16 +17,48 @@ mod generated;
 /// Wrapper for the actual [`Logos`] parser.
 #[derive(Debug)]
 pub struct Lexer<'a> {
+    input: &'a str,
     inner: logos::Lexer<'a, TokenKind>,
+    /// Position of the input of `inner` within `input`, as lexing restarts
+    /// after an unterminated literal.
+    offset: usize,
+    single_line_literals: bool,
 }
 
 impl<'a> Lexer<'a> {
     /// Creates a new parsing from an input.
     pub fn new(input: &'a str) -> Self {
         Self {
+            input,
             inner: TokenKind::lexer(input),
+            offset: 0,
+            single_line_literals: false,
         }
     }
+
+    /// Whether a string literal must end on the line it starts. Oracle allows
+    /// literals spanning lines, but a missing `'` then pairs all following
+    /// quotes wrongly. If enabled, such a literal is returned as
+    /// [`TokenKind::Error`] up to the end of its first line instead.
+    pub fn single_line_literals(self, enabled: bool) -> Self {
+        Self {
+            single_line_literals: enabled,
+            ..self
+        }
+    }
+
+    /// Ends the current token, an unterminated literal, at the end of its
+    /// line and restarts lexing on the next one.
+    fn split_at_line_end(&mut self) -> ops::Range<usize> {
+        let start = self.offset + self.inner.span().start;
+        let end = self.input[start..]
+            .find(['\r', '\n'])
+            .map_or(self.input.len(), |len| start + len);
+
+        self.inner = TokenKind::lexer(&self.input[end..]);
+        self.offset = end;
+        start..end
+    }
 }
 
 impl<'a> Iterator for Lexer<'a> {
@@ -34,11 +66,26 @@ impl<'a> Iterator for Lexer<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let kind = self.inner.next()?;
-        let kind = kind.unwrap();
-        let text = self.inner.slice();
+        let is_unterminated = match kind {
+            Err(()) => self.inner.slice().starts_with('\''),
+            Ok(TokenKind::QuotedLiteral) => {
+                self.single_line_literals && self.inner.slice().contains(['\r', '\n'])
+            }
+            Ok(_) => false,
+        };
+        let (kind, span) = if is_unterminated {
+            (TokenKind::Error, self.split_at_line_end())
+        } else {
+            let ops::Range { start, end } = self.inner.span();
+            (
+                kind.unwrap_or(TokenKind::Error),
+                start + self.offset..end + self.offset,
+            )
+        };
+        let text = &self.input[span.clone()];
 
         let range = {
-            let ops::Range { start, end } = self.inner.span();
+            let ops::Range { start, end } = span;
             let start = TextSize::try_from(start).unwrap();
             let end = TextSize::try_from(end).unwrap();
 
@@ -57,6 +104,14 @@ pub struct Token<'a> {
     pub range: TextRange,
 }
 
+impl Token<'_> {
+    /// Returns whether this is a string literal missing its closing quote,
+    /// which is cut off at the end of its line.
+    pub fn is_unterminated_literal(&self) -> bool {
+        self.kind == TokenKind::Error && self.text.starts_with('\'')
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::lexer::Lexer;
@@ -76,6 +131,36 @@ mod tests {
         check(" \t \n", T![whitespace]);
     }
 
+    #[test]
+    fn lex_byte_order_mark() {
+        check("\u{feff}", T![whitespace]);
+    }
+
+    #[test]
+    fn lex_inline_comment_before_crlf() {
+        let tokens = Lexer::new("-- note\r\nx")
+            .map(|t| (t.kind, t.text))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            vec![
+                (T![inline_comment], "-- note"),
+                (T![whitespace], "\r\n"),
+                (T![unquoted_ident], "x"),
+            ]
+        );
+    }
+
+    #[test]
+    fn lex_block_comment() {
+        check("/* a ** comment */", T![block_comment]);
+    }
+
+    #[test]
+    fn lex_hint() {
+        check("/*+ materialize */", T![hint]);
+    }
+
     #[test]
     fn lex_ident() {
         check("hello1$#", T![unquoted_ident]);
@@ -86,6 +171,13 @@ mod tests {
         check(r#""读文👩🏼‍🔬""#, T![quoted_ident]);
     }
 
+    #[test]
+    fn lex_substitution_var() {
+        check("&schema", T![substitution_var]);
+        check("&&run_id", T![substitution_var]);
+        check("&schema.", T![substitution_var]);
+    }
+
     #[test]
     fn test_integer() {
         check(r#"69"#, T![int_literal]);
@@ -105,4 +197,34 @@ mod tests {
     fn test_decimal_dot_last() {
         check(r#"420."#, T![decimal_literal]);
     }
+
+    #[test]
+    fn lex_unterminated_literal() {
+        let tokens = Lexer::new("x := 'abc;\ny := 1;")
+            .map(|t| (t.kind, t.text))
+            .collect::<Vec<_>>();
+        assert_eq!(tokens[4], (TokenKind::Error, "'abc;"));
+        assert_eq!(tokens[5], (T![whitespace], "\n"));
+        assert_eq!(tokens[6], (T![unquoted_ident], "y"));
+        assert_eq!(tokens.last().unwrap().0, T![;]);
+    }
+
+    #[test]
+    fn lex_multi_line_literal() {
+        const INPUT: &str = "x := 'abc;\ny := 'd';";
+        assert_eq!(
+            Lexer::new(INPUT).nth(4).unwrap().kind,
+            T![quoted_literal]
+        );
+
+        let tokens = Lexer::new(INPUT)
+            .single_line_literals(true)
+            .collect::<Vec<_>>();
+        assert_eq!(tokens[4].kind, TokenKind::Error);
+        assert_eq!(tokens[4].text, "'abc;");
+        assert_eq!(tokens[6].text, "y");
+        assert_eq!(u32::from(tokens[6].range.start()), 11);
+        assert_eq!(tokens[10].kind, T![quoted_literal]);
+        assert_eq!(tokens[10].text, "'d'");
+    }
 }
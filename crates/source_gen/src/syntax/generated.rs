@@ -50,20 +50,34 @@ pub enum SyntaxKind {
     BulkIntoClause,
     #[doc = "A node containing a calc meas clause"]
     CalcMeasClause,
+    #[doc = "A node containing an external call specification"]
+    CallSpec,
+    #[doc = "A node containing a PL/SQL CASE statement, choosing between statements instead of values"]
+    CaseBlock,
     #[doc = "A node containing a CASE statement"]
     CaseStmt,
+    #[doc = "A node containing an associative array, nested table or varray type definition"]
+    CollectionTypeDef,
     #[doc = "A colon token"]
     Colon,
+    #[doc = "A node containing the column aliases of a FROM list item, e.g. `x (a, b)`"]
+    ColumnAliasList,
     #[doc = "A single column expression, as part of an SELECT clause"]
     ColumnExpr,
     #[doc = "A single comma"]
     Comma,
     #[doc = "Inline comment starting with `--`"]
     InlineComment,
+    #[doc = "A node containing a subquery in a FROM clause, optionally LATERAL"]
+    InlineView,
+    #[doc = "A node that contains a full CLOSE statement"]
+    CloseStmt,
     #[doc = "A node containing a full commit statement"]
     CommitStmt,
-    #[doc = "Inline comment starting with `--`"]
+    #[doc = "Block comment enclosed in `/*` and `*/`"]
     Comment,
+    #[doc = "A node containing a full COMMENT ON statement"]
+    CommentStmt,
     #[doc = "A node containing a comparisson expression"]
     ComparissonExpression,
     #[doc = "Represents an arithmetic SQL comparison operator (=, <>, <, >, <=, >=) or other types of comparison operators of SQL (ilike, like)"]
@@ -86,6 +100,8 @@ pub enum SyntaxKind {
     CrossJoinClause,
     #[doc = "A node that contains a full cross outer apply clause"]
     CrossOuterApplyClause,
+    #[doc = "A node containing a cursor attribute, e.g. `SQL%ROWCOUNT`"]
+    CursorAttribute,
     #[doc = "A node containing a cursor parameter declaration"]
     CursorParameterDeclaration,
     #[doc = "A node containing cursor parameter declarations"]
@@ -116,18 +132,30 @@ pub enum SyntaxKind {
     Error,
     #[doc = "An exclamation mark `!`"]
     Exclam,
+    #[doc = "A single `WHEN .. THEN` handler inside an exception section"]
+    ExceptionHandler,
+    #[doc = "A node that contains the EXCEPTION section of a block"]
+    ExceptionSection,
     #[doc = "A node that contains a full EXECUTE IMMEDIATE statement"]
     ExecuteImmediateStmt,
     #[doc = "A node that contains a full EXIT statement"]
     ExitStmt,
     #[doc = "Holds a generic SQL logic/arithmetic expression"]
     Expression,
+    #[doc = "The association operator `=>` of named notation"]
+    FatArrow,
+    #[doc = "A node that contains a full FETCH statement"]
+    FetchStmt,
+    #[doc = "A node containing an attribute access on a call result, e.g. `v.method(x).attr`"]
+    FieldAccess,
     #[doc = "A node that contains a full filter clause"]
     FilterClause,
     #[doc = "A node that contains a full filter clauses"]
     FilterClauses,
     #[doc = "A node containing a FOR LOOP"]
     ForLoop,
+    #[doc = "A node containing a FORALL statement"]
+    ForallStmt,
     #[doc = "A node containing a func_decl_in_type"]
     FuncDeclInType,
     #[doc = "A node that marks a full CREATE [..] FUNCTION block"]
@@ -138,6 +166,8 @@ pub enum SyntaxKind {
     FunctionInvocation,
     #[doc = "A node containing hier_ids"]
     HierIds,
+    #[doc = "An optimizer hint, e.g. `/*+ materialize */`"]
+    Hint,
     #[doc = "A node containing a function_spec"]
     FunctionSpec,
     #[doc = "A node containing a group by clause"]
@@ -154,6 +184,8 @@ pub enum SyntaxKind {
     Ident,
     #[doc = "An identifier group, consisting of multiple idents"]
     IdentGroup,
+    #[doc = "A physical property without meaning in PostgreSQL, e.g. `PARALLEL 4` or `NOLOGGING`, skipped as a whole"]
+    IgnoredClause,
     #[doc = "A node containing an iteration control block"]
     IterationControl,
     #[doc = "A node that marks a full INSERT statement"]
@@ -180,8 +212,12 @@ pub enum SyntaxKind {
     Loop,
     #[doc = "Left Paren"]
     LParen,
+    #[doc = "A node that marks a full CREATE MATERIALIZED VIEW statement"]
+    MaterializedView,
     #[doc = "A node containing a map_order_func_declaration"]
     MapOrderFuncDeclaration,
+    #[doc = "A node containing a method call, e.g. `tab.EXISTS(i)` or `v.method(x).other(y)`"]
+    MethodInvocation,
     #[doc = "A minus `-`"]
     Minus,
     #[doc = "A node containing a full nested_table_type_spec"]
@@ -196,13 +232,15 @@ pub enum SyntaxKind {
     ObjectSubtypeDef,
     #[doc = "A node containing a full object_type_def"]
     ObjectTypeDef,
+    #[doc = "A node that contains a full OPEN statement"]
+    OpenStmt,
     #[doc = "Logical operator OR"]
     Or,
     #[doc = "A node containing a full order by clause"]
     OrderByClause,
     #[doc = "A node containing a full OUTER JOIN clause"]
     OuterJoinClause,
-    #[doc = "A node that marks a full CREATE PACKAGE BODY block"]
+    #[doc = "A node that marks a full CREATE PACKAGE or CREATE PACKAGE BODY block"]
     Package,
     #[doc = "A single Param node, consisting of name & type"]
     Param,
@@ -220,6 +258,8 @@ pub enum SyntaxKind {
     PlsqlBodyTypeSource,
     #[doc = "A plus `+`"]
     Plus,
+    #[doc = "A node containing a PRAGMA, e.g. PRAGMA INLINE(p, 'YES')"]
+    PragmaStmt,
     #[doc = "The PL/SQL unary prior operator"]
     Prior,
     #[doc = "A node containing a proc_decl_in_type"]
@@ -234,12 +274,22 @@ pub enum SyntaxKind {
     QuotedLiteral,
     #[doc = "Two dots"]
     Range,
+    #[doc = "A record providing a whole row in `INSERT .. VALUES` or `UPDATE .. SET ROW =`"]
+    RecordRow,
+    #[doc = "A node containing the `REFERENCES` clause of a foreign key constraint"]
+    ReferencesClause,
+    #[doc = "A node containing the REFRESH clause of a materialized view"]
+    RefreshClause,
     #[doc = "A node containing a result_cache clause"]
     ResultCacheClause,
     #[doc = "A node containing a return into clause"]
     ReturnIntoClause,
+    #[doc = "A node containing the `RETURNING .. INTO` clause of a DML statement"]
+    ReturningClause,
     #[doc = "A node that contains the whole RAISE statement for exceptions"]
     RaiseStmt,
+    #[doc = "A node containing a full rollback statement"]
+    RollbackStmt,
     #[doc = "A node containing a rollup_cube_clause"]
     RollupCubeClause,
     #[doc = "The root node element"]
@@ -248,6 +298,8 @@ pub enum SyntaxKind {
     RowtypeClause,
     #[doc = "Right Paren"]
     RParen,
+    #[doc = "A node containing a SAVEPOINT statement"]
+    SavepointStmt,
     #[doc = "A node containing a search clause"]
     SearchClause,
     #[doc = "A node containing a searched case expression"]
@@ -282,8 +334,16 @@ pub enum SyntaxKind {
     StreamingClause,
     #[doc = "A node containing a subprog_decl_in_type"]
     SubprogDeclInType,
+    #[doc = "A SQL*Plus substitution variable including the `.` ending it, e.g. `&schema.` or `&&run_id`"]
+    SubstitutionVar,
+    #[doc = "A node that marks a full CREATE TABLE .. AS SELECT statement"]
+    Table,
+    #[doc = "A node containing a `TABLE(..)` collection expression in a FROM clause"]
+    TableCollectionExpr,
     #[doc = "A text slice node"]
     Text,
+    #[doc = "Tokens following a complete object, e.g. a SQL*Plus `/`"]
+    TrailingContent,
     #[doc = "A node that marks a full CREATE [..] TRIGGER block"]
     Trigger,
     #[doc = "A node that marks a TRIGGER header"]
@@ -292,12 +352,18 @@ pub enum SyntaxKind {
     TypeAttribute,
     #[doc = "A type name"]
     TypeName,
+    #[doc = "A literal prefixed with its type, e.g. `DATE '2020-01-01'`"]
+    TypedLiteral,
     #[doc = "A node containing a UDT-Definitions"]
     UdtDefinitionStmt,
+    #[doc = "A recognized clause without PostgreSQL counterpart, e.g. `PIVOT`, skipped as a whole"]
+    UnsupportedClause,
     #[doc = "A node that marks a full UPDATE statement"]
     UpdateStmt,
     #[doc = "A node containing a using clause"]
     UsingClause,
+    #[doc = "A node containing the `USING INDEX` clause of a constraint"]
+    UsingIndexClause,
     #[doc = "A node containing a values clause"]
     ValuesClause,
     #[doc = "A node containing a full varray_type_spec"]
@@ -325,6 +391,8 @@ impl From<SyntaxKind> for rowan::SyntaxKind {
 impl From<TokenKind> for SyntaxKind {
     fn from(kind: TokenKind) -> Self {
         match kind {
+            TokenKind::BlockComment => SyntaxKind::Comment,
+            TokenKind::Hint => SyntaxKind::Hint,
             TokenKind::InlineComment => SyntaxKind::InlineComment,
             TokenKind::Whitespace => SyntaxKind::Whitespace,
             TokenKind::DollarQuote => SyntaxKind::DollarQuote,
@@ -336,6 +404,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::DoubleDot => SyntaxKind::Range,
             TokenKind::DoublePipe => SyntaxKind::Concat,
             TokenKind::Equals => SyntaxKind::ComparisonOp,
+            TokenKind::FatArrow => SyntaxKind::FatArrow,
             TokenKind::Exclam => SyntaxKind::Exclam,
             TokenKind::LParen => SyntaxKind::LParen,
             TokenKind::Minus => SyntaxKind::ArithmeticOp,
@@ -351,6 +420,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::QuotedIdent => SyntaxKind::Ident,
             TokenKind::QuotedLiteral => SyntaxKind::QuotedLiteral,
             TokenKind::BindVar => SyntaxKind::BindVar,
+            TokenKind::SubstitutionVar => SyntaxKind::SubstitutionVar,
             TokenKind::LoopLabel => SyntaxKind::Ident,
             TokenKind::IterRange => SyntaxKind::IterRange,
             TokenKind::AccessibleKw => SyntaxKind::Keyword,
@@ -421,6 +491,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::CrossKw => SyntaxKind::Keyword,
             TokenKind::CrosseditionKw => SyntaxKind::Keyword,
             TokenKind::CubeKw => SyntaxKind::Keyword,
+            TokenKind::CurrentKw => SyntaxKind::Keyword,
             TokenKind::CurrentUserKw => SyntaxKind::Keyword,
             TokenKind::CursorKw => SyntaxKind::Keyword,
             TokenKind::CycleKw => SyntaxKind::Keyword,
@@ -444,6 +515,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::DisableKw => SyntaxKind::Keyword,
             TokenKind::DisallowKw => SyntaxKind::Keyword,
             TokenKind::DisassociateKw => SyntaxKind::Keyword,
+            TokenKind::DistinctKw => SyntaxKind::Keyword,
             TokenKind::DoubleKw => SyntaxKind::Keyword,
             TokenKind::DropKw => SyntaxKind::Keyword,
             TokenKind::DurationKw => SyntaxKind::Keyword,
@@ -458,6 +530,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::EnvKw => SyntaxKind::Keyword,
             TokenKind::ExceptionKw => SyntaxKind::Keyword,
             TokenKind::ExceptionsKw => SyntaxKind::Keyword,
+            TokenKind::ExcludeKw => SyntaxKind::Keyword,
             TokenKind::ExecuteKw => SyntaxKind::Keyword,
             TokenKind::ExistsKw => SyntaxKind::Keyword,
             TokenKind::ExitKw => SyntaxKind::Keyword,
@@ -465,12 +538,14 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::ExtendedKw => SyntaxKind::Keyword,
             TokenKind::ExternalKw => SyntaxKind::Keyword,
             TokenKind::FactKw => SyntaxKind::Keyword,
+            TokenKind::FetchKw => SyntaxKind::Keyword,
             TokenKind::FilterKw => SyntaxKind::Keyword,
             TokenKind::FinalKw => SyntaxKind::Keyword,
             TokenKind::FirstKw => SyntaxKind::Keyword,
             TokenKind::FloatKw => SyntaxKind::Keyword,
             TokenKind::FollowsKw => SyntaxKind::Keyword,
             TokenKind::ForKw => SyntaxKind::Keyword,
+            TokenKind::ForallKw => SyntaxKind::Keyword,
             TokenKind::ForceKw => SyntaxKind::Keyword,
             TokenKind::ForeignKw => SyntaxKind::Keyword,
             TokenKind::ForwardKw => SyntaxKind::Keyword,
@@ -491,6 +566,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::ImmediateKw => SyntaxKind::Keyword,
             TokenKind::ImmutableKw => SyntaxKind::Keyword,
             TokenKind::InKw => SyntaxKind::Keyword,
+            TokenKind::IncludeKw => SyntaxKind::Keyword,
             TokenKind::IncrementKw => SyntaxKind::Keyword,
             TokenKind::IndexKw => SyntaxKind::Keyword,
             TokenKind::IndicatorKw => SyntaxKind::Keyword,
@@ -513,10 +589,12 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::LanguageKw => SyntaxKind::Keyword,
             TokenKind::LargeKw => SyntaxKind::Keyword,
             TokenKind::LastKw => SyntaxKind::Keyword,
+            TokenKind::LateralKw => SyntaxKind::Keyword,
             TokenKind::LeftKw => SyntaxKind::Keyword,
             TokenKind::LengthKw => SyntaxKind::Keyword,
             TokenKind::LibraryKw => SyntaxKind::Keyword,
             TokenKind::LikeKw => SyntaxKind::ComparisonOp,
+            TokenKind::LimitKw => SyntaxKind::Keyword,
             TokenKind::LobsKw => SyntaxKind::Keyword,
             TokenKind::LocalKw => SyntaxKind::Keyword,
             TokenKind::LogoffKw => SyntaxKind::Keyword,
@@ -524,6 +602,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::LongKw => SyntaxKind::Keyword,
             TokenKind::LoopKw => SyntaxKind::Keyword,
             TokenKind::MapKw => SyntaxKind::Keyword,
+            TokenKind::MaterializedKw => SyntaxKind::Keyword,
             TokenKind::MaxlenKw => SyntaxKind::Keyword,
             TokenKind::MeasuresKw => SyntaxKind::Keyword,
             TokenKind::MaxvalueKw => SyntaxKind::Keyword,
@@ -531,6 +610,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::MetadataKw => SyntaxKind::Keyword,
             TokenKind::MinvalueKw => SyntaxKind::Keyword,
             TokenKind::MleKw => SyntaxKind::Keyword,
+            TokenKind::ModelKw => SyntaxKind::Keyword,
             TokenKind::ModuleKw => SyntaxKind::Keyword,
             TokenKind::MonthKw => SyntaxKind::Keyword,
             TokenKind::MutableKw => SyntaxKind::Keyword,
@@ -586,6 +666,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::PartitionKw => SyntaxKind::Keyword,
             TokenKind::PersistableKw => SyntaxKind::Keyword,
             TokenKind::PipelinedKw => SyntaxKind::Keyword,
+            TokenKind::PivotKw => SyntaxKind::Keyword,
             TokenKind::PlpgsqlKw => SyntaxKind::Keyword,
             TokenKind::PlsIntegerKw => SyntaxKind::Keyword,
             TokenKind::PluggableKw => SyntaxKind::Keyword,
@@ -620,11 +701,14 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::RevokeKw => SyntaxKind::Keyword,
             TokenKind::RndsKw => SyntaxKind::Keyword,
             TokenKind::RnpsKw => SyntaxKind::Keyword,
+            TokenKind::RollbackKw => SyntaxKind::Keyword,
             TokenKind::RollupKw => SyntaxKind::Keyword,
             TokenKind::RightKw => SyntaxKind::Keyword,
             TokenKind::RowKw => SyntaxKind::Keyword,
             TokenKind::RowidKw => SyntaxKind::Keyword,
             TokenKind::RowtypeKw => SyntaxKind::Keyword,
+            TokenKind::SaveKw => SyntaxKind::Keyword,
+            TokenKind::SavepointKw => SyntaxKind::Keyword,
             TokenKind::ScaleKw => SyntaxKind::Keyword,
             TokenKind::SchemaKw => SyntaxKind::Keyword,
             TokenKind::ScopeKw => SyntaxKind::Keyword,
@@ -643,6 +727,8 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::SiblingsKw => SyntaxKind::Keyword,
             TokenKind::SignatureKw => SyntaxKind::Keyword,
             TokenKind::SmallintKw => SyntaxKind::Keyword,
+            TokenKind::SqlcodeKw => SyntaxKind::Keyword,
+            TokenKind::SqlerrmKw => SyntaxKind::Keyword,
             TokenKind::StartKw => SyntaxKind::Keyword,
             TokenKind::StartsKw => SyntaxKind::Keyword,
             TokenKind::StartupKw => SyntaxKind::Keyword,
@@ -653,6 +739,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::StructKw => SyntaxKind::Keyword,
             TokenKind::SubtypeKw => SyntaxKind::Keyword,
             TokenKind::SuspendKw => SyntaxKind::Keyword,
+            TokenKind::SymmetricKw => SyntaxKind::Keyword,
             TokenKind::TableKw => SyntaxKind::Keyword,
             TokenKind::TablesKw => SyntaxKind::Keyword,
             TokenKind::TdoKw => SyntaxKind::Keyword,
@@ -666,6 +753,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::TypeKw => SyntaxKind::Keyword,
             TokenKind::UnderKw => SyntaxKind::Keyword,
             TokenKind::UniqueKw => SyntaxKind::Keyword,
+            TokenKind::UnpivotKw => SyntaxKind::Keyword,
             TokenKind::UnplugKw => SyntaxKind::Keyword,
             TokenKind::UpdateKw => SyntaxKind::Keyword,
             TokenKind::UrowidKw => SyntaxKind::Keyword,
@@ -690,6 +778,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::WnpsKw => SyntaxKind::Keyword,
             TokenKind::WorkKw => SyntaxKind::Keyword,
             TokenKind::WriteKw => SyntaxKind::Keyword,
+            TokenKind::XmlKw => SyntaxKind::Keyword,
             TokenKind::XmlschemaKw => SyntaxKind::Keyword,
             TokenKind::XmltypeKw => SyntaxKind::Keyword,
             TokenKind::YearKw => SyntaxKind::Keyword,
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Locates where attributes of the PostgreSQL function, e.g. `IMMUTABLE`,
+//! belong in routines.
+
+use crate::ast::{AstNode, Root};
+use crate::util::KeywordCase;
+use source_gen::syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
+
+/// Returns the function or procedure of `root`, if it is one.
+pub(super) fn routine(root: &Root) -> Option<SyntaxNode> {
+    root.function()
+        .map(|f| f.syntax().clone())
+        .or_else(|| root.procedure().map(|p| p.syntax().clone()))
+}
+
+/// Returns the `IS` or `AS` keyword between the header and the body of
+/// `routine`. PostgreSQL takes the attributes of a function before the `AS`
+/// of its body, e.g. `RETURNS int IMMUTABLE AS $$`.
+pub(super) fn body_keyword(routine: &SyntaxNode) -> Option<SyntaxToken> {
+    routine
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find(|t| {
+            t.kind() == SyntaxKind::Keyword
+                && (t.text().eq_ignore_ascii_case("is") || t.text().eq_ignore_ascii_case("as"))
+        })
+}
+
+/// Returns the keyword `keyword` of the header of `routine`, e.g.
+/// `DETERMINISTIC`.
+pub(super) fn header_keyword(routine: &SyntaxNode, keyword: &str) -> Option<SyntaxToken> {
+    routine
+        .first_child()?
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find(|t| t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case(keyword))
+}
+
+/// Returns `case`, or the case of the `CREATE` keyword of `routine`.
+pub(super) fn attribute_case(routine: &SyntaxNode, case: Option<KeywordCase>) -> KeywordCase {
+    case.or_else(|| {
+        routine
+            .first_token()
+            .and_then(|t| KeywordCase::of(t.text()))
+    })
+    .unwrap_or_default()
+}
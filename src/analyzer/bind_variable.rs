@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects the bind and host variables an object uses.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::SyntaxKind;
+
+/// A bind or host variable, e.g. `:customer_id`, which callers embedding the
+/// SQL, e.g. in Pro*C or ODP.NET, have to provide.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboBindVariable {
+    /// The lowercased name without the colon.
+    pub name: String,
+    /// How often the variable is used.
+    pub count: usize,
+    pub locations: Vec<SourceLocation>,
+}
+
+/// Finds all distinct bind variables in order of their first use. The
+/// correlation names of triggers, e.g. `:NEW`, are not bind variables.
+pub(super) fn find_bind_variables(root: &Root, index: &LineIndex) -> Vec<DboBindVariable> {
+    let correlation_names = root
+        .trigger()
+        .and_then(|trigger| trigger.header())
+        .map(|header| header.correlation_names())
+        .unwrap_or_default();
+
+    let mut variables: Vec<DboBindVariable> = Vec::new();
+    for token in root
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|t| t.kind() == SyntaxKind::BindVar)
+    {
+        // Correlation names in the `WHEN` condition of a trigger are written
+        // without the colon
+        let Some(name) = token.text().strip_prefix(':').map(str::to_lowercase) else {
+            continue;
+        };
+        if correlation_names.contains(&name) {
+            continue;
+        }
+
+        let location = SourceLocation::new(token.text_range(), index);
+        match variables.iter_mut().find(|v| v.name == name) {
+            Some(variable) => {
+                variable.count += 1;
+                variable.locations.push(location);
+            }
+            None => variables.push(DboBindVariable {
+                name,
+                count: 1,
+                locations: vec![location],
+            }),
+        }
+    }
+    variables
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    #[test]
+    fn test_find_bind_variables() {
+        const INPUT: &str = r#"
+SELECT o.id, o.amount
+  FROM orders o
+ WHERE o.customer_id = :customer_id
+   AND o.created >= :since
+   AND o.status = NVL(:Customer_Id, o.status);
+"#;
+        let result = analyze(DboType::Query, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let variables = result.unwrap().bind_variables;
+        assert_eq!(
+            variables
+                .iter()
+                .map(|v| (
+                    v.name.as_str(),
+                    v.count,
+                    v.locations.iter().map(|l| l.start.line).collect::<Vec<_>>()
+                ))
+                .collect::<Vec<_>>(),
+            vec![("customer_id", 2, vec![3, 5]), ("since", 1, vec![4])]
+        );
+    }
+
+    #[test]
+    fn test_find_bind_variables_skips_correlation_names() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE TRIGGER audit_sal
+    BEFORE UPDATE ON emp
+    REFERENCING OLD AS prev
+    FOR EACH ROW
+    WHEN (NEW.sal > prev.sal)
+BEGIN
+    :new.changed_by := :app_user;
+    :new.previous := :prev.sal;
+END;
+"#;
+        let result = analyze(DboType::Trigger, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let variables = result.unwrap().bind_variables;
+        assert_eq!(
+            variables
+                .iter()
+                .map(|v| (v.name.as_str(), v.count))
+                .collect::<Vec<_>>(),
+            vec![("app_user", 1)]
+        );
+    }
+}
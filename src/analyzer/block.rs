@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements the analysis of anonymous blocks.
+
+use crate::analyzer::{AnalyzeError, DboAnalyzeContext, DboMetaData};
+use crate::ast::Root;
+
+/// Analyzes an anonymous block, e.g. of a migration script. Blocks have no
+/// metadata of their own, only the analyses common to all objects apply.
+pub(super) fn analyze_block(
+    root: Root,
+    _ctx: &DboAnalyzeContext,
+) -> Result<DboMetaData, AnalyzeError> {
+    root.block()
+        .ok_or_else(|| AnalyzeError::ParseError("failed to find block".to_owned()))?;
+
+    Ok(DboMetaData::default())
+}
@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Builds the call graph of functions and procedures and detects recursion.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::grants::{is_builtin_routine, is_same_routine, normalize_name};
+use crate::analyzer::DboMetaData;
+use crate::ast::{AstNode, Function, FunctionInvocation, Procedure, Root};
+
+/// A call from one function or procedure to another.
+#[derive(Tsify, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboCallEdge {
+    /// The calling routine, lowercased unless quoted.
+    pub caller: String,
+    /// The called routine, lowercased unless quoted.
+    pub callee: String,
+    /// The call is part of a cycle, i.e. the callee calls the caller again,
+    /// either directly or through one other routine.
+    pub is_recursive: bool,
+}
+
+/// Collects all calls from the analyzed object and its nested subprograms to
+/// non-builtin routines, sorted by caller and callee. Keywords parsed like
+/// invocations, e.g. `ANY (..)`, are not calls. Calls outside of a
+/// function or procedure, e.g. in trigger bodies, are ignored.
+pub(super) fn find_call_graph(root: &Root) -> Vec<DboCallEdge> {
+    let calls = root
+        .syntax()
+        .descendants()
+        .filter_map(FunctionInvocation::cast)
+        .filter_map(|invocation| {
+            let caller = invocation.syntax().ancestors().find_map(|node| {
+                Function::cast(node.clone())
+                    .and_then(|f| f.name())
+                    .or_else(|| Procedure::cast(node).and_then(|p| p.name()))
+            })?;
+            let callee = normalize_name(invocation.ident()?.name()?);
+
+            (!is_builtin_routine(&callee)).then(|| (normalize_name(caller), callee))
+        })
+        .collect::<BTreeSet<_>>();
+
+    calls
+        .iter()
+        .map(|(caller, callee)| DboCallEdge {
+            caller: caller.clone(),
+            callee: callee.clone(),
+            is_recursive: is_cycle(&calls, caller, callee),
+        })
+        .collect()
+}
+
+/// Marks calls between the passed objects which form a cycle, e.g. when two
+/// procedures of a batch call each other. Only calls of the top-level
+/// functions and procedures are considered, as nested subprograms of different
+/// objects may share their names.
+pub(super) fn mark_mutual_recursion(objects: &mut [&mut DboMetaData]) {
+    let calls = objects
+        .iter()
+        .flat_map(|meta_data| top_level_calls(meta_data))
+        .map(|edge| (edge.caller.clone(), edge.callee.clone()))
+        .collect::<BTreeSet<_>>();
+
+    for meta_data in objects.iter_mut() {
+        let Some(name) = object_name(meta_data) else {
+            continue;
+        };
+
+        for edge in meta_data.call_graph.iter_mut() {
+            if edge.caller == name && is_cycle(&calls, &edge.caller, &edge.callee) {
+                edge.is_recursive = true;
+            }
+        }
+        meta_data.is_recursive = meta_data.call_graph.iter().any(|e| e.is_recursive);
+    }
+}
+
+/// Returns whether `callee` calls `caller` again, either directly or through
+/// one other routine. Names only differing in their schema are the same.
+fn is_cycle(calls: &BTreeSet<(String, String)>, caller: &str, callee: &str) -> bool {
+    is_same_routine(caller, callee)
+        || calls
+            .iter()
+            .any(|(from, to)| is_same_routine(from, callee) && is_same_routine(to, caller))
+}
+
+fn object_name(meta_data: &DboMetaData) -> Option<String> {
+    meta_data
+        .function
+        .as_ref()
+        .map(|f| f.name.clone())
+        .or_else(|| meta_data.procedure.as_ref().map(|p| p.name.clone()))
+        .map(normalize_name)
+}
+
+fn top_level_calls(meta_data: &DboMetaData) -> impl Iterator<Item = &DboCallEdge> {
+    let name = object_name(meta_data);
+    meta_data
+        .call_graph
+        .iter()
+        .filter(move |edge| Some(&edge.caller) == name.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, analyze_batch, DboAnalyzeBatch, DboAnalyzeItem, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    fn edge(caller: &str, callee: &str, is_recursive: bool) -> DboCallEdge {
+        DboCallEdge {
+            caller: caller.to_owned(),
+            callee: callee.to_owned(),
+            is_recursive,
+        }
+    }
+
+    #[test]
+    fn test_find_direct_recursion() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE FUNCTION factorial(n NUMBER) RETURN NUMBER IS
+BEGIN
+    IF n <= 1 THEN
+        RETURN 1;
+    END IF;
+    log_step(n);
+    RETURN n * Factorial(ABS(n) - 1);
+END factorial;
+"#;
+        let result = analyze(DboType::Function, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let meta_data = result.unwrap();
+        assert!(meta_data.is_recursive);
+        assert_eq!(
+            meta_data.call_graph,
+            vec![
+                edge("factorial", "factorial", true),
+                edge("factorial", "log_step", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_qualified_recursion() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE FUNCTION "HR".factorial(n NUMBER) RETURN NUMBER IS
+BEGIN
+    IF n <= 1 OR n = ANY (2, 3) OR EXISTS (SELECT 1 FROM dual WHERE n < 0) THEN
+        RETURN n;
+    END IF;
+    dbms_output.put_line(n);
+    RETURN n * factorial(n - 1);
+END factorial;
+"#;
+        let result = analyze(DboType::Function, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let meta_data = result.unwrap();
+        assert!(meta_data.is_recursive);
+        assert_eq!(
+            meta_data.call_graph,
+            vec![edge("hr.factorial", "factorial", true)]
+        );
+    }
+
+    #[test]
+    fn test_find_nested_mutual_recursion() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE walk(p_id NUMBER) IS
+    PROCEDURE visit_odd(n NUMBER);
+    PROCEDURE visit_even(n NUMBER) IS
+    BEGIN
+        IF n > 0 THEN
+            visit_odd(n - 1);
+        END IF;
+    END visit_even;
+    PROCEDURE visit_odd(n NUMBER) IS
+    BEGIN
+        visit_even(n - 1);
+    END visit_odd;
+BEGIN
+    visit_even(p_id);
+END walk;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let meta_data = result.unwrap();
+        assert!(meta_data.is_recursive);
+        assert_eq!(
+            meta_data.call_graph,
+            vec![
+                edge("visit_even", "visit_odd", true),
+                edge("visit_odd", "visit_even", true),
+                edge("walk", "visit_even", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_mutual_recursion_in_batch() {
+        let batch = DboAnalyzeBatch(vec![
+            DboAnalyzeItem {
+                typ: DboType::Procedure,
+                sql: "CREATE PROCEDURE ping(n NUMBER) IS BEGIN pong(n - 1); END ping;".to_owned(),
+            },
+            DboAnalyzeItem {
+                typ: DboType::Procedure,
+                sql: "CREATE PROCEDURE pong(n NUMBER) IS BEGIN ping(n - 1); notify(n); END pong;"
+                    .to_owned(),
+            },
+            DboAnalyzeItem {
+                typ: DboType::Procedure,
+                sql: "CREATE PROCEDURE notify(n NUMBER) IS BEGIN NULL; END notify;".to_owned(),
+            },
+        ]);
+
+        let results = analyze_batch(&batch, &DboAnalyzeContext::default()).0;
+        let meta_data = results
+            .into_iter()
+            .map(|r| r.meta_data.unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(meta_data[0].is_recursive);
+        assert_eq!(meta_data[0].call_graph, vec![edge("ping", "pong", true)]);
+        assert!(meta_data[1].is_recursive);
+        assert_eq!(
+            meta_data[1].call_graph,
+            vec![edge("pong", "notify", false), edge("pong", "ping", true)]
+        );
+        assert!(!meta_data[2].is_recursive);
+        assert!(meta_data[2].call_graph.is_empty());
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Describes functions and procedures which are implemented externally.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, CallSpec};
+use source_gen::syntax::{SyntaxElement, SyntaxKind};
+
+/// The language of an external implementation.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboCallSpecLanguage {
+    C,
+    Java,
+    JavaScript,
+}
+
+/// An external implementation of a function or procedure, which has to be
+/// completely reimplemented.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboCallSpec {
+    pub language: DboCallSpecLanguage,
+    /// The C library, Java class or MLE module containing the implementation.
+    pub library: Option<String>,
+    /// The name of the C function or the Java method signature.
+    pub name: Option<String>,
+}
+
+pub(super) fn analyze_call_spec(call_spec: &CallSpec) -> DboCallSpec {
+    let mut language = DboCallSpecLanguage::C;
+    let mut library = None;
+    let mut name = None;
+    let mut previous_keyword = String::new();
+
+    for element in call_spec.syntax().children_with_tokens() {
+        match &element {
+            SyntaxElement::Token(token) if token.kind() == SyntaxKind::Keyword => {
+                previous_keyword = token.text().to_lowercase();
+                match previous_keyword.as_str() {
+                    "java" => language = DboCallSpecLanguage::Java,
+                    "mle" => language = DboCallSpecLanguage::JavaScript,
+                    _ => {}
+                }
+            }
+            SyntaxElement::Node(node) => {
+                let text = node.text().to_string();
+                match previous_keyword.as_str() {
+                    "library" | "module" => library = Some(text),
+                    "name" => name = Some(text.trim_matches('\'').to_string()),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Java names are given as `'<class>.<method> (<args>)'`
+    if language == DboCallSpecLanguage::Java {
+        library = name
+            .as_ref()
+            .and_then(|n| n.split('(').next())
+            .and_then(|n| n.trim().rsplit_once('.'))
+            .map(|(class, _)| class.to_string());
+    }
+
+    DboCallSpec {
+        language,
+        library,
+        name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_analyze_java_call_spec() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE raise_salary (empno NUMBER, pct NUMBER)
+AS LANGUAGE JAVA NAME 'com.acme.Adjuster.raiseSalary (int, float)';
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let procedure = result.unwrap().procedure.unwrap();
+        assert_eq!(procedure.lines_of_code, 0);
+        assert_eq!(
+            procedure.call_spec,
+            Some(DboCallSpec {
+                language: DboCallSpecLanguage::Java,
+                library: Some("com.acme.Adjuster".to_string()),
+                name: Some("com.acme.Adjuster.raiseSalary (int, float)".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_analyze_c_call_spec() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE FUNCTION checksum (data VARCHAR2) RETURN NUMBER
+AS LANGUAGE C NAME "crc32" LIBRARY utils_lib;
+"#;
+        let result = analyze(DboType::Function, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let function = result.unwrap().function.unwrap();
+        assert_eq!(function.lines_of_code, 0);
+        assert_eq!(
+            function.call_spec,
+            Some(DboCallSpec {
+                language: DboCallSpecLanguage::C,
+                library: Some("utils_lib".to_string()),
+                name: Some("\"crc32\"".to_string()),
+            })
+        );
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects `CASE` statements without an `ELSE` branch.
+
+use rowan::TextRange;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, CaseBlock, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::SyntaxKind;
+
+/// A `CASE` statement without `ELSE`, which raises `CASE_NOT_FOUND` if no
+/// branch matches. Oracle raises it as `ORA-06592`, i.e. `SQLCODE` -6592,
+/// PostgreSQL with `SQLSTATE` `20000`, so only handlers naming the exception
+/// keep working.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboCaseWithoutElse {
+    /// The branch ignoring unmatched values, e.g. `ELSE NULL;\n    `, as
+    /// inserted by the `case_else` rule of [`apply_rules_filtered()`].
+    ///
+    /// [`apply_rules_filtered()`]: crate::analyzer::apply_rules_filtered
+    pub else_branch: String,
+    /// Where to insert the `ELSE` branch, i.e. right before `END CASE`.
+    pub else_location: SourceLocation,
+    pub location: SourceLocation,
+}
+
+/// Finds all `CASE` statements without `ELSE`, in source order. `CASE`
+/// expressions are left alone, as they yield `NULL` instead.
+pub(super) fn find_cases_without_else(root: &Root, index: &LineIndex) -> Vec<DboCaseWithoutElse> {
+    root.syntax()
+        .descendants()
+        .filter_map(CaseBlock::cast)
+        .filter(|case| !case.has_else())
+        .filter_map(|case| {
+            let end = case.end_keyword()?;
+            // Indent the new branch like the `END CASE` it precedes
+            let indent = end
+                .prev_token()
+                .filter(|t| t.kind() == SyntaxKind::Whitespace)
+                .map_or_else(|| " ".to_string(), |t| t.text().to_string());
+
+            Some(DboCaseWithoutElse {
+                else_branch: format!("ELSE NULL;{indent}"),
+                else_location: SourceLocation::new(
+                    TextRange::empty(end.text_range().start()),
+                    index,
+                ),
+                location: SourceLocation::new(case.syntax().text_range(), index),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, apply_rules_filtered, DboType, RuleFilter};
+    use crate::DboAnalyzeContext;
+
+    #[test]
+    fn test_find_cases_without_else() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE grade_bonus(p_grade VARCHAR2) IS
+    l_bonus NUMBER;
+BEGIN
+    CASE p_grade
+        WHEN 'A' THEN l_bonus := 100;
+        WHEN 'B' THEN l_bonus := 50;
+    END CASE;
+    CASE WHEN l_bonus > 50 THEN notify(l_bonus); ELSE NULL; END CASE;
+    l_bonus := CASE p_grade WHEN 'C' THEN 10 END;
+    CASE WHEN l_bonus IS NULL THEN l_bonus := 0; END CASE;
+END grade_bonus;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let cases = result.unwrap().cases_without_else;
+        assert_eq!(
+            cases
+                .iter()
+                .map(|c| (c.location.start.line, c.else_location.start.line))
+                .collect::<Vec<_>>(),
+            vec![(4, 7), (10, 10)]
+        );
+        let filter = RuleFilter {
+            ids: vec!["case_else".to_string()],
+            ..Default::default()
+        };
+        let result = apply_rules_filtered(
+            DboType::Procedure,
+            INPUT,
+            &filter,
+            &DboAnalyzeContext::default(),
+        );
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result.unwrap().sql,
+            r#"
+CREATE OR REPLACE PROCEDURE grade_bonus(p_grade VARCHAR2) IS
+    l_bonus NUMBER;
+BEGIN
+    CASE p_grade
+        WHEN 'A' THEN l_bonus := 100;
+        WHEN 'B' THEN l_bonus := 50;
+    ELSE NULL;
+    END CASE;
+    CASE WHEN l_bonus > 50 THEN notify(l_bonus); ELSE NULL; END CASE;
+    l_bonus := CASE p_grade WHEN 'C' THEN 10 END;
+    CASE WHEN l_bonus IS NULL THEN l_bonus := 0; ELSE NULL; END CASE;
+END grade_bonus;
+"#
+        );
+    }
+}
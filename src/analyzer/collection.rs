@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Summarizes the usage of collection types, which have no direct PostgreSQL
+//! counterpart and are usually rewritten to arrays.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, MethodInvocation, Root};
+use source_gen::syntax::SyntaxKind;
+
+/// Collection types declared and collection methods called by an object.
+#[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboCollectionUsage {
+    /// Number of `TABLE OF .. INDEX BY` types.
+    pub associative_arrays: usize,
+    /// Number of `TABLE OF` types without an index.
+    pub nested_tables: usize,
+    /// Number of `VARRAY` types.
+    pub varrays: usize,
+    /// The called methods, lowercased and sorted, e.g. `["count", "extend"]`.
+    pub methods: Vec<String>,
+}
+
+/// Collects the collection usage of the analyzed object, including its nested
+/// subprograms. Returns [`None`] if no collections are used at all.
+pub(super) fn find_collection_usage(root: &Root) -> Option<DboCollectionUsage> {
+    let mut usage = DboCollectionUsage::default();
+
+    for node in root.syntax().descendants() {
+        if node.kind() == SyntaxKind::CollectionTypeDef {
+            let has_keyword = |keywords: &[&str]| {
+                node.children_with_tokens()
+                    .filter_map(|it| it.into_token())
+                    .filter(|t| t.kind() == SyntaxKind::Keyword)
+                    .any(|t| keywords.contains(&t.text().to_lowercase().as_str()))
+            };
+
+            if has_keyword(&["array", "varray"]) {
+                usage.varrays += 1;
+            } else if has_keyword(&["index"]) {
+                usage.associative_arrays += 1;
+            } else {
+                usage.nested_tables += 1;
+            }
+        }
+    }
+
+    usage.methods = root
+        .syntax()
+        .descendants()
+        .filter_map(MethodInvocation::cast)
+        .filter(|m| m.is_collection_method())
+        .filter_map(|m| m.method())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    (usage != DboCollectionUsage::default()).then_some(usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_find_collection_usage() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE collect_names IS
+    TYPE name_map IS TABLE OF VARCHAR2(100) INDEX BY PLS_INTEGER;
+    TYPE name_list IS TABLE OF VARCHAR2(100);
+    TYPE top_ten IS VARRAY(10) OF NUMBER;
+    names name_list := name_list();
+BEGIN
+    names.EXTEND(2);
+    IF names.EXISTS(1) AND names.COUNT > 0 THEN
+        names.extend;
+    END IF;
+END collect_names;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        assert_eq!(
+            result.unwrap().collection_usage,
+            Some(DboCollectionUsage {
+                associative_arrays: 1,
+                nested_tables: 1,
+                varrays: 1,
+                methods: vec!["count".into(), "exists".into(), "extend".into()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_no_collection_usage() {
+        const INPUT: &str = "CREATE PROCEDURE p IS BEGIN NULL; END p;";
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(result.unwrap().collection_usage, None);
+    }
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Measures the structural complexity of function and procedure bodies.
+
+use crate::ast::{AstNode, Block};
+use crate::util::{LineIndex, SourceLocation};
+use rowan::TextRange;
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
+
+/// Returns the maximum number of nested blocks, `IF` statements and loops,
+/// starting at `1` for the body itself. Nested subprograms are not taken into
+/// account, as they are lifted into standalone functions anyway.
+pub(super) fn max_nesting_depth(body: &Block) -> usize {
+    own_descendants(body)
+        .filter(is_nesting)
+        .map(|node| {
+            node.ancestors()
+                .take_while(|a| a != body.syntax())
+                .filter(is_nesting)
+                .count()
+                + 1
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the location of the longest statement in the body, excluding
+/// nested subprograms.
+pub(super) fn longest_statement(body: &Block, index: &LineIndex) -> Option<SourceLocation> {
+    own_descendants(body)
+        .filter(|node| node.kind() == SyntaxKind::BlockStatement)
+        .map(|node| trimmed_range(&node))
+        // Prefer the first statement on ties
+        .max_by_key(|range| (range.len(), std::cmp::Reverse(range.start())))
+        .map(|range| SourceLocation::new(range, index))
+}
+
+/// Returns the range of the node without leading and trailing whitespace and
+/// comments.
+pub(super) fn trimmed_range(node: &SyntaxNode) -> TextRange {
+    let mut tokens = node
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|t| {
+            !matches!(
+                t.kind(),
+                SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+            )
+        })
+        .map(|t| t.text_range());
+
+    match (tokens.next(), tokens.last()) {
+        (Some(first), Some(last)) => first.cover(last),
+        (Some(first), None) => first,
+        _ => node.text_range(),
+    }
+}
+
+/// Returns all descendants of `body` including itself, which are not part of
+/// a nested subprogram.
+fn own_descendants(body: &Block) -> impl Iterator<Item = SyntaxNode> + '_ {
+    body.syntax().descendants().filter(move |node| {
+        !node
+            .ancestors()
+            .take_while(|a| a != body.syntax())
+            .any(|a| matches!(a.kind(), SyntaxKind::Function | SyntaxKind::Procedure))
+    })
+}
+
+fn is_nesting(node: &SyntaxNode) -> bool {
+    match node.kind() {
+        SyntaxKind::Block | SyntaxKind::Loop => true,
+        // `IF` statements have no node of their own
+        SyntaxKind::BlockStatement => node
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|t| t.kind() == SyntaxKind::Keyword)
+            .map(|t| t.text().eq_ignore_ascii_case("if"))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    #[test]
+    fn test_measure_nesting_and_longest_statement() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE process_orders IS
+    PROCEDURE helper IS
+    BEGIN
+        BEGIN
+            BEGIN
+                BEGIN
+                    NULL;
+                END;
+            END;
+        END;
+    END helper;
+BEGIN
+    NULL;
+    FOR i IN 1..10 LOOP
+        IF i > 5 THEN
+            helper();
+        END IF;
+    END LOOP;
+END process_orders;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let procedure = result.unwrap().procedure.unwrap();
+        assert_eq!(procedure.max_nesting_depth, 3);
+
+        let location = procedure.longest_statement.unwrap();
+        assert!(INPUT[location.offset_range()].starts_with("FOR i IN 1..10 LOOP"));
+        assert!(INPUT[location.offset_range()].ends_with("END LOOP;"));
+        assert_eq!(location.start.line, 14);
+        assert_eq!(location.end.line, 18);
+    }
+}
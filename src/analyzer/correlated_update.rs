@@ -0,0 +1,350 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects `UPDATE` statements setting columns from correlated subqueries.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{
+    AssignmentExpr, AstNode, Expression, FunctionInvocation, IdentGroup, Root, SelectStmt,
+    UpdateStmt,
+};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
+
+const UNMATCHED_ROWS_HINT: &str = "Rows without a match keep their values in the `UPDATE .. FROM` \
+     form, whereas Oracle sets them to NULL. Filter the updated rows by `EXISTS` on the \
+     subquery to rewrite it";
+
+/// Aggregate functions, which prevent a rewrite as they collapse all
+/// matching rows into one.
+const AGGREGATES: &[&str] = &["avg", "count", "listagg", "max", "min", "sum"];
+
+/// An `UPDATE` setting columns from a subquery correlated with the updated
+/// table, e.g. `SET sal = (SELECT .. FROM dept d WHERE d.id = e.dept_id)`.
+/// PostgreSQL supports these as well, but they are usually rewritten into the
+/// faster `UPDATE .. FROM` form.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboCorrelatedUpdate {
+    /// The updated table.
+    pub table: String,
+    /// The columns set from correlated subqueries, in source order.
+    pub columns: Vec<String>,
+    /// The tables queried by the correlated subqueries, in source order.
+    pub source_tables: Vec<String>,
+    /// The statement in `UPDATE .. FROM` form, if a single subquery reads a
+    /// single table without aggregates and is correlated by equality only.
+    /// Rows without a match keep their values, whereas Oracle sets them to
+    /// `NULL`, so this is only given if the `WHERE` clause ensures a match by
+    /// `EXISTS` on the same table.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub rewrite: Option<String>,
+    /// Why the `UPDATE .. FROM` form is not given, although the subquery
+    /// would allow it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub hint: Option<String>,
+    pub location: SourceLocation,
+}
+
+/// Finds all `UPDATE` statements with at least one subquery referencing the
+/// updated table, in source order.
+pub(super) fn find_correlated_updates(root: &Root, index: &LineIndex) -> Vec<DboCorrelatedUpdate> {
+    root.syntax()
+        .descendants()
+        .filter_map(UpdateStmt::cast)
+        .filter_map(|update| {
+            let table = update.table()?;
+            // Once aliased, the table can only be referenced by its alias
+            let qualifier = update.alias().or_else(|| update.table())?.last()?.text();
+
+            let assignments = update.set_clause()?.assignments();
+            let correlated = assignments
+                .iter()
+                .filter(|a| a.query().is_some_and(|q| references(&q, &qualifier)))
+                .collect::<Vec<_>>();
+            if correlated.is_empty() {
+                return None;
+            }
+
+            let mut source_tables = Vec::new();
+            for table in correlated
+                .iter()
+                .filter_map(|a| a.query())
+                .flat_map(|q| q.tables())
+            {
+                if let Some(name) = table.name() {
+                    if !source_tables.contains(&name) {
+                        source_tables.push(name);
+                    }
+                }
+            }
+
+            let rewrite = match correlated[..] {
+                [assignment] => rewrite(&update, &assignments, assignment, &qualifier),
+                _ => None,
+            };
+            let (rewrite, hint) = match rewrite {
+                Some(rewrite) if ensures_match(&update, &source_tables, &qualifier) => {
+                    (Some(rewrite), None)
+                }
+                Some(_) => (None, Some(UNMATCHED_ROWS_HINT.to_string())),
+                None => (None, None),
+            };
+
+            Some(DboCorrelatedUpdate {
+                table: table.name()?,
+                columns: correlated
+                    .iter()
+                    .flat_map(|a| a.columns())
+                    .filter_map(|c| c.name())
+                    .collect(),
+                source_tables,
+                rewrite,
+                hint,
+                location: SourceLocation::new(update.syntax().text_range(), index),
+            })
+        })
+        .collect()
+}
+
+/// Returns whether the `WHERE` clause of the update only selects rows with a
+/// match in the source table, i.e. has an `EXISTS` subquery on it which is
+/// correlated with the updated table.
+fn ensures_match(update: &UpdateStmt, source_tables: &[String], qualifier: &str) -> bool {
+    let Some(condition) = update.where_clause().and_then(|w| w.expression()) else {
+        return false;
+    };
+    let mut conjuncts = Vec::new();
+    if !split_conjuncts(condition.syntax(), &mut conjuncts) {
+        return false;
+    }
+
+    conjuncts.iter().any(|conjunct| {
+        let starts_with_exists = conjunct
+            .children_with_tokens()
+            .find(|it| it.kind() != SyntaxKind::Whitespace)
+            .and_then(|it| it.into_token())
+            .is_some_and(|t| t.text().eq_ignore_ascii_case("exists"));
+
+        starts_with_exists
+            && conjunct
+                .children()
+                .find_map(SelectStmt::cast)
+                .is_some_and(|query| {
+                    references(&query, qualifier)
+                        && query
+                            .tables()
+                            .iter()
+                            .filter_map(|t| t.name())
+                            .any(|name| source_tables.iter().any(|s| s.eq_ignore_ascii_case(&name)))
+                })
+    })
+}
+
+/// Returns whether the `WHERE` clause of `query` references `qualifier`.
+fn references(query: &SelectStmt, qualifier: &str) -> bool {
+    query
+        .where_clause()
+        .and_then(|w| w.expression())
+        .is_some_and(|expr| references_node(expr.syntax(), qualifier))
+}
+
+fn references_node(node: &SyntaxNode, qualifier: &str) -> bool {
+    node.descendants()
+        .filter_map(IdentGroup::cast)
+        .any(|ident| {
+            ident
+                .qualifier()
+                .is_some_and(|q| q.eq_ignore_ascii_case(qualifier))
+        })
+}
+
+/// Builds the `UPDATE .. FROM` form of the statement, replacing the
+/// `correlated` assignment by one assignment per selected column.
+fn rewrite(
+    update: &UpdateStmt,
+    assignments: &[AssignmentExpr],
+    correlated: &AssignmentExpr,
+    qualifier: &str,
+) -> Option<String> {
+    let query = correlated.query()?;
+    let [source] = &query.from_items()[..] else {
+        return None;
+    };
+    let source_table = source.table()?;
+
+    let selected = query
+        .select_clause()?
+        .columns()
+        .iter()
+        .map(|column| Some(column.syntax().first_child()?.text().to_string()))
+        .collect::<Option<Vec<_>>>()?;
+    let columns = correlated.columns();
+    let has_aggregate = query
+        .select_clause()?
+        .syntax()
+        .descendants()
+        .filter_map(FunctionInvocation::cast)
+        .filter_map(|f| f.ident()?.name())
+        .any(|name| AGGREGATES.contains(&name.to_lowercase().as_str()));
+    let has_group_by = query
+        .syntax()
+        .children()
+        .any(|node| node.kind() == SyntaxKind::GroupByClause);
+    if selected.len() != columns.len() || has_aggregate || has_group_by {
+        return None;
+    }
+
+    let condition = query.where_clause()?.expression()?;
+    let mut conjuncts = Vec::new();
+    if !split_conjuncts(condition.syntax(), &mut conjuncts)
+        || !conjuncts
+            .iter()
+            .filter(|c| references_node(c, qualifier))
+            .all(is_equality)
+    {
+        return None;
+    }
+
+    let set_list = assignments
+        .iter()
+        .map(|assignment| {
+            if assignment.syntax() != correlated.syntax() {
+                return assignment.syntax().text().to_string();
+            }
+            columns
+                .iter()
+                .zip(&selected)
+                .map(|(column, value)| format!("{} = {value}", column.syntax().text()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut conditions = vec![condition_text(&condition)];
+    if let Some(where_clause) = update.where_clause() {
+        // `WHERE CURRENT OF` has no expression and cannot be combined
+        conditions.push(condition_text(&where_clause.expression()?));
+    }
+
+    let mut rewrite = format!("UPDATE {}", update.table()?.syntax().text());
+    if let Some(alias) = update.alias() {
+        rewrite.push_str(&format!(" {}", alias.syntax().text()));
+    }
+    rewrite.push_str(&format!(
+        " SET {set_list} FROM {}",
+        source_table.syntax().text()
+    ));
+    if let Some(alias) = source.alias() {
+        rewrite.push_str(&format!(" {}", alias.syntax().text()));
+    }
+    rewrite.push_str(&format!(" WHERE {}", conditions.join(" AND ")));
+    if update.syntax().last_token().map(|t| t.kind()) == Some(SyntaxKind::Semicolon) {
+        rewrite.push(';');
+    }
+
+    Some(rewrite)
+}
+
+/// Collects the operands of all top-level `AND`s. Returns `false` if the
+/// condition contains an `OR`, thus cannot be split.
+fn split_conjuncts(node: &SyntaxNode, conjuncts: &mut Vec<SyntaxNode>) -> bool {
+    let operator = node
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find(|t| t.kind() == SyntaxKind::LogicOp)
+        .map(|t| t.text().to_lowercase());
+
+    match operator.as_deref() {
+        Some("and") => node
+            .children()
+            .all(|child| split_conjuncts(&child, conjuncts)),
+        Some(_) => false,
+        None => {
+            conjuncts.push(node.clone());
+            true
+        }
+    }
+}
+
+fn is_equality(node: &SyntaxNode) -> bool {
+    node.children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .any(|t| t.kind() == SyntaxKind::ComparisonOp && t.text() == "=")
+}
+
+/// Returns the condition as text, parenthesized if it contains an `OR`.
+fn condition_text(expr: &Expression) -> String {
+    let has_or = expr
+        .filter_tokens(|t| t.kind() == SyntaxKind::LogicOp)
+        .any(|t| t.text().eq_ignore_ascii_case("or"));
+
+    if has_or {
+        format!("({})", expr.syntax().text())
+    } else {
+        expr.syntax().text().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    #[test]
+    fn test_find_correlated_updates() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE sync_salaries IS
+BEGIN
+    UPDATE emp e
+       SET (sal, grade) = (SELECT s.sal, s.grade FROM hr.salaries s
+                            WHERE s.emp_id = e.id AND s.valid = 1),
+           updated = SYSDATE
+     WHERE e.dept_id = 10 OR e.dept_id = 20;
+    UPDATE emp
+       SET sal = (SELECT MAX(sal) FROM salaries s WHERE s.emp_id = emp.id);
+    UPDATE emp SET sal = (SELECT MAX(sal) FROM salaries);
+    UPDATE emp e
+       SET sal = (SELECT s.sal FROM salaries s WHERE s.emp_id = e.id)
+     WHERE EXISTS (SELECT 1 FROM salaries s WHERE s.emp_id = e.id);
+END sync_salaries;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let updates = result.unwrap().correlated_updates;
+        assert_eq!(updates.len(), 3);
+
+        // Rows of other departments without a salary would keep theirs
+        assert_eq!(updates[0].table, "emp");
+        assert_eq!(updates[0].columns, vec!["sal", "grade"]);
+        assert_eq!(updates[0].source_tables, vec!["hr.salaries"]);
+        assert_eq!(updates[0].rewrite, None);
+        assert!(updates[0].hint.is_some());
+        assert_eq!(updates[0].location.start.line, 3);
+
+        assert_eq!(updates[1].columns, vec!["sal"]);
+        assert_eq!(updates[1].source_tables, vec!["salaries"]);
+        assert_eq!(updates[1].rewrite, None);
+        assert_eq!(updates[1].hint, None);
+
+        assert_eq!(
+            updates[2].rewrite.as_deref(),
+            Some(
+                "UPDATE emp e SET sal = s.sal FROM salaries s WHERE s.emp_id = e.id \
+                 AND EXISTS (SELECT 1 FROM salaries s WHERE s.emp_id = e.id);"
+            )
+        );
+        assert_eq!(updates[2].hint, None);
+    }
+}
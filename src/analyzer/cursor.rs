@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects explicit cursor declarations and their result shapes.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, CursorStmt, Root};
+use crate::util::{LineIndex, SourceLocation};
+
+/// An explicit cursor. Cursors returned from or shared between subprograms
+/// are replaced by set-returning functions, whose result types are derived
+/// from the return type or the selected columns.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboCursor {
+    pub name: String,
+    /// The parameter declarations as written, e.g. `p_id NUMBER`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<String>,
+    /// The declared row type, e.g. `emp%ROWTYPE`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub return_type: Option<String>,
+    /// The selected column expressions, empty for `SELECT *` and cursors
+    /// without a query.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<String>,
+    pub location: SourceLocation,
+}
+
+/// Finds all cursor declarations, in source order. A cursor declared in a
+/// package specification and defined later is listed twice.
+pub(super) fn find_cursors(root: &Root, index: &LineIndex) -> Vec<DboCursor> {
+    root.syntax()
+        .descendants()
+        .filter_map(CursorStmt::cast)
+        .map(|cursor| DboCursor {
+            name: cursor.name().unwrap_or_else(|| "<unknown>".to_string()),
+            parameters: cursor.parameters(),
+            return_type: cursor
+                .return_type()
+                .map(|r| r.syntax().text().to_string().trim().to_string()),
+            columns: cursor
+                .query()
+                .and_then(|q| q.select_clause())
+                .map(|clause| {
+                    clause
+                        .columns()
+                        .iter()
+                        .map(|c| c.syntax().text().to_string().trim().to_string())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            location: SourceLocation::new(cursor.syntax().text_range(), index),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::{parse_procedure, DboAnalyzeContext};
+
+    #[test]
+    fn test_find_cursors() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE print_emps IS
+    CURSOR c_emps(p_dept NUMBER) RETURN emp%ROWTYPE IS
+        SELECT * FROM emp WHERE dept_id = p_dept;
+    CURSOR c_names IS SELECT id, first_name || ' ' || last_name full_name FROM emp;
+    r_emp emp%ROWTYPE;
+BEGIN
+    OPEN c_emps(10);
+    FETCH c_emps INTO r_emp;
+    IF c_emps%ISOPEN THEN
+        CLOSE c_emps;
+    END IF;
+END print_emps;
+"#;
+        let parse = parse_procedure(INPUT).unwrap();
+        assert!(parse.errors.is_empty(), "{:?}", parse.errors);
+
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let cursors = result.unwrap().cursors;
+        assert_eq!(cursors.len(), 2);
+        assert_eq!(cursors[0].name, "c_emps");
+        assert_eq!(cursors[0].parameters, vec!["p_dept NUMBER"]);
+        assert_eq!(cursors[0].return_type.as_deref(), Some("emp%ROWTYPE"));
+        assert!(cursors[0].columns.is_empty());
+        assert_eq!(cursors[0].location.start.line, 2);
+        assert_eq!(cursors[1].name, "c_names");
+        assert_eq!(cursors[1].return_type, None);
+        assert_eq!(
+            cursors[1].columns,
+            vec!["id", "first_name || ' ' || last_name full_name"]
+        );
+    }
+}
@@ -0,0 +1,466 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Maps Oracle datatypes to their PostgreSQL counterparts.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::references::declaring_scope;
+use crate::analyzer::{DboAnalyzeContext, DboColumnType};
+use crate::ast::{AstNode, AstToken, Datatype, IdentGroup, ParamList, Root};
+use crate::util::{LineIndex, SourceLocation};
+use crate::SqlIdent;
+use source_gen::syntax::{SyntaxElement, SyntaxKind};
+
+/// A single parameter of a function or procedure.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboParameterMetaData {
+    pub name: String,
+    /// The PostgreSQL type of the parameter, if it could be mapped.
+    pub mapped_type: Option<DboColumnType>,
+}
+
+/// How a mapped datatype differs from the Oracle one.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboPrecisionLoss {
+    /// `NUMBER(p, s)` too large for `bigint` becomes `numeric` without
+    /// precision and scale. Values are then neither rounded to `s` digits nor
+    /// checked against `p` digits anymore.
+    UnconstrainedNumeric,
+    /// `FLOAT(p)`, `DOUBLE PRECISION` or `REAL` are decimal in Oracle, with
+    /// up to 38 significant digits, but binary with at most 15 digits in
+    /// PostgreSQL.
+    BinaryFloat,
+}
+
+/// A parameter, variable or return type whose datatype is mapped to a
+/// PostgreSQL type of different precision or scale.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboPrecisionWarning {
+    /// The parameter, variable or record field, or `RETURN` for the return
+    /// type of a function.
+    pub name: String,
+    pub loss: DboPrecisionLoss,
+    /// The Oracle datatype as written, e.g. `NUMBER(38)`.
+    pub oracle_type: String,
+    pub mapped_type: DboColumnType,
+    pub location: SourceLocation,
+}
+
+/// A `%TYPE` reference qualified by something other than a table of the
+/// context or a variable, e.g. `pkg.g_rate%TYPE` or `hr.pkg.t_amount%TYPE`.
+/// These refer to package variables, whose types are not known to the
+/// analyzer, unlike a misspelled column of a known table.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboUnresolvedPackage {
+    /// The qualifier as written, e.g. `hr.pkg`.
+    pub package: String,
+    /// The referenced variable or type, e.g. `t_amount`.
+    pub name: String,
+    pub location: SourceLocation,
+}
+
+/// Maps an Oracle datatype to the matching PostgreSQL column type.
+///
+/// `%TYPE` references are resolved through the tables in `ctx`. Returns
+/// [`None`] for datatypes without a sensible mapping, e.g. `%ROWTYPE`,
+/// intervals or user-defined types.
+pub fn map_oracle_type_to_pg(
+    datatype: &Datatype,
+    ctx: &DboAnalyzeContext,
+) -> Option<DboColumnType> {
+    if let Some(reference) = datatype.referenced_type() {
+        return resolve_type_reference(&reference, ctx);
+    }
+
+    let keywords = datatype
+        .syntax()
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|it| it.kind() == SyntaxKind::Keyword)
+        .map(|it| it.text().to_lowercase())
+        .collect::<Vec<_>>();
+
+    let Some(first) = keywords.first() else {
+        // User-defined types and PL/SQL-only types are parsed as identifiers
+        let name = IdentGroup::cast(datatype.syntax().first_child()?)?.name()?;
+        return (name.to_lowercase() == "boolean").then_some(DboColumnType::Boolean);
+    };
+
+    let typ = match first.as_str() {
+        "number" | "numeric" | "dec" | "decimal" => map_numeric(datatype),
+        "int" | "integer" | "pls_integer" | "binary_integer" | "natural" => DboColumnType::Integer,
+        "smallint" => DboColumnType::SmallInt,
+        "binary_float" | "real" => DboColumnType::Real,
+        "binary_double" | "double" | "float" => DboColumnType::DoublePrecision,
+        "long" if keywords.iter().any(|k| k == "raw") => DboColumnType::Bytea,
+        "char" | "character" | "clob" | "long" | "national" | "nchar" | "nclob" | "nvarchar2"
+        | "rowid" | "string" | "urowid" | "varchar" | "varchar2" => DboColumnType::Text,
+        "binary" | "blob" | "raw" => DboColumnType::Bytea,
+        "date" => DboColumnType::Timestamp,
+        "timestamp" if keywords.iter().any(|k| k == "zone") => DboColumnType::TimestampWithTz,
+        "timestamp" => DboColumnType::Timestamp,
+        _ => return None,
+    };
+
+    Some(typ)
+}
+
+/// Maps `NUMBER(p[, s])` to the smallest fitting integer type, if there is
+/// no scale. `NUMBER(*, 0)` has the maximum precision of 38 digits.
+fn map_numeric(datatype: &Datatype) -> DboColumnType {
+    match (datatype.precision(), datatype.scale()) {
+        (Some(precision), None | Some(0)) if precision < 5 => DboColumnType::SmallInt,
+        (Some(precision), None | Some(0)) if precision < 10 => DboColumnType::Integer,
+        (Some(precision), None | Some(0)) if precision < 19 => DboColumnType::BigInt,
+        _ => DboColumnType::Numeric,
+    }
+}
+
+/// Returns how the mapping of `datatype` to `mapped` loses precision, if at
+/// all. Oracle gives the precision of floats in binary digits.
+fn precision_loss(datatype: &Datatype, mapped: DboColumnType) -> Option<DboPrecisionLoss> {
+    let binary_precision = match datatype.name()?.as_str() {
+        "float" => datatype.precision().unwrap_or(126),
+        "double precision" => 126,
+        "real" => 63,
+        _ => 0,
+    };
+
+    match mapped {
+        DboColumnType::Numeric if datatype.precision().is_some() || datatype.scale().is_some() => {
+            Some(DboPrecisionLoss::UnconstrainedNumeric)
+        }
+        DboColumnType::DoublePrecision if binary_precision > 53 => {
+            Some(DboPrecisionLoss::BinaryFloat)
+        }
+        DboColumnType::Real if binary_precision > 24 => Some(DboPrecisionLoss::BinaryFloat),
+        _ => None,
+    }
+}
+
+/// Finds all datatypes of parameters, variables, record fields and return
+/// types which lose precision or scale when mapped, in source order.
+pub(super) fn find_precision_warnings(
+    root: &Root,
+    ctx: &DboAnalyzeContext,
+    index: &LineIndex,
+) -> Vec<DboPrecisionWarning> {
+    root.syntax()
+        .descendants()
+        .filter_map(Datatype::cast)
+        .filter_map(|datatype| {
+            let mapped_type = map_oracle_type_to_pg(&datatype, ctx)?;
+            Some(DboPrecisionWarning {
+                name: declared_name(&datatype).unwrap_or_else(|| "<unknown>".to_string()),
+                loss: precision_loss(&datatype, mapped_type)?,
+                oracle_type: datatype.syntax().text().to_string(),
+                mapped_type,
+                location: SourceLocation::new(datatype.syntax().text_range(), index),
+            })
+        })
+        .collect()
+}
+
+/// Finds all `%TYPE` references to packages, in source order. Without any
+/// tables in the context, packages cannot be told apart from tables, so
+/// nothing is reported then.
+pub(super) fn find_unresolved_packages(
+    root: &Root,
+    ctx: &DboAnalyzeContext,
+    index: &LineIndex,
+) -> Vec<DboUnresolvedPackage> {
+    if ctx.tables.is_empty() {
+        return Vec::new();
+    }
+
+    root.syntax()
+        .descendants()
+        .filter_map(Datatype::cast)
+        .filter_map(|datatype| datatype.referenced_type())
+        .filter_map(|reference| {
+            let package = reference.qualifier()?;
+            let first = reference.nth(0)?;
+            let is_variable =
+                declaring_scope(first.syntax(), &SqlIdent::from(first.text())).is_some();
+            if is_variable || ctx.table(&SqlIdent::from(package.as_str())).is_some() {
+                return None;
+            }
+
+            Some(DboUnresolvedPackage {
+                package,
+                name: reference.last()?.text(),
+                location: SourceLocation::new(reference.syntax().text_range(), index),
+            })
+        })
+        .collect()
+}
+
+/// Returns the name declared with `datatype`, which precedes it along with
+/// modes like `IN OUT` or `CONSTANT`, or `RETURN` for return types.
+fn declared_name(datatype: &Datatype) -> Option<String> {
+    let mut sibling = datatype.syntax().prev_sibling_or_token();
+    while let Some(element) = sibling {
+        match &element {
+            SyntaxElement::Node(node) => return IdentGroup::cast(node.clone())?.name(),
+            SyntaxElement::Token(token) if token.kind() == SyntaxKind::Keyword => {
+                if token.text().eq_ignore_ascii_case("return") {
+                    return Some("RETURN".to_string());
+                }
+            }
+            SyntaxElement::Token(token)
+                if !matches!(
+                    token.kind(),
+                    SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+                ) =>
+            {
+                return None
+            }
+            SyntaxElement::Token(_) => {}
+        }
+        sibling = element.prev_sibling_or_token();
+    }
+    None
+}
+
+/// Resolves a `[schema.]table.column%TYPE` reference to the type of the
+/// referenced column. References to packages are reported by
+/// [`find_unresolved_packages()`] instead.
+fn resolve_type_reference(
+    reference: &IdentGroup,
+    ctx: &DboAnalyzeContext,
+) -> Option<DboColumnType> {
+    let table = SqlIdent::from(reference.last_nth(1)?.text());
+    let column = SqlIdent::from(reference.last()?.text());
+
+    ctx.table_column(&table, &column).map(|c| c.typ())
+}
+
+/// Collects all parameters of the passed parameter list, with their mapped
+/// types.
+pub(super) fn map_parameters(
+    params: Option<ParamList>,
+    ctx: &DboAnalyzeContext,
+) -> Vec<DboParameterMetaData> {
+    params
+        .map(|p| p.params())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|param| DboParameterMetaData {
+            name: param.name().unwrap_or_else(|| "<unknown>".to_string()),
+            mapped_type: param
+                .datatype()
+                .and_then(|d| map_oracle_type_to_pg(&d, ctx)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboTable, DboTableColumn, DboType};
+
+    use super::*;
+
+    fn map_type(datatype: &str, ctx: &DboAnalyzeContext) -> Option<DboColumnType> {
+        let input = format!("CREATE PROCEDURE p (x {datatype}) IS BEGIN NULL; END p;");
+        let parse = crate::parse_procedure(&input).unwrap();
+        assert!(parse.ok(), "{:?}", parse.errors);
+
+        let datatype = Root::cast(parse.syntax())
+            .and_then(|r| r.procedure())
+            .and_then(|p| p.header())
+            .and_then(|h| h.param_list())
+            .and_then(|l| l.params().into_iter().next())
+            .and_then(|p| p.datatype())
+            .unwrap();
+        map_oracle_type_to_pg(&datatype, ctx)
+    }
+
+    #[test]
+    fn test_map_oracle_type_to_pg() {
+        let ctx = DboAnalyzeContext::default();
+        let cases = [
+            ("NUMBER", Some(DboColumnType::Numeric)),
+            ("NUMBER(4)", Some(DboColumnType::SmallInt)),
+            ("NUMBER(9, 0)", Some(DboColumnType::Integer)),
+            ("NUMBER(18)", Some(DboColumnType::BigInt)),
+            ("NUMBER(10, 2)", Some(DboColumnType::Numeric)),
+            ("NUMBER(*, 0)", Some(DboColumnType::Numeric)),
+            ("PLS_INTEGER", Some(DboColumnType::Integer)),
+            ("BINARY_DOUBLE", Some(DboColumnType::DoublePrecision)),
+            ("VARCHAR2(100 CHAR)", Some(DboColumnType::Text)),
+            ("CLOB", Some(DboColumnType::Text)),
+            ("LONG RAW", Some(DboColumnType::Bytea)),
+            ("BLOB", Some(DboColumnType::Bytea)),
+            ("DATE", Some(DboColumnType::Timestamp)),
+            ("TIMESTAMP(6)", Some(DboColumnType::Timestamp)),
+            (
+                "TIMESTAMP WITH LOCAL TIME ZONE",
+                Some(DboColumnType::TimestampWithTz),
+            ),
+            ("BOOLEAN", Some(DboColumnType::Boolean)),
+            ("INTERVAL DAY TO SECOND", None),
+            ("my_record_type", None),
+            ("emp%ROWTYPE", None),
+        ];
+
+        for (datatype, expected) in cases {
+            assert_eq!(map_type(datatype, &ctx), expected, "{datatype}");
+        }
+    }
+
+    #[test]
+    fn test_map_type_reference() {
+        let ctx = DboAnalyzeContext::new(HashMap::from([(
+            "employees".into(),
+            DboTable::new(HashMap::from([(
+                "hire_date".into(),
+                DboTableColumn::new(DboColumnType::Date),
+            )])),
+        )]));
+
+        assert_eq!(
+            map_type("employees.hire_date%TYPE", &ctx),
+            Some(DboColumnType::Date)
+        );
+        assert_eq!(
+            map_type("hr.EMPLOYEES.HIRE_DATE%TYPE", &ctx),
+            Some(DboColumnType::Date)
+        );
+        assert_eq!(map_type("employees.salary%TYPE", &ctx), None);
+    }
+
+    #[test]
+    fn test_find_precision_warnings() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE FUNCTION convert_amount(
+    p_amount IN NUMBER,
+    p_rate FLOAT
+) RETURN FLOAT IS
+    l_total NUMBER(38);
+    l_price NUMBER(12, 2);
+    l_count NUMBER(9);
+    l_id NUMBER(*,0);
+    l_factor CONSTANT REAL := 1.5;
+    l_result BINARY_DOUBLE;
+BEGIN
+    RETURN p_amount * p_rate;
+END convert_amount;
+"#;
+        let result = analyze(DboType::Function, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let warnings = result.unwrap().precision_warnings;
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| (
+                    w.name.as_str(),
+                    w.loss,
+                    w.oracle_type.as_str(),
+                    w.mapped_type,
+                    w.location.start.line
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "p_rate",
+                    DboPrecisionLoss::BinaryFloat,
+                    "FLOAT",
+                    DboColumnType::DoublePrecision,
+                    3
+                ),
+                (
+                    "RETURN",
+                    DboPrecisionLoss::BinaryFloat,
+                    "FLOAT",
+                    DboColumnType::DoublePrecision,
+                    4
+                ),
+                (
+                    "l_total",
+                    DboPrecisionLoss::UnconstrainedNumeric,
+                    "NUMBER(38)",
+                    DboColumnType::Numeric,
+                    5
+                ),
+                (
+                    "l_price",
+                    DboPrecisionLoss::UnconstrainedNumeric,
+                    "NUMBER(12, 2)",
+                    DboColumnType::Numeric,
+                    6
+                ),
+                (
+                    "l_id",
+                    DboPrecisionLoss::UnconstrainedNumeric,
+                    "NUMBER(*,0)",
+                    DboColumnType::Numeric,
+                    8
+                ),
+                (
+                    "l_factor",
+                    DboPrecisionLoss::BinaryFloat,
+                    "REAL",
+                    DboColumnType::Real,
+                    9
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_unresolved_packages() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE pay(
+    p_date employees.hire_date%TYPE,
+    p_amount hr.pay_api.t_amount%TYPE
+) IS
+    l_rate pay_api.g_rate%TYPE;
+    l_salary employees.salary%TYPE;
+    r_emp employees%ROWTYPE;
+    l_name r_emp.name%TYPE;
+BEGIN
+    NULL;
+END pay;
+"#;
+        let ctx = DboAnalyzeContext::new(HashMap::from([(
+            "employees".into(),
+            DboTable::new(HashMap::from([(
+                "hire_date".into(),
+                DboTableColumn::new(DboColumnType::Date),
+            )])),
+        )]));
+
+        let result = analyze(DboType::Procedure, INPUT, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result
+                .unwrap()
+                .unresolved_packages
+                .iter()
+                .map(|p| (p.package.as_str(), p.name.as_str(), p.location.start.line))
+                .collect::<Vec<_>>(),
+            vec![("hr.pay_api", "t_amount", 3), ("pay_api", "g_rate", 5)]
+        );
+
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+        assert!(result.unwrap().unresolved_packages.is_empty());
+    }
+}
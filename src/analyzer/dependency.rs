@@ -0,0 +1,275 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Combines the call graphs and table references of many objects into one
+//! dependency graph, which can be rendered as Graphviz DOT.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::grants::{normalize_name, DboPrivilege};
+use crate::analyzer::DboMetaData;
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+use crate::analyzer::{analyze_batch, DboAnalyzeBatch, DboAnalyzeContext};
+
+/// What an object depends on.
+#[derive(Tsify, Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboDependencyKind {
+    /// A called function or procedure.
+    Routine,
+    /// A table or view which is read or modified.
+    Table,
+}
+
+/// A single outgoing edge of the [`DboDependencyGraph`].
+#[derive(Tsify, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboDependency {
+    /// The referenced routine or table, lowercased unless quoted.
+    pub target: String,
+    pub kind: DboDependencyKind,
+    /// The call is part of a cycle, see `DboCallEdge::is_recursive`.
+    pub is_recursive: bool,
+}
+
+/// The dependencies between many objects, e.g. of a whole schema.
+#[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboDependencyGraph {
+    /// Maps every object and nested subprogram to its dependencies, both
+    /// sorted by name. Tables only appear as targets.
+    #[tsify(type = "Record<string, DboDependency[]>")]
+    pub adjacency: BTreeMap<String, Vec<DboDependency>>,
+}
+
+impl DboDependencyGraph {
+    /// Renders the graph in the Graphviz DOT format. Tables are drawn as
+    /// boxes and recursive calls in red.
+    pub fn to_dot(&self) -> String {
+        let tables = self
+            .adjacency
+            .values()
+            .flatten()
+            .filter(|dep| dep.kind == DboDependencyKind::Table)
+            .map(|dep| dep.target.as_str())
+            .collect::<BTreeSet<_>>();
+
+        let mut dot = "digraph dependencies {\n".to_owned();
+        for table in tables {
+            let _ = writeln!(dot, "    {} [shape=box];", quote(table));
+        }
+        for (source, dependencies) in &self.adjacency {
+            if dependencies.is_empty() {
+                let _ = writeln!(dot, "    {};", quote(source));
+            }
+            for dep in dependencies {
+                let style = match (dep.kind, dep.is_recursive) {
+                    (DboDependencyKind::Table, _) => " [style=dashed]",
+                    (DboDependencyKind::Routine, true) => " [color=red]",
+                    (DboDependencyKind::Routine, false) => "",
+                };
+                let _ = writeln!(
+                    dot,
+                    "    {} -> {}{style};",
+                    quote(source),
+                    quote(&dep.target)
+                );
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Builds the dependency graph of the passed objects from their call graphs
+/// and required grants. Table references are attributed to the named object
+/// itself, as grants are not tracked per nested subprogram. Objects without a
+/// name, e.g. plain queries, are skipped.
+///
+/// Pass the results of [`analyze_batch()`] to detect recursion between the
+/// objects as well.
+///
+/// [`analyze_batch()`]: super::analyze_batch
+pub fn build_dependency_graph<'a>(
+    objects: impl IntoIterator<Item = &'a DboMetaData>,
+) -> DboDependencyGraph {
+    let mut edges = BTreeMap::<String, BTreeMap<String, DboDependency>>::new();
+    let mut add = |source: &str, target: &str, kind, is_recursive| {
+        edges
+            .entry(source.to_owned())
+            .or_default()
+            .entry(target.to_owned())
+            .and_modify(|dep: &mut DboDependency| dep.is_recursive |= is_recursive)
+            .or_insert_with(|| DboDependency {
+                target: target.to_owned(),
+                kind,
+                is_recursive,
+            });
+    };
+
+    for meta_data in objects {
+        let name = object_name(meta_data);
+        if let Some(name) = &name {
+            add(name, name, DboDependencyKind::Routine, false);
+        }
+
+        for edge in &meta_data.call_graph {
+            add(
+                &edge.caller,
+                &edge.callee,
+                DboDependencyKind::Routine,
+                edge.is_recursive,
+            );
+        }
+
+        let Some(name) = name else {
+            continue;
+        };
+        for grant in &meta_data.required_grants {
+            let kind = match grant.privilege {
+                DboPrivilege::Execute => DboDependencyKind::Routine,
+                _ => DboDependencyKind::Table,
+            };
+            add(&name, &grant.object, kind, false);
+        }
+    }
+
+    // The object itself was only added to register it as a node
+    let adjacency = edges
+        .into_iter()
+        .map(|(source, targets)| {
+            let dependencies = targets
+                .into_values()
+                .filter(|dep| dep.target != source || dep.is_recursive)
+                .collect();
+            (source, dependencies)
+        })
+        .collect();
+
+    DboDependencyGraph { adjacency }
+}
+
+/// WASM export, which analyzes a whole batch and builds the dependency graph
+/// of all successfully analyzed objects. Should _never_ be called from other
+/// Rust code.
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+#[wasm_bindgen(js_name = "dependencyGraph")]
+pub fn js_dependency_graph(batch: DboAnalyzeBatch, ctx: DboAnalyzeContext) -> DboDependencyGraph {
+    let results = analyze_batch(&batch, &ctx).0;
+    build_dependency_graph(results.iter().filter_map(|r| r.meta_data.as_ref()))
+}
+
+/// WASM export of [`DboDependencyGraph::to_dot()`]. Should _never_ be called
+/// from other Rust code.
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+#[wasm_bindgen(js_name = "dependencyGraphToDot")]
+pub fn js_dependency_graph_to_dot(graph: DboDependencyGraph) -> String {
+    graph.to_dot()
+}
+
+fn object_name(meta_data: &DboMetaData) -> Option<String> {
+    meta_data
+        .function
+        .as_ref()
+        .map(|f| f.name.clone())
+        .or_else(|| meta_data.procedure.as_ref().map(|p| p.name.clone()))
+        .or_else(|| meta_data.trigger.as_ref().map(|t| t.name.clone()))
+        .or_else(|| meta_data.view.as_ref().map(|v| v.name.clone()))
+        .or_else(|| meta_data.materialized_view.as_ref().map(|v| v.name.clone()))
+        .map(normalize_name)
+}
+
+/// Quotes a name as DOT identifier. Quoted SQL identifiers keep their quotes.
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze_batch, DboAnalyzeBatch, DboAnalyzeItem, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    fn dep(target: &str, kind: DboDependencyKind, is_recursive: bool) -> DboDependency {
+        DboDependency {
+            target: target.to_owned(),
+            kind,
+            is_recursive,
+        }
+    }
+
+    #[test]
+    fn test_build_dependency_graph() {
+        let batch = DboAnalyzeBatch(vec![
+            DboAnalyzeItem {
+                typ: DboType::Procedure,
+                sql: "CREATE PROCEDURE ping(n NUMBER) IS BEGIN pong(n - 1); END ping;".to_owned(),
+            },
+            DboAnalyzeItem {
+                typ: DboType::Procedure,
+                sql: r#"
+CREATE PROCEDURE pong(n NUMBER) IS
+BEGIN
+    ping(n - 1);
+    INSERT INTO "Audit" (n) VALUES (n);
+END pong;
+"#
+                .to_owned(),
+            },
+            DboAnalyzeItem {
+                typ: DboType::View,
+                sql: "CREATE VIEW active_orders AS SELECT * FROM orders WHERE active = 1;"
+                    .to_owned(),
+            },
+        ]);
+
+        let results = analyze_batch(&batch, &DboAnalyzeContext::default()).0;
+        let graph = build_dependency_graph(results.iter().filter_map(|r| r.meta_data.as_ref()));
+
+        assert_eq!(
+            graph.adjacency,
+            BTreeMap::from([
+                (
+                    "active_orders".to_owned(),
+                    vec![dep("orders", DboDependencyKind::Table, false)]
+                ),
+                (
+                    "ping".to_owned(),
+                    vec![dep("pong", DboDependencyKind::Routine, true)]
+                ),
+                (
+                    "pong".to_owned(),
+                    vec![
+                        dep("\"Audit\"", DboDependencyKind::Table, false),
+                        dep("ping", DboDependencyKind::Routine, true),
+                    ]
+                ),
+            ])
+        );
+
+        assert_eq!(
+            graph.to_dot(),
+            r#"digraph dependencies {
+    "\"Audit\"" [shape=box];
+    "orders" [shape=box];
+    "active_orders" -> "orders" [style=dashed];
+    "ping" -> "pong" [color=red];
+    "pong" -> "\"Audit\"" [style=dashed];
+    "pong" -> "ping" [color=red];
+}
+"#
+        );
+    }
+}
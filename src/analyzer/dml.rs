@@ -0,0 +1,346 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects DML statements that deserve an explicit review.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, DeleteStmt, IdentGroup, InsertStmt, Root, UpdateStmt};
+use crate::util::{LineIndex, SourceLocation};
+
+/// The kind of a DML statement.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboDmlKind {
+    Delete,
+    Insert,
+    Update,
+}
+
+/// An `UPDATE` or `DELETE` statement without a `WHERE` clause, thus
+/// affecting every row of its target table.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboUnfilteredDml {
+    pub kind: DboDmlKind,
+    pub table: String,
+    pub statement: String,
+    pub location: SourceLocation,
+}
+
+/// An `INSERT INTO .. VALUES <record>` or `UPDATE .. SET ROW = <record>`
+/// statement. PostgreSQL has no such shorthand, the record has to be
+/// expanded into its columns.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboRecordDml {
+    pub kind: DboDmlKind,
+    pub table: String,
+    /// The expression providing the record, e.g. a variable.
+    pub record: String,
+    pub location: SourceLocation,
+}
+
+/// A DML statement returning the values of all affected rows into
+/// collections with `RETURNING .. BULK COLLECT INTO`. PL/pgSQL only returns
+/// a single row, so the statement is wrapped in a CTE and its rows are
+/// aggregated with `array_agg()` instead.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboBulkReturning {
+    pub kind: DboDmlKind,
+    pub table: String,
+    /// The collections the values are returned into.
+    pub targets: Vec<String>,
+    pub location: SourceLocation,
+}
+
+fn table_name(table: Option<IdentGroup>) -> String {
+    table
+        .and_then(|t| t.name())
+        .unwrap_or_else(|| "<unknown>".to_string())
+}
+
+/// Finds all `UPDATE` and `DELETE` statements without a `WHERE` clause, in
+/// source order. `WHERE CURRENT OF` counts as a filter.
+pub(super) fn find_unfiltered_dml(root: &Root, index: &LineIndex) -> Vec<DboUnfilteredDml> {
+    root.syntax()
+        .descendants()
+        .filter_map(|node| {
+            if let Some(delete) = DeleteStmt::cast(node.clone()) {
+                delete.where_clause().is_none().then(|| DboUnfilteredDml {
+                    kind: DboDmlKind::Delete,
+                    table: table_name(delete.table()),
+                    statement: node.text().to_string(),
+                    location: SourceLocation::new(node.text_range(), index),
+                })
+            } else if let Some(update) = UpdateStmt::cast(node.clone()) {
+                update.where_clause().is_none().then(|| DboUnfilteredDml {
+                    kind: DboDmlKind::Update,
+                    table: table_name(update.table()),
+                    statement: node.text().to_string(),
+                    location: SourceLocation::new(node.text_range(), index),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Finds all `INSERT` and `UPDATE` statements taking a whole row from a
+/// record, in source order.
+pub(super) fn find_record_dml(root: &Root, index: &LineIndex) -> Vec<DboRecordDml> {
+    root.syntax()
+        .descendants()
+        .filter_map(|node| {
+            let (kind, table, row) = if let Some(insert) = InsertStmt::cast(node.clone()) {
+                (DboDmlKind::Insert, insert.table(), insert.record_row()?)
+            } else {
+                let update = UpdateStmt::cast(node.clone())?;
+                (DboDmlKind::Update, update.table(), update.record_row()?)
+            };
+
+            Some(DboRecordDml {
+                kind,
+                table: table_name(table),
+                record: row.record(),
+                location: SourceLocation::new(node.text_range(), index),
+            })
+        })
+        .collect()
+}
+
+/// Finds all DML statements with a `RETURNING .. BULK COLLECT INTO` clause,
+/// in source order.
+pub(super) fn find_bulk_returnings(root: &Root, index: &LineIndex) -> Vec<DboBulkReturning> {
+    root.syntax()
+        .descendants()
+        .filter_map(|node| {
+            let (kind, table, returning) = if let Some(insert) = InsertStmt::cast(node.clone()) {
+                (
+                    DboDmlKind::Insert,
+                    insert.table(),
+                    insert.returning_clause()?,
+                )
+            } else if let Some(update) = UpdateStmt::cast(node.clone()) {
+                (
+                    DboDmlKind::Update,
+                    update.table(),
+                    update.returning_clause()?,
+                )
+            } else {
+                let delete = DeleteStmt::cast(node.clone())?;
+                (
+                    DboDmlKind::Delete,
+                    delete.table(),
+                    delete.returning_clause()?,
+                )
+            };
+
+            returning.is_bulk().then(|| DboBulkReturning {
+                kind,
+                table: table_name(table),
+                targets: returning
+                    .targets()
+                    .iter()
+                    .filter_map(IdentGroup::name)
+                    .collect(),
+                location: SourceLocation::new(node.text_range(), index),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboAnalyzeOptions, DboType};
+    use crate::util::{LineCol, SourceExcerpt};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_find_unfiltered_dml() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE purge IS
+BEGIN
+    DELETE FROM audit_log;
+    DELETE FROM emp WHERE emp_id = 1;
+    UPDATE emp SET salary = 0;
+    UPDATE emp SET salary = 1 WHERE CURRENT OF emp_cur;
+END purge;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let location = |line: u32, statement: &str| {
+            let start = INPUT.find(statement).unwrap() as u32;
+            let len = statement.len() as u32;
+            SourceLocation {
+                start_offset: start,
+                end_offset: start + len,
+                start: LineCol {
+                    line,
+                    col: 4,
+                    col_utf16: 4,
+                },
+                end: LineCol {
+                    line,
+                    col: 4 + len,
+                    col_utf16: 4 + len,
+                },
+                excerpt: None,
+            }
+        };
+
+        assert_eq!(
+            result.unwrap().unfiltered_dml,
+            vec![
+                DboUnfilteredDml {
+                    kind: DboDmlKind::Delete,
+                    table: "audit_log".to_string(),
+                    statement: "DELETE FROM audit_log;".to_string(),
+                    location: location(3, "DELETE FROM audit_log;"),
+                },
+                DboUnfilteredDml {
+                    kind: DboDmlKind::Update,
+                    table: "emp".to_string(),
+                    statement: "UPDATE emp SET salary = 0;".to_string(),
+                    location: location(5, "UPDATE emp SET salary = 0;"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_unfiltered_dml_in_trigger() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE TRIGGER clear_cache
+    AFTER INSERT ON emp
+BEGIN
+    DELETE FROM emp_cache;
+END;
+"#;
+        let result = analyze(DboType::Trigger, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let unfiltered_dml = result.unwrap().unfiltered_dml;
+        assert_eq!(unfiltered_dml.len(), 1);
+        assert_eq!(unfiltered_dml[0].table, "emp_cache");
+    }
+
+    #[test]
+    fn test_find_unfiltered_dml_with_excerpt() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE purge IS
+BEGIN
+    DELETE FROM audit_log;
+END purge;
+"#;
+        let ctx = DboAnalyzeContext::default().with_options(DboAnalyzeOptions {
+            excerpt_lines: Some(1),
+            ..Default::default()
+        });
+        let result = analyze(DboType::Procedure, INPUT, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+
+        let unfiltered_dml = result.unwrap().unfiltered_dml;
+        assert_eq!(
+            unfiltered_dml[0].location.excerpt,
+            Some(SourceExcerpt {
+                start_line: 2,
+                text: "BEGIN\n    DELETE FROM audit_log;\nEND purge;".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_record_dml() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE copy_emp(p_emp emp%ROWTYPE) IS
+BEGIN
+    INSERT INTO emp_archive VALUES p_emp;
+    INSERT INTO emp_log (emp_id, action) VALUES (p_emp.emp_id, DEFAULT);
+    UPDATE emp SET ROW = p_emp WHERE emp_id = p_emp.emp_id;
+END copy_emp;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let record_dml = result
+            .unwrap()
+            .record_dml
+            .into_iter()
+            .map(|dml| (dml.kind, dml.table, dml.record, dml.location.start.line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            record_dml,
+            vec![
+                (
+                    DboDmlKind::Insert,
+                    "emp_archive".to_string(),
+                    "p_emp".to_string(),
+                    3
+                ),
+                (
+                    DboDmlKind::Update,
+                    "emp".to_string(),
+                    "p_emp".to_string(),
+                    5
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_bulk_returnings() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE purge_orders(p_status NUMBER) IS
+    l_ids id_list;
+    l_totals total_list;
+    l_id NUMBER;
+BEGIN
+    DELETE FROM orders WHERE status = p_status
+        RETURNING id, total BULK COLLECT INTO l_ids, l_totals;
+    UPDATE orders SET status = 0 WHERE id = 1 RETURNING id INTO l_id;
+    INSERT INTO order_log (id) VALUES (1) RETURNING id BULK COLLECT INTO l_ids;
+END purge_orders;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let returnings = result
+            .unwrap()
+            .bulk_returnings
+            .into_iter()
+            .map(|r| (r.kind, r.table, r.targets, r.location.start.line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            returnings,
+            vec![
+                (
+                    DboDmlKind::Delete,
+                    "orders".to_string(),
+                    vec!["l_ids".to_string(), "l_totals".to_string()],
+                    6
+                ),
+                (
+                    DboDmlKind::Insert,
+                    "order_log".to_string(),
+                    vec!["l_ids".to_string()],
+                    9
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Finds queries selecting from Oracle's `DUAL` table.
+
+use rowan::TextRange;
+
+use crate::analyzer::grants::normalize_name;
+use crate::ast::{AstNode, Root, SelectStmt};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::{SyntaxKind, SyntaxToken};
+
+/// Returns the edits removing the `FROM` clauses listing only `DUAL` or
+/// `SYS.DUAL`, which PostgreSQL does not need, in source order. Each removal
+/// covers the clause along with the whitespace before it, such that e.g.
+/// `SELECT 1 WHERE ..` is left instead of `SELECT 1  WHERE ..`.
+///
+/// Hierarchical queries, e.g. `SELECT level FROM dual CONNECT BY level <= 3`,
+/// generate rows from `DUAL` and are thus kept.
+pub(super) fn find_dual_from_clauses(
+    root: &Root,
+    index: &LineIndex,
+) -> Vec<(String, SourceLocation)> {
+    root.syntax()
+        .descendants()
+        .filter_map(SelectStmt::cast)
+        .filter(|select| {
+            !select
+                .syntax()
+                .children()
+                .any(|node| matches!(node.kind(), SyntaxKind::Connect | SyntaxKind::Starts))
+        })
+        .filter_map(|select| {
+            let [item] = select.from_items().try_into().ok()?;
+            let table = normalize_name(item.table()?.name()?);
+            if table != "dual" && table != "sys.dual" {
+                return None;
+            }
+
+            let from = select.syntax().children_with_tokens().find_map(|it| {
+                it.into_token().filter(|t| {
+                    t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("from")
+                })
+            })?;
+            let last = item
+                .alias()
+                .map_or(item.reference().clone(), |alias| alias.syntax().clone())
+                .last_token()?;
+            Some(removal(&from, &last, index))
+        })
+        .collect()
+}
+
+/// Returns the edit removing the clause from `from` up to `last`, including
+/// the whitespace before it. If that whitespace ends an inline comment, the
+/// terminating semicolon following the clause is moved before the comment
+/// instead of being left on a line of its own.
+fn removal(from: &SyntaxToken, last: &SyntaxToken, index: &LineIndex) -> (String, SourceLocation) {
+    let end = last.text_range().end();
+    let whitespace = from
+        .prev_token()
+        .filter(|t| t.kind() == SyntaxKind::Whitespace);
+    let comment = whitespace
+        .as_ref()
+        .and_then(|t| t.prev_token())
+        .filter(|t| t.kind() == SyntaxKind::InlineComment);
+    let semicolon = std::iter::successors(last.next_token(), |t| t.next_token())
+        .find(|t| t.kind() != SyntaxKind::Whitespace)
+        .filter(|t| t.kind() == SyntaxKind::Semicolon);
+
+    let after_comment = comment.is_some();
+
+    let (Some(comment), Some(semicolon)) = (comment, semicolon) else {
+        let start = match whitespace {
+            Some(whitespace) if !after_comment => whitespace.text_range().start(),
+            _ => from.text_range().start(),
+        };
+        return (
+            String::new(),
+            SourceLocation::new(TextRange::new(start, end), index),
+        );
+    };
+
+    // Keep the comment, along with any trivia between it and the code before
+    let mut trivia = vec![comment];
+    while let Some(prev) = trivia.last().and_then(|t| t.prev_token()).filter(is_trivia) {
+        trivia.push(prev);
+    }
+    let text = trivia.iter().rev().map(|t| t.text()).collect::<String>();
+    let start = trivia.last().map_or(end, |t| t.text_range().start());
+
+    (
+        format!(";{text}"),
+        SourceLocation::new(TextRange::new(start, semicolon.text_range().end()), index),
+    )
+}
+
+fn is_trivia(token: &SyntaxToken) -> bool {
+    matches!(
+        token.kind(),
+        SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{apply_rules_filtered, DboAnalyzeContext, DboType, RuleFilter};
+
+    #[test]
+    fn test_remove_dual_from_clauses() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE stamp(p_id NUMBER) IS
+    l_now DATE;
+    l_count NUMBER;
+BEGIN
+    SELECT SYSDATE
+      INTO l_now
+      FROM dual;
+    SELECT 1 INTO l_count FROM SYS.DUAL d WHERE EXISTS (SELECT 1 FROM orders, dual);
+    SELECT COUNT(*) INTO l_count -- from the dummy table
+    FROM "DUAL";
+    SELECT COUNT(*) INTO l_count FROM (SELECT level FROM dual CONNECT BY level <= 3);
+END stamp;
+"#;
+        let filter = RuleFilter {
+            ids: vec!["dual".to_string()],
+            ..Default::default()
+        };
+        let result = apply_rules_filtered(
+            DboType::Procedure,
+            INPUT,
+            &filter,
+            &DboAnalyzeContext::default(),
+        );
+        assert!(result.is_ok(), "{result:#?}");
+
+        let application = result.unwrap();
+        assert_eq!(application.applied.len(), 3);
+        assert_eq!(
+            application.sql,
+            r#"
+CREATE OR REPLACE PROCEDURE stamp(p_id NUMBER) IS
+    l_now DATE;
+    l_count NUMBER;
+BEGIN
+    SELECT SYSDATE
+      INTO l_now;
+    SELECT 1 INTO l_count WHERE EXISTS (SELECT 1 FROM orders, dual);
+    SELECT COUNT(*) INTO l_count; -- from the dummy table
+    SELECT COUNT(*) INTO l_count FROM (SELECT level FROM dual CONNECT BY level <= 3);
+END stamp;
+"#
+        );
+    }
+}
@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects exception handlers silently swallowing all errors, and uses of
+//! Oracle error codes.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::complexity::trimmed_range;
+use crate::ast::{AstNode, Function, Procedure, Root, Trigger};
+use crate::util::{KeywordCase, LineIndex, SourceLocation};
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
+
+/// A `WHEN OTHERS THEN NULL;` exception handler. Errors are raised in
+/// different places after the migration, so each of these needs a review.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboSwallowedException {
+    /// The enclosing function, procedure or trigger.
+    pub routine: String,
+    pub location: SourceLocation,
+}
+
+/// Finds all `WHEN OTHERS` handlers consisting only of `NULL` statements, in
+/// source order.
+pub(super) fn find_swallowed_exceptions(
+    root: &Root,
+    index: &LineIndex,
+) -> Vec<DboSwallowedException> {
+    root.syntax()
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::ExceptionHandler)
+        .filter(is_swallowing)
+        .map(|handler| DboSwallowedException {
+            routine: handler
+                .ancestors()
+                .find_map(|node| {
+                    Function::cast(node.clone())
+                        .and_then(|f| f.name())
+                        .or_else(|| Procedure::cast(node.clone()).and_then(|p| p.name()))
+                        .or_else(|| Trigger::cast(node).and_then(|t| t.name()))
+                })
+                .unwrap_or_else(|| "<unknown>".to_string()),
+            location: SourceLocation::new(trimmed_range(&handler), index),
+        })
+        .collect()
+}
+
+fn is_swallowing(handler: &SyntaxNode) -> bool {
+    let catches_all = handler
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .any(|t| t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("others"));
+
+    let mut statements = handler
+        .children()
+        .filter(|node| node.kind() == SyntaxKind::BlockStatement)
+        .peekable();
+
+    catches_all
+        && statements.peek().is_some()
+        && statements.all(|stmt| {
+            stmt.children_with_tokens()
+                .filter_map(|it| it.into_token())
+                .filter(|t| t.kind() == SyntaxKind::Keyword)
+                .all(|t| t.text().eq_ignore_ascii_case("null"))
+                && stmt.children().next().is_none()
+        })
+}
+
+/// Returns the edits replacing each `SQLCODE` by `SQLSTATE`, written in the
+/// same case, in source order. Oracle's numeric codes have no counterpart in
+/// the five-character codes of PostgreSQL, e.g. `-1` is `'23505'`, so the
+/// comparisons have to be rewritten along with them. `SQLERRM` is supported
+/// by PL/pgSQL as is.
+pub(super) fn find_sqlcode_references(
+    root: &Root,
+    index: &LineIndex,
+) -> Vec<(String, SourceLocation)> {
+    root.syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("sqlcode"))
+        .map(|t| {
+            let case = KeywordCase::of(t.text()).unwrap_or_default();
+            (
+                case.apply("sqlstate"),
+                SourceLocation::new(t.text_range(), index),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, apply_rules_filtered, DboType, RuleApplicability, RuleFilter};
+    use crate::DboAnalyzeContext;
+
+    #[test]
+    fn test_find_swallowed_exceptions() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE sync_orders IS
+    PROCEDURE cleanup IS
+    BEGIN
+        DELETE FROM tmp_orders WHERE processed = 1;
+    EXCEPTION
+        WHEN OTHERS THEN
+            NULL;
+    END cleanup;
+BEGIN
+    cleanup;
+EXCEPTION
+    WHEN no_data_found THEN
+        NULL;
+    WHEN OTHERS THEN
+        log_error(SQLERRM);
+END sync_orders;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let swallowed = result.unwrap().swallowed_exceptions;
+        assert_eq!(swallowed.len(), 1);
+        assert_eq!(swallowed[0].routine, "cleanup");
+        assert_eq!(swallowed[0].location.start.line, 6);
+        assert_eq!(swallowed[0].location.end.line, 7);
+    }
+
+    #[test]
+    fn test_hint_sqlcode_references() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE log_failure IS
+BEGIN
+    NULL;
+EXCEPTION
+    WHEN OTHERS THEN
+        dbms_output.put_line(SQLCODE || ': ' || SQLERRM);
+        IF sqlcode = -1 THEN
+            RAISE;
+        END IF;
+END log_failure;
+"#;
+        let result = apply_rules_filtered(
+            DboType::Procedure,
+            INPUT,
+            &RuleFilter::default(),
+            &DboAnalyzeContext::default(),
+        );
+        assert!(result.is_ok(), "{result:#?}");
+        let result = result.unwrap();
+        assert!(!result.sql.to_lowercase().contains("sqlstate"));
+
+        let hints = result
+            .hints
+            .into_iter()
+            .filter(|hint| hint.rule == "sqlcode")
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hints
+                .iter()
+                .map(|hint| (
+                    hint.replacement.as_str(),
+                    hint.location.start.line,
+                    hint.applicability
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("SQLSTATE", 6, RuleApplicability::HintOnly),
+                ("sqlstate", 7, RuleApplicability::HintOnly),
+            ]
+        );
+        assert!(hints.iter().all(|hint| hint.note.is_some()));
+    }
+}
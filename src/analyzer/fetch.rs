@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects bulk fetches from cursors and loops exiting on `%NOTFOUND`.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, BasicLoop, CursorAttribute, ExitStmt, FetchStmt, Root};
+use crate::util::{LineIndex, SourceLocation};
+
+/// A `FETCH .. BULK COLLECT INTO` statement. PL/pgSQL has no bulk fetches,
+/// so these are rewritten to a loop over the cursor, in batches of `limit`
+/// rows if given.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboBulkFetch {
+    pub cursor: String,
+    /// The `LIMIT` expression, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub limit: Option<String>,
+    pub location: SourceLocation,
+}
+
+/// Finds all bulk fetches, in source order.
+pub(super) fn find_bulk_fetches(root: &Root, index: &LineIndex) -> Vec<DboBulkFetch> {
+    root.syntax()
+        .descendants()
+        .filter_map(FetchStmt::cast)
+        .filter(FetchStmt::is_bulk)
+        .map(|fetch| DboBulkFetch {
+            cursor: fetch
+                .cursor()
+                .and_then(|c| c.name())
+                .unwrap_or_else(|| "<unknown>".to_string()),
+            limit: fetch
+                .limit()
+                .map(|limit| limit.syntax().text().to_string().trim().to_string()),
+            location: SourceLocation::new(fetch.syntax().text_range(), index),
+        })
+        .collect()
+}
+
+/// How the fetch and the `EXIT WHEN c%NOTFOUND` of a loop are arranged.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboFetchLoopPattern {
+    /// The exit directly follows the fetch, so it becomes
+    /// `EXIT WHEN NOT FOUND` as is.
+    ExitAfterFetch,
+    /// Statements between the fetch and the exit run once more after the
+    /// last row, processing it twice. SQL statements among them also
+    /// overwrite `FOUND` in PL/pgSQL, so the exit must be moved up.
+    StatementsBeforeExit,
+    /// The exit precedes the fetch. `c%NOTFOUND` is `NULL` before the first
+    /// fetch, whereas `FOUND` reflects the last statement before the loop.
+    ExitBeforeFetch,
+    /// A bulk fetch with `LIMIT`, which sets `%NOTFOUND` as soon as fewer
+    /// rows are fetched and thus skips the last batch. Exit when the
+    /// collection is empty instead.
+    PartialBatch,
+}
+
+/// A basic loop fetching from a cursor and exiting on `c%NOTFOUND`.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboFetchLoop {
+    pub cursor: String,
+    /// The variables fetched into, e.g. `l_id`.
+    pub targets: Vec<String>,
+    pub pattern: DboFetchLoopPattern,
+    /// The location of the `EXIT WHEN` statement.
+    pub exit_location: SourceLocation,
+    pub location: SourceLocation,
+}
+
+/// Finds all basic loops with a fetch and an `EXIT WHEN c%NOTFOUND` of the
+/// same cursor directly in their body, in source order. Any other exit
+/// condition is left alone.
+pub(super) fn find_fetch_loops(root: &Root, index: &LineIndex) -> Vec<DboFetchLoop> {
+    root.syntax()
+        .descendants()
+        .filter_map(BasicLoop::cast)
+        .filter_map(|basic_loop| {
+            let statements = basic_loop
+                .statements()
+                .filter_map(|stmt| stmt.first_child())
+                .collect::<Vec<_>>();
+            let (fetch_at, fetch) = statements
+                .iter()
+                .enumerate()
+                .find_map(|(i, node)| Some((i, FetchStmt::cast(node.clone())?)))?;
+            let cursor = fetch.cursor()?.name()?;
+
+            let (exit_at, exit) = statements.iter().enumerate().find_map(|(i, node)| {
+                let exit = ExitStmt::cast(node.clone())?;
+                is_notfound_of(&exit, &cursor).then_some((i, exit))
+            })?;
+
+            let pattern = if exit_at < fetch_at {
+                DboFetchLoopPattern::ExitBeforeFetch
+            } else if fetch.is_bulk() && fetch.limit().is_some() {
+                DboFetchLoopPattern::PartialBatch
+            } else if exit_at == fetch_at + 1 {
+                DboFetchLoopPattern::ExitAfterFetch
+            } else {
+                DboFetchLoopPattern::StatementsBeforeExit
+            };
+
+            Some(DboFetchLoop {
+                cursor,
+                targets: fetch
+                    .targets()
+                    .iter()
+                    .map(|target| target.syntax().text().to_string())
+                    .collect(),
+                pattern,
+                exit_location: SourceLocation::new(exit.syntax().text_range(), index),
+                location: SourceLocation::new(basic_loop.syntax().text_range(), index),
+            })
+        })
+        .collect()
+}
+
+/// Checks whether the condition of `exit` is exactly `cursor%NOTFOUND`.
+fn is_notfound_of(exit: &ExitStmt, cursor: &str) -> bool {
+    let Some(condition) = exit.condition() else {
+        return false;
+    };
+    let Some(attribute) = condition
+        .syntax()
+        .children()
+        .find_map(CursorAttribute::cast)
+    else {
+        return false;
+    };
+
+    condition.syntax().text_range() == attribute.syntax().text_range()
+        && attribute
+            .attribute()
+            .is_some_and(|a| a.eq_ignore_ascii_case("notfound"))
+        && attribute
+            .cursor()
+            .and_then(|c| c.name())
+            .is_some_and(|c| c.eq_ignore_ascii_case(cursor))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::{parse_procedure, DboAnalyzeContext};
+
+    use super::*;
+
+    #[test]
+    fn test_find_bulk_fetches() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE archive_orders IS
+    CURSOR c_orders IS SELECT id FROM orders;
+    TYPE id_list IS TABLE OF NUMBER;
+    l_ids id_list;
+    l_id NUMBER;
+BEGIN
+    LOOP
+        FETCH c_orders BULK COLLECT INTO l_ids LIMIT 500;
+        EXIT WHEN l_ids.COUNT = 0;
+    END LOOP;
+    FETCH c_orders INTO l_id;
+    FETCH c_orders BULK COLLECT INTO l_ids;
+END archive_orders;
+"#;
+        assert!(parse_procedure(INPUT).unwrap().errors.is_empty());
+
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let fetches = result
+            .unwrap()
+            .bulk_fetches
+            .into_iter()
+            .map(|f| (f.cursor, f.limit, f.location.start.line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fetches,
+            vec![
+                ("c_orders".to_string(), Some("500".to_string()), 8),
+                ("c_orders".to_string(), None, 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_fetch_loops() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE export_orders IS
+    CURSOR c_orders IS SELECT id, total FROM orders;
+    TYPE id_list IS TABLE OF NUMBER;
+    l_ids id_list;
+    l_id NUMBER;
+    l_total NUMBER;
+BEGIN
+    OPEN c_orders;
+    LOOP
+        FETCH c_orders INTO l_id, l_total;
+        EXIT WHEN c_orders%NOTFOUND;
+        export(l_id, l_total);
+    END LOOP;
+    LOOP
+        FETCH c_orders INTO l_id, l_total;
+        export(l_id, l_total);
+        EXIT WHEN c_orders%NOTFOUND;
+    END LOOP;
+    LOOP
+        EXIT WHEN c_orders%NOTFOUND;
+        FETCH c_orders INTO l_id, l_total;
+    END LOOP;
+    LOOP
+        FETCH c_orders BULK COLLECT INTO l_ids LIMIT 100;
+        EXIT WHEN c_orders%NOTFOUND;
+    END LOOP;
+    LOOP
+        FETCH c_orders INTO l_id, l_total;
+        EXIT WHEN c_orders%NOTFOUND OR l_total > 100;
+    END LOOP;
+    CLOSE c_orders;
+END export_orders;
+"#;
+        assert!(parse_procedure(INPUT).unwrap().errors.is_empty());
+
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let loops = result
+            .unwrap()
+            .fetch_loops
+            .into_iter()
+            .map(|l| (l.cursor, l.targets, l.pattern, l.exit_location.start.line))
+            .collect::<Vec<_>>();
+        let targets = vec!["l_id".to_string(), "l_total".to_string()];
+        assert_eq!(
+            loops,
+            vec![
+                (
+                    "c_orders".to_string(),
+                    targets.clone(),
+                    DboFetchLoopPattern::ExitAfterFetch,
+                    11
+                ),
+                (
+                    "c_orders".to_string(),
+                    targets.clone(),
+                    DboFetchLoopPattern::StatementsBeforeExit,
+                    17
+                ),
+                (
+                    "c_orders".to_string(),
+                    targets,
+                    DboFetchLoopPattern::ExitBeforeFetch,
+                    20
+                ),
+                (
+                    "c_orders".to_string(),
+                    vec!["l_ids".to_string()],
+                    DboFetchLoopPattern::PartialBatch,
+                    25
+                ),
+            ]
+        );
+    }
+}
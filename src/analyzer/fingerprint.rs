@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements a fingerprint of objects, which only changes with their code.
+
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::{cast_to_root, parse_object, AnalyzeError, DboAnalyzeContext, DboType};
+use crate::ast::{AstNode, Root};
+use source_gen::syntax::SyntaxKind;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Returns a fingerprint of the object, which is the same as the one in
+/// [`DboMetaData::fingerprint`]. It does not change with whitespace, comments
+/// or the case of keywords and unquoted identifiers, so repeated runs can skip
+/// unchanged objects.
+///
+/// [`DboMetaData::fingerprint`]: super::DboMetaData::fingerprint
+pub fn fingerprint(sql: &str, typ: DboType) -> Result<String, AnalyzeError> {
+    let (parse, _) = parse_object(typ, sql, &DboAnalyzeContext::default())?;
+    Ok(fingerprint_root(&cast_to_root(&parse)?))
+}
+
+/// WASM export of [`fingerprint()`]. Should _never_ be called from other Rust
+/// code.
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+#[wasm_bindgen(js_name = "fingerprint")]
+pub fn js_fingerprint(sql: &str, typ: DboType) -> Result<String, JsValue> {
+    fingerprint(sql, typ).or_else(|err| Err(serde_wasm_bindgen::to_value(&err)?))
+}
+
+/// Hashes the tokens of the syntax tree.
+pub(super) fn fingerprint_root(root: &Root) -> String {
+    let mut hasher = Fnv1a::new();
+    for token in root
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|t| {
+            !matches!(
+                t.kind(),
+                SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+            )
+        })
+    {
+        // Literals and quoted identifiers are case-sensitive
+        let text = token.text();
+        if token.kind() == SyntaxKind::QuotedLiteral || text.starts_with('"') {
+            hasher.write(text.as_bytes());
+        } else {
+            hasher.write(text.to_lowercase().as_bytes());
+        }
+        // Separates the tokens, such that e.g. `a.bc` and `ab.c` differ
+        hasher.write(&[0]);
+    }
+
+    hasher.finish()
+}
+
+/// A 64-bit FNV-1a hasher, which, unlike the hasher of the standard library,
+/// yields the same hashes across Rust versions and platforms.
+pub(super) struct Fnv1a(u64);
+
+impl Fnv1a {
+    pub(super) fn new() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+
+    pub(super) fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    /// Returns the hash as 16 hexadecimal digits.
+    pub(super) fn finish(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use crate::analyzer::analyze;
+
+    use super::*;
+
+    const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE purge(p_days NUMBER) IS
+BEGIN
+    DELETE FROM orders WHERE created < SYSDATE - p_days AND note = 'Old';
+END purge;
+"#;
+
+    #[test]
+    fn test_fingerprint_ignores_trivia_and_case() {
+        let reformatted = r#"create or replace procedure PURGE (P_DAYS number) is
+begin
+  -- keep the recent ones
+  delete from ORDERS
+   where CREATED < sysdate - P_DAYS and NOTE = 'Old';
+end Purge;"#;
+
+        let fingerprint = fingerprint(INPUT, DboType::Procedure).unwrap();
+        assert_eq!(fingerprint.len(), 16);
+        assert_eq!(
+            fingerprint,
+            super::fingerprint(reformatted, DboType::Procedure).unwrap()
+        );
+        assert_eq!(
+            fingerprint,
+            analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default())
+                .unwrap()
+                .fingerprint
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_changes_with_code() {
+        let fingerprint = fingerprint(INPUT, DboType::Procedure).unwrap();
+        assert_ne!(
+            fingerprint,
+            super::fingerprint(&INPUT.replace("'Old'", "'OLD'"), DboType::Procedure).unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            super::fingerprint(
+                &INPUT.replace("p_days AND", "p_days OR"),
+                DboType::Procedure
+            )
+            .unwrap()
+        );
+    }
+}
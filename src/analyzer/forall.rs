@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects `FORALL` statements and reads of their bulk cursor attributes.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::dml::DboDmlKind;
+use crate::ast::{AstNode, CursorAttribute, ForallStmt, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::SyntaxKind;
+
+/// How a `FORALL` statement is rewritten for PostgreSQL, which has no bulk
+/// binds.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboForallRewrite {
+    /// A `FOREACH` loop running the statement per element, or a single
+    /// statement over `unnest()`ed arrays. Both abort on the first error,
+    /// like Oracle does without `SAVE EXCEPTIONS`.
+    Loop,
+    /// A loop with a `BEGIN .. EXCEPTION` block per element, collecting the
+    /// failed indexes and `SQLSTATE`s in place of `SQL%BULK_EXCEPTIONS` and
+    /// raising a single error afterwards. Without it, the first failed row
+    /// either aborts all of them or, with `WHEN OTHERS` in the loop, is
+    /// silently skipped.
+    ExceptionBlock,
+    /// As [`DboForallRewrite::ExceptionBlock`], or
+    /// `INSERT .. ON CONFLICT DO NOTHING` if only duplicate keys are expected
+    /// to fail. The latter neither raises nor reports the skipped rows.
+    OnConflict,
+}
+
+/// A `FORALL` statement, binding collections to a DML statement.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboForall {
+    pub index: String,
+    /// The kind of the bound statement, none for `EXECUTE IMMEDIATE`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub statement: Option<DboDmlKind>,
+    /// Whether `SAVE EXCEPTIONS` is given, i.e. failed rows don't stop the
+    /// remaining ones.
+    pub save_exceptions: bool,
+    pub rewrite: DboForallRewrite,
+    pub location: SourceLocation,
+}
+
+/// A read of `SQL%BULK_EXCEPTIONS` or `SQL%BULK_ROWCOUNT`. The former is
+/// replaced by the errors collected in the exception block of the loop, the
+/// latter by `GET DIAGNOSTICS .. = ROW_COUNT` after each statement.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboBulkAttribute {
+    /// The attribute in uppercase, e.g. `BULK_EXCEPTIONS`.
+    pub attribute: String,
+    pub location: SourceLocation,
+}
+
+/// Finds all `FORALL` statements, in source order.
+pub(super) fn find_foralls(root: &Root, index: &LineIndex) -> Vec<DboForall> {
+    root.syntax()
+        .descendants()
+        .filter_map(ForallStmt::cast)
+        .filter_map(|forall| {
+            let statement = forall.statement()?;
+            let save_exceptions = forall.saves_exceptions();
+            let rewrite = match statement.kind() {
+                _ if !save_exceptions => DboForallRewrite::Loop,
+                SyntaxKind::InsertStmt => DboForallRewrite::OnConflict,
+                _ => DboForallRewrite::ExceptionBlock,
+            };
+
+            Some(DboForall {
+                index: forall.index()?.name()?,
+                statement: match statement.kind() {
+                    SyntaxKind::InsertStmt => Some(DboDmlKind::Insert),
+                    SyntaxKind::UpdateStmt => Some(DboDmlKind::Update),
+                    SyntaxKind::DeleteStmt => Some(DboDmlKind::Delete),
+                    _ => None,
+                },
+                save_exceptions,
+                rewrite,
+                location: SourceLocation::new(forall.syntax().text_range(), index),
+            })
+        })
+        .collect()
+}
+
+/// Finds all reads of the bulk attributes of the implicit cursor, in source
+/// order.
+pub(super) fn find_bulk_attributes(root: &Root, index: &LineIndex) -> Vec<DboBulkAttribute> {
+    root.syntax()
+        .descendants()
+        .filter_map(CursorAttribute::cast)
+        .filter(CursorAttribute::is_implicit)
+        .filter_map(|attribute| {
+            let name = attribute.attribute()?.to_uppercase();
+            matches!(name.as_str(), "BULK_EXCEPTIONS" | "BULK_ROWCOUNT").then(|| DboBulkAttribute {
+                attribute: name,
+                location: SourceLocation::new(attribute.syntax().text_range(), index),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_find_foralls() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE load_orders(p_ids id_list) IS
+    bulk_errors EXCEPTION;
+    PRAGMA EXCEPTION_INIT(bulk_errors, -24381);
+BEGIN
+    FORALL i IN 1 .. p_ids.COUNT
+        DELETE FROM staging WHERE id = p_ids(i);
+    FORALL i IN INDICES OF p_ids SAVE EXCEPTIONS
+        INSERT INTO orders (id) VALUES (p_ids(i));
+    FORALL j IN p_ids.FIRST..p_ids.LAST SAVE EXCEPTIONS
+        UPDATE orders SET status = 1 WHERE id = p_ids(j);
+    log_count(SQL%BULK_ROWCOUNT(1));
+EXCEPTION
+    WHEN bulk_errors THEN
+        FOR k IN 1 .. SQL%BULK_EXCEPTIONS.COUNT LOOP
+            log_error(SQL%BULK_EXCEPTIONS(k).ERROR_INDEX, SQL%ROWCOUNT);
+        END LOOP;
+END load_orders;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+        let meta_data = result.unwrap();
+
+        let foralls = meta_data
+            .foralls
+            .iter()
+            .map(|f| (f.index.as_str(), f.statement, f.save_exceptions, f.rewrite))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            foralls,
+            vec![
+                ("i", Some(DboDmlKind::Delete), false, DboForallRewrite::Loop),
+                (
+                    "i",
+                    Some(DboDmlKind::Insert),
+                    true,
+                    DboForallRewrite::OnConflict
+                ),
+                (
+                    "j",
+                    Some(DboDmlKind::Update),
+                    true,
+                    DboForallRewrite::ExceptionBlock
+                ),
+            ]
+        );
+        assert_eq!(meta_data.foralls[0].location.start.line, 5);
+
+        let attributes = meta_data
+            .bulk_attributes
+            .iter()
+            .map(|a| (a.attribute.as_str(), a.location.start.line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attributes,
+            vec![
+                ("BULK_ROWCOUNT", 11),
+                ("BULK_EXCEPTIONS", 14),
+                ("BULK_EXCEPTIONS", 15),
+            ]
+        );
+    }
+}
@@ -2,12 +2,22 @@
 // SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
 // <office@cybertec.at>
 
+use rowan::Direction;
 use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
 
-use crate::analyzer::{AnalyzeError, DboMetaData};
-use crate::ast::Root;
+use crate::analyzer::call_spec::{analyze_call_spec, DboCallSpec};
+use crate::analyzer::complexity::{longest_statement, max_nesting_depth};
+use crate::analyzer::datatype::{map_oracle_type_to_pg, map_parameters, DboParameterMetaData};
+use crate::analyzer::lines_of_code::count_lines;
+use crate::analyzer::references::declaring_scope;
+use crate::analyzer::security::{map_invoker_rights, DboSecurity};
+use crate::analyzer::{AnalyzeError, DboAnalyzeContext, DboColumnType, DboMetaData};
+use crate::ast::{AstNode, AstToken, Block, FunctionInvocation, IdentGroup, Root};
+use crate::util::{LineIndex, SourceLocation};
+use crate::SqlIdent;
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
 
 #[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
@@ -16,31 +26,274 @@ pub struct DboFunctionMetaData {
     pub name: String,
     pub body: String,
     pub lines_of_code: usize,
+    /// The maximum number of nested blocks, `IF` statements and loops.
+    pub max_nesting_depth: usize,
+    /// The location of the longest statement of the body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longest_statement: Option<SourceLocation>,
+    /// The security mode declared through `AUTHID`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<DboSecurity>,
+    pub volatility: DboFunctionVolatility,
+    pub parameters: Vec<DboParameterMetaData>,
+    /// The PostgreSQL return type, if it could be mapped.
+    pub return_type: Option<DboColumnType>,
+    /// The properties declared in the header, e.g. `DETERMINISTIC`.
+    pub attributes: DboFunctionAttributes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_spec: Option<DboCallSpec>,
 }
 
-pub(super) fn analyze_function(root: Root) -> Result<DboMetaData, AnalyzeError> {
+/// Properties declared in the function header.
+#[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboFunctionAttributes {
+    pub deterministic: bool,
+    pub parallel_enable: bool,
+    pub pipelined: bool,
+    pub result_cache: bool,
+}
+
+/// The PostgreSQL volatility category a function can be declared with.
+///
+/// See <https://www.postgresql.org/docs/current/xfunc-volatility.html>.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboFunctionVolatility {
+    /// The function body is a pure expression.
+    Immutable,
+    /// The function body only reads from tables or package state.
+    Stable,
+    /// The function body writes to tables, calls volatile builtins, ends
+    /// transactions, advances sequences or does something the analyzer cannot
+    /// classify.
+    Volatile,
+}
+
+impl DboFunctionVolatility {
+    /// Returns the keyword declaring the volatility, in lowercase.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Self::Immutable => "immutable",
+            Self::Stable => "stable",
+            Self::Volatile => "volatile",
+        }
+    }
+}
+
+/// Builtins whose result may change within a single statement.
+pub(super) const VOLATILE_BUILTINS: &[&str] = &[
+    "current_date",
+    "current_timestamp",
+    "dbms_random",
+    "localtimestamp",
+    "sys_guid",
+    "sysdate",
+    "systimestamp",
+];
+
+/// Builtins which read the locale or time zone settings, thus are `STABLE` in
+/// PostgreSQL.
+pub(super) const STABLE_BUILTINS: &[&str] = &["to_char", "to_date", "to_number"];
+
+/// Builtins which are known to be free of side effects.
+pub(super) const IMMUTABLE_BUILTINS: &[&str] = &[
+    "abs",
+    "avg",
+    "bitand",
+    "ceil",
+    "coalesce",
+    "concat",
+    "count",
+    "decode",
+    "floor",
+    "greatest",
+    "initcap",
+    "instr",
+    "least",
+    "length",
+    "lower",
+    "lpad",
+    "ltrim",
+    "max",
+    "min",
+    "mod",
+    "nvl",
+    "nvl2",
+    "power",
+    "remainder",
+    "replace",
+    "round",
+    "rpad",
+    "rtrim",
+    "sign",
+    "sqrt",
+    "substr",
+    "sum",
+    "translate",
+    "trim",
+    "trunc",
+    "upper",
+];
+
+pub(super) fn analyze_function(
+    root: Root,
+    ctx: &DboAnalyzeContext,
+) -> Result<DboMetaData, AnalyzeError> {
     let function = root
         .function()
         .ok_or_else(|| AnalyzeError::ParseError("failed to find function".to_owned()))?;
 
-    let body = function
-        .body()
-        .map(|b| b.text())
-        .ok_or_else(|| AnalyzeError::ParseError("failed to find function body".to_owned()))?;
+    // Externally implemented functions have no body, thus no lines of code
+    let call_spec = function.call_spec().map(|c| analyze_call_spec(&c));
+    let (body, lines_of_code) = match (function.body(), &call_spec) {
+        (Some(body), _) => {
+            let lines_of_code = count_lines(body.syntax()).get(ctx.options().lines_of_code);
+            (body.text(), lines_of_code)
+        }
+        (None, Some(_)) => (String::new(), 0),
+        (None, None) => {
+            return Err(AnalyzeError::ParseError(
+                "failed to find function body".to_owned(),
+            ))
+        }
+    };
 
     let name = function.name().unwrap_or_else(|| "<unknown>".to_string());
-    let lines_of_code = body.matches('\n').count() + 1;
+    let index =
+        LineIndex::new(&root.syntax().to_string()).with_excerpt_lines(ctx.options().excerpt_lines);
+    let max_nesting_depth = function.body().map(|b| max_nesting_depth(&b)).unwrap_or(0);
+    let longest_statement = function.body().and_then(|b| longest_statement(&b, &index));
+    let volatility = function
+        .body()
+        .map(|b| classify_volatility(&b))
+        .unwrap_or(DboFunctionVolatility::Volatile);
+    let header = function.header();
+    let parameters = map_parameters(header.as_ref().and_then(|h| h.param_list()), ctx);
+    let return_type = header
+        .as_ref()
+        .and_then(|h| h.return_type())
+        .and_then(|d| map_oracle_type_to_pg(&d, ctx));
+    let security = map_invoker_rights(header.as_ref().and_then(|h| h.invoker_rights()));
+    let attributes = header
+        .map(|h| DboFunctionAttributes {
+            deterministic: h.is_deterministic(),
+            parallel_enable: h.is_parallel_enabled(),
+            pipelined: h.is_pipelined(),
+            result_cache: h.is_result_cached(),
+        })
+        .unwrap_or_default();
 
     Ok(DboMetaData {
         function: Some(DboFunctionMetaData {
             name,
             body,
             lines_of_code,
+            max_nesting_depth,
+            longest_statement,
+            security,
+            volatility,
+            parameters,
+            return_type,
+            attributes,
+            call_spec,
         }),
         ..Default::default()
     })
 }
 
+/// Sequence pseudocolumns, which change the sequence or read its session
+/// state.
+pub(super) const SEQUENCE_PSEUDOCOLUMNS: &[&str] = &["currval", "nextval"];
+
+/// Classifies the side effects of a function body, defaulting to
+/// [`DboFunctionVolatility::Volatile`] for anything not known to be safe.
+///
+/// Qualified names whose first segment is not a variable or parameter, e.g.
+/// `pkg.g_rate`, may read package state and thus make the body at least
+/// [`DboFunctionVolatility::Stable`]. Assigning to them or fetching into them
+/// writes package state, which is [`DboFunctionVolatility::Volatile`].
+pub(super) fn classify_volatility(body: &Block) -> DboFunctionVolatility {
+    let mut volatility = DboFunctionVolatility::Immutable;
+
+    for node in body.syntax().descendants() {
+        match node.kind() {
+            SyntaxKind::InsertStmt
+            | SyntaxKind::UpdateStmt
+            | SyntaxKind::DeleteStmt
+            | SyntaxKind::CommitStmt
+            | SyntaxKind::RollbackStmt
+            | SyntaxKind::SavepointStmt
+            | SyntaxKind::ExecuteImmediateStmt => return DboFunctionVolatility::Volatile,
+            SyntaxKind::SelectStmt | SyntaxKind::CursorStmt => {
+                volatility = DboFunctionVolatility::Stable;
+            }
+            SyntaxKind::FunctionInvocation => {
+                let name = FunctionInvocation::cast(node)
+                    .and_then(|f| f.ident())
+                    .and_then(|i| i.name())
+                    .unwrap_or_default()
+                    .to_lowercase();
+
+                if STABLE_BUILTINS.contains(&name.as_str()) {
+                    volatility = DboFunctionVolatility::Stable;
+                } else if !IMMUTABLE_BUILTINS.contains(&name.as_str()) {
+                    return DboFunctionVolatility::Volatile;
+                }
+            }
+            SyntaxKind::IdentGroup => {
+                let Some(group) = IdentGroup::cast(node.clone()) else {
+                    continue;
+                };
+                let Some(first) = group.nth(0) else {
+                    continue;
+                };
+
+                let is_volatile = VOLATILE_BUILTINS.contains(&first.text().to_lowercase().as_str())
+                    || (group.qualifier().is_some()
+                        && group.last().is_some_and(|last| {
+                            SEQUENCE_PSEUDOCOLUMNS.contains(&last.text().to_lowercase().as_str())
+                        }));
+                if is_volatile {
+                    return DboFunctionVolatility::Volatile;
+                }
+
+                // Types like `emp.salary%TYPE` are resolved when compiling
+                let is_unresolved = group.qualifier().is_some()
+                    && !node.ancestors().any(|a| a.kind() == SyntaxKind::Datatype)
+                    && declaring_scope(first.syntax(), &SqlIdent::from(first.text())).is_none();
+                if is_unresolved && is_assignment_target(&node) {
+                    return DboFunctionVolatility::Volatile;
+                } else if is_unresolved {
+                    volatility = DboFunctionVolatility::Stable;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    volatility
+}
+
+/// Whether `node` is assigned to, either by `:=` or as target of `INTO`.
+fn is_assignment_target(node: &SyntaxNode) -> bool {
+    let is_into_target = node.parent().is_some_and(|p| {
+        matches!(
+            p.kind(),
+            SyntaxKind::IntoClause | SyntaxKind::BulkIntoClause
+        )
+    });
+    let is_assigned = node
+        .siblings_with_tokens(Direction::Next)
+        .skip(1)
+        .find(|it| it.kind() != SyntaxKind::Whitespace)
+        .is_some_and(|it| it.kind() == SyntaxKind::Assign);
+
+    is_into_target || is_assigned
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -65,6 +318,8 @@ mod tests {
                     Some(DboFunctionMetaData {
                         name,
                         lines_of_code,
+                        parameters,
+                        return_type,
                         ..
                     }),
                 procedure,
@@ -75,8 +330,164 @@ mod tests {
                 assert_eq!(query, None);
                 assert_eq!(name, "function_heading_example");
                 assert_eq!(lines_of_code, 3);
+                assert_eq!(
+                    parameters
+                        .into_iter()
+                        .map(|p| (p.name, p.mapped_type))
+                        .collect::<Vec<_>>(),
+                    vec![
+                        ("p_1".to_string(), Some(DboColumnType::Text)),
+                        ("p_2".to_string(), Some(DboColumnType::Numeric)),
+                        ("p_3".to_string(), Some(DboColumnType::Boolean)),
+                        ("p_4".to_string(), Some(DboColumnType::Timestamp)),
+                        ("p_5".to_string(), None),
+                    ]
+                );
+                assert_eq!(return_type, Some(DboColumnType::Numeric));
             }
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn test_analyze_function_attributes() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE FUNCTION get_rate(p_cur VARCHAR2 DEFAULT 'EUR') RETURN NUMBER
+DETERMINISTIC RESULT_CACHE RELIES_ON (rates)
+IS
+BEGIN
+    RETURN 1;
+END get_rate;
+"#;
+        let result = analyze(DboType::Function, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        assert_eq!(
+            result.unwrap().function.unwrap().attributes,
+            DboFunctionAttributes {
+                deterministic: true,
+                parallel_enable: false,
+                pipelined: false,
+                result_cache: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_analyze_function_volatility() {
+        let volatility = |input: &str| {
+            analyze(DboType::Function, input, &DboAnalyzeContext::default())
+                .unwrap()
+                .function
+                .unwrap()
+                .volatility
+        };
+
+        assert_eq!(
+            volatility("CREATE FUNCTION f RETURN NUMBER IS BEGIN RETURN NVL(1, 2) + 1; END f;"),
+            DboFunctionVolatility::Immutable,
+        );
+        assert_eq!(
+            volatility(
+                r#"CREATE FUNCTION f RETURN NUMBER IS
+                   BEGIN
+                       SELECT MAX(salary) INTO cnt FROM emp;
+                       RETURN cnt;
+                   END f;"#
+            ),
+            DboFunctionVolatility::Stable,
+        );
+        assert_eq!(
+            volatility(
+                r#"CREATE FUNCTION f RETURN NUMBER IS
+                   BEGIN
+                       SELECT id INTO cnt FROM emp;
+                       RETURN cnt;
+                   END f;"#
+            ),
+            DboFunctionVolatility::Stable,
+        );
+        assert_eq!(
+            volatility(
+                r#"CREATE FUNCTION f RETURN NUMBER IS
+                   BEGIN
+                       DELETE FROM emp WHERE id = 1;
+                       RETURN 1;
+                   END f;"#
+            ),
+            DboFunctionVolatility::Volatile,
+        );
+        assert_eq!(
+            volatility(
+                "CREATE FUNCTION f(p VARCHAR2) RETURN NUMBER IS BEGIN RETURN TO_NUMBER(p); END f;"
+            ),
+            DboFunctionVolatility::Stable,
+        );
+        assert_eq!(
+            volatility("CREATE FUNCTION f RETURN DATE IS BEGIN RETURN SYSDATE; END f;"),
+            DboFunctionVolatility::Volatile,
+        );
+    }
+
+    #[test]
+    fn test_analyze_function_volatility_of_state() {
+        let volatility = |input: &str| {
+            analyze(DboType::Function, input, &DboAnalyzeContext::default())
+                .unwrap()
+                .function
+                .unwrap()
+                .volatility
+        };
+
+        assert_eq!(
+            volatility("CREATE FUNCTION f RETURN NUMBER IS BEGIN RETURN my_seq.NEXTVAL; END f;"),
+            DboFunctionVolatility::Volatile,
+        );
+        assert_eq!(
+            volatility(
+                "CREATE FUNCTION f(p NUMBER) RETURN NUMBER IS BEGIN RETURN pkg.g_rate * p; END f;"
+            ),
+            DboFunctionVolatility::Stable,
+        );
+        assert_eq!(
+            volatility(
+                r#"CREATE FUNCTION f RETURN NUMBER IS
+                   BEGIN
+                       pkg.counter := pkg.counter + 1;
+                       RETURN pkg.counter;
+                   END f;"#
+            ),
+            DboFunctionVolatility::Volatile,
+        );
+        assert_eq!(
+            volatility(
+                r#"CREATE FUNCTION f RETURN NUMBER IS
+                   BEGIN
+                       ROLLBACK;
+                       RETURN 1;
+                   END f;"#
+            ),
+            DboFunctionVolatility::Volatile,
+        );
+        assert_eq!(
+            volatility(
+                r#"CREATE FUNCTION f RETURN NUMBER IS
+                   BEGIN
+                       SAVEPOINT before_return;
+                       RETURN 1;
+                   END f;"#
+            ),
+            DboFunctionVolatility::Volatile,
+        );
+        assert_eq!(
+            volatility(
+                r#"CREATE FUNCTION f(p_emp emp%ROWTYPE) RETURN NUMBER IS
+                   l_rate rates.rate%TYPE := 2;
+                   BEGIN
+                       RETURN p_emp.salary * l_rate;
+                   END f;"#
+            ),
+            DboFunctionVolatility::Immutable,
+        );
+    }
 }
@@ -0,0 +1,272 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Derives the privileges an object needs on the objects it references.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::function::{IMMUTABLE_BUILTINS, STABLE_BUILTINS, VOLATILE_BUILTINS};
+use crate::ast::{
+    AstNode, DeleteStmt, Function, FunctionInvocation, IdentGroup, InsertStmt, Procedure, Root,
+    SelectStmt, UpdateStmt,
+};
+
+/// Builtin procedures which never require a grant.
+const BUILTIN_PROCEDURES: &[&str] = &["raise_application_error"];
+
+/// Supplied packages which are granted to `PUBLIC`, thus never require a
+/// grant.
+const PUBLIC_PACKAGES: &[&str] = &[
+    "dbms_application_info",
+    "dbms_lob",
+    "dbms_output",
+    "dbms_random",
+    "dbms_sql",
+    "dbms_utility",
+    "utl_file",
+    "utl_raw",
+];
+
+/// Keywords taking a parenthesized operand, which parse like invocations,
+/// e.g. `= ANY (1, 2)`.
+const KEYWORDS: &[&str] = &["all", "any", "exists", "in", "some"];
+
+/// Tables which every user may select from.
+const PUBLIC_TABLES: &[&str] = &["dual", "sys.dual"];
+
+/// A privilege which can be granted on a table or routine.
+#[derive(Tsify, Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboPrivilege {
+    Delete,
+    Execute,
+    Insert,
+    Select,
+    Update,
+}
+
+/// A privilege the analyzed object requires on a referenced object.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboRequiredGrant {
+    pub privilege: DboPrivilege,
+    /// The referenced table or routine, lowercased unless quoted.
+    pub object: String,
+}
+
+/// Finds all privileges needed on referenced tables, sequences and routines,
+/// sorted by object and privilege. All queries are walked, including
+/// subqueries. Calls to builtins, to public packages and to subprograms
+/// declared within the analyzed object are ignored, as are selects from
+/// `DUAL`.
+pub(super) fn find_required_grants(root: &Root) -> Vec<DboRequiredGrant> {
+    let local_routines = local_routines(root);
+
+    let mut grants = BTreeSet::new();
+    for node in root.syntax().descendants() {
+        let (privilege, objects) = if let Some(select) = SelectStmt::cast(node.clone()) {
+            (DboPrivilege::Select, select.tables())
+        } else if let Some(insert) = InsertStmt::cast(node.clone()) {
+            (DboPrivilege::Insert, insert.table().into_iter().collect())
+        } else if let Some(update) = UpdateStmt::cast(node.clone()) {
+            (DboPrivilege::Update, update.table().into_iter().collect())
+        } else if let Some(delete) = DeleteStmt::cast(node.clone()) {
+            (DboPrivilege::Delete, delete.table().into_iter().collect())
+        } else if let Some(invocation) = FunctionInvocation::cast(node.clone()) {
+            (
+                DboPrivilege::Execute,
+                invocation.ident().into_iter().collect(),
+            )
+        } else if let Some(sequence) = IdentGroup::cast(node).and_then(|i| sequence_name(&i)) {
+            grants.insert((sequence, DboPrivilege::Select));
+            continue;
+        } else {
+            continue;
+        };
+
+        for object in objects
+            .into_iter()
+            .filter_map(|ident| ident.name().map(normalize_name))
+        {
+            let is_exempt = match privilege {
+                DboPrivilege::Execute => {
+                    is_builtin_routine(&object) || local_routines.contains(&object)
+                }
+                DboPrivilege::Select => PUBLIC_TABLES.contains(&object.as_str()),
+                _ => false,
+            };
+            if !is_exempt {
+                grants.insert((object, privilege));
+            }
+        }
+    }
+
+    grants
+        .into_iter()
+        .map(|(object, privilege)| DboRequiredGrant { privilege, object })
+        .collect()
+}
+
+/// Returns the normalized name of the sequence if the identifier is a
+/// `<sequence>.NEXTVAL` or `<sequence>.CURRVAL` pseudocolumn.
+fn sequence_name(ident: &IdentGroup) -> Option<String> {
+    let pseudocolumn = ident.last()?.text().to_lowercase();
+    if pseudocolumn != "nextval" && pseudocolumn != "currval" {
+        return None;
+    }
+    ident.qualifier().map(normalize_name)
+}
+
+/// Returns the normalized names of the analyzed function or procedure and of
+/// all subprograms declared within it.
+pub(super) fn local_routines(root: &Root) -> BTreeSet<String> {
+    root.syntax()
+        .descendants()
+        .filter_map(|node| {
+            Function::cast(node.clone())
+                .and_then(|f| f.header()?.identifier())
+                .or_else(|| Procedure::cast(node).and_then(|p| p.header()?.identifier()))
+        })
+        .filter_map(|ident| ident.name().map(normalize_name))
+        .collect()
+}
+
+/// Lowercases each segment of an object name, unless it is quoted. Quoted
+/// segments in uppercase are the same as unquoted ones, so `"HR".EMP`
+/// becomes `hr.emp`.
+pub(super) fn normalize_name(name: String) -> String {
+    let mut segments = Vec::new();
+    let mut rest = name.as_str();
+    while !rest.is_empty() {
+        let end = if let Some(quoted) = rest.strip_prefix('"') {
+            quoted.find('"').map_or(rest.len(), |i| i + 2)
+        } else {
+            rest.find('.').unwrap_or(rest.len())
+        };
+        segments.push(normalize_segment(&rest[..end]));
+        rest = rest[end..].strip_prefix('.').unwrap_or(&rest[end..]);
+    }
+    segments.join(".")
+}
+
+fn normalize_segment(segment: &str) -> String {
+    match segment.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(quoted)
+            if quoted
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || "_$#".contains(c)) =>
+        {
+            quoted.to_lowercase()
+        }
+        Some(_) => segment.to_owned(),
+        None => segment.to_lowercase(),
+    }
+}
+
+/// Returns whether two normalized names refer to the same routine, i.e. are
+/// equal or only one of them is qualified, e.g. `hr.factorial` and
+/// `factorial`.
+pub(super) fn is_same_routine(a: &str, b: &str) -> bool {
+    let is_qualified = |qualified: &str, name: &str| {
+        qualified
+            .strip_suffix(name)
+            .is_some_and(|qualifier| qualifier.ends_with('.'))
+    };
+    a == b || is_qualified(a, b) || is_qualified(b, a)
+}
+
+/// Returns whether the normalized name refers to a builtin function or
+/// procedure, including those of packages granted to `PUBLIC`, or is a
+/// keyword.
+pub(super) fn is_builtin_routine(name: &str) -> bool {
+    let unqualified = name.strip_prefix("sys.").unwrap_or(name);
+    if let Some((package, _)) = unqualified.split_once('.') {
+        if PUBLIC_PACKAGES.contains(&package) {
+            return true;
+        }
+    }
+
+    [
+        IMMUTABLE_BUILTINS,
+        STABLE_BUILTINS,
+        VOLATILE_BUILTINS,
+        BUILTIN_PROCEDURES,
+        KEYWORDS,
+    ]
+    .iter()
+    .any(|builtins| builtins.contains(&name))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_find_required_grants() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE transfer_employee(p_id NUMBER, p_dept NUMBER) IS
+    PROCEDURE log_it IS BEGIN NULL; END log_it;
+BEGIN
+    SELECT d.name INTO v_name FROM departments d JOIN locations l ON d.loc_id = l.id WHERE d.id = p_dept;
+    UPDATE Employees SET dept_id = p_dept WHERE id = p_id;
+    INSERT INTO transfer_log (emp_id) VALUES (p_id);
+    DELETE FROM pending_transfers WHERE emp_id = p_id;
+    INSERT INTO audit_log (id, emp_id) SELECT audit_seq.NEXTVAL, p_id FROM dual
+     WHERE EXISTS (SELECT 1 FROM audited_depts WHERE id = p_dept)
+       AND p_id IN (SELECT emp_id FROM managers);
+    notify_hr(p_id, NVL(p_dept, 0));
+    dbms_output.put_line(dbms_random.value(1, 10));
+    log_it();
+    IF p_dept = 0 THEN
+        raise_application_error(-20001, 'invalid department');
+    END IF;
+END transfer_employee;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let grants = result
+            .unwrap()
+            .required_grants
+            .into_iter()
+            .map(|g| (g.privilege, g.object))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            grants,
+            vec![
+                (DboPrivilege::Insert, "audit_log".to_string()),
+                (DboPrivilege::Select, "audit_seq".to_string()),
+                (DboPrivilege::Select, "audited_depts".to_string()),
+                (DboPrivilege::Select, "departments".to_string()),
+                (DboPrivilege::Update, "employees".to_string()),
+                (DboPrivilege::Select, "locations".to_string()),
+                (DboPrivilege::Select, "managers".to_string()),
+                (DboPrivilege::Execute, "notify_hr".to_string()),
+                (DboPrivilege::Delete, "pending_transfers".to_string()),
+                (DboPrivilege::Insert, "transfer_log".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("HR.Emp".to_string()), "hr.emp");
+        assert_eq!(normalize_name(r#""HR"."Emp""#.to_string()), r#"hr."Emp""#);
+        assert_eq!(normalize_name(r#""a.b".C"#.to_string()), r#""a.b".c"#);
+        assert!(is_same_routine("hr.factorial", "factorial"));
+        assert!(!is_same_routine("hr.factorial", "sys.factorial"));
+        assert!(!is_same_routine("hr.factorial", "torial"));
+    }
+}
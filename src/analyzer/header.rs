@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Normalizes the `CREATE` statements of objects for PostgreSQL.
+
+use rowan::TextRange;
+
+use crate::analyzer::rules::RuleMatch;
+use crate::analyzer::TargetVersion;
+use crate::ast::{AstNode, Root};
+use crate::util::{KeywordCase, LineIndex, SourceLocation};
+use source_gen::syntax::{SyntaxKind, SyntaxToken};
+
+/// Objects of the Oracle source whose `CREATE` statements get `OR REPLACE`.
+/// PostgreSQL has no packages, but their headers are normalized just the
+/// same until they are split into schemas and routines.
+const REPLACEABLE_OBJECTS: &[&str] = &["function", "package", "procedure", "trigger", "view"];
+
+/// Returns the edits normalizing the header of an object, in source order:
+///
+/// * `OR REPLACE` is added after `CREATE` of functions, procedures,
+///   triggers, views and packages, written in `case`. It is only a hint
+///   for triggers if `target` cannot replace them.
+/// * `EDITIONABLE` and `NONEDITIONABLE` are removed.
+/// * Physical properties like `TABLESPACE` or `NOLOGGING` are removed.
+/// * `SHARING` and `ACCESSIBLE BY` clauses are removed, PostgreSQL has no
+///   equivalent.
+pub(super) fn normalize_header(
+    root: &Root,
+    case: Option<KeywordCase>,
+    target: TargetVersion,
+    index: &LineIndex,
+) -> Vec<RuleMatch> {
+    let mut edits = Vec::new();
+
+    let mut tokens = root
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|t| !is_trivia(t));
+    if let Some(create) = tokens.next().filter(|t| is_keyword(t, "create")) {
+        let keywords = tokens
+            .take_while(|t| t.kind() == SyntaxKind::Keyword)
+            .collect::<Vec<_>>();
+
+        let is_replaceable = !keywords.iter().any(|t| is_keyword(t, "materialized"))
+            && keywords
+                .iter()
+                .any(|t| REPLACEABLE_OBJECTS.iter().any(|o| is_keyword(t, o)));
+        if is_replaceable && !keywords.iter().any(|t| is_keyword(t, "replace")) {
+            let case = case.or_else(|| KeywordCase::of(create.text()));
+            let end = create.text_range().end();
+            let edit = RuleMatch::from((
+                format!(" {}", case.unwrap_or_default().apply("or replace")),
+                SourceLocation::new(TextRange::empty(end), index),
+            ));
+            if !target.has_replaceable_triggers()
+                && keywords.iter().any(|t| is_keyword(t, "trigger"))
+            {
+                edits.push(edit.hint_only(
+                    "Triggers can only be replaced from PostgreSQL 14 on, drop it first",
+                ));
+            } else {
+                edits.push(edit);
+            }
+        }
+
+        edits.extend(
+            keywords
+                .iter()
+                .filter(|t| is_keyword(t, "editionable") || is_keyword(t, "noneditionable"))
+                .map(|t| {
+                    let location = removal(t.text_range(), t.next_token(), index);
+                    (String::new(), location).into()
+                }),
+        );
+    }
+
+    edits.extend(
+        root.syntax()
+            .descendants()
+            .filter(|node| {
+                matches!(
+                    node.kind(),
+                    SyntaxKind::IgnoredClause
+                        | SyntaxKind::SharingClause
+                        | SyntaxKind::AccessibleByClause
+                )
+            })
+            .map(|node| {
+                let range = node.text_range();
+                let before = node.first_token().and_then(|t| t.prev_token());
+                let after = node
+                    .last_token()
+                    .and_then(|t| t.next_token())
+                    .filter(|t| t.kind() == SyntaxKind::Whitespace);
+                let location = match after {
+                    // Keeps the parameter list next to the name
+                    Some(after)
+                        if after.next_token().map(|t| t.kind()) == Some(SyntaxKind::LParen) =>
+                    {
+                        removal(range.cover(after.text_range()), before, index)
+                    }
+                    Some(after) => removal(range, Some(after), index),
+                    None => removal(range, before, index),
+                };
+                (String::new(), location).into()
+            }),
+    );
+
+    edits
+}
+
+/// Returns the location of `range` along with `whitespace` next to it, if
+/// that is whitespace indeed.
+fn removal(range: TextRange, whitespace: Option<SyntaxToken>, index: &LineIndex) -> SourceLocation {
+    let range = match whitespace.filter(|t| t.kind() == SyntaxKind::Whitespace) {
+        Some(whitespace) => range.cover(whitespace.text_range()),
+        None => range,
+    };
+    SourceLocation::new(range, index)
+}
+
+fn is_keyword(token: &SyntaxToken, keyword: &str) -> bool {
+    token.kind() == SyntaxKind::Keyword && token.text().eq_ignore_ascii_case(keyword)
+}
+
+fn is_trivia(token: &SyntaxToken) -> bool {
+    matches!(
+        token.kind(),
+        SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{
+        apply_rules_filtered, DboAnalyzeContext, DboAnalyzeOptions, DboType, RuleApplicability,
+        RuleApplication, RuleFilter, TargetVersion,
+    };
+
+    fn apply_header(typ: DboType, sql: &str) -> String {
+        apply_header_for(TargetVersion::default(), typ, sql).sql
+    }
+
+    fn apply_header_for(target_version: TargetVersion, typ: DboType, sql: &str) -> RuleApplication {
+        let filter = RuleFilter {
+            ids: vec!["header".to_string()],
+            ..Default::default()
+        };
+        let ctx = DboAnalyzeContext::default().with_options(DboAnalyzeOptions {
+            target_version,
+            ..Default::default()
+        });
+        let result = apply_rules_filtered(typ, sql, &filter, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+        result.unwrap()
+    }
+
+    #[test]
+    fn test_normalize_headers() {
+        assert_eq!(
+            apply_header(
+                DboType::Procedure,
+                "create editionable procedure touch IS BEGIN NULL; END touch;"
+            ),
+            "create or replace procedure touch IS BEGIN NULL; END touch;"
+        );
+        assert_eq!(
+            apply_header(
+                DboType::Function,
+                "CREATE OR REPLACE NONEDITIONABLE FUNCTION one RETURN NUMBER IS BEGIN RETURN 1; END one;"
+            ),
+            "CREATE OR REPLACE FUNCTION one RETURN NUMBER IS BEGIN RETURN 1; END one;"
+        );
+        assert_eq!(
+            apply_header(
+                DboType::Trigger,
+                "CREATE EDITIONABLE TRIGGER stamp BEFORE INSERT ON orders FOR EACH ROW BEGIN NULL; END;"
+            ),
+            "CREATE OR REPLACE TRIGGER stamp BEFORE INSERT ON orders FOR EACH ROW BEGIN NULL; END;"
+        );
+        assert_eq!(
+            apply_header(DboType::View, "CREATE VIEW recent AS SELECT * FROM orders"),
+            "CREATE OR REPLACE VIEW recent AS SELECT * FROM orders"
+        );
+    }
+
+    #[test]
+    fn test_hint_replacing_triggers_for_old_targets() {
+        const INPUT: &str =
+            "CREATE EDITIONABLE TRIGGER stamp BEFORE INSERT ON orders FOR EACH ROW BEGIN NULL; END;";
+        let application = apply_header_for(TargetVersion::Pg13, DboType::Trigger, INPUT);
+        assert_eq!(
+            application.sql,
+            "CREATE TRIGGER stamp BEFORE INSERT ON orders FOR EACH ROW BEGIN NULL; END;"
+        );
+        assert_eq!(application.hints.len(), 1);
+        assert_eq!(application.hints[0].replacement, " OR REPLACE");
+        assert_eq!(
+            application.hints[0].applicability,
+            RuleApplicability::HintOnly
+        );
+        assert!(application.hints[0].note.is_some());
+
+        assert_eq!(
+            apply_header_for(TargetVersion::Pg14, DboType::Trigger, INPUT).sql,
+            "CREATE OR REPLACE TRIGGER stamp BEFORE INSERT ON orders FOR EACH ROW BEGIN NULL; END;"
+        );
+        assert_eq!(
+            apply_header_for(
+                TargetVersion::Pg13,
+                DboType::View,
+                "CREATE VIEW v AS SELECT 1 FROM t"
+            )
+            .sql,
+            "CREATE OR REPLACE VIEW v AS SELECT 1 FROM t"
+        );
+    }
+
+    #[test]
+    fn test_remove_sharing_and_accessible_by() {
+        const INPUT: &str =
+            include_str!("../../tests/procedure/heading/ignore_sharing_accessible_by.ora.sql");
+        const EXPECTED: &str =
+            include_str!("../../tests/procedure/heading/ignore_sharing_accessible_by.pg.sql");
+
+        let result = apply_rules_filtered(
+            DboType::Procedure,
+            INPUT,
+            &RuleFilter::default(),
+            &DboAnalyzeContext::default(),
+        );
+        assert!(result.is_ok(), "{result:#?}");
+        let result = result.unwrap();
+        assert!(result.hints.is_empty(), "{:#?}", result.hints);
+
+        // No rule quotes the body yet, so only the headers are compared
+        assert_eq!(
+            result.sql.split_once("\nIS\n").unwrap().0,
+            EXPECTED.split_once("\nAS $body$\n").unwrap().0
+        );
+    }
+
+    #[test]
+    fn test_remove_physical_properties() {
+        assert_eq!(
+            apply_header(
+                DboType::Table,
+                "CREATE TABLE stage NOLOGGING PCTFREE 0 AS SELECT * FROM orders"
+            ),
+            "CREATE TABLE stage AS SELECT * FROM orders"
+        );
+        assert_eq!(
+            apply_header(
+                DboType::MaterializedView,
+                "CREATE MATERIALIZED VIEW totals TABLESPACE users AS SELECT 1 FROM orders"
+            ),
+            "CREATE MATERIALIZED VIEW totals AS SELECT 1 FROM orders"
+        );
+    }
+}
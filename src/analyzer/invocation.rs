@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Classifies all function and procedure invocations of an object.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::grants::{is_builtin_routine, local_routines, normalize_name};
+use crate::ast::{AstNode, FunctionInvocation, Root};
+use crate::util::{LineIndex, SourceLocation};
+
+/// Builtins without a PostgreSQL function of the same name.
+const REWRITTEN_BUILTINS: &[&str] = &[
+    "bitand",
+    "decode",
+    "instr",
+    "nvl",
+    "nvl2",
+    "raise_application_error",
+    "remainder",
+    "sys_guid",
+    "sysdate",
+    "systimestamp",
+];
+
+/// What an invoked routine resolves to.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboInvocationKind {
+    /// A builtin Oracle function or procedure, e.g. `NVL`.
+    Builtin,
+    /// A routine of a `DBMS_*` package supplied by Oracle.
+    DbmsPackage,
+    /// The analyzed routine itself or a subprogram declared within it.
+    LocalSubprogram,
+    /// Any other routine, e.g. a standalone function or a package member.
+    Unknown,
+}
+
+/// How a builtin is migrated to PostgreSQL.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboBuiltinMapping {
+    /// PostgreSQL has a function of the same name.
+    Native,
+    /// The invocation must be rewritten, e.g. `NVL` to `COALESCE`.
+    Rewrite,
+}
+
+/// An invocation of a function or procedure with arguments.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboInvocation {
+    /// The invoked routine, lowercased unless quoted, e.g.
+    /// `dbms_output.put_line`.
+    pub name: String,
+    pub kind: DboInvocationKind,
+    /// How the builtin is migrated, only set for builtins.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub mapping: Option<DboBuiltinMapping>,
+    pub location: SourceLocation,
+}
+
+/// Returns all invocations of the object in source order, classified using
+/// the same builtins as the call graph and the required grants.
+///
+/// Subprograms declared within the object take precedence over builtins of
+/// the same name, as they shadow them.
+pub fn find_invocations(root: &Root) -> Vec<DboInvocation> {
+    let index = LineIndex::new(&root.syntax().to_string());
+    let local_routines = local_routines(root);
+
+    root.syntax()
+        .descendants()
+        .filter_map(FunctionInvocation::cast)
+        .filter_map(|invocation| {
+            let name = normalize_name(invocation.ident()?.name()?);
+            let kind = if local_routines.contains(&name) {
+                DboInvocationKind::LocalSubprogram
+            } else if name.trim_start_matches("sys.").starts_with("dbms_") {
+                DboInvocationKind::DbmsPackage
+            } else if is_builtin_routine(&name) {
+                DboInvocationKind::Builtin
+            } else {
+                DboInvocationKind::Unknown
+            };
+            let mapping = (kind == DboInvocationKind::Builtin).then(|| {
+                if REWRITTEN_BUILTINS.contains(&name.as_str()) {
+                    DboBuiltinMapping::Rewrite
+                } else {
+                    DboBuiltinMapping::Native
+                }
+            });
+
+            Some(DboInvocation {
+                name,
+                kind,
+                mapping,
+                location: SourceLocation::new(invocation.syntax().text_range(), &index),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::parse_procedure;
+
+    use super::*;
+
+    #[test]
+    fn test_find_invocations() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE close_account(p_id NUMBER) IS
+    FUNCTION balance(p_id NUMBER) RETURN NUMBER IS
+    BEGIN
+        RETURN NVL(get_balance(p_id), 0);
+    END balance;
+BEGIN
+    IF balance(p_id) > 0 THEN
+        raise_application_error(-20001, 'balance left');
+    END IF;
+    DBMS_OUTPUT.PUT_LINE(UPPER('closing ' || p_id));
+END close_account;
+"#;
+        let parse = parse_procedure(INPUT).unwrap();
+        let root = Root::cast(parse.syntax()).unwrap();
+
+        let invocations = find_invocations(&root)
+            .into_iter()
+            .map(|i| (i.name, i.kind, i.mapping, i.location.start.line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            invocations,
+            vec![
+                (
+                    "nvl".to_owned(),
+                    DboInvocationKind::Builtin,
+                    Some(DboBuiltinMapping::Rewrite),
+                    4
+                ),
+                (
+                    "get_balance".to_owned(),
+                    DboInvocationKind::Unknown,
+                    None,
+                    4
+                ),
+                (
+                    "balance".to_owned(),
+                    DboInvocationKind::LocalSubprogram,
+                    None,
+                    7
+                ),
+                (
+                    "raise_application_error".to_owned(),
+                    DboInvocationKind::Builtin,
+                    Some(DboBuiltinMapping::Rewrite),
+                    8
+                ),
+                (
+                    "dbms_output.put_line".to_owned(),
+                    DboInvocationKind::DbmsPackage,
+                    None,
+                    10
+                ),
+                (
+                    "upper".to_owned(),
+                    DboInvocationKind::Builtin,
+                    Some(DboBuiltinMapping::Native),
+                    10
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Counts the lines of code of an object in different ways.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, Root};
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
+
+/// How lines of code are counted, see [`DboLineCounts`].
+#[derive(Tsify, Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboLineCountKind {
+    #[default]
+    Physical,
+    NonComment,
+    Logical,
+}
+
+/// The lines of code of the body of functions, procedures and triggers, or of
+/// the whole object otherwise.
+#[derive(Tsify, Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboLineCounts {
+    /// All lines, including blank and comment-only ones.
+    pub physical: usize,
+    /// Lines containing code, i.e. neither blank nor only a comment.
+    pub non_comment: usize,
+    /// The number of statements, including nested ones. Objects without
+    /// PL/SQL code, e.g. views, count their SQL statements instead, without
+    /// subqueries and common table expressions.
+    pub logical: usize,
+}
+
+impl DboLineCounts {
+    pub fn get(&self, kind: DboLineCountKind) -> usize {
+        match kind {
+            DboLineCountKind::Physical => self.physical,
+            DboLineCountKind::NonComment => self.non_comment,
+            DboLineCountKind::Logical => self.logical,
+        }
+    }
+}
+
+/// Counts the lines of the body of functions, procedures and triggers, or of
+/// the whole object otherwise. Externally implemented routines have no body,
+/// thus no lines of code.
+pub(super) fn count_object_lines(root: &Root) -> DboLineCounts {
+    let body = root
+        .function()
+        .and_then(|f| f.body())
+        .or_else(|| root.procedure().and_then(|p| p.body()))
+        .or_else(|| root.trigger().and_then(|t| t.body()));
+
+    match body {
+        Some(body) => count_lines(body.syntax()),
+        None if root.function().is_some() || root.procedure().is_some() => DboLineCounts::default(),
+        None if root.package().is_some() => count_lines(root.syntax()),
+        None => DboLineCounts {
+            logical: count_sql_statements(root.syntax()),
+            ..count_lines(root.syntax())
+        },
+    }
+}
+
+/// Counts the SQL statements of `node` which are not part of another one.
+fn count_sql_statements(node: &SyntaxNode) -> usize {
+    let is_statement = |kind| {
+        matches!(
+            kind,
+            SyntaxKind::SelectStmt
+                | SyntaxKind::InsertStmt
+                | SyntaxKind::UpdateStmt
+                | SyntaxKind::DeleteStmt
+                // Its queries belong to the statement following it
+                | SyntaxKind::WithClause
+        )
+    };
+
+    node.descendants()
+        .filter(|n| is_statement(n.kind()) && n.kind() != SyntaxKind::WithClause)
+        .filter(|n| !n.ancestors().skip(1).any(|a| is_statement(a.kind())))
+        .count()
+}
+
+/// Counts the lines of `node`, ignoring leading and trailing whitespace.
+pub(super) fn count_lines(node: &SyntaxNode) -> DboLineCounts {
+    let tokens = node
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .collect::<Vec<_>>();
+    let start = tokens
+        .iter()
+        .position(|t| t.kind() != SyntaxKind::Whitespace);
+    let end = tokens
+        .iter()
+        .rposition(|t| t.kind() != SyntaxKind::Whitespace);
+    let (Some(start), Some(end)) = (start, end) else {
+        return DboLineCounts::default();
+    };
+
+    // Lines are numbered from 1, a token may span multiple ones
+    let mut physical = 1;
+    let mut code_lines = BTreeSet::new();
+    for token in &tokens[start..=end] {
+        let newlines = token.text().matches('\n').count();
+        let is_trivia = matches!(
+            token.kind(),
+            SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+        );
+        if !is_trivia {
+            code_lines.extend(physical..=physical + newlines);
+        }
+        physical += newlines;
+    }
+
+    DboLineCounts {
+        physical,
+        non_comment: code_lines.len(),
+        logical: node
+            .descendants()
+            .filter(|n| n.kind() == SyntaxKind::BlockStatement)
+            .count(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboAnalyzeContext, DboAnalyzeOptions, DboType};
+
+    use super::*;
+
+    #[test]
+    fn test_count_lines() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE purge IS
+BEGIN
+    -- Remove everything
+
+    DELETE FROM orders; /* all of them
+                           really */
+    COMMIT;
+END purge;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let meta_data = result.unwrap();
+        assert_eq!(
+            meta_data.line_counts,
+            DboLineCounts {
+                physical: 7,
+                non_comment: 4,
+                logical: 2,
+            }
+        );
+        assert_eq!(meta_data.procedure.unwrap().lines_of_code, 7);
+
+        let ctx = DboAnalyzeContext::default().with_options(DboAnalyzeOptions {
+            lines_of_code: DboLineCountKind::NonComment,
+            ..Default::default()
+        });
+        let result = analyze(DboType::Procedure, INPUT, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(result.unwrap().procedure.unwrap().lines_of_code, 4);
+    }
+
+    #[test]
+    fn test_count_view_lines() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE VIEW open_orders AS
+SELECT o.id, o.created
+  FROM orders o
+ WHERE o.id NOT IN (SELECT order_id FROM shipments);
+"#;
+        let result = analyze(DboType::View, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result.unwrap().line_counts,
+            DboLineCounts {
+                physical: 4,
+                non_comment: 4,
+                logical: 1,
+            }
+        );
+
+        let result = analyze(
+            DboType::Query,
+            "SELECT id FROM orders",
+            &DboAnalyzeContext::default(),
+        );
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(result.unwrap().line_counts.logical, 1);
+    }
+}
@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects string literals concatenated with `CHR()` control characters.
+
+use rowan::TextRange;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::DboAnalyzeContext;
+use crate::ast::{AstNode, FunctionInvocation, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::{SyntaxElement, SyntaxKind, SyntaxNode};
+
+/// Consecutive string literals and `CHR(9)`, `CHR(10)` or `CHR(13)` of a
+/// concatenation, e.g. `'a' || CHR(10) || 'b'`, which PostgreSQL writes as a
+/// single escape string `E'a\nb'`.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboChrConcatenation {
+    /// The operands as written, including the operators between them.
+    pub original: String,
+    /// The escape string. Backslashes of the literals are doubled, doubled
+    /// single quotes are kept as is.
+    pub replacement: String,
+    pub location: SourceLocation,
+}
+
+/// Finds all concatenations of string literals with control characters, in
+/// source order, unless [`DboAnalyzeOptions::keep_chr_concatenations`] is set.
+///
+/// [`DboAnalyzeOptions::keep_chr_concatenations`]: super::DboAnalyzeOptions::keep_chr_concatenations
+pub(super) fn find_chr_concatenations(
+    root: &Root,
+    ctx: &DboAnalyzeContext,
+    index: &LineIndex,
+) -> Vec<DboChrConcatenation> {
+    if ctx.options().keep_chr_concatenations {
+        return Vec::new();
+    }
+
+    root.syntax()
+        .descendants()
+        .filter(|node| {
+            is_concatenation(node) && !node.parent().is_some_and(|p| is_concatenation(&p))
+        })
+        .flat_map(|node| {
+            let mut operands = Vec::new();
+            collect_operands(&node, &mut operands);
+
+            // Split the operands into runs of convertible ones
+            let mut runs: Vec<Vec<(SyntaxElement, String)>> = vec![Vec::new()];
+            for operand in operands {
+                match escaped(&operand) {
+                    Some(text) => runs.last_mut().unwrap().push((operand, text)),
+                    None => runs.push(Vec::new()),
+                }
+            }
+            runs.into_iter()
+                .filter(|run| {
+                    run.iter()
+                        .any(|(operand, _)| operand.kind() == SyntaxKind::FunctionInvocation)
+                })
+                .map(|run| {
+                    let range = TextRange::new(
+                        run[0].0.text_range().start(),
+                        run[run.len() - 1].0.text_range().end(),
+                    );
+                    DboChrConcatenation {
+                        original: node
+                            .text()
+                            .slice(range - node.text_range().start())
+                            .to_string(),
+                        replacement: format!(
+                            "E'{}'",
+                            run.iter()
+                                .map(|(_, text)| text.as_str())
+                                .collect::<String>()
+                        ),
+                        location: SourceLocation::new(range, index),
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn is_concatenation(node: &SyntaxNode) -> bool {
+    node.kind() == SyntaxKind::Expression
+        && node
+            .children_with_tokens()
+            .any(|it| it.kind() == SyntaxKind::Concat)
+}
+
+/// Collects the operands of a concatenation from left to right, flattening
+/// nested concatenations.
+fn collect_operands(node: &SyntaxNode, operands: &mut Vec<SyntaxElement>) {
+    for element in node.children_with_tokens() {
+        match element {
+            SyntaxElement::Node(n) if is_concatenation(&n) => collect_operands(&n, operands),
+            SyntaxElement::Token(t)
+                if matches!(
+                    t.kind(),
+                    SyntaxKind::Concat
+                        | SyntaxKind::Whitespace
+                        | SyntaxKind::Comment
+                        | SyntaxKind::InlineComment
+                ) => {}
+            element => operands.push(element),
+        }
+    }
+}
+
+/// Returns the content of the operand within an escape string, if it is a
+/// string literal or a supported control character.
+fn escaped(operand: &SyntaxElement) -> Option<String> {
+    match operand {
+        SyntaxElement::Token(t) if t.kind() == SyntaxKind::QuotedLiteral => {
+            let content = t.text().strip_prefix('\'')?.strip_suffix('\'')?;
+            Some(content.replace('\\', "\\\\"))
+        }
+        SyntaxElement::Node(n) => {
+            let invocation = FunctionInvocation::cast(n.clone())?;
+            let is_chr = invocation
+                .ident()
+                .is_some_and(|i| i.syntax().text().to_string().eq_ignore_ascii_case("chr"));
+            let arguments = invocation.arguments()?;
+            match (is_chr, arguments.as_slice()) {
+                (true, [argument]) => match argument.text().as_str() {
+                    "9" => Some("\\t".to_string()),
+                    "10" => Some("\\n".to_string()),
+                    "13" => Some("\\r".to_string()),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, apply_rules_filtered, DboAnalyzeOptions, DboType, RuleFilter};
+
+    use super::*;
+
+    const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE log_line(p_text VARCHAR2) IS
+    l_line VARCHAR2(4000);
+BEGIN
+    l_line := 'it''s C:\temp' || CHR(13) || chr(10) || p_text || Chr(9);
+    l_line := 'emp' || p_text;
+    INSERT INTO emp (line) VALUES ('one' || CHR(10) || 'two');
+END log_line;
+"#;
+
+    #[test]
+    fn test_find_chr_concatenations() {
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let concatenations = result.unwrap().chr_concatenations;
+        assert_eq!(
+            concatenations
+                .iter()
+                .map(|c| (
+                    c.original.as_str(),
+                    c.replacement.as_str(),
+                    c.location.start.line
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    r"'it''s C:\temp' || CHR(13) || chr(10)",
+                    r"E'it''s C:\\temp\r\n'",
+                    4
+                ),
+                ("Chr(9)", r"E'\t'", 4),
+                ("'one' || CHR(10) || 'two'", r"E'one\ntwo'", 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_keep_chr_concatenations() {
+        let ctx = DboAnalyzeContext::default().with_options(DboAnalyzeOptions {
+            keep_chr_concatenations: true,
+            ..Default::default()
+        });
+        let result = analyze(DboType::Procedure, INPUT, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(result.unwrap().chr_concatenations, vec![]);
+    }
+
+    #[test]
+    fn test_apply_chr_escapes_keeps_doubled_quotes() {
+        let ctx = DboAnalyzeContext::default()
+            .with_renames(HashMap::from([("emp".into(), "staff".into())]));
+        let apply = |rule: &str| {
+            let filter = RuleFilter {
+                ids: vec![rule.to_string()],
+                ..Default::default()
+            };
+            let result = apply_rules_filtered(DboType::Procedure, INPUT, &filter, &ctx);
+            assert!(result.is_ok(), "{result:#?}");
+            result.unwrap().sql
+        };
+
+        let escaped = apply("chr_escapes");
+        assert!(escaped.contains(r"l_line := E'it''s C:\\temp\r\n' || p_text || E'\t';"));
+        assert!(escaped.contains("VALUES (E'one\\ntwo')"));
+
+        // Renames only touch identifiers, never the literals
+        let renamed = apply("renames");
+        assert!(renamed.contains(r"'it''s C:\temp'"));
+        assert!(renamed.contains("'emp' || p_text"));
+        assert!(renamed.contains("INSERT INTO staff"));
+    }
+}
@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements analysis of Oracle materialized views.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::{AnalyzeError, DboAnalyzeContext, DboMetaData};
+use crate::ast::{AstNode, Root};
+
+/// How a materialized view is refreshed.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboRefreshMethod {
+    Complete,
+    Fast,
+    /// Fast if possible, complete otherwise. The Oracle default.
+    Force,
+    Never,
+}
+
+/// When a materialized view is refreshed.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboRefreshMode {
+    Commit,
+    /// Only on explicit refreshes. The Oracle default.
+    Demand,
+    Statement,
+}
+
+/// Features of Oracle materialized views PostgreSQL has no equivalent for.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboMaterializedViewFeature {
+    /// `REFRESH FAST`, PostgreSQL always refreshes completely.
+    FastRefresh,
+    /// `ON COMMIT` or `ON STATEMENT`, PostgreSQL only refreshes on demand.
+    AutomaticRefresh,
+    /// `START WITH` or `NEXT`, which needs an external scheduler.
+    ScheduledRefresh,
+    /// `ENABLE QUERY REWRITE`.
+    QueryRewrite,
+}
+
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboMaterializedViewMetaData {
+    pub name: String,
+    pub refresh_method: DboRefreshMethod,
+    pub refresh_mode: DboRefreshMode,
+    /// `BUILD DEFERRED` was given, i.e. `WITH NO DATA` in PostgreSQL.
+    pub build_deferred: bool,
+    pub query_rewrite: bool,
+    /// The query the materialized view is based on.
+    pub query: String,
+    pub unsupported_features: Vec<DboMaterializedViewFeature>,
+}
+
+pub(super) fn analyze_materialized_view(
+    root: Root,
+    _ctx: &DboAnalyzeContext,
+) -> Result<DboMetaData, AnalyzeError> {
+    let view = root
+        .materialized_view()
+        .ok_or_else(|| AnalyzeError::ParseError("failed to find materialized view".to_owned()))?;
+
+    let refresh = view.refresh_clause();
+    let refresh_method = match refresh.as_ref() {
+        Some(refresh) if refresh.is_never() => DboRefreshMethod::Never,
+        Some(refresh) => match refresh.method().as_deref() {
+            Some("fast") => DboRefreshMethod::Fast,
+            Some("complete") => DboRefreshMethod::Complete,
+            _ => DboRefreshMethod::Force,
+        },
+        None => DboRefreshMethod::Force,
+    };
+    let refresh_mode = match refresh.as_ref().and_then(|r| r.mode()).as_deref() {
+        Some("commit") => DboRefreshMode::Commit,
+        Some("statement") => DboRefreshMode::Statement,
+        _ => DboRefreshMode::Demand,
+    };
+    let query_rewrite = view.is_query_rewrite_enabled();
+
+    let mut unsupported_features = Vec::new();
+    if refresh_method == DboRefreshMethod::Fast {
+        unsupported_features.push(DboMaterializedViewFeature::FastRefresh);
+    }
+    if refresh_mode != DboRefreshMode::Demand {
+        unsupported_features.push(DboMaterializedViewFeature::AutomaticRefresh);
+    }
+    if refresh.as_ref().is_some_and(|r| r.is_scheduled()) {
+        unsupported_features.push(DboMaterializedViewFeature::ScheduledRefresh);
+    }
+    if query_rewrite {
+        unsupported_features.push(DboMaterializedViewFeature::QueryRewrite);
+    }
+
+    Ok(DboMetaData {
+        materialized_view: Some(DboMaterializedViewMetaData {
+            name: view.name().unwrap_or_else(|| "<unknown>".to_string()),
+            refresh_method,
+            refresh_mode,
+            build_deferred: view.is_build_deferred(),
+            query_rewrite,
+            query: view
+                .query()
+                .map(|q| q.syntax().text().to_string().trim().to_string())
+                .unwrap_or_default(),
+            unsupported_features,
+        }),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_analyze_materialized_view() {
+        const INPUT: &str = r#"
+CREATE MATERIALIZED VIEW sales_mv
+BUILD DEFERRED
+REFRESH FAST ON COMMIT
+ENABLE QUERY REWRITE
+AS SELECT region, SUM(amount) total FROM sales GROUP BY region;
+"#;
+        let result = analyze(
+            DboType::MaterializedView,
+            INPUT,
+            &DboAnalyzeContext::default(),
+        );
+        assert!(result.is_ok(), "{result:#?}");
+
+        let view = result.unwrap().materialized_view.unwrap();
+        assert_eq!(view.name, "sales_mv");
+        assert_eq!(view.refresh_method, DboRefreshMethod::Fast);
+        assert_eq!(view.refresh_mode, DboRefreshMode::Commit);
+        assert!(view.build_deferred);
+        assert!(view.query_rewrite);
+        assert_eq!(
+            view.query,
+            "SELECT region, SUM(amount) total FROM sales GROUP BY region;"
+        );
+        assert_eq!(
+            view.unsupported_features,
+            vec![
+                DboMaterializedViewFeature::FastRefresh,
+                DboMaterializedViewFeature::AutomaticRefresh,
+                DboMaterializedViewFeature::QueryRewrite,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_analyze_materialized_view_defaults() {
+        const INPUT: &str = "CREATE MATERIALIZED VIEW emp_mv AS SELECT id FROM emp";
+        let result = analyze(
+            DboType::MaterializedView,
+            INPUT,
+            &DboAnalyzeContext::default(),
+        );
+        assert!(result.is_ok(), "{result:#?}");
+
+        let view = result.unwrap().materialized_view.unwrap();
+        assert_eq!(view.refresh_method, DboRefreshMethod::Force);
+        assert_eq!(view.refresh_mode, DboRefreshMode::Demand);
+        assert!(!view.build_deferred);
+        assert!(view.unsupported_features.is_empty());
+    }
+}
@@ -10,18 +10,134 @@ use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
 
+use crate::analyzer::bind_variable::{find_bind_variables, DboBindVariable};
+use crate::analyzer::block::analyze_block;
+use crate::analyzer::call_graph::{find_call_graph, mark_mutual_recursion, DboCallEdge};
+use crate::analyzer::case::find_cases_without_else;
+pub use crate::analyzer::case::DboCaseWithoutElse;
+use crate::analyzer::collection::{find_collection_usage, DboCollectionUsage};
+use crate::analyzer::correlated_update::{find_correlated_updates, DboCorrelatedUpdate};
+use crate::analyzer::cursor::{find_cursors, DboCursor};
+pub use crate::analyzer::datatype::map_oracle_type_to_pg;
+use crate::analyzer::datatype::{
+    find_precision_warnings, find_unresolved_packages, DboPrecisionWarning, DboUnresolvedPackage,
+};
+pub use crate::analyzer::dependency::{
+    build_dependency_graph, DboDependency, DboDependencyGraph, DboDependencyKind,
+};
+use crate::analyzer::dml::{
+    find_bulk_returnings, find_record_dml, find_unfiltered_dml, DboBulkReturning, DboRecordDml,
+    DboUnfilteredDml,
+};
+use crate::analyzer::exception::{find_swallowed_exceptions, DboSwallowedException};
+use crate::analyzer::fetch::{find_bulk_fetches, find_fetch_loops, DboBulkFetch, DboFetchLoop};
+pub use crate::analyzer::fingerprint::fingerprint;
+use crate::analyzer::fingerprint::fingerprint_root;
+use crate::analyzer::forall::{find_bulk_attributes, find_foralls, DboBulkAttribute, DboForall};
 use crate::analyzer::function::{analyze_function, DboFunctionMetaData};
+use crate::analyzer::grants::{find_required_grants, DboRequiredGrant};
+pub use crate::analyzer::invocation::{
+    find_invocations, DboBuiltinMapping, DboInvocation, DboInvocationKind,
+};
+use crate::analyzer::lines_of_code::count_object_lines;
+pub use crate::analyzer::lines_of_code::{DboLineCountKind, DboLineCounts};
+use crate::analyzer::literal::{find_chr_concatenations, DboChrConcatenation};
+use crate::analyzer::materialized_view::{analyze_materialized_view, DboMaterializedViewMetaData};
+use crate::analyzer::number_format::{find_number_conversions, DboNumberConversion};
+use crate::analyzer::numeric::{find_numeric_functions, DboNumericFunction};
+use crate::analyzer::outer_join::{find_unsafe_outer_joins, DboUnsafeOuterJoin};
+use crate::analyzer::package::analyze_package;
+pub use crate::analyzer::package::{
+    validate_package, DboPackageMismatch, DboPackageMismatchKind, DboPackageValidation,
+};
+use crate::analyzer::param_conflict::{find_parameter_conflicts, DboParameterConflict};
+use crate::analyzer::pragma::{find_pragmas, DboPragma};
 use crate::analyzer::procedure::{analyze_procedure, DboProcedureMetaData};
+#[cfg(feature = "profiling")]
+pub use crate::analyzer::profile::analyze_with_profile;
+use crate::analyzer::profile::Profiler;
+pub use crate::analyzer::profile::{ProfilePhase, ProfileReport};
 use crate::analyzer::query::{analyze_query, DboQueryMetaData};
+pub use crate::analyzer::references::find_references;
+use crate::analyzer::rename::find_renames;
+pub use crate::analyzer::rename::DboRename;
+pub use crate::analyzer::report::{
+    analyze_bundle, AnalysisBundle, DboDiagnostic, DboUnsupportedFeature, REPORT_FORMAT_VERSION,
+};
+pub use crate::analyzer::rules::{
+    apply_rule_batch, apply_rules_filtered, Rule, RuleApplicability, RuleApplication, RuleBatch,
+    RuleCategory, RuleConfidence, RuleEdit, RuleFilter, RuleSeverity, TargetVersion, RULES,
+};
+use crate::analyzer::scheduler::{find_scheduled_jobs, DboScheduledJob};
+use crate::analyzer::select_into::{find_select_into_mismatches, DboSelectIntoMismatch};
+pub use crate::analyzer::session::Analyzer;
+pub use crate::analyzer::statement_kind::DboStatementKind;
+use crate::analyzer::statement_kind::{count_statement_kinds, DboStatementCount};
+pub use crate::analyzer::statements::{extract_sql_statements, DboSqlStatementKind};
+use crate::analyzer::subprogram::{find_nested_subprograms, DboNestedSubprogram};
+use crate::analyzer::substitution::{find_substitution_variables, DboSubstitutionVariable};
+pub use crate::analyzer::summary::{analyze_summary, DboHintCounts, DboSummary};
+use crate::analyzer::table::{analyze_table, DboTableMetaData};
+use crate::analyzer::transaction::{
+    find_loop_transactions, find_transaction_routine, DboLoopTransaction, DboTransactionRoutine,
+};
 use crate::analyzer::trigger::{analyze_trigger, DboTriggerMetaData};
 use crate::analyzer::view::{analyze_view, DboViewMetaData};
 use crate::ast::{AstNode, Root};
 use crate::parser::*;
+use crate::util::{KeywordCase, LineIndex};
 use crate::SqlIdent;
 
+mod attributes;
+mod bind_variable;
+mod block;
+mod call_graph;
+mod call_spec;
+mod case;
+mod collection;
+mod complexity;
+mod correlated_update;
+mod cursor;
+mod datatype;
+mod dependency;
+mod dml;
+mod dual;
+mod exception;
+mod fetch;
+mod fingerprint;
+mod forall;
 mod function;
+mod grants;
+mod header;
+mod invocation;
+mod lines_of_code;
+mod literal;
+mod materialized_view;
+mod number_format;
+mod numeric;
+mod outer_join;
+mod package;
+mod param_conflict;
+mod pragma;
 mod procedure;
+mod profile;
 mod query;
+mod references;
+mod rename;
+mod report;
+mod rules;
+mod scheduler;
+mod security;
+mod select_into;
+mod session;
+mod statement_kind;
+mod statements;
+mod subprogram;
+mod substitution;
+mod summary;
+mod table;
+mod terminator;
+mod transaction;
 mod trigger;
 mod view;
 
@@ -33,13 +149,16 @@ mod view;
 #[tsify(into_wasm_abi, from_wasm_abi)]
 #[serde(rename_all = "camelCase")]
 pub enum DboType {
+    AnonymousBlock,
     CheckConstraint,
     DefaultExpr,
     Function,
     IndexExpr,
+    MaterializedView,
     Package,
     Procedure,
     Query,
+    Table,
     Trigger,
     View,
 }
@@ -47,6 +166,7 @@ pub enum DboType {
 /// The result of parsing and analyzing a piece of SQL code.
 #[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
 pub struct DboMetaData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub function: Option<DboFunctionMetaData>,
@@ -58,6 +178,134 @@ pub struct DboMetaData {
     pub trigger: Option<DboTriggerMetaData>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub view: Option<DboViewMetaData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub materialized_view: Option<DboMaterializedViewMetaData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table: Option<DboTableMetaData>,
+    /// `UPDATE` and `DELETE` statements which affect every row of a table.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unfiltered_dml: Vec<DboUnfilteredDml>,
+    /// `INSERT` and `UPDATE` statements taking a whole row from a record,
+    /// which has to be expanded into columns.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub record_dml: Vec<DboRecordDml>,
+    /// Functions and procedures defined in declare sections.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nested_subprograms: Vec<DboNestedSubprogram>,
+    /// Privileges needed on referenced tables and routines.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_grants: Vec<DboRequiredGrant>,
+    /// Calls between functions and procedures, excluding builtins.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub call_graph: Vec<DboCallEdge>,
+    /// The object or one of its nested subprograms calls itself, directly or
+    /// through one other routine. Recursive PL/pgSQL code may need a higher
+    /// `max_stack_depth`.
+    #[serde(default)]
+    pub is_recursive: bool,
+    /// Parameters named like a column of a table they are used with, which
+    /// PL/pgSQL rejects as ambiguous.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameter_conflicts: Vec<DboParameterConflict>,
+    /// `WHEN OTHERS THEN NULL;` exception handlers, which silently swallow
+    /// all errors.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub swallowed_exceptions: Vec<DboSwallowedException>,
+    /// `FETCH .. BULK COLLECT INTO` statements, which are rewritten to loops.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bulk_fetches: Vec<DboBulkFetch>,
+    /// `CASE` statements without `ELSE`, raising `CASE_NOT_FOUND` with a
+    /// different error code in PostgreSQL.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cases_without_else: Vec<DboCaseWithoutElse>,
+    /// Loops fetching from a cursor until `%NOTFOUND`, which become
+    /// `EXIT WHEN NOT FOUND` unless arranged differently.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fetch_loops: Vec<DboFetchLoop>,
+    /// `RETURNING .. BULK COLLECT INTO` clauses of DML statements, which are
+    /// rewritten to aggregate the returned rows.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bulk_returnings: Vec<DboBulkReturning>,
+    /// `FORALL` statements, which are rewritten to loops. With
+    /// `SAVE EXCEPTIONS`, a naive rewrite changes which rows are written.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub foralls: Vec<DboForall>,
+    /// Reads of `SQL%BULK_EXCEPTIONS` and `SQL%BULK_ROWCOUNT`, which only
+    /// exist after `FORALL` statements.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bulk_attributes: Vec<DboBulkAttribute>,
+    /// Explicit cursors with their result shapes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cursors: Vec<DboCursor>,
+    /// `SELECT .. INTO` statements with more or less targets than columns.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub select_into_mismatches: Vec<DboSelectIntoMismatch>,
+    /// `COMMIT` and `ROLLBACK` statements inside loops.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub loop_transactions: Vec<DboLoopTransaction>,
+    /// Jobs created through `DBMS_SCHEDULER` or `DBMS_JOB`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scheduled_jobs: Vec<DboScheduledJob>,
+    /// `UPDATE` statements setting columns from correlated subqueries, which
+    /// can be rewritten to `UPDATE .. FROM`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub correlated_updates: Vec<DboCorrelatedUpdate>,
+    /// Numeric builtins like `MOD` or `BITAND` which behave differently or
+    /// don't exist in PostgreSQL.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub numeric_functions: Vec<DboNumericFunction>,
+    /// `TO_NUMBER` and `TO_CHAR` conversions depending on Oracle number format
+    /// models or NLS parameters.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub number_conversions: Vec<DboNumberConversion>,
+    /// Parameters, variables and return types mapped to PostgreSQL types of
+    /// different precision or scale, e.g. `NUMBER(38)` to `numeric`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub precision_warnings: Vec<DboPrecisionWarning>,
+    /// `%TYPE` references to package variables, whose types are unknown.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unresolved_packages: Vec<DboUnresolvedPackage>,
+    /// Oracle-style `(+)` outer joins combined with `OR` or `IN`, which cannot
+    /// be rewritten to ANSI joins automatically.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unsafe_outer_joins: Vec<DboUnsafeOuterJoin>,
+    /// String literals concatenated with control characters, which become
+    /// escape strings, see the `chr_escapes` rule of [`apply_rules_filtered()`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chr_concatenations: Vec<DboChrConcatenation>,
+    /// Bind and host variables, which callers embedding the SQL provide.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bind_variables: Vec<DboBindVariable>,
+    /// All pragmas, none of which PostgreSQL supports.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pragmas: Vec<DboPragma>,
+    /// SQL*Plus substitution variables, which must become parameters before
+    /// the code runs on PostgreSQL.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub substitution_variables: Vec<DboSubstitutionVariable>,
+    /// References to the objects and columns renamed in the context, see the
+    /// `renames` rule of [`apply_rules_filtered()`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub renames: Vec<DboRename>,
+    /// The lines of code, counted in all supported ways, see
+    /// [`DboAnalyzeOptions::lines_of_code`].
+    #[serde(default)]
+    pub line_counts: DboLineCounts,
+    /// The statements of functions, procedures and triggers by their kind,
+    /// including nested ones.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub statement_counts: Vec<DboStatementCount>,
+    /// A hash of the code, which ignores whitespace, comments and case, see
+    /// [`fingerprint()`].
+    #[serde(default)]
+    pub fingerprint: String,
+    /// The procedure a function or procedure ending transactions has to
+    /// become in PostgreSQL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_routine: Option<DboTransactionRoutine>,
+    /// Declared collection types and called collection methods.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_usage: Option<DboCollectionUsage>,
 }
 
 /// List of possible datatypes for tuple fields.
@@ -69,9 +317,12 @@ pub struct DboMetaData {
 #[serde(rename_all = "camelCase")]
 pub enum DboColumnType {
     BigInt,
+    Boolean,
+    Bytea,
     Date,
     DoublePrecision,
     Integer,
+    Numeric,
     Real,
     SmallInt,
     Text,
@@ -92,6 +343,10 @@ impl DboTableColumn {
     pub fn new(typ: DboColumnType) -> Self {
         Self { typ }
     }
+
+    pub fn typ(&self) -> DboColumnType {
+        self.typ
+    }
 }
 
 #[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
@@ -105,6 +360,10 @@ impl DboTable {
     pub fn new(columns: HashMap<SqlIdent, DboTableColumn>) -> Self {
         Self { columns }
     }
+
+    pub fn column_count(&self) -> usize {
+        self.columns.len()
+    }
 }
 
 #[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
@@ -112,20 +371,114 @@ impl DboTable {
 pub struct DboAnalyzeContext {
     #[tsify(type = "Record<string, DboTable>")]
     tables: HashMap<SqlIdent, DboTable>,
+    #[serde(default)]
+    #[tsify(optional)]
+    options: DboAnalyzeOptions,
+    /// Objects and columns renamed during the migration, mapping the old
+    /// name to the new one, see [`DboMetaData::renames`].
+    #[serde(default)]
+    #[tsify(optional, type = "Record<string, string>")]
+    renames: HashMap<SqlIdent, SqlIdent>,
+}
+
+/// Options changing what [`analyze()`] reports.
+#[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboAnalyzeOptions {
+    /// How the `linesOfCode` of functions, procedures, triggers and
+    /// summaries are counted. All variants are reported in
+    /// [`DboMetaData::line_counts`] regardless.
+    #[serde(default)]
+    #[tsify(optional)]
+    pub lines_of_code: DboLineCountKind,
+    /// Whether string literals must end on the line they start. A missing
+    /// closing quote is then reported as an error on its line, instead of
+    /// garbling the rest of the object.
+    #[serde(default)]
+    #[tsify(optional)]
+    pub single_line_literals: bool,
+    /// Attaches a [`SourceExcerpt`] to every reported location, with that
+    /// many lines of context before and after the covered lines.
+    ///
+    /// [`SourceExcerpt`]: crate::SourceExcerpt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excerpt_lines: Option<u32>,
+    /// Whether `CHR(9)`, `CHR(10)` and `CHR(13)` concatenated with string
+    /// literals are kept, instead of reporting them as escape strings in
+    /// [`DboMetaData::chr_concatenations`].
+    #[serde(default)]
+    #[tsify(optional)]
+    pub keep_chr_concatenations: bool,
+    /// The case the `keyword_case` rule writes all keywords in, e.g.
+    /// [`KeywordCase::Lower`] for `begin .. end`. Without it, the rule
+    /// changes nothing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyword_case: Option<KeywordCase>,
+    /// The prefix the `parameter_names` rule adds to parameters named like
+    /// a column, see [`DboMetaData::parameter_conflicts`]. Defaults to `p_`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameter_prefix: Option<String>,
+    /// The PostgreSQL version rewrites are written for, e.g. triggers only
+    /// get `OR REPLACE` from [`TargetVersion::Pg14`] on.
+    #[serde(default)]
+    #[tsify(optional)]
+    pub target_version: TargetVersion,
 }
 
 impl DboAnalyzeContext {
     pub fn new(tables: HashMap<SqlIdent, DboTable>) -> Self {
-        Self { tables }
+        Self {
+            tables,
+            options: DboAnalyzeOptions::default(),
+            renames: HashMap::new(),
+        }
+    }
+
+    pub fn with_options(self, options: DboAnalyzeOptions) -> Self {
+        Self { options, ..self }
+    }
+
+    pub fn options(&self) -> &DboAnalyzeOptions {
+        &self.options
+    }
+
+    pub fn with_renames(self, renames: HashMap<SqlIdent, SqlIdent>) -> Self {
+        Self { renames, ..self }
+    }
+
+    pub fn renames(&self) -> &HashMap<SqlIdent, SqlIdent> {
+        &self.renames
+    }
+
+    /// Looks up a table. If there is no exact match, a qualified name also
+    /// matches an unqualified table and vice versa. An unqualified name
+    /// matching tables in more than one schema is ambiguous, thus yields
+    /// `None`.
+    pub fn table(&self, table: &SqlIdent) -> Option<&DboTable> {
+        self.tables.get(table).or_else(|| match table.schema() {
+            Some(_) => self.tables.get(&table.unqualified()),
+            None => {
+                let mut matches = self
+                    .tables
+                    .iter()
+                    .filter(|(name, _)| name.schema().is_some() && name.unqualified() == *table)
+                    .map(|(_, t)| t);
+                match (matches.next(), matches.next()) {
+                    (Some(t), None) => Some(t),
+                    _ => None,
+                }
+            }
+        })
     }
 
     pub fn table_column(&self, table: &SqlIdent, column: &SqlIdent) -> Option<&DboTableColumn> {
-        self.tables.get(table).and_then(|t| t.columns.get(column))
+        self.table(table).and_then(|t| t.columns.get(column))
     }
 }
 
 /// Possible errors that might occur during analyzing.
-#[derive(Debug, Eq, thiserror::Error, PartialEq, Serialize)]
+#[derive(Tsify, Debug, Eq, thiserror::Error, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum AnalyzeError {
     #[error("Language construct unsupported: {0:?}")]
@@ -145,26 +498,288 @@ impl From<ParseError> for AnalyzeError {
     }
 }
 
+/// Analyzer for a single [`DboType`], working on the already parsed syntax tree.
+type AnalyzeFn = fn(Root, &DboAnalyzeContext) -> Result<DboMetaData, AnalyzeError>;
+
 pub fn analyze(
     typ: DboType,
     sql: &str,
-    _ctx: &DboAnalyzeContext,
+    ctx: &DboAnalyzeContext,
 ) -> Result<DboMetaData, AnalyzeError> {
-    let cast_to_root = |p: Parse| {
-        Root::cast(p.syntax())
-            .ok_or_else(|| AnalyzeError::ParseError("failed to find root node".to_owned()))
+    let (parse, analyze_fn) = parse_object(typ, sql, ctx)?;
+    analyze_root(
+        cast_to_root(&parse)?,
+        sql,
+        ctx,
+        analyze_fn,
+        &mut Profiler::disabled(),
+    )
+}
+
+/// Parses `sql` as `typ`, returning the parse along with the analyzer for it.
+fn parse_object(
+    typ: DboType,
+    sql: &str,
+    ctx: &DboAnalyzeContext,
+) -> Result<(Parse, AnalyzeFn), AnalyzeError> {
+    let options = ParseOptions {
+        single_line_literals: ctx.options().single_line_literals,
     };
+    Ok(match typ {
+        DboType::AnonymousBlock => (parse_block_with_options(sql, options)?, analyze_block),
+        DboType::Function => (parse_function_with_options(sql, options)?, analyze_function),
+        DboType::MaterializedView => (
+            parse_materialized_view_with_options(sql, options)?,
+            analyze_materialized_view,
+        ),
+        DboType::Package => (parse_package_with_options(sql, options)?, analyze_package),
+        DboType::Procedure => (
+            parse_procedure_with_options(sql, options)?,
+            analyze_procedure,
+        ),
+        DboType::Query => (parse_query_with_options(sql, options)?, analyze_query),
+        DboType::Table => (parse_table_with_options(sql, options)?, analyze_table),
+        DboType::Trigger => (parse_trigger_with_options(sql, options)?, analyze_trigger),
+        DboType::View => (parse_view_with_options(sql, options)?, analyze_view),
+        _ => return Err(AnalyzeError::Unsupported(typ)),
+    })
+}
+
+fn cast_to_root(parse: &Parse) -> Result<Root, AnalyzeError> {
+    Root::cast(parse.syntax())
+        .ok_or_else(|| AnalyzeError::ParseError("failed to find root node".to_owned()))
+}
+
+/// Runs the object-specific `analyze_fn` and all analyses common to every
+/// object type on an already parsed object, timing each with `profiler`.
+fn analyze_root(
+    root: Root,
+    sql: &str,
+    ctx: &DboAnalyzeContext,
+    analyze_fn: AnalyzeFn,
+    profiler: &mut Profiler,
+) -> Result<DboMetaData, AnalyzeError> {
+    let index = LineIndex::new(sql).with_excerpt_lines(ctx.options().excerpt_lines);
+    let unfiltered_dml = profiler.measure("unfiltered_dml", || find_unfiltered_dml(&root, &index));
+    let record_dml = profiler.measure("record_dml", || find_record_dml(&root, &index));
+    let nested_subprograms = profiler.measure("nested_subprograms", || {
+        find_nested_subprograms(&root, &index)
+    });
+    let required_grants = profiler.measure("required_grants", || find_required_grants(&root));
+    let call_graph = profiler.measure("call_graph", || find_call_graph(&root));
+    let is_recursive = call_graph.iter().any(|e| e.is_recursive);
+    let collection_usage = profiler.measure("collection_usage", || find_collection_usage(&root));
+    let parameter_conflicts = profiler.measure("parameter_conflicts", || {
+        find_parameter_conflicts(&root, ctx, &index)
+    });
+    let swallowed_exceptions = profiler.measure("swallowed_exceptions", || {
+        find_swallowed_exceptions(&root, &index)
+    });
+    let bulk_fetches = profiler.measure("bulk_fetches", || find_bulk_fetches(&root, &index));
+    let fetch_loops = profiler.measure("fetch_loops", || find_fetch_loops(&root, &index));
+    let cases_without_else = profiler.measure("cases_without_else", || {
+        find_cases_without_else(&root, &index)
+    });
+    let bulk_returnings =
+        profiler.measure("bulk_returnings", || find_bulk_returnings(&root, &index));
+    let foralls = profiler.measure("foralls", || find_foralls(&root, &index));
+    let bulk_attributes =
+        profiler.measure("bulk_attributes", || find_bulk_attributes(&root, &index));
+    let cursors = profiler.measure("cursors", || find_cursors(&root, &index));
+    let select_into_mismatches = profiler.measure("select_into_mismatches", || {
+        find_select_into_mismatches(&root, ctx, &index)
+    });
+    let loop_transactions = profiler.measure("loop_transactions", || {
+        find_loop_transactions(&root, &index)
+    });
+    let transaction_routine = profiler.measure("transaction_routine", || {
+        find_transaction_routine(&root, &index)
+    });
+    let scheduled_jobs =
+        profiler.measure("scheduled_jobs", || find_scheduled_jobs(&root, ctx, &index));
+    let correlated_updates = profiler.measure("correlated_updates", || {
+        find_correlated_updates(&root, &index)
+    });
+    let numeric_functions = profiler.measure("numeric_functions", || {
+        find_numeric_functions(&root, &index)
+    });
+    let number_conversions = profiler.measure("number_conversions", || {
+        find_number_conversions(&root, &index)
+    });
+    let precision_warnings = profiler.measure("precision_warnings", || {
+        find_precision_warnings(&root, ctx, &index)
+    });
+    let unresolved_packages = profiler.measure("unresolved_packages", || {
+        find_unresolved_packages(&root, ctx, &index)
+    });
+    let unsafe_outer_joins = profiler.measure("unsafe_outer_joins", || {
+        find_unsafe_outer_joins(&root, &index)
+    });
+    let chr_concatenations = profiler.measure("chr_concatenations", || {
+        find_chr_concatenations(&root, ctx, &index)
+    });
+    let bind_variables = profiler.measure("bind_variables", || find_bind_variables(&root, &index));
+    let pragmas = profiler.measure("pragmas", || find_pragmas(&root, &index));
+    let substitution_variables = profiler.measure("substitution_variables", || {
+        find_substitution_variables(&root, &index)
+    });
+    let renames = profiler.measure("renames", || find_renames(&root, ctx, &index));
+    let line_counts = profiler.measure("line_counts", || count_object_lines(&root));
+    let statement_counts = profiler.measure("statement_counts", || count_statement_kinds(&root));
+    let fingerprint = profiler.measure("fingerprint", || fingerprint_root(&root));
+    let meta_data = profiler.measure("object", || analyze_fn(root, ctx))?;
+
+    Ok(DboMetaData {
+        unfiltered_dml,
+        record_dml,
+        nested_subprograms,
+        required_grants,
+        call_graph,
+        is_recursive,
+        collection_usage,
+        parameter_conflicts,
+        swallowed_exceptions,
+        bulk_fetches,
+        cases_without_else,
+        fetch_loops,
+        bulk_returnings,
+        foralls,
+        bulk_attributes,
+        cursors,
+        select_into_mismatches,
+        loop_transactions,
+        transaction_routine,
+        scheduled_jobs,
+        correlated_updates,
+        numeric_functions,
+        number_conversions,
+        precision_warnings,
+        unresolved_packages,
+        unsafe_outer_joins,
+        chr_concatenations,
+        bind_variables,
+        pragmas,
+        substitution_variables,
+        renames,
+        line_counts,
+        statement_counts,
+        fingerprint,
+        ..meta_data
+    })
+}
+
+/// A single object to analyze as part of a batch, see [`analyze_batch()`].
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboAnalyzeItem {
+    pub typ: DboType,
+    pub sql: String,
+}
+
+/// A list of objects to analyze in one go.
+#[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+pub struct DboAnalyzeBatch(pub Vec<DboAnalyzeItem>);
 
-    match typ {
-        DboType::Function => analyze_function(cast_to_root(parse_function(sql)?)?),
-        DboType::Procedure => analyze_procedure(cast_to_root(parse_procedure(sql)?)?),
-        DboType::Query => analyze_query(cast_to_root(parse_query(sql)?)?),
-        DboType::Trigger => analyze_trigger(cast_to_root(parse_trigger(sql)?)?),
-        DboType::View => analyze_view(cast_to_root(parse_view(sql)?)?),
-        _ => Err(AnalyzeError::Unsupported(typ)),
+/// The outcome of analyzing a single item of a [`DboAnalyzeBatch`], holding
+/// either the metadata or the error.
+#[derive(Tsify, Debug, Eq, PartialEq, Serialize)]
+#[tsify(into_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboAnalyzeResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub meta_data: Option<DboMetaData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub error: Option<AnalyzeError>,
+}
+
+impl From<Result<DboMetaData, AnalyzeError>> for DboAnalyzeResult {
+    fn from(result: Result<DboMetaData, AnalyzeError>) -> Self {
+        match result {
+            Ok(meta_data) => Self {
+                meta_data: Some(meta_data),
+                error: None,
+            },
+            Err(error) => Self {
+                meta_data: None,
+                error: Some(error),
+            },
+        }
     }
 }
 
+/// The results of [`analyze_batch()`], in the same order as the input items.
+#[derive(Tsify, Debug, Default, Eq, PartialEq, Serialize)]
+#[tsify(into_wasm_abi)]
+pub struct DboAnalyzeBatchResult(pub Vec<DboAnalyzeResult>);
+
+/// The progress of [`analyze_batch_with_progress()`], passed to the callback.
+#[derive(Tsify, Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+#[tsify(into_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboBatchProgress {
+    pub total: usize,
+    pub processed: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+/// Analyzes many objects with a shared context. A failure of one item does
+/// not affect the others. Recursion between the analyzed objects is detected
+/// as well.
+pub fn analyze_batch(batch: &DboAnalyzeBatch, ctx: &DboAnalyzeContext) -> DboAnalyzeBatchResult {
+    analyze_batch_with_progress(batch, ctx, 0, |_| {})
+}
+
+/// Same as [`analyze_batch()`], but calls `on_progress` after every
+/// `interval` objects and once more after the last one. An `interval` of `0`
+/// only reports the final count.
+pub fn analyze_batch_with_progress(
+    batch: &DboAnalyzeBatch,
+    ctx: &DboAnalyzeContext,
+    interval: usize,
+    mut on_progress: impl FnMut(DboBatchProgress),
+) -> DboAnalyzeBatchResult {
+    let mut progress = DboBatchProgress {
+        total: batch.0.len(),
+        ..DboBatchProgress::default()
+    };
+
+    let mut results = batch
+        .0
+        .iter()
+        .map(|item| {
+            let result = DboAnalyzeResult::from(analyze(item.typ, &item.sql, ctx));
+
+            progress.processed += 1;
+            if result.error.is_none() {
+                progress.succeeded += 1;
+            } else {
+                progress.failed += 1;
+            }
+            if progress.processed == progress.total
+                || (interval > 0 && progress.processed % interval == 0)
+            {
+                on_progress(progress);
+            }
+
+            result
+        })
+        .collect::<Vec<_>>();
+
+    mark_mutual_recursion(
+        &mut results
+            .iter_mut()
+            .filter_map(|r| r.meta_data.as_mut())
+            .collect::<Vec<_>>(),
+    );
+
+    DboAnalyzeBatchResult(results)
+}
+
 /// WASM export of [`analyze()`]. Should _never_ be called from other Rust code.
 ///
 /// A second, WASM-specific function is required here, as the only allowed [`Result`] type for
@@ -180,3 +795,46 @@ pub fn analyze(
 pub fn js_analyze(typ: DboType, sql: &str, ctx: DboAnalyzeContext) -> Result<DboMetaData, JsValue> {
     analyze(typ, sql, &ctx).or_else(|err| Err(serde_wasm_bindgen::to_value(&err)?))
 }
+
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+#[wasm_bindgen]
+extern "C" {
+    /// A JS function receiving the [`DboBatchProgress`] of a batch analysis.
+    #[wasm_bindgen(typescript_type = "(progress: DboBatchProgress) => void")]
+    pub type DboBatchProgressCallback;
+}
+
+/// Calls the JS `callback`, if any, with the `progress`.
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+pub(crate) fn report_progress(
+    callback: Option<&DboBatchProgressCallback>,
+    progress: DboBatchProgress,
+) {
+    if let (Some(callback), Ok(value)) = (callback, serde_wasm_bindgen::to_value(&progress)) {
+        let _ = callback
+            .unchecked_ref::<js_sys::Function>()
+            .call1(&JsValue::NULL, &value);
+    }
+}
+
+/// WASM export of [`analyze_batch_with_progress()`], which avoids crossing the
+/// JS/WASM boundary for every single object. Should _never_ be called from
+/// other Rust code.
+///
+/// The optional `on_progress` callback receives a [`DboBatchProgress`] every
+/// `progress_interval` objects, defaulting to every object. Exceptions thrown
+/// by the callback are ignored.
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+#[wasm_bindgen(js_name = "analyzeBatch")]
+pub fn js_analyze_batch(
+    batch: DboAnalyzeBatch,
+    ctx: DboAnalyzeContext,
+    on_progress: Option<DboBatchProgressCallback>,
+    progress_interval: Option<u32>,
+) -> DboAnalyzeBatchResult {
+    let interval = progress_interval.unwrap_or(1) as usize;
+
+    analyze_batch_with_progress(&batch, &ctx, interval, |progress| {
+        report_progress(on_progress.as_ref(), progress)
+    })
+}
@@ -0,0 +1,275 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects conversions between numbers and strings depending on Oracle
+//! number format models or NLS parameters.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::grants::{local_routines, normalize_name};
+use crate::analyzer::numeric::operand;
+use crate::ast::{AstNode, FunctionInvocation, Root};
+use crate::util::{LineIndex, SourceLocation};
+
+/// How a `TO_NUMBER` or `TO_CHAR` conversion has to be changed.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboNumberConversionKind {
+    /// `TO_NUMBER` without a format, or with one any cast accepts, e.g. `TM`,
+    /// becomes a cast to `numeric`.
+    Cast,
+    /// `TO_NUMBER` with a format model, which PostgreSQL's `to_number()`
+    /// accepts after translating Oracle-only elements.
+    Format,
+    /// A format model PostgreSQL cannot express, e.g. hexadecimal `XXXX`.
+    UnsupportedFormat,
+    /// `TO_NUMBER` or `TO_CHAR` with NLS parameters, which PostgreSQL does
+    /// not have.
+    NlsParameters,
+    /// `TO_CHAR` of a number with group separators, e.g. `9G999`.
+    Grouping,
+}
+
+/// An invocation of `TO_NUMBER` or `TO_CHAR` whose number formatting differs
+/// in PostgreSQL.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboNumberConversion {
+    /// The function in uppercase, i.e. `TO_NUMBER` or `TO_CHAR`.
+    pub function: String,
+    pub kind: DboNumberConversionKind,
+    /// The equivalent PostgreSQL expression, if there is one.
+    #[tsify(optional)]
+    pub replacement: Option<String>,
+    /// What to check or change by hand.
+    pub hint: String,
+    pub location: SourceLocation,
+}
+
+/// The result of translating a format model to PostgreSQL.
+enum Translation {
+    Cast,
+    Format(String),
+    Unsupported(&'static str),
+}
+
+/// Finds all invocations of `TO_NUMBER`, and of `TO_CHAR` with a number
+/// format, in source order. `TO_CHAR` of dates and without a format is not
+/// reported.
+pub(super) fn find_number_conversions(root: &Root, index: &LineIndex) -> Vec<DboNumberConversion> {
+    let local_routines = local_routines(root);
+
+    root.syntax()
+        .descendants()
+        .filter_map(FunctionInvocation::cast)
+        .filter_map(|invocation| {
+            let name = normalize_name(invocation.ident()?.name()?);
+            if local_routines.contains(&name) {
+                return None;
+            }
+
+            let values = invocation
+                .arguments()?
+                .iter()
+                .map(|arg| arg.value())
+                .collect::<Vec<_>>();
+            let (kind, replacement, hint) = match (name.as_str(), values.as_slice()) {
+                ("to_number", [x]) => (
+                    DboNumberConversionKind::Cast,
+                    Some(format!("{}::numeric", operand(x))),
+                    "PostgreSQL only accepts `.` as the decimal separator when casting".to_string(),
+                ),
+                ("to_number", [x, format]) => map_to_number(x, format),
+                ("to_number" | "to_char", [_, format, _]) if is_number_format(format) => (
+                    DboNumberConversionKind::NlsParameters,
+                    None,
+                    "PostgreSQL has no NLS parameters, `D` and `G` follow `lc_numeric` instead. \
+                     Replace the separators in the string or use `.` and `,` in the format"
+                        .to_string(),
+                ),
+                ("to_number", [_, _, _]) => (
+                    DboNumberConversionKind::NlsParameters,
+                    None,
+                    "PostgreSQL has no NLS parameters, check the format for `D` and `G`, which \
+                     follow `lc_numeric` instead"
+                        .to_string(),
+                ),
+                ("to_char", [_, format]) if is_number_format(format) && has_grouping(format) => (
+                    DboNumberConversionKind::Grouping,
+                    None,
+                    "`G` follows `lc_numeric` instead of `NLS_NUMERIC_CHARACTERS`, check the \
+                     group and decimal separators of the result"
+                        .to_string(),
+                ),
+                _ => return None,
+            };
+
+            Some(DboNumberConversion {
+                function: name.to_uppercase(),
+                kind,
+                replacement,
+                hint,
+                location: SourceLocation::new(invocation.syntax().text_range(), index),
+            })
+        })
+        .collect()
+}
+
+fn map_to_number(x: &str, format: &str) -> (DboNumberConversionKind, Option<String>, String) {
+    let Some(model) = format_model(format) else {
+        return (
+            DboNumberConversionKind::Format,
+            Some(format!("to_number({x}, {format})")),
+            "The format is not a literal, check it for Oracle-only elements like `X`, `C` or `TM`"
+                .to_string(),
+        );
+    };
+
+    match translate_format(model) {
+        Translation::Cast => (
+            DboNumberConversionKind::Cast,
+            Some(format!("{}::numeric", operand(x))),
+            "PostgreSQL only accepts `.` as the decimal separator when casting".to_string(),
+        ),
+        Translation::Format(model) => (
+            DboNumberConversionKind::Format,
+            Some(format!("to_number({x}, '{model}')")),
+            "`D` and `G` follow `lc_numeric` instead of `NLS_NUMERIC_CHARACTERS`".to_string(),
+        ),
+        Translation::Unsupported(hint) => (
+            DboNumberConversionKind::UnsupportedFormat,
+            None,
+            hint.to_string(),
+        ),
+    }
+}
+
+/// Translates the Oracle-only elements of a number format model. Currency
+/// symbols become the local one, `B` is dropped as it only affects output,
+/// and `TM` and `EEEE` are accepted by a plain cast.
+fn translate_format(model: &str) -> Translation {
+    let upper = model.to_uppercase();
+    if upper.starts_with("TM") || upper.contains("EEEE") {
+        return Translation::Cast;
+    }
+    if upper.contains('X') {
+        return Translation::Unsupported(
+            "PostgreSQL has no hexadecimal format, convert with e.g. \
+             `('x' || lpad(x, 16, '0'))::bit(64)::bigint`",
+        );
+    }
+
+    let translated = model
+        .chars()
+        .filter_map(|c| match c.to_ascii_uppercase() {
+            'C' | 'U' => Some('L'),
+            'B' => None,
+            _ => Some(c),
+        })
+        .collect();
+    Translation::Format(translated)
+}
+
+/// Returns the content of a string literal, i.e. a format model written as
+/// is.
+fn format_model(format: &str) -> Option<&str> {
+    format
+        .strip_prefix('\'')?
+        .strip_suffix('\'')
+        .filter(|model| !model.contains('\''))
+}
+
+/// Whether the literal looks like a number format model, i.e. has digits and
+/// no date format elements.
+fn is_number_format(format: &str) -> bool {
+    format_model(format).is_some_and(|model| {
+        let upper = model.to_uppercase();
+        upper.contains(['9', '0']) && upper.chars().all(|c| "90,.$GDLCUSBVEXMIPRFT ".contains(c))
+    })
+}
+
+fn has_grouping(format: &str) -> bool {
+    format_model(format).is_some_and(|model| model.contains([',', 'G', 'g']))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_find_number_conversions() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE import_price(p_text VARCHAR2) IS
+    l_value NUMBER;
+    l_text VARCHAR2(40);
+BEGIN
+    l_value := TO_NUMBER(p_text) + TO_NUMBER(p_text, 'TM');
+    l_value := TO_NUMBER(p_text, 'C9G999D99B');
+    l_value := TO_NUMBER(p_text, 'XXXX');
+    l_value := TO_NUMBER(p_text, '9D99', 'NLS_NUMERIC_CHARACTERS = '',.''');
+    l_text := TO_CHAR(l_value, '9,999.99') || TO_CHAR(l_value, 'FM990D00');
+    l_text := TO_CHAR(SYSDATE, 'DD.MM.YYYY') || TO_CHAR(l_value);
+END import_price;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let conversions = result
+            .unwrap()
+            .number_conversions
+            .into_iter()
+            .map(|c| (c.function, c.kind, c.replacement, c.location.start.line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            conversions,
+            vec![
+                (
+                    "TO_NUMBER".to_string(),
+                    DboNumberConversionKind::Cast,
+                    Some("p_text::numeric".to_string()),
+                    5
+                ),
+                (
+                    "TO_NUMBER".to_string(),
+                    DboNumberConversionKind::Cast,
+                    Some("p_text::numeric".to_string()),
+                    5
+                ),
+                (
+                    "TO_NUMBER".to_string(),
+                    DboNumberConversionKind::Format,
+                    Some("to_number(p_text, 'L9G999D99')".to_string()),
+                    6
+                ),
+                (
+                    "TO_NUMBER".to_string(),
+                    DboNumberConversionKind::UnsupportedFormat,
+                    None,
+                    7
+                ),
+                (
+                    "TO_NUMBER".to_string(),
+                    DboNumberConversionKind::NlsParameters,
+                    None,
+                    8
+                ),
+                (
+                    "TO_CHAR".to_string(),
+                    DboNumberConversionKind::Grouping,
+                    None,
+                    9
+                ),
+            ]
+        );
+    }
+}
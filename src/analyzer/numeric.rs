@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects numeric builtins behaving differently in PostgreSQL.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::function::{SEQUENCE_PSEUDOCOLUMNS, VOLATILE_BUILTINS};
+use crate::analyzer::grants::{local_routines, normalize_name};
+use crate::ast::{Argument, AstNode, FunctionInvocation, IdentGroup, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::{SyntaxElement, SyntaxKind};
+
+/// Why a numeric builtin cannot be kept as is.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboNumericMapping {
+    /// PostgreSQL only has the function for `numeric` arguments, e.g.
+    /// `TRUNC(x, 2)` or `ROUND(x, -2)`, which fail for `double precision`.
+    NumericCast,
+    /// PostgreSQL has no such function, e.g. `BITAND(a, b)` becomes `a & b`.
+    Rewrite,
+    /// `MOD` with a divisor which may be zero. Oracle then returns the
+    /// dividend, PostgreSQL raises `division_by_zero`. Both take the sign of
+    /// the dividend otherwise, e.g. `MOD(-11, 4)` is `-3`.
+    ZeroDivisor,
+}
+
+/// An invocation of `MOD`, `REMAINDER`, `TRUNC`, `ROUND` or `BITAND` which has
+/// to be changed for PostgreSQL.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboNumericFunction {
+    /// The function in uppercase, e.g. `BITAND`.
+    pub function: String,
+    pub mapping: DboNumericMapping,
+    /// The equivalent PostgreSQL expression, built from the arguments, if
+    /// there is one.
+    #[tsify(optional)]
+    pub replacement: Option<String>,
+    /// What to change by hand, if there is no replacement.
+    #[tsify(optional)]
+    pub hint: Option<String>,
+    pub location: SourceLocation,
+}
+
+/// Finds all invocations of numeric builtins which differ in PostgreSQL, in
+/// source order. The decision depends on the arguments, e.g. `TRUNC` is only
+/// reported with a number of digits, as it truncates dates with a format, and
+/// `MOD` is only reported if the divisor is not a non-zero number.
+pub(super) fn find_numeric_functions(root: &Root, index: &LineIndex) -> Vec<DboNumericFunction> {
+    let local_routines = local_routines(root);
+
+    root.syntax()
+        .descendants()
+        .filter_map(FunctionInvocation::cast)
+        .filter_map(|invocation| {
+            let name = normalize_name(invocation.ident()?.name()?);
+            if local_routines.contains(&name) {
+                return None;
+            }
+
+            let args = invocation.arguments()?;
+            let (mapping, replacement, hint) = map_numeric_function(&name, &args)?;
+            Some(DboNumericFunction {
+                function: name.to_uppercase(),
+                mapping,
+                replacement,
+                hint,
+                location: SourceLocation::new(invocation.syntax().text_range(), index),
+            })
+        })
+        .collect()
+}
+
+fn map_numeric_function(
+    name: &str,
+    args: &[Argument],
+) -> Option<(DboNumericMapping, Option<String>, Option<String>)> {
+    let values = args.iter().map(Argument::value).collect::<Vec<_>>();
+
+    let (mapping, replacement) = match (name, values.as_slice()) {
+        ("bitand", [a, b]) => (
+            DboNumericMapping::Rewrite,
+            format!("{} & {}", operand(a), operand(b)),
+        ),
+        // The replacement repeats both arguments, which must thus not be
+        // evaluated twice
+        ("mod", [x, y]) if !number(y).is_some_and(|y| y != 0.0) => {
+            if !args.iter().all(is_plain) {
+                return Some((
+                    DboNumericMapping::ZeroDivisor,
+                    None,
+                    Some(
+                        "Assign the arguments to variables first, or make sure the divisor is \
+                         never zero and keep `mod()`"
+                            .to_string(),
+                    ),
+                ));
+            }
+            (
+                DboNumericMapping::ZeroDivisor,
+                format!("CASE WHEN {y} = 0 THEN {x} ELSE mod({x}, {y}) END"),
+            )
+        }
+        // Oracle takes the integer nearest to `x / y` and the even one on
+        // ties, `round()` rounds those away from zero. The division has to be
+        // of `numeric`, as integers would be truncated.
+        ("remainder", [x, y]) => {
+            let (x, y) = (operand(x), operand(y));
+            (
+                DboNumericMapping::Rewrite,
+                format!(
+                    "{x} - {y} * CASE WHEN abs(mod({x}::numeric, {y})) * 2 = abs({y}) \
+                     THEN 2 * round({x}::numeric / {y} / 2) ELSE round({x}::numeric / {y}) END"
+                ),
+            )
+        }
+        ("round" | "trunc", [x, digits]) if number(digits).is_some() => (
+            DboNumericMapping::NumericCast,
+            format!("{name}({}::numeric, {digits})", operand(x)),
+        ),
+        _ => return None,
+    };
+
+    Some((mapping, Some(replacement), None))
+}
+
+/// Returns whether an argument is a column, variable or literal, which can be
+/// repeated without evaluating anything twice.
+fn is_plain(arg: &Argument) -> bool {
+    let mut element = arg.expression();
+    // Unwrap expressions consisting of a single operand
+    while let Some(SyntaxElement::Node(node)) = &element {
+        if node.kind() != SyntaxKind::Expression {
+            break;
+        }
+        let mut children = node.children_with_tokens().filter(|it| {
+            !matches!(
+                it.kind(),
+                SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+            )
+        });
+        element = match (children.next(), children.next()) {
+            (Some(only), None) => Some(only),
+            _ => return false,
+        };
+    }
+
+    match element {
+        Some(SyntaxElement::Token(token)) => matches!(
+            token.kind(),
+            SyntaxKind::Integer | SyntaxKind::Decimal | SyntaxKind::QuotedLiteral
+        ),
+        Some(SyntaxElement::Node(node)) => IdentGroup::cast(node).is_some_and(|group| {
+            let is_builtin = group
+                .nth(0)
+                .is_some_and(|i| VOLATILE_BUILTINS.contains(&i.text().to_lowercase().as_str()));
+            let is_sequence = group.qualifier().is_some()
+                && group.last().is_some_and(|i| {
+                    SEQUENCE_PSEUDOCOLUMNS.contains(&i.text().to_lowercase().as_str())
+                });
+            !is_builtin && !is_sequence
+        }),
+        None => false,
+    }
+}
+
+/// Returns the value of a numeric literal, e.g. `-2`.
+fn number(value: &str) -> Option<f64> {
+    value.replace(' ', "").parse().ok()
+}
+
+/// Parenthesizes an argument unless it is a single identifier or literal.
+pub(super) fn operand(value: &str) -> String {
+    let is_atom = value
+        .chars()
+        .all(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '#' | '.' | ':' | '"'));
+    if is_atom {
+        value.to_string()
+    } else {
+        format!("({value})")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_find_numeric_functions() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE bucket(p_amount NUMBER, p_size NUMBER, p_flags NUMBER) IS
+    l_value NUMBER;
+    l_unit VARCHAR2(2) := 'MM';
+BEGIN
+    l_value := MOD(p_amount, 10) + MOD(p_amount, p_size) + MOD(p_amount, next_size(p_size));
+    l_value := REMAINDER(p_amount + 1, p_size);
+    l_value := TRUNC(p_amount, 2) + TRUNC(SYSDATE, 'MM') - TRUNC(p_amount);
+    l_value := SYSDATE - TRUNC(SYSDATE, l_unit) + REMAINDER(7, 2);
+    l_value := ROUND(p_amount, -3) + ROUND(p_amount, 2);
+    IF BITAND(p_flags, 4) = 4 THEN
+        NULL;
+    END IF;
+END bucket;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let numeric_functions = result.unwrap().numeric_functions;
+        assert!(numeric_functions[1].hint.is_some());
+        assert!(numeric_functions
+            .iter()
+            .all(|f| f.hint.is_some() != f.replacement.is_some()));
+
+        let functions = numeric_functions
+            .into_iter()
+            .map(|f| {
+                (
+                    f.function,
+                    f.mapping,
+                    f.replacement.unwrap_or_default(),
+                    f.location.start.line,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            functions,
+            vec![
+                (
+                    "MOD".to_string(),
+                    DboNumericMapping::ZeroDivisor,
+                    "CASE WHEN p_size = 0 THEN p_amount ELSE mod(p_amount, p_size) END".to_string(),
+                    5
+                ),
+                ("MOD".to_string(), DboNumericMapping::ZeroDivisor, String::new(), 5),
+                (
+                    "REMAINDER".to_string(),
+                    DboNumericMapping::Rewrite,
+                    "(p_amount + 1) - p_size * CASE WHEN abs(mod((p_amount + 1)::numeric, p_size)) \
+                     * 2 = abs(p_size) THEN 2 * round((p_amount + 1)::numeric / p_size / 2) \
+                     ELSE round((p_amount + 1)::numeric / p_size) END"
+                        .to_string(),
+                    6
+                ),
+                (
+                    "TRUNC".to_string(),
+                    DboNumericMapping::NumericCast,
+                    "trunc(p_amount::numeric, 2)".to_string(),
+                    7
+                ),
+                (
+                    "REMAINDER".to_string(),
+                    DboNumericMapping::Rewrite,
+                    "7 - 2 * CASE WHEN abs(mod(7::numeric, 2)) * 2 = abs(2) \
+                     THEN 2 * round(7::numeric / 2 / 2) ELSE round(7::numeric / 2) END"
+                        .to_string(),
+                    8
+                ),
+                (
+                    "ROUND".to_string(),
+                    DboNumericMapping::NumericCast,
+                    "round(p_amount::numeric, -3)".to_string(),
+                    9
+                ),
+                (
+                    "ROUND".to_string(),
+                    DboNumericMapping::NumericCast,
+                    "round(p_amount::numeric, 2)".to_string(),
+                    9
+                ),
+                (
+                    "BITAND".to_string(),
+                    DboNumericMapping::Rewrite,
+                    "p_flags & 4".to_string(),
+                    10
+                ),
+            ]
+        );
+    }
+}
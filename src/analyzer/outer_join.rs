@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects Oracle-style outer joins which cannot be rewritten to ANSI joins
+//! automatically.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
+
+/// Why an outer join predicate must not be moved into a `LEFT JOIN .. ON`.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboOuterJoinConflict {
+    /// The predicate is an operand of `OR`, e.g. `b.x(+) = 1 OR b.y(+) = 2`.
+    Or,
+    /// The predicate compares with an `IN` list, e.g. `b.x(+) IN (1, 2)`.
+    In,
+}
+
+/// A predicate with the `(+)` outer join operator, which has to be rewritten
+/// to an ANSI join by hand. These are errors, as a mechanical rewrite would
+/// silently return other rows.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboUnsafeOuterJoin {
+    pub conflict: DboOuterJoinConflict,
+    /// The predicate as written, e.g. `b.x(+) IN (1, 2)`.
+    pub predicate: String,
+    /// Why the predicate cannot be rewritten as is.
+    pub explanation: String,
+    pub location: SourceLocation,
+}
+
+/// Finds all predicates combining `(+)` with `OR` or `IN`, in source order.
+pub(super) fn find_unsafe_outer_joins(root: &Root, index: &LineIndex) -> Vec<DboUnsafeOuterJoin> {
+    let mut joins: Vec<DboUnsafeOuterJoin> = Vec::new();
+
+    for token in root
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Keyword && t.text() == "(+)")
+    {
+        let Some(predicate) = token
+            .parent()
+            .filter(|p| p.kind() == SyntaxKind::Expression)
+        else {
+            continue;
+        };
+        let Some(conflict) = conflict(&predicate) else {
+            continue;
+        };
+
+        let location = SourceLocation::new(predicate.text_range(), index);
+        if joins.last().is_some_and(|j| j.location == location) {
+            continue;
+        }
+        joins.push(DboUnsafeOuterJoin {
+            conflict,
+            predicate: predicate.text().to_string(),
+            explanation: explanation(conflict).to_string(),
+            location,
+        });
+    }
+    joins
+}
+
+/// Returns the conflict of a predicate containing `(+)`, if any. `IN` takes
+/// precedence, as it needs a rewrite even without `OR`.
+fn conflict(predicate: &SyntaxNode) -> Option<DboOuterJoinConflict> {
+    let tokens = |node: &SyntaxNode| {
+        node.children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .collect::<Vec<SyntaxToken>>()
+    };
+
+    if tokens(predicate)
+        .iter()
+        .any(|t| t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("in"))
+    {
+        return Some(DboOuterJoinConflict::In);
+    }
+
+    predicate
+        .ancestors()
+        .take_while(|node| node.kind() == SyntaxKind::Expression)
+        .any(|node| {
+            tokens(&node)
+                .iter()
+                .any(|t| t.kind() == SyntaxKind::LogicOp && t.text().eq_ignore_ascii_case("or"))
+        })
+        .then_some(DboOuterJoinConflict::Or)
+}
+
+fn explanation(conflict: DboOuterJoinConflict) -> &'static str {
+    match conflict {
+        DboOuterJoinConflict::Or => {
+            "The outer join depends on the other operand of `OR`. Moving the disjunction into \
+             `LEFT JOIN .. ON` changes which rows are null-extended, so decide whether it \
+             filters the joined or the resulting rows."
+        }
+        DboOuterJoinConflict::In => {
+            "The `IN` list only filters the joined rows. Put it into `LEFT JOIN .. ON` to keep \
+             unmatched rows, moving it into `WHERE` drops them instead."
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_find_unsafe_outer_joins() {
+        const INPUT: &str = r#"
+SELECT o.id, c.name
+  FROM orders o, customers c, regions r
+ WHERE o.customer_id = c.id(+)
+   AND (c.status(+) = 'active' OR c.vip(+) = 1)
+   AND c.region_id = r.id(+)
+   AND r.code(+) IN ('EU', 'US');
+"#;
+        let result = analyze(DboType::Query, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let joins = result.unwrap().unsafe_outer_joins;
+        assert_eq!(
+            joins
+                .iter()
+                .map(|j| (j.conflict, j.predicate.as_str(), j.location.start.line))
+                .collect::<Vec<_>>(),
+            vec![
+                (DboOuterJoinConflict::Or, "c.status(+) = 'active'", 4),
+                (DboOuterJoinConflict::Or, "c.vip(+) = 1", 4),
+                (DboOuterJoinConflict::In, "r.code(+) IN ('EU', 'US')", 6),
+            ]
+        );
+        assert!(joins[2].explanation.contains("LEFT JOIN .. ON"));
+    }
+}
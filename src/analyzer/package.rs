@@ -0,0 +1,406 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Validates that a package specification and body fit together.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::grants::normalize_name;
+use crate::analyzer::subprogram::DboSubprogramKind;
+use crate::analyzer::{AnalyzeError, DboAnalyzeContext, DboMetaData};
+use crate::ast::{AstNode, Datatype, IdentGroup, Param, ParamList, Root};
+use crate::parser::parse_package;
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::{SyntaxElement, SyntaxKind};
+
+/// The kind of a mismatch between a package specification and its body.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboPackageMismatchKind {
+    /// The subprogram is declared in the specification, but not implemented
+    /// in the body.
+    MissingBody,
+    /// The subprogram is only defined in the body, thus private to the
+    /// package. PostgreSQL has no private functions, so it becomes visible
+    /// after the migration.
+    BodyOnly,
+    /// The parameters or return type differ between the specification and
+    /// the body.
+    SignatureMismatch,
+}
+
+/// A subprogram of a package, which is not declared and implemented
+/// consistently.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboPackageMismatch {
+    pub kind: DboPackageMismatchKind,
+    pub subprogram_kind: DboSubprogramKind,
+    /// The name of the subprogram, lowercased unless quoted.
+    pub name: String,
+    /// The location of the declaration in the specification, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub spec_location: Option<SourceLocation>,
+    /// The location of the implementation in the body, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub body_location: Option<SourceLocation>,
+}
+
+/// The result of [`validate_package()`].
+#[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboPackageValidation {
+    /// All mismatches, in the order of the specification, followed by
+    /// subprograms only defined in the body.
+    pub mismatches: Vec<DboPackageMismatch>,
+}
+
+/// A subprogram declared or defined in a package.
+struct Subprogram {
+    kind: DboSubprogramKind,
+    name: String,
+    signature: String,
+    location: SourceLocation,
+}
+
+/// Checks that every subprogram declared in the package specification is
+/// implemented in the body with the same signature and reports subprograms
+/// only defined in the body. Overloaded subprograms are matched by their
+/// signatures.
+pub fn validate_package(spec: &str, body: &str) -> Result<DboPackageValidation, AnalyzeError> {
+    let spec_entries = subprograms(spec, false)?;
+    let mut body_entries = subprograms(body, true)?
+        .into_iter()
+        .map(Some)
+        .collect::<Vec<_>>();
+
+    let mut take_body = |entry: &Subprogram, same_signature: bool| {
+        body_entries
+            .iter_mut()
+            .find(|other| {
+                other.as_ref().is_some_and(|other| {
+                    other.kind == entry.kind
+                        && other.name == entry.name
+                        && (!same_signature || other.signature == entry.signature)
+                })
+            })
+            .and_then(Option::take)
+    };
+
+    // Match identical signatures first, so overloads are paired correctly
+    let pairs = spec_entries
+        .into_iter()
+        .map(|entry| {
+            let implementation = take_body(&entry, true);
+            (entry, implementation)
+        })
+        .collect::<Vec<_>>();
+
+    let mut mismatches = Vec::new();
+    for (entry, implementation) in pairs {
+        if implementation.is_some() {
+            continue;
+        }
+
+        let implementation = take_body(&entry, false);
+        mismatches.push(DboPackageMismatch {
+            kind: if implementation.is_some() {
+                DboPackageMismatchKind::SignatureMismatch
+            } else {
+                DboPackageMismatchKind::MissingBody
+            },
+            subprogram_kind: entry.kind,
+            name: entry.name,
+            spec_location: Some(entry.location),
+            body_location: implementation.map(|i| i.location),
+        });
+    }
+
+    mismatches.extend(
+        body_entries
+            .into_iter()
+            .flatten()
+            .map(|entry| DboPackageMismatch {
+                kind: DboPackageMismatchKind::BodyOnly,
+                subprogram_kind: entry.kind,
+                name: entry.name,
+                spec_location: None,
+                body_location: Some(entry.location),
+            }),
+    );
+
+    Ok(DboPackageValidation { mismatches })
+}
+
+/// WASM export of [`validate_package()`]. Should _never_ be called from other
+/// Rust code.
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+#[wasm_bindgen(js_name = "validatePackage")]
+pub fn js_validate_package(spec: &str, body: &str) -> Result<DboPackageValidation, JsValue> {
+    validate_package(spec, body).or_else(|err| Err(serde_wasm_bindgen::to_value(&err)?))
+}
+
+/// Analyzes a package specification or body. Packages have no metadata of
+/// their own, only the analyses common to all objects apply.
+pub(super) fn analyze_package(
+    root: Root,
+    _ctx: &DboAnalyzeContext,
+) -> Result<DboMetaData, AnalyzeError> {
+    root.package()
+        .ok_or_else(|| AnalyzeError::ParseError("failed to find package".to_owned()))?;
+
+    Ok(DboMetaData::default())
+}
+
+/// Parses a package and collects its subprograms. Forward declarations in
+/// package bodies are skipped.
+fn subprograms(sql: &str, expect_body: bool) -> Result<Vec<Subprogram>, AnalyzeError> {
+    let parse = parse_package(sql)?;
+    let package = Root::cast(parse.syntax())
+        .and_then(|root| root.package())
+        .ok_or_else(|| AnalyzeError::ParseError("failed to find package node".to_owned()))?;
+
+    let kind_name = |is_body| {
+        if is_body {
+            "package body"
+        } else {
+            "package specification"
+        }
+    };
+    if package.is_body() != expect_body {
+        return Err(AnalyzeError::NodeError(
+            kind_name(expect_body).to_owned(),
+            kind_name(package.is_body()).to_owned(),
+        ));
+    }
+
+    let index = LineIndex::new(sql);
+    let functions = package.functions().filter_map(|f| {
+        if expect_body && f.body().is_none() && f.call_spec().is_none() {
+            return None;
+        }
+        let header = f.header()?;
+        Some((
+            DboSubprogramKind::Function,
+            header.identifier()?,
+            signature(header.param_list(), header.return_type()),
+            f.syntax().text_range(),
+        ))
+    });
+    let procedures = package.procedures().filter_map(|p| {
+        if expect_body && p.body().is_none() && p.call_spec().is_none() {
+            return None;
+        }
+        let header = p.header()?;
+        Some((
+            DboSubprogramKind::Procedure,
+            header.identifier()?,
+            signature(header.param_list(), None),
+            p.syntax().text_range(),
+        ))
+    });
+
+    let mut entries = functions
+        .chain(procedures)
+        .filter_map(|(kind, ident, signature, range)| {
+            Some(Subprogram {
+                kind,
+                name: unqualified_name(&ident)?,
+                signature,
+                location: SourceLocation::new(range, &index),
+            })
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by_key(|entry| entry.location.offset_range().start);
+
+    Ok(entries)
+}
+
+fn unqualified_name(ident: &IdentGroup) -> Option<String> {
+    Some(normalize_name(ident.last()?.text()))
+}
+
+/// Returns a normalized representation of the parameters and return type,
+/// ignoring case and whitespace. An omitted mode equals `IN`, and `:=`
+/// equals `DEFAULT`.
+fn signature(params: Option<ParamList>, return_type: Option<Datatype>) -> String {
+    let params = params
+        .map(|list| list.params())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|param| normalize_param(&param))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    match return_type {
+        Some(datatype) => format!(
+            "({params}) RETURN {}",
+            normalize_text(datatype.syntax().text().to_string())
+        ),
+        None => format!("({params})"),
+    }
+}
+
+fn normalize_param(param: &Param) -> String {
+    let parts = param
+        .syntax()
+        .children_with_tokens()
+        .filter(|it| {
+            !matches!(
+                it.kind(),
+                SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+            )
+        })
+        .map(|it| match it {
+            SyntaxElement::Token(token) if token.kind() == SyntaxKind::Assign => {
+                "default".to_owned()
+            }
+            SyntaxElement::Token(token) => token.text().to_lowercase(),
+            SyntaxElement::Node(node) => normalize_text(node.text().to_string()),
+        })
+        .collect::<Vec<_>>();
+
+    parts
+        .iter()
+        .enumerate()
+        .filter(|(i, part)| *part != "in" || parts.get(i + 1).is_some_and(|next| next == "out"))
+        .map(|(_, part)| part.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn normalize_text(text: String) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const SPEC: &str = r#"
+CREATE OR REPLACE PACKAGE hr.emp_api AS
+    FUNCTION salary(p_id NUMBER) RETURN NUMBER;
+    FUNCTION salary(p_name VARCHAR2) RETURN NUMBER;
+    PROCEDURE hire(p_name VARCHAR2, p_dept NUMBER);
+    PROCEDURE fire(p_id NUMBER);
+END emp_api;
+"#;
+
+    const BODY: &str = r#"
+CREATE OR REPLACE PACKAGE BODY hr.emp_api AS
+    PROCEDURE log_it(p_msg VARCHAR2);
+
+    function SALARY(p_name  varchar2) return NUMBER IS
+    BEGIN
+        RETURN 0;
+    END salary;
+
+    FUNCTION salary(p_id NUMBER) RETURN NUMBER IS
+    BEGIN
+        RETURN 1;
+    END salary;
+
+    PROCEDURE hire(p_name VARCHAR2, p_dept VARCHAR2) IS
+    BEGIN
+        log_it(p_name);
+    END hire;
+
+    PROCEDURE log_it(p_msg VARCHAR2) IS
+    BEGIN
+        NULL;
+    END log_it;
+END emp_api;
+"#;
+
+    #[test]
+    fn test_validate_package() {
+        let result = validate_package(SPEC, BODY);
+        assert!(result.is_ok(), "{result:#?}");
+
+        let mismatches = result
+            .unwrap()
+            .mismatches
+            .into_iter()
+            .map(|m| {
+                (
+                    m.kind,
+                    m.subprogram_kind,
+                    m.name,
+                    m.spec_location.map(|l| l.start.line),
+                    m.body_location.map(|l| l.start.line),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            mismatches,
+            vec![
+                (
+                    DboPackageMismatchKind::SignatureMismatch,
+                    DboSubprogramKind::Procedure,
+                    "hire".to_string(),
+                    Some(4),
+                    Some(14),
+                ),
+                (
+                    DboPackageMismatchKind::MissingBody,
+                    DboSubprogramKind::Procedure,
+                    "fire".to_string(),
+                    Some(5),
+                    None,
+                ),
+                (
+                    DboPackageMismatchKind::BodyOnly,
+                    DboSubprogramKind::Procedure,
+                    "log_it".to_string(),
+                    None,
+                    Some(19),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_package_equivalent_signatures() {
+        const SPEC: &str = r#"
+CREATE PACKAGE pay AS
+    PROCEDURE raise_salary(p_id IN NUMBER, p_pct NUMBER := 10, p_new IN OUT NUMBER);
+END pay;
+"#;
+        const BODY: &str = r#"
+CREATE PACKAGE BODY pay AS
+    PROCEDURE raise_salary(p_id NUMBER, p_pct IN NUMBER DEFAULT 10, p_new IN OUT NUMBER) IS
+    BEGIN
+        NULL;
+    END raise_salary;
+END pay;
+"#;
+        let result = validate_package(SPEC, BODY);
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(result.unwrap().mismatches, vec![]);
+    }
+
+    #[test]
+    fn test_validate_package_swapped() {
+        assert_eq!(
+            validate_package(BODY, SPEC),
+            Err(AnalyzeError::NodeError(
+                "package specification".to_owned(),
+                "package body".to_owned()
+            ))
+        );
+    }
+}
@@ -0,0 +1,390 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Detects parameters named like columns of the tables they are used with.
+
+use std::collections::BTreeSet;
+
+use rowan::Direction;
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::references::declaring_scope;
+use crate::analyzer::rename::statement_tables;
+use crate::analyzer::DboAnalyzeContext;
+use crate::ast::{AstNode, AstToken, Function, Ident, IdentGroup, ParamList, Procedure, Root};
+use crate::util::{LineIndex, SourceLocation};
+use crate::SqlIdent;
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
+
+/// A parameter with the same name as a column of a table referenced in the
+/// routine. PL/pgSQL raises an error for such ambiguous references, so the
+/// parameter has to be renamed.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboParameterConflict {
+    pub routine: String,
+    pub parameter: String,
+    pub table: String,
+    pub location: SourceLocation,
+}
+
+/// Finds all parameters of the analyzed object and its nested subprograms,
+/// which are named like a column of a table used in the same routine, in
+/// source order. Only tables known to the context are taken into account.
+pub(super) fn find_parameter_conflicts(
+    root: &Root,
+    ctx: &DboAnalyzeContext,
+    index: &LineIndex,
+) -> Vec<DboParameterConflict> {
+    conflicts(root, ctx)
+        .into_iter()
+        .map(|conflict| DboParameterConflict {
+            routine: conflict
+                .routine_name
+                .unwrap_or_else(|| "<unknown>".to_string()),
+            parameter: conflict.parameter,
+            table: conflict.table,
+            location: SourceLocation::new(conflict.ident.syntax().text_range(), index),
+        })
+        .collect()
+}
+
+/// Returns the edits renaming the parameters [`find_parameter_conflicts()`]
+/// reports to `prefix` followed by their name, along with all references to
+/// them in their routine. References in queries and DML statements on a table
+/// with a column of that name are left alone, as Oracle resolves them to the
+/// column. Quoted parameters and those whose new name is already used in the
+/// routine are skipped.
+pub(super) fn rename_conflicting_parameters(
+    root: &Root,
+    ctx: &DboAnalyzeContext,
+    prefix: &str,
+    index: &LineIndex,
+) -> Vec<(String, SourceLocation)> {
+    let mut edits = Vec::new();
+
+    for conflict in conflicts(root, ctx) {
+        if conflict.parameter.starts_with('"') {
+            continue;
+        }
+        let parameter = SqlIdent::from(conflict.parameter.as_str());
+        let renamed = format!("{prefix}{}", conflict.parameter);
+
+        let idents = conflict
+            .routine
+            .descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter_map(Ident::cast)
+            .collect::<Vec<_>>();
+        if idents
+            .iter()
+            .any(|ident| SqlIdent::from(ident.text()) == SqlIdent::from(renamed.as_str()))
+        {
+            continue;
+        }
+
+        edits.extend(
+            idents
+                .iter()
+                .filter(|ident| SqlIdent::from(ident.text()) == parameter)
+                .filter(|ident| refers_to_parameter(ident, &conflict, &parameter, ctx))
+                .map(|ident| {
+                    (
+                        renamed.clone(),
+                        SourceLocation::new(ident.syntax().text_range(), index),
+                    )
+                }),
+        );
+    }
+
+    edits.sort_by_key(|(_, location)| location.start_offset);
+    edits
+}
+
+/// A parameter named like a column, see [`find_parameter_conflicts()`].
+struct Conflict {
+    routine: SyntaxNode,
+    routine_name: Option<String>,
+    ident: IdentGroup,
+    parameter: String,
+    table: String,
+}
+
+fn conflicts(root: &Root, ctx: &DboAnalyzeContext) -> Vec<Conflict> {
+    let mut conflicts = Vec::new();
+
+    for node in root.syntax().descendants() {
+        let (routine_name, params) = if let Some(function) = Function::cast(node.clone()) {
+            (
+                function.name(),
+                function.header().and_then(|h| h.param_list()),
+            )
+        } else if let Some(procedure) = Procedure::cast(node.clone()) {
+            (
+                procedure.name(),
+                procedure.header().and_then(|h| h.param_list()),
+            )
+        } else {
+            continue;
+        };
+
+        let tables = referenced_tables(&node);
+        for param in params.iter().flat_map(ParamList::params) {
+            let Some(ident) = param.syntax().children().find_map(IdentGroup::cast) else {
+                continue;
+            };
+            let Some(parameter) = ident.name() else {
+                continue;
+            };
+
+            let column = SqlIdent::from(parameter.as_str());
+            let table = tables.iter().find(|table| {
+                ctx.table_column(&SqlIdent::from(table.as_str()), &column)
+                    .is_some()
+            });
+
+            if let Some(table) = table {
+                conflicts.push(Conflict {
+                    routine: node.clone(),
+                    routine_name: routine_name.clone(),
+                    ident,
+                    parameter,
+                    table: table.clone(),
+                });
+            }
+        }
+    }
+
+    conflicts
+}
+
+/// Returns whether `ident` refers to the parameter of `conflict`, either
+/// unqualified or qualified with the routine name.
+fn refers_to_parameter(
+    ident: &Ident,
+    conflict: &Conflict,
+    parameter: &SqlIdent,
+    ctx: &DboAnalyzeContext,
+) -> bool {
+    let Some(group) = ident.syntax().parent().and_then(IdentGroup::cast) else {
+        return false;
+    };
+    let segments = group.segments().collect::<Vec<_>>();
+    match segments.iter().position(|s| s.syntax() == ident.syntax()) {
+        Some(0) => {
+            declaring_scope(ident.syntax(), parameter).as_ref() == Some(&conflict.routine)
+                && !is_named_argument(&group)
+                && !is_column(&group, parameter, ctx)
+        }
+        Some(1) => {
+            let qualifier = SqlIdent::from(segments[0].text());
+            conflict
+                .routine_name
+                .as_deref()
+                .is_some_and(|name| SqlIdent::from(name) == qualifier)
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether `group` is the parameter name of an argument in named
+/// notation, e.g. `id` in `close(id => 1)`.
+fn is_named_argument(group: &IdentGroup) -> bool {
+    group.syntax().parent().map(|p| p.kind()) == Some(SyntaxKind::Argument)
+        && group
+            .syntax()
+            .siblings_with_tokens(Direction::Next)
+            .skip(1)
+            .find(|it| it.kind() != SyntaxKind::Whitespace)
+            .is_some_and(|it| it.kind() == SyntaxKind::FatArrow)
+}
+
+/// Returns whether `group` names a column, i.e. is the table or a target
+/// column of an `INSERT`, or is used in a query or DML statement on a table
+/// with a column named `parameter`.
+fn is_column(group: &IdentGroup, parameter: &SqlIdent, ctx: &DboAnalyzeContext) -> bool {
+    let is_values_item = group
+        .syntax()
+        .siblings_with_tokens(Direction::Prev)
+        .filter_map(|it| it.into_token())
+        .any(|t| t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("values"));
+    if group.syntax().parent().map(|p| p.kind()) == Some(SyntaxKind::InsertStmt) && !is_values_item
+    {
+        return true;
+    }
+
+    group
+        .syntax()
+        .ancestors()
+        .filter(|node| node.kind() != SyntaxKind::InsertStmt)
+        .flat_map(|node| statement_tables(&node))
+        .filter_map(|table| table.last())
+        .any(|table| {
+            ctx.table_column(&SqlIdent::from(table.text()), parameter)
+                .is_some()
+        })
+}
+
+/// Returns the unqualified names of all tables used in queries and DML
+/// statements below `node`.
+fn referenced_tables(node: &SyntaxNode) -> BTreeSet<String> {
+    node.descendants()
+        .flat_map(|node| statement_tables(&node))
+        .filter_map(|table| table.last())
+        .map(|ident| ident.text())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{
+        analyze, apply_rules_filtered, DboAnalyzeOptions, DboColumnType, DboTable, DboTableColumn,
+        DboType, RuleFilter,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_find_parameter_conflicts() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE update_salary(employee_id NUMBER, p_salary NUMBER) IS
+    FUNCTION lookup(salary NUMBER) RETURN NUMBER IS
+    BEGIN
+        RETURN salary;
+    END lookup;
+BEGIN
+    UPDATE hr.Employees SET salary = p_salary WHERE employee_id = employee_id;
+END update_salary;
+"#;
+        let column = || DboTableColumn::new(DboColumnType::Integer);
+        let ctx = DboAnalyzeContext::new(HashMap::from([(
+            "employees".into(),
+            DboTable::new(HashMap::from([
+                ("employee_id".into(), column()),
+                ("salary".into(), column()),
+            ])),
+        )]));
+
+        let result = analyze(DboType::Procedure, INPUT, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+
+        let conflicts = result.unwrap().parameter_conflicts;
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].routine, "update_salary");
+        assert_eq!(conflicts[0].parameter, "employee_id");
+        assert_eq!(conflicts[0].table, "Employees");
+        assert_eq!(conflicts[0].location.start.line, 1);
+    }
+
+    #[test]
+    fn test_find_parameter_conflicts_with_qualified_context() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE close_account(ID NUMBER) IS
+BEGIN
+    DELETE FROM Accounts WHERE id = ID;
+END close_account;
+"#;
+        let ctx = DboAnalyzeContext::new(HashMap::from([(
+            "finance.\"ACCOUNTS\"".into(),
+            DboTable::new(HashMap::from([(
+                "ID".into(),
+                DboTableColumn::new(DboColumnType::Integer),
+            )])),
+        )]));
+
+        let result = analyze(DboType::Procedure, INPUT, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+
+        let conflicts = result.unwrap().parameter_conflicts;
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].parameter, "ID");
+    }
+
+    #[test]
+    fn test_find_parameter_conflicts_with_ambiguous_context() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE close_account(ID NUMBER) IS
+BEGIN
+    DELETE FROM Accounts WHERE id = ID;
+END close_account;
+"#;
+        let table = || {
+            DboTable::new(HashMap::from([(
+                "ID".into(),
+                DboTableColumn::new(DboColumnType::Integer),
+            )]))
+        };
+        let ctx = DboAnalyzeContext::new(HashMap::from([
+            ("finance.accounts".into(), table()),
+            ("archive.accounts".into(), table()),
+        ]));
+        assert!(ctx.table(&"accounts".into()).is_none());
+        assert!(ctx.table(&"archive.accounts".into()).is_some());
+
+        let result = analyze(DboType::Procedure, INPUT, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+        assert!(result.unwrap().parameter_conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_rename_conflicting_parameters() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE update_salary(employee_id NUMBER, salary NUMBER) IS
+    l_old NUMBER := salary;
+BEGIN
+    SELECT salary INTO l_old FROM employees WHERE employee_id = update_salary.employee_id;
+    UPDATE employees SET salary = update_salary.salary WHERE employee_id = employee_id;
+    INSERT INTO salary_log (employee_id, salary) VALUES (employee_id, salary);
+    notify(employee_id => employee_id);
+END update_salary;
+"#;
+        let column = || DboTableColumn::new(DboColumnType::Integer);
+        let ctx = DboAnalyzeContext::new(HashMap::from([(
+            "employees".into(),
+            DboTable::new(HashMap::from([
+                ("employee_id".into(), column()),
+                ("salary".into(), column()),
+            ])),
+        )]));
+        let filter = RuleFilter {
+            ids: vec!["parameter_names".to_string()],
+            ..Default::default()
+        };
+
+        let result = apply_rules_filtered(DboType::Procedure, INPUT, &filter, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result.unwrap().sql,
+            r#"
+CREATE OR REPLACE PROCEDURE update_salary(p_employee_id NUMBER, p_salary NUMBER) IS
+    l_old NUMBER := p_salary;
+BEGIN
+    SELECT salary INTO l_old FROM employees WHERE employee_id = update_salary.p_employee_id;
+    UPDATE employees SET salary = update_salary.p_salary WHERE employee_id = employee_id;
+    INSERT INTO salary_log (employee_id, salary) VALUES (p_employee_id, p_salary);
+    notify(employee_id => p_employee_id);
+END update_salary;
+"#
+        );
+
+        let ctx = ctx.with_options(DboAnalyzeOptions {
+            parameter_prefix: Some("in_".to_string()),
+            ..Default::default()
+        });
+        let result = apply_rules_filtered(DboType::Procedure, INPUT, &filter, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+        assert!(result
+            .unwrap()
+            .sql
+            .contains("update_salary(in_employee_id NUMBER, in_salary NUMBER)"));
+    }
+}
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects the pragmas of an object.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, PragmaStmt, Root};
+use crate::util::{LineIndex, SourceLocation};
+
+/// The pragmas known to the analyzer.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboPragmaKind {
+    AutonomousTransaction,
+    /// `EXCEPTION_INIT(exception, error_code)`
+    ExceptionInit,
+    SeriallyReusable,
+    RestrictReferences,
+    /// `INLINE(subprogram, 'YES' | 'NO')`
+    Inline,
+    /// `DEPRECATE(item [, message])`
+    Deprecate,
+    Udf,
+    Other,
+}
+
+/// A pragma along with what to do about it in PostgreSQL, which has none.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboPragma {
+    pub kind: DboPragmaKind,
+    /// The name as written, e.g. `INLINE`.
+    pub name: String,
+    /// The arguments as written, e.g. `calc` and `'YES'`.
+    pub arguments: Vec<String>,
+    pub hint: String,
+    pub location: SourceLocation,
+}
+
+/// Finds all pragmas in declare sections and before statements, in source
+/// order.
+pub(super) fn find_pragmas(root: &Root, index: &LineIndex) -> Vec<DboPragma> {
+    root.syntax()
+        .descendants()
+        .filter_map(PragmaStmt::cast)
+        .map(|pragma| {
+            let name = pragma.name().unwrap_or_default();
+            let kind = pragma_kind(&name);
+            DboPragma {
+                kind,
+                name,
+                arguments: pragma.arguments().iter().map(|a| a.text()).collect(),
+                hint: pragma_hint(kind).to_string(),
+                location: SourceLocation::new(pragma.syntax().text_range(), index),
+            }
+        })
+        .collect()
+}
+
+fn pragma_kind(name: &str) -> DboPragmaKind {
+    match name.to_lowercase().as_str() {
+        "autonomous_transaction" => DboPragmaKind::AutonomousTransaction,
+        "exception_init" => DboPragmaKind::ExceptionInit,
+        "serially_reusable" => DboPragmaKind::SeriallyReusable,
+        "restrict_references" => DboPragmaKind::RestrictReferences,
+        "inline" => DboPragmaKind::Inline,
+        "deprecate" => DboPragmaKind::Deprecate,
+        "udf" => DboPragmaKind::Udf,
+        _ => DboPragmaKind::Other,
+    }
+}
+
+fn pragma_hint(kind: DboPragmaKind) -> &'static str {
+    match kind {
+        DboPragmaKind::AutonomousTransaction => {
+            "PostgreSQL has no autonomous transactions, run the statements through dblink or \
+             pg_background instead"
+        }
+        DboPragmaKind::ExceptionInit => {
+            "raise the exception with `USING ERRCODE` and catch it with `WHEN SQLSTATE`"
+        }
+        DboPragmaKind::SeriallyReusable => {
+            "package state is kept per session, reset it explicitly if needed"
+        }
+        DboPragmaKind::RestrictReferences | DboPragmaKind::Udf => "only affects Oracle, remove it",
+        DboPragmaKind::Inline => "PostgreSQL does not inline PL/pgSQL calls, remove it",
+        DboPragmaKind::Deprecate => {
+            "remove it, and document the deprecation with `COMMENT ON FUNCTION` instead"
+        }
+        DboPragmaKind::Other => "unknown pragma, remove it if it only affects Oracle",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_find_pragmas() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE log_error(p_code NUMBER) IS
+    PRAGMA AUTONOMOUS_TRANSACTION;
+    PRAGMA DEPRECATE(log_error, 'Use logger.error instead');
+    e_locked EXCEPTION;
+    PRAGMA EXCEPTION_INIT(e_locked, -54);
+BEGIN
+    PRAGMA INLINE(write_log, 'YES');
+    write_log(p_code);
+    COMMIT;
+END log_error;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let pragmas = result.unwrap().pragmas;
+        assert_eq!(
+            pragmas
+                .iter()
+                .map(|p| (p.kind, p.arguments.join(", "), p.location.start.line))
+                .collect::<Vec<_>>(),
+            vec![
+                (DboPragmaKind::AutonomousTransaction, "".to_string(), 2),
+                (
+                    DboPragmaKind::Deprecate,
+                    "log_error, 'Use logger.error instead'".to_string(),
+                    3
+                ),
+                (DboPragmaKind::ExceptionInit, "e_locked, -54".to_string(), 5),
+                (DboPragmaKind::Inline, "write_log, 'YES'".to_string(), 7),
+            ]
+        );
+        assert!(pragmas[0].hint.contains("dblink"));
+    }
+}
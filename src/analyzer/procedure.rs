@@ -6,8 +6,14 @@ use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
 
-use crate::analyzer::{AnalyzeError, DboMetaData};
-use crate::ast::Root;
+use crate::analyzer::call_spec::{analyze_call_spec, DboCallSpec};
+use crate::analyzer::complexity::{longest_statement, max_nesting_depth};
+use crate::analyzer::datatype::{map_parameters, DboParameterMetaData};
+use crate::analyzer::lines_of_code::count_lines;
+use crate::analyzer::security::{map_invoker_rights, DboSecurity};
+use crate::analyzer::{AnalyzeError, DboAnalyzeContext, DboMetaData};
+use crate::ast::{AstNode, Root};
+use crate::util::{LineIndex, SourceLocation};
 
 #[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
@@ -16,26 +22,60 @@ pub struct DboProcedureMetaData {
     pub name: String,
     pub body: String,
     pub lines_of_code: usize,
+    /// The maximum number of nested blocks, `IF` statements and loops.
+    pub max_nesting_depth: usize,
+    /// The location of the longest statement of the body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longest_statement: Option<SourceLocation>,
+    /// The security mode declared through `AUTHID`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<DboSecurity>,
+    pub parameters: Vec<DboParameterMetaData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_spec: Option<DboCallSpec>,
 }
 
-pub(super) fn analyze_procedure(root: Root) -> Result<DboMetaData, AnalyzeError> {
+pub(super) fn analyze_procedure(
+    root: Root,
+    ctx: &DboAnalyzeContext,
+) -> Result<DboMetaData, AnalyzeError> {
     let procedure = root
         .procedure()
         .ok_or_else(|| AnalyzeError::ParseError("failed to find procedure".to_owned()))?;
 
-    let body = procedure
-        .body()
-        .map(|b| b.text())
-        .ok_or_else(|| AnalyzeError::ParseError("failed to find procedure body".to_owned()))?;
+    // Externally implemented procedures have no body, thus no lines of code
+    let call_spec = procedure.call_spec().map(|c| analyze_call_spec(&c));
+    let (body, lines_of_code) = match (procedure.body(), &call_spec) {
+        (Some(body), _) => {
+            let lines_of_code = count_lines(body.syntax()).get(ctx.options().lines_of_code);
+            (body.text(), lines_of_code)
+        }
+        (None, Some(_)) => (String::new(), 0),
+        (None, None) => {
+            return Err(AnalyzeError::ParseError(
+                "failed to find procedure body".to_owned(),
+            ))
+        }
+    };
 
     let name = procedure.name().unwrap_or_else(|| "<unknown>".to_string());
-    let lines_of_code = body.matches('\n').count() + 1;
+    let index =
+        LineIndex::new(&root.syntax().to_string()).with_excerpt_lines(ctx.options().excerpt_lines);
+    let max_nesting_depth = procedure.body().map(|b| max_nesting_depth(&b)).unwrap_or(0);
+    let longest_statement = procedure.body().and_then(|b| longest_statement(&b, &index));
+    let parameters = map_parameters(procedure.header().and_then(|h| h.param_list()), ctx);
+    let security = map_invoker_rights(procedure.header().and_then(|h| h.invoker_rights()));
 
     Ok(DboMetaData {
         procedure: Some(DboProcedureMetaData {
             name,
             body,
             lines_of_code,
+            max_nesting_depth,
+            longest_statement,
+            security,
+            parameters,
+            call_spec,
         }),
         ..Default::default()
     })
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Measures the time spent in each phase of an analysis.
+
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "profiling")]
+use crate::analyzer::{
+    analyze_root, cast_to_root, parse_object, AnalyzeError, DboAnalyzeContext, DboMetaData, DboType,
+};
+#[cfg(feature = "profiling")]
+use source_gen::lexer::Lexer;
+
+/// The time spent in a single phase, e.g. parsing or one analysis pass.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProfilePhase {
+    pub name: &'static str,
+    pub duration: Duration,
+}
+
+/// The phases of a profiled analysis, in the order they ran.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProfileReport {
+    pub phases: Vec<ProfilePhase>,
+}
+
+impl ProfileReport {
+    /// Returns the duration of the phase with the given name, if it ran.
+    pub fn duration(&self, name: &str) -> Option<Duration> {
+        self.phases
+            .iter()
+            .find(|phase| phase.name == name)
+            .map(|phase| phase.duration)
+    }
+
+    /// Returns the sum of all phases.
+    pub fn total(&self) -> Duration {
+        self.phases.iter().map(|phase| phase.duration).sum()
+    }
+}
+
+/// Records the duration of phases into a [`ProfileReport`]. A disabled
+/// profiler never reads the clock, which is not available on WASM.
+pub(super) struct Profiler(Option<ProfileReport>);
+
+impl Profiler {
+    pub(super) fn disabled() -> Self {
+        Self(None)
+    }
+
+    #[cfg(any(test, feature = "profiling"))]
+    fn enabled() -> Self {
+        Self(Some(ProfileReport::default()))
+    }
+
+    /// Runs `f`, recording its duration as phase `name` if enabled.
+    pub(super) fn measure<T>(&mut self, name: &'static str, f: impl FnOnce() -> T) -> T {
+        let Some(report) = self.0.as_mut() else {
+            return f();
+        };
+
+        let start = Instant::now();
+        let result = f();
+        report.phases.push(ProfilePhase {
+            name,
+            duration: start.elapsed(),
+        });
+        result
+    }
+}
+
+/// Same as [`analyze()`], but additionally returns the time spent lexing,
+/// parsing and in every analysis pass. Parsing lexes the input once more, so
+/// the grammar itself takes the difference of both.
+///
+/// [`analyze()`]: super::analyze
+#[cfg(feature = "profiling")]
+pub fn analyze_with_profile(
+    typ: DboType,
+    sql: &str,
+    ctx: &DboAnalyzeContext,
+) -> Result<(DboMetaData, ProfileReport), AnalyzeError> {
+    let mut profiler = Profiler::enabled();
+
+    profiler.measure("lexing", || Lexer::new(sql).count());
+    let (parse, analyze_fn) = profiler.measure("parsing", || parse_object(typ, sql, ctx))?;
+    let meta_data = analyze_root(cast_to_root(&parse)?, sql, ctx, analyze_fn, &mut profiler)?;
+
+    Ok((meta_data, profiler.0.unwrap_or_default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze_root, cast_to_root, parse_object, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_profiler_records_analysis_passes() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE purge IS
+BEGIN
+    DELETE FROM orders;
+END purge;
+"#;
+        let (parse, analyze_fn) =
+            parse_object(DboType::Procedure, INPUT, &DboAnalyzeContext::default()).unwrap();
+        let mut profiler = Profiler::enabled();
+        let result = analyze_root(
+            cast_to_root(&parse).unwrap(),
+            INPUT,
+            &DboAnalyzeContext::default(),
+            analyze_fn,
+            &mut profiler,
+        );
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(result.unwrap().unfiltered_dml.len(), 1);
+
+        let report = profiler.0.unwrap();
+        let names = report.phases.iter().map(|p| p.name).collect::<Vec<_>>();
+        assert_eq!(names.first(), Some(&"unfiltered_dml"));
+        assert_eq!(names.last(), Some(&"object"));
+        assert!(report.duration("call_graph").is_some());
+        assert!(report.total() >= report.duration("call_graph").unwrap());
+    }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn test_analyze_with_profile() {
+        let result = analyze_with_profile(
+            DboType::Query,
+            "SELECT * FROM dual;",
+            &DboAnalyzeContext::default(),
+        );
+        assert!(result.is_ok(), "{result:#?}");
+
+        let (meta_data, report) = result.unwrap();
+        assert!(meta_data.query.is_some());
+        assert_eq!(report.phases[0].name, "lexing");
+        assert_eq!(report.phases[1].name, "parsing");
+        assert!(report.duration("object").is_some());
+    }
+}
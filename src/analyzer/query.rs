@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
 
-use crate::analyzer::{AnalyzeError, DboMetaData};
+use crate::analyzer::{AnalyzeError, DboAnalyzeContext, DboMetaData};
 use crate::ast::Root;
 use source_gen::syntax::SyntaxKind;
 
@@ -19,7 +19,10 @@ pub struct DboQueryMetaData {
     pub outer_joins: usize,
 }
 
-pub(super) fn analyze_query(root: Root) -> Result<DboMetaData, AnalyzeError> {
+pub(super) fn analyze_query(
+    root: Root,
+    _ctx: &DboAnalyzeContext,
+) -> Result<DboMetaData, AnalyzeError> {
     let query = root
         .query()
         .ok_or_else(|| AnalyzeError::ParseError("failed to find query".to_owned()))?;
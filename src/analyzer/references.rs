@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Finds all references to an identifier, e.g. for renaming it.
+
+use crate::ast::{AstNode, AstToken, Function, Ident, IdentGroup, Procedure, Root};
+use crate::util::{LineIndex, SourceLocation};
+use crate::SqlIdent;
+use source_gen::syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
+
+/// Returns the locations of all references to `ident` in the object, in
+/// source order, including its declaration.
+///
+/// Variables and parameters are resolved to the innermost declare section or
+/// parameter list declaring them. Only the references resolving to the same
+/// declaration as the first occurrence are returned, so variables shadowing
+/// it in nested blocks or subprograms are left out. Identifiers without any
+/// declaration, e.g. tables, match anywhere outside such shadowing scopes.
+/// Qualified names are matched per segment.
+pub fn find_references(root: &Root, ident: &SqlIdent) -> Vec<SourceLocation> {
+    let index = LineIndex::new(&root.syntax().to_string());
+
+    let occurrences = root
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter_map(Ident::cast)
+        .filter(|token| SqlIdent::from(token.text()) == *ident)
+        .map(|token| (declaring_scope(token.syntax(), ident), token))
+        .collect::<Vec<_>>();
+
+    let Some(target) = occurrences.first().map(|(scope, _)| scope.clone()) else {
+        return Vec::new();
+    };
+
+    occurrences
+        .into_iter()
+        .filter(|(scope, _)| *scope == target)
+        .map(|(_, token)| SourceLocation::new(token.syntax().text_range(), &index))
+        .collect()
+}
+
+/// Returns the innermost block or subprogram declaring `ident`, if any.
+pub(super) fn declaring_scope(token: &SyntaxToken, ident: &SqlIdent) -> Option<SyntaxNode> {
+    token.parent_ancestors().find(|node| {
+        let names = match node.kind() {
+            SyntaxKind::Block => node
+                .children()
+                .filter(|child| child.kind() == SyntaxKind::DeclareSection)
+                .flat_map(|section| section.children().filter_map(IdentGroup::cast))
+                .filter_map(|group| group.name())
+                .collect::<Vec<_>>(),
+            SyntaxKind::Function => Function::cast(node.clone())
+                .and_then(|f| f.header()?.param_list())
+                .map(|list| list.params().iter().filter_map(|p| p.name()).collect())
+                .unwrap_or_default(),
+            SyntaxKind::Procedure => Procedure::cast(node.clone())
+                .and_then(|p| p.header()?.param_list())
+                .map(|list| list.params().iter().filter_map(|p| p.name()).collect())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+
+        names.into_iter().any(|name| SqlIdent::from(name) == *ident)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::parse_procedure;
+
+    use super::*;
+
+    fn lines(input: &str, ident: &str) -> Vec<(u32, u32)> {
+        let parse = parse_procedure(input).unwrap();
+        assert!(parse.errors.is_empty(), "{:?}", parse.errors);
+        let root = Root::cast(parse.syntax()).unwrap();
+
+        find_references(&root, &SqlIdent::from(ident))
+            .into_iter()
+            .map(|location| (location.start.line, location.start.col))
+            .collect()
+    }
+
+    #[test]
+    fn test_find_references_respects_scopes() {
+        const INPUT: &str = r#"CREATE PROCEDURE raise_salary(p_id NUMBER) IS
+    v_total NUMBER := 0;
+    PROCEDURE log_it(p_id NUMBER) IS
+    BEGIN
+        NULL;
+    END log_it;
+BEGIN
+    V_TOTAL := p_id;
+    DECLARE
+        v_total NUMBER;
+    BEGIN
+        v_total := 1;
+    END;
+    log_it(p_id);
+    UPDATE emp SET total = v_total WHERE id = p_id;
+END raise_salary;
+"#;
+        assert_eq!(lines(INPUT, "v_total"), vec![(1, 4), (7, 4), (14, 27)]);
+        assert_eq!(
+            lines(INPUT, "p_id"),
+            vec![(0, 30), (7, 15), (13, 11), (14, 46)]
+        );
+        assert_eq!(lines(INPUT, "emp"), vec![(14, 11)]);
+        assert!(lines(INPUT, "\"v_total\"").is_empty());
+    }
+}
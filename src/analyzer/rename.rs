@@ -0,0 +1,266 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Applies the objects and columns renamed during the migration.
+
+use std::collections::HashSet;
+
+use rowan::Direction;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::references::declaring_scope;
+use crate::analyzer::DboAnalyzeContext;
+use crate::ast::{
+    AstNode, AstToken, DeleteStmt, IdentGroup, InsertStmt, Root, SelectStmt, UpdateStmt,
+};
+use crate::util::{LineIndex, SourceLocation};
+use crate::SqlIdent;
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
+
+/// A reference to a renamed object or column, which the `renames` rule of
+/// [`apply_rules_filtered()`] replaces by its new name.
+///
+/// [`apply_rules_filtered()`]: crate::analyzer::apply_rules_filtered
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboRename {
+    /// The name as written.
+    pub from: String,
+    pub to: String,
+    pub location: SourceLocation,
+}
+
+/// Finds all references to the renamed objects and columns of the context,
+/// in source order.
+///
+/// Qualified names, e.g. `hr.emp`, are only replaced as a whole. Unqualified
+/// names are replaced where an object or column name is written, i.e. the
+/// last segment of a name and the table qualifier of a column, e.g. `emp` in
+/// both `emp.sal` and `hr.emp`, but not `hr` in `hr.emp.sal`.
+///
+/// Unqualified references to variables and parameters of the same name are
+/// left alone, unless they appear in SQL where a column of that name takes
+/// precedence. The column is assumed to exist unless all tables of the
+/// statement are known to the context and none has it.
+pub(super) fn find_renames(
+    root: &Root,
+    ctx: &DboAnalyzeContext,
+    index: &LineIndex,
+) -> Vec<DboRename> {
+    if ctx.renames().is_empty() {
+        return Vec::new();
+    }
+
+    let table_references = root
+        .syntax()
+        .descendants()
+        .flat_map(|node| statement_tables(&node))
+        .map(|table| table.syntax().text_range())
+        .collect::<HashSet<_>>();
+
+    let mut renames = Vec::new();
+    for group in root.syntax().descendants().filter_map(IdentGroup::cast) {
+        let qualified = group
+            .name()
+            .map(SqlIdent::from)
+            .filter(|name| name.schema().is_some());
+        if let Some(to) = qualified.and_then(|name| ctx.renames().get(&name)) {
+            renames.push(DboRename {
+                from: group.syntax().text().to_string(),
+                to: to.to_string(),
+                location: SourceLocation::new(group.syntax().text_range(), index),
+            });
+            continue;
+        }
+
+        let segments = group.segments().collect::<Vec<_>>();
+        // Tables are only named by their last segment, columns may also be
+        // qualified by their table
+        let first_renamed = if table_references.contains(&group.syntax().text_range()) {
+            segments.len() - 1
+        } else {
+            segments.len().saturating_sub(2)
+        };
+
+        for (i, segment) in segments.iter().enumerate().skip(first_renamed) {
+            let name = SqlIdent::from(segment.text());
+            let Some(to) = ctx.renames().get(&name) else {
+                continue;
+            };
+            // Only the first segment may refer to a variable or parameter
+            let is_variable = i == 0
+                && declaring_scope(segment.syntax(), &name).is_some()
+                && !(segments.len() == 1 && may_be_column(&group, &name, ctx));
+            if !is_variable {
+                renames.push(DboRename {
+                    from: segment.text().to_string(),
+                    to: to.to_string(),
+                    location: SourceLocation::new(segment.syntax().text_range(), index),
+                });
+            }
+        }
+    }
+    renames
+}
+
+/// Whether the unqualified `name` may refer to a column, which SQL prefers
+/// over variables. This is the case within SQL statements, except for the
+/// targets of `INTO` and the values of `INSERT`, unless the context knows
+/// every table of the enclosing statements and none has such a column.
+fn may_be_column(group: &IdentGroup, name: &SqlIdent, ctx: &DboAnalyzeContext) -> bool {
+    let mut tables = Vec::new();
+    let mut in_statement = false;
+    let mut child = group.syntax().clone();
+    for node in group.syntax().ancestors().skip(1) {
+        let is_value = node.kind() == SyntaxKind::InsertStmt
+            && child
+                .siblings_with_tokens(Direction::Prev)
+                .filter_map(|it| it.into_token())
+                .any(|t| t.text().eq_ignore_ascii_case("values"));
+        if !in_statement && (node.kind() == SyntaxKind::IntoClause || is_value) {
+            return false;
+        }
+
+        let statement = statement_tables(&node);
+        in_statement |= !statement.is_empty();
+        tables.extend(statement);
+        child = node;
+    }
+
+    tables.iter().any(|table| {
+        table.name().map(SqlIdent::from).map_or(true, |table| {
+            ctx.table(&table).is_none() || ctx.table_column(&table, name).is_some()
+        })
+    })
+}
+
+/// Returns the tables a query or DML statement reads or writes, without the
+/// ones of subqueries.
+pub(super) fn statement_tables(node: &SyntaxNode) -> Vec<IdentGroup> {
+    if let Some(select) = SelectStmt::cast(node.clone()) {
+        return select.tables();
+    }
+    InsertStmt::cast(node.clone())
+        .and_then(|s| s.table())
+        .or_else(|| UpdateStmt::cast(node.clone()).and_then(|s| s.table()))
+        .or_else(|| DeleteStmt::cast(node.clone()).and_then(|s| s.table()))
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{
+        analyze, apply_rules_filtered, DboColumnType, DboTable, DboTableColumn, DboType, RuleFilter,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_find_renames() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE raise_salary(p_id NUMBER) IS
+    sal NUMBER;
+BEGIN
+    SELECT e.sal INTO sal FROM hr.emp e WHERE e.id = p_id;
+    UPDATE emp SET bonus = sal * 0.1 WHERE id = p_id;
+    INSERT INTO hr.emp_log (id) VALUES (p_id);
+END raise_salary;
+"#;
+        let ctx = DboAnalyzeContext::default().with_renames(HashMap::from([
+            ("emp".into(), "employees".into()),
+            ("hr.emp_log".into(), "audit.salary_log".into()),
+            ("sal".into(), "salary".into()),
+        ]));
+        let result = analyze(DboType::Procedure, INPUT, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+        let renames = result.unwrap().renames;
+
+        assert_eq!(
+            renames
+                .iter()
+                .map(|r| (r.from.as_str(), r.to.as_str(), r.location.start.line))
+                .collect::<Vec<_>>(),
+            vec![
+                ("sal", "salary", 4),
+                ("emp", "employees", 4),
+                ("emp", "employees", 5),
+                ("sal", "salary", 5),
+                ("hr.emp_log", "audit.salary_log", 6),
+            ]
+        );
+        let filter = RuleFilter {
+            ids: vec!["renames".to_string()],
+            ..Default::default()
+        };
+        let result = apply_rules_filtered(DboType::Procedure, INPUT, &filter, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result.unwrap().sql,
+            r#"
+CREATE OR REPLACE PROCEDURE raise_salary(p_id NUMBER) IS
+    sal NUMBER;
+BEGIN
+    SELECT e.salary INTO sal FROM hr.employees e WHERE e.id = p_id;
+    UPDATE employees SET bonus = salary * 0.1 WHERE id = p_id;
+    INSERT INTO audit.salary_log (id) VALUES (p_id);
+END raise_salary;
+"#
+        );
+    }
+
+    #[test]
+    fn test_find_renames_by_position() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE raise_salary(p_id NUMBER) IS
+    sal NUMBER;
+BEGIN
+    UPDATE hr.emp SET bonus = sal * 0.1 WHERE hr.emp.id = p_id;
+    UPDATE dept SET budget = sal WHERE id = p_id;
+    INSERT INTO emp (id, sal) VALUES (p_id, sal);
+END raise_salary;
+"#;
+        let column = || DboTableColumn::new(DboColumnType::Integer);
+        let ctx = DboAnalyzeContext::new(HashMap::from([
+            (
+                "emp".into(),
+                DboTable::new(HashMap::from([
+                    ("id".into(), column()),
+                    ("bonus".into(), column()),
+                    ("sal".into(), column()),
+                ])),
+            ),
+            (
+                "dept".into(),
+                DboTable::new(HashMap::from([
+                    ("id".into(), column()),
+                    ("budget".into(), column()),
+                ])),
+            ),
+        ]))
+        .with_renames(HashMap::from([
+            ("hr".into(), "human_resources".into()),
+            ("sal".into(), "salary".into()),
+        ]));
+        let result = analyze(DboType::Procedure, INPUT, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+
+        assert_eq!(
+            result
+                .unwrap()
+                .renames
+                .iter()
+                .map(|r| (r.from.as_str(), r.location.start.line, r.location.start.col))
+                .collect::<Vec<_>>(),
+            vec![("sal", 4, 30), ("sal", 6, 25)]
+        );
+    }
+}
@@ -0,0 +1,267 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements a versioned JSON report of a complete analysis, e.g. for
+//! archiving it and diffing it between migration runs.
+
+use rowan::TextRange;
+use serde::{Deserialize, Serialize};
+
+use crate::analyzer::rules::{find_rule_edits, into_hints};
+use crate::analyzer::{
+    analyze_root, cast_to_root, parse_object, AnalyzeError, DboAnalyzeContext, DboMetaData,
+    DboType, Profiler, RuleEdit,
+};
+use crate::ast::AstNode;
+use crate::parser::{Parse, ParseError, ParseErrorType};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::SyntaxKind;
+
+/// The version of the report format, increased on every incompatible change
+/// of [`AnalysisBundle`] or the metadata it contains.
+pub const REPORT_FORMAT_VERSION: u32 = 2;
+
+/// A parser error or warning.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DboDiagnostic {
+    pub message: String,
+    /// Whether the object was still parsed completely.
+    pub is_warning: bool,
+    pub location: SourceLocation,
+}
+
+/// A construct the parser or PostgreSQL does not support, as counted in
+/// [`DboSummary::unsupported_features`].
+///
+/// [`DboSummary::unsupported_features`]: super::DboSummary::unsupported_features
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DboUnsupportedFeature {
+    /// The construct, e.g. `NOLOGGING`.
+    pub feature: String,
+    /// Where the construct is written. Features of the object as a whole,
+    /// e.g. the refresh of a materialized view, have none.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<SourceLocation>,
+}
+
+/// Everything known about a single object. Serialized fields keep the
+/// declaration order, such that reports of the same input are identical.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisBundle {
+    /// See [`REPORT_FORMAT_VERSION`].
+    pub format_version: u32,
+    /// The version of the analyzer which created the report.
+    pub analyzer_version: String,
+    pub typ: DboType,
+    pub meta_data: DboMetaData,
+    /// The edits of all rules, ordered by rule id, then in source order.
+    pub hints: Vec<RuleEdit>,
+    pub diagnostics: Vec<DboDiagnostic>,
+    pub unsupported_features: Vec<DboUnsupportedFeature>,
+}
+
+impl AnalysisBundle {
+    /// Serializes the bundle as pretty-printed JSON.
+    pub fn to_report_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("bundle is always serializable")
+    }
+}
+
+/// Same as [`analyze()`], but additionally collects the edits of all rules,
+/// the parser diagnostics and unsupported features.
+///
+/// [`analyze()`]: super::analyze
+pub fn analyze_bundle(
+    typ: DboType,
+    sql: &str,
+    ctx: &DboAnalyzeContext,
+) -> Result<AnalysisBundle, AnalyzeError> {
+    let (parse, analyze_fn) = parse_object(typ, sql, ctx)?;
+    let meta_data = analyze_root(
+        cast_to_root(&parse)?,
+        sql,
+        ctx,
+        analyze_fn,
+        &mut Profiler::disabled(),
+    )?;
+    let index = LineIndex::new(sql).with_excerpt_lines(ctx.options().excerpt_lines);
+
+    let diagnostics = parse
+        .errors
+        .iter()
+        .filter(|e| !matches!(e.typ, ParseErrorType::Unimplemented(_)))
+        .map(|e| DboDiagnostic {
+            message: e.typ.to_string(),
+            is_warning: e.typ.is_warning(),
+            location: error_location(e, &index),
+        })
+        .collect();
+    let unsupported_features = find_unsupported_features(&parse, &meta_data, &index)?;
+    let hints = into_hints(find_rule_edits(cast_to_root(&parse)?, sql, ctx));
+
+    Ok(AnalysisBundle {
+        format_version: REPORT_FORMAT_VERSION,
+        analyzer_version: env!("CARGO_PKG_VERSION").to_string(),
+        typ,
+        meta_data,
+        hints,
+        diagnostics,
+        unsupported_features,
+    })
+}
+
+/// Collects unimplemented constructs, skipped clauses and unsupported
+/// features of materialized views, in this order.
+fn find_unsupported_features(
+    parse: &Parse,
+    meta_data: &DboMetaData,
+    index: &LineIndex,
+) -> Result<Vec<DboUnsupportedFeature>, AnalyzeError> {
+    let root = cast_to_root(parse)?;
+
+    let unimplemented = parse.errors.iter().filter_map(|e| match &e.typ {
+        ParseErrorType::Unimplemented(construct) => Some(DboUnsupportedFeature {
+            feature: construct.clone(),
+            location: Some(error_location(e, index)),
+        }),
+        _ => None,
+    });
+    let ignored_clauses = root
+        .syntax()
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::IgnoredClause)
+        .map(|node| DboUnsupportedFeature {
+            feature: node.text().to_string(),
+            location: Some(SourceLocation::new(node.text_range(), index)),
+        });
+    let view_features = meta_data
+        .materialized_view
+        .iter()
+        .flat_map(|view| &view.unsupported_features)
+        .map(|feature| DboUnsupportedFeature {
+            feature: serde_json::to_value(feature)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_owned))
+                .unwrap_or_default(),
+            location: None,
+        });
+
+    Ok(unimplemented
+        .chain(ignored_clauses)
+        .chain(view_features)
+        .collect())
+}
+
+fn error_location(error: &ParseError, index: &LineIndex) -> SourceLocation {
+    SourceLocation::new(
+        TextRange::new(error.offset.start.into(), error.offset.end.into()),
+        index,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_analyze_bundle() {
+        const INPUT: &str = r#"
+CREATE TABLE stage_orders NOLOGGING
+AS SELECT id FROM orders;
+"#;
+        let result = analyze_bundle(DboType::Table, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let bundle = result.unwrap();
+        assert_eq!(bundle.format_version, REPORT_FORMAT_VERSION);
+        assert!(bundle.diagnostics.is_empty());
+        assert_eq!(
+            bundle
+                .hints
+                .iter()
+                .map(|h| (
+                    h.rule.as_str(),
+                    h.replacement.as_str(),
+                    h.location.start.line
+                ))
+                .collect::<Vec<_>>(),
+            vec![("header", "", 1)]
+        );
+        assert_eq!(
+            bundle
+                .unsupported_features
+                .iter()
+                .map(|f| (
+                    f.feature.as_str(),
+                    f.location.as_ref().map(|l| l.start.line)
+                ))
+                .collect::<Vec<_>>(),
+            vec![("NOLOGGING", Some(1))]
+        );
+        assert_eq!(
+            bundle.meta_data.table.as_ref().map(|t| t.name.as_str()),
+            Some("stage_orders")
+        );
+    }
+
+    #[test]
+    fn test_analyze_bundle_of_materialized_view() {
+        const INPUT: &str = r#"
+CREATE MATERIALIZED VIEW mv_orders REFRESH FAST ON COMMIT AS SELECT id FROM orders;
+"#;
+        let result = analyze_bundle(
+            DboType::MaterializedView,
+            INPUT,
+            &DboAnalyzeContext::default(),
+        );
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result
+                .unwrap()
+                .unsupported_features
+                .iter()
+                .map(|f| (f.feature.as_str(), f.location.is_some()))
+                .collect::<Vec<_>>(),
+            vec![("fastRefresh", false), ("automaticRefresh", false)]
+        );
+    }
+
+    #[test]
+    fn test_report_json_is_stable() {
+        const INPUT: &str = r#"
+CREATE PROCEDURE purge IS
+BEGIN
+    DELETE FROM orders;
+END cleanup;
+"#;
+        let report = || {
+            analyze_bundle(DboType::Procedure, INPUT, &DboAnalyzeContext::default())
+                .unwrap()
+                .to_report_json()
+        };
+
+        let json = report();
+        assert_eq!(json, report());
+        assert!(
+            json.starts_with(&format!(
+                "{{\n  \"formatVersion\": {REPORT_FORMAT_VERSION},\n  \"analyzerVersion\": \"{}\",\n  \"typ\": \"procedure\",",
+                env!("CARGO_PKG_VERSION")
+            )),
+            "{json}"
+        );
+        assert!(json.contains("\"isWarning\": true"), "{json}");
+
+        let bundle: AnalysisBundle = serde_json::from_str(&json).unwrap();
+        assert_eq!(bundle.diagnostics.len(), 1);
+        assert_eq!(bundle.hints[0].replacement, " OR REPLACE");
+        assert_eq!(bundle.meta_data.unfiltered_dml.len(), 1);
+    }
+}
@@ -0,0 +1,1122 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Applies a selection of the rewrites [`analyze()`] reports in one go.
+//!
+//! [`analyze()`]: super::analyze
+
+use std::collections::HashMap;
+
+use rowan::{TextRange, TextSize};
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::attributes::{attribute_case, body_keyword, header_keyword, routine};
+use crate::analyzer::case::find_cases_without_else;
+use crate::analyzer::dual::find_dual_from_clauses;
+use crate::analyzer::exception::find_sqlcode_references;
+use crate::analyzer::fingerprint::Fnv1a;
+use crate::analyzer::function::{classify_volatility, DboFunctionVolatility};
+use crate::analyzer::header::normalize_header;
+use crate::analyzer::literal::find_chr_concatenations;
+use crate::analyzer::number_format::find_number_conversions;
+use crate::analyzer::param_conflict::rename_conflicting_parameters;
+use crate::analyzer::rename::find_renames;
+use crate::analyzer::security::translate_invoker_rights;
+use crate::analyzer::select_into::align_select_lists;
+use crate::analyzer::terminator::normalize_terminator;
+use crate::analyzer::transaction::find_loop_commits;
+use crate::analyzer::{
+    cast_to_root, parse_object, AnalyzeError, DboAnalyzeContext, DboAnalyzeOptions, DboType,
+};
+use crate::ast::{AstNode, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::SyntaxKind;
+
+/// Whether a rule only changes how the code is written or also what it does.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum RuleCategory {
+    /// Keeps the behavior, safe to apply without review.
+    Syntax,
+    /// Changes the behavior, e.g. ignoring unmatched `CASE` values.
+    Semantic,
+}
+
+/// How pressing the edits of a rule are.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum RuleSeverity {
+    /// Style or performance, the code works without the edit.
+    Info,
+    /// The code runs, but might behave differently.
+    Warning,
+    /// PostgreSQL rejects the code without the edit.
+    Error,
+}
+
+/// Whether an edit is applied when its rule is selected.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum RuleApplicability {
+    Automatic,
+    /// Always reported as a hint, e.g. when the target version lacks the
+    /// replacement.
+    HintOnly,
+}
+
+/// How certain a rule is that its edit keeps the code correct.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum RuleConfidence {
+    High,
+    /// Based on heuristics, see the note of the edit.
+    Medium,
+    Low,
+}
+
+/// The PostgreSQL version the rules write code for. Rewrites relying on
+/// features the target lacks are left out.
+#[derive(
+    Tsify, Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize,
+)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum TargetVersion {
+    Pg13,
+    Pg14,
+    Pg15,
+    #[default]
+    Pg16,
+}
+
+impl TargetVersion {
+    /// Whether triggers can be created with `OR REPLACE`, added in 14.
+    pub fn has_replaceable_triggers(self) -> bool {
+        self >= Self::Pg14
+    }
+}
+
+/// A rewrite [`apply_rules_filtered()`] can apply.
+#[derive(Copy, Clone, Debug)]
+pub struct Rule {
+    pub id: &'static str,
+    pub category: RuleCategory,
+    pub severity: RuleSeverity,
+    edits: fn(&RuleContext) -> Vec<RuleMatch>,
+}
+
+/// All rules, ordered by their ids.
+pub const RULES: &[Rule] = &[
+    Rule {
+        id: "case_else",
+        category: RuleCategory::Semantic,
+        severity: RuleSeverity::Warning,
+        edits: case_else,
+    },
+    Rule {
+        id: "chr_escapes",
+        category: RuleCategory::Syntax,
+        severity: RuleSeverity::Info,
+        edits: chr_escapes,
+    },
+    Rule {
+        id: "dual",
+        category: RuleCategory::Syntax,
+        severity: RuleSeverity::Error,
+        edits: dual,
+    },
+    Rule {
+        id: "header",
+        category: RuleCategory::Syntax,
+        severity: RuleSeverity::Error,
+        edits: header,
+    },
+    Rule {
+        id: "keyword_case",
+        category: RuleCategory::Syntax,
+        severity: RuleSeverity::Info,
+        edits: keyword_case,
+    },
+    Rule {
+        id: "loop_commits",
+        category: RuleCategory::Semantic,
+        severity: RuleSeverity::Warning,
+        edits: loop_commits,
+    },
+    Rule {
+        id: "number_conversions",
+        category: RuleCategory::Semantic,
+        severity: RuleSeverity::Warning,
+        edits: number_conversions,
+    },
+    Rule {
+        id: "parameter_names",
+        category: RuleCategory::Semantic,
+        severity: RuleSeverity::Error,
+        edits: parameter_names,
+    },
+    Rule {
+        id: "renames",
+        category: RuleCategory::Syntax,
+        severity: RuleSeverity::Error,
+        edits: renames,
+    },
+    Rule {
+        id: "security",
+        category: RuleCategory::Syntax,
+        severity: RuleSeverity::Error,
+        edits: security,
+    },
+    Rule {
+        id: "select_into",
+        category: RuleCategory::Semantic,
+        severity: RuleSeverity::Warning,
+        edits: select_into,
+    },
+    Rule {
+        id: "sqlcode",
+        category: RuleCategory::Semantic,
+        severity: RuleSeverity::Warning,
+        edits: sqlcode,
+    },
+    Rule {
+        id: "terminator",
+        category: RuleCategory::Syntax,
+        severity: RuleSeverity::Error,
+        edits: terminator,
+    },
+    Rule {
+        id: "volatility",
+        category: RuleCategory::Semantic,
+        severity: RuleSeverity::Info,
+        edits: volatility,
+    },
+];
+
+/// An edit found by a rule, see [`RuleEdit`].
+pub(super) struct RuleMatch {
+    replacement: String,
+    location: SourceLocation,
+    applicability: RuleApplicability,
+    confidence: RuleConfidence,
+    note: Option<String>,
+}
+
+impl RuleMatch {
+    pub(super) fn hint_only(self, note: &str) -> Self {
+        Self {
+            applicability: RuleApplicability::HintOnly,
+            note: Some(note.to_string()),
+            ..self
+        }
+    }
+
+    pub(super) fn with_confidence(self, confidence: RuleConfidence, note: &str) -> Self {
+        Self {
+            confidence,
+            note: Some(note.to_string()),
+            ..self
+        }
+    }
+}
+
+impl From<(String, SourceLocation)> for RuleMatch {
+    fn from((replacement, location): (String, SourceLocation)) -> Self {
+        Self {
+            replacement,
+            location,
+            applicability: RuleApplicability::Automatic,
+            confidence: RuleConfidence::High,
+            note: None,
+        }
+    }
+}
+
+/// What every rule works on, built once per object, such that all rules see
+/// the same text, tree and locations.
+struct RuleContext<'a> {
+    /// The source the tree was parsed from.
+    sql: &'a str,
+    root: Root,
+    index: LineIndex,
+    ctx: &'a DboAnalyzeContext,
+    options: &'a DboAnalyzeOptions,
+    target_version: TargetVersion,
+}
+
+impl RuleContext<'_> {
+    /// Returns the source text of `location`.
+    fn text(&self, location: &SourceLocation) -> &str {
+        &self.sql[location.offset_range()]
+    }
+
+    /// Returns the indentation of the line containing `offset`.
+    fn indent_at(&self, offset: TextSize) -> &str {
+        let line = &self.sql[self.line_start(offset)..];
+        &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+    }
+
+    /// Returns the edit inserting the lines of `text` before `anchor`. If the
+    /// anchor starts its line, each line of `text` gets a line of its own,
+    /// indented like the anchor, otherwise they are inserted inline.
+    fn insert_before(&self, anchor: TextSize, text: &str) -> (String, SourceLocation) {
+        let starts_line = self.sql[self.line_start(anchor)..usize::from(anchor)]
+            .chars()
+            .all(|c| c == ' ' || c == '\t');
+        let replacement = if starts_line {
+            let indent = self.indent_at(anchor);
+            text.lines()
+                .map(|line| format!("{line}\n{indent}"))
+                .collect()
+        } else {
+            text.lines().map(|line| format!("{line} ")).collect()
+        };
+
+        (
+            replacement,
+            SourceLocation::new(TextRange::empty(anchor), &self.index),
+        )
+    }
+
+    /// Returns the edit inserting the lines of `text` after `anchor`. If the
+    /// anchor ends its line, each line of `text` gets a line of its own,
+    /// indented like the line of the anchor, otherwise they are inserted
+    /// inline.
+    fn insert_after(&self, anchor: TextSize, text: &str) -> (String, SourceLocation) {
+        let rest = &self.sql[usize::from(anchor)..];
+        let ends_line = rest
+            .lines()
+            .next()
+            .map_or(true, |line| line.trim().is_empty());
+        let replacement = if ends_line {
+            let indent = self.indent_at(anchor);
+            text.lines()
+                .map(|line| format!("\n{indent}{line}"))
+                .collect()
+        } else {
+            text.lines().map(|line| format!(" {line}")).collect()
+        };
+
+        (
+            replacement,
+            SourceLocation::new(TextRange::empty(anchor), &self.index),
+        )
+    }
+
+    /// Returns the edit removing `range`, along with its line if nothing else
+    /// is written there.
+    fn remove(&self, range: TextRange) -> (String, SourceLocation) {
+        let line_start = self.line_start(range.start());
+        let rest = &self.sql[usize::from(range.end())..];
+        let line_end = rest.find('\n').map_or(rest.len(), |i| i + 1);
+        let own_line = self.sql[line_start..usize::from(range.start())]
+            .trim()
+            .is_empty()
+            && rest[..line_end].trim().is_empty();
+
+        let range = if own_line {
+            TextRange::new(
+                TextSize::from(line_start as u32),
+                range.end() + TextSize::from(line_end as u32),
+            )
+        } else {
+            range
+        };
+        (String::new(), SourceLocation::new(range, &self.index))
+    }
+
+    fn line_start(&self, offset: TextSize) -> usize {
+        self.sql[..usize::from(offset)]
+            .rfind('\n')
+            .map_or(0, |i| i + 1)
+    }
+}
+
+/// Selects the rules to apply. Empty lists select every rule, otherwise a
+/// rule must match all lists.
+#[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleFilter {
+    /// Prefixes of rule ids, e.g. `chr` for `chr_escapes`.
+    #[serde(default)]
+    pub ids: Vec<String>,
+    #[serde(default)]
+    pub categories: Vec<RuleCategory>,
+    #[serde(default)]
+    pub severities: Vec<RuleSeverity>,
+}
+
+impl RuleFilter {
+    pub fn matches(&self, rule: &Rule) -> bool {
+        (self.ids.is_empty()
+            || self
+                .ids
+                .iter()
+                .any(|prefix| rule.id.starts_with(prefix.as_str())))
+            && (self.categories.is_empty() || self.categories.contains(&rule.category))
+            && (self.severities.is_empty() || self.severities.contains(&rule.severity))
+    }
+}
+
+/// A single replacement of a rule.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleEdit {
+    /// A hash of the rule, the replaced text and the replacement, which
+    /// stays the same when unrelated code changes. Identical edits get `-2`,
+    /// `-3` and so on appended in source order.
+    pub id: String,
+    pub rule: String,
+    pub severity: RuleSeverity,
+    pub replacement: String,
+    pub location: SourceLocation,
+    pub applicability: RuleApplicability,
+    pub confidence: RuleConfidence,
+    /// What to review before applying the edit by hand.
+    #[tsify(optional)]
+    pub note: Option<String>,
+}
+
+/// The result of [`apply_rules_filtered()`].
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleApplication {
+    /// The source with all applied edits.
+    pub sql: String,
+    /// The edits made, in source order.
+    pub applied: Vec<RuleEdit>,
+    /// The edits of rules not selected by the filter, hint-only edits and
+    /// those overlapping an applied edit, to be reviewed by hand. Ordered by
+    /// rule id, then in source order.
+    pub hints: Vec<RuleEdit>,
+}
+
+/// The result of [`apply_rule_batch()`].
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleBatch {
+    /// The source with the edits of all steps, or the unchanged source if a
+    /// step failed.
+    pub sql: String,
+    /// The result of each step, empty if a step failed. Locations refer to
+    /// the source as left by the step before.
+    pub steps: Vec<RuleApplication>,
+    /// The index of the step which failed.
+    #[tsify(optional)]
+    pub failed_step: Option<usize>,
+    #[tsify(optional)]
+    pub error: Option<String>,
+}
+
+/// Applies the rules selected by each of `steps` in turn, each step working
+/// on the result of the one before. A step fails if the object cannot be
+/// parsed or an edit of a selected rule overlaps another one. Either all
+/// steps succeed, or the source is returned unchanged along with the failing
+/// step.
+pub fn apply_rule_batch(
+    typ: DboType,
+    sql: &str,
+    steps: &[RuleFilter],
+    ctx: &DboAnalyzeContext,
+) -> RuleBatch {
+    let mut current = sql.to_string();
+    let mut applications = Vec::new();
+    for (i, filter) in steps.iter().enumerate() {
+        match apply_rule_step(typ, &current, filter, ctx) {
+            Ok(application) => {
+                current = application.sql.clone();
+                applications.push(application);
+            }
+            Err(error) => {
+                return RuleBatch {
+                    sql: sql.to_string(),
+                    steps: Vec::new(),
+                    failed_step: Some(i),
+                    error: Some(error),
+                }
+            }
+        }
+    }
+
+    RuleBatch {
+        sql: current,
+        steps: applications,
+        failed_step: None,
+        error: None,
+    }
+}
+
+fn apply_rule_step(
+    typ: DboType,
+    sql: &str,
+    filter: &RuleFilter,
+    ctx: &DboAnalyzeContext,
+) -> Result<RuleApplication, String> {
+    let application =
+        apply_rules_filtered(typ, sql, filter, ctx).map_err(|error| error.to_string())?;
+
+    let skipped = application.hints.iter().find(|edit| {
+        edit.applicability == RuleApplicability::Automatic
+            && RULES
+                .iter()
+                .any(|rule| rule.id == edit.rule && filter.matches(rule))
+    });
+    match skipped {
+        Some(edit) => Err(format!(
+            "Edit {} of {} on line {} overlaps another edit",
+            edit.id, edit.rule, edit.location.start.line
+        )),
+        None => Ok(application),
+    }
+}
+
+/// Parses the object and applies the edits of the rules selected by
+/// `filter`, leaving the others as hints.
+pub fn apply_rules_filtered(
+    typ: DboType,
+    sql: &str,
+    filter: &RuleFilter,
+    ctx: &DboAnalyzeContext,
+) -> Result<RuleApplication, AnalyzeError> {
+    let (parse, _) = parse_object(typ, sql, ctx)?;
+    let edits = find_rule_edits(cast_to_root(&parse)?, sql, ctx);
+    Ok(apply_edits(sql, edits, filter))
+}
+
+/// Applies those of `edits`, as found by [`find_rule_edits()`], whose rules
+/// are selected by `filter`.
+pub(super) fn apply_edits(
+    sql: &str,
+    edits: Vec<(&'static Rule, RuleEdit)>,
+    filter: &RuleFilter,
+) -> RuleApplication {
+    let mut applied: Vec<RuleEdit> = Vec::new();
+    let mut hints = Vec::new();
+    for (rule, edit) in edits {
+        let overlaps = applied
+            .last()
+            .is_some_and(|last| edit.location.start_offset < last.location.end_offset);
+
+        if filter.matches(rule) && edit.applicability == RuleApplicability::Automatic && !overlaps {
+            applied.push(edit);
+        } else {
+            hints.push(edit);
+        }
+    }
+
+    hints.sort_by(|a, b| a.rule.cmp(&b.rule));
+
+    // Copies the source once, instead of shifting its rest on every edit
+    let mut result = String::with_capacity(sql.len());
+    let mut copied = 0;
+    for edit in &applied {
+        let range = edit.location.offset_range();
+        result.push_str(&sql[copied..range.start]);
+        result.push_str(&edit.replacement);
+        copied = range.end;
+    }
+    result.push_str(&sql[copied..]);
+
+    RuleApplication {
+        sql: result,
+        applied,
+        hints,
+    }
+}
+
+/// Returns `edits`, as found by [`find_rule_edits()`], ordered by rule id,
+/// then in source order.
+pub(super) fn into_hints(edits: Vec<(&'static Rule, RuleEdit)>) -> Vec<RuleEdit> {
+    let mut hints = edits.into_iter().map(|(_, edit)| edit).collect::<Vec<_>>();
+    hints.sort_by(|a, b| a.rule.cmp(&b.rule));
+    hints
+}
+
+/// Returns the edits of all rules on `root`, parsed from `sql`, in source
+/// order.
+pub(super) fn find_rule_edits(
+    root: Root,
+    sql: &str,
+    ctx: &DboAnalyzeContext,
+) -> Vec<(&'static Rule, RuleEdit)> {
+    let rule_ctx = RuleContext {
+        sql,
+        root,
+        index: LineIndex::new(sql).with_excerpt_lines(ctx.options().excerpt_lines),
+        ctx,
+        options: ctx.options(),
+        target_version: ctx.options().target_version,
+    };
+
+    let mut edits = RULES
+        .iter()
+        .flat_map(|rule| {
+            (rule.edits)(&rule_ctx).into_iter().map(move |m| {
+                let edit = RuleEdit {
+                    id: String::new(),
+                    rule: rule.id.to_string(),
+                    severity: rule.severity,
+                    replacement: m.replacement,
+                    location: m.location,
+                    applicability: m.applicability,
+                    confidence: m.confidence,
+                    note: m.note,
+                };
+                (rule, edit)
+            })
+        })
+        .collect::<Vec<_>>();
+    // Edits at the same location stay in the order of `RULES`
+    edits.sort_by_key(|(_, edit)| (edit.location.start_offset, edit.location.end_offset));
+
+    let mut occurrences = HashMap::<String, usize>::new();
+    for (_, edit) in &mut edits {
+        let mut hasher = Fnv1a::new();
+        for part in [&edit.rule, rule_ctx.text(&edit.location), &edit.replacement] {
+            hasher.write(part.as_bytes());
+            hasher.write(&[0]);
+        }
+        let hash = hasher.finish();
+
+        let count = occurrences.entry(hash.clone()).or_default();
+        *count += 1;
+        edit.id = match *count {
+            1 => hash,
+            n => format!("{hash}-{n}"),
+        };
+    }
+
+    edits
+}
+
+fn case_else(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    find_cases_without_else(&rule_ctx.root, &rule_ctx.index)
+        .into_iter()
+        .map(|case| {
+            let end = TextSize::from(case.else_location.start_offset);
+            RuleMatch::from(rule_ctx.insert_before(end, "ELSE NULL;")).with_confidence(
+                RuleConfidence::Medium,
+                "Unmatched values no longer raise CASE_NOT_FOUND",
+            )
+        })
+        .collect()
+}
+
+fn chr_escapes(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    find_chr_concatenations(&rule_ctx.root, rule_ctx.ctx, &rule_ctx.index)
+        .into_iter()
+        .map(|c| (c.replacement, c.location).into())
+        .collect()
+}
+
+fn dual(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    find_dual_from_clauses(&rule_ctx.root, &rule_ctx.index)
+        .into_iter()
+        .map(RuleMatch::from)
+        .collect()
+}
+
+fn header(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    let case = rule_ctx.options.keyword_case;
+    normalize_header(
+        &rule_ctx.root,
+        case,
+        rule_ctx.target_version,
+        &rule_ctx.index,
+    )
+}
+
+/// Rewrites every keyword not written in the configured case. Just like
+/// [`Parse::keyword_case()`], keywords used as identifiers are left alone.
+///
+/// [`Parse::keyword_case()`]: crate::Parse::keyword_case
+fn keyword_case(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    let Some(case) = rule_ctx.options.keyword_case else {
+        return Vec::new();
+    };
+
+    rule_ctx
+        .root
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|token| matches!(token.kind(), SyntaxKind::Keyword | SyntaxKind::LogicOp))
+        .filter_map(|token| {
+            let replacement = case.apply(token.text());
+            (replacement != token.text()).then(|| {
+                let location = SourceLocation::new(token.text_range(), &rule_ctx.index);
+                (replacement, location).into()
+            })
+        })
+        .collect()
+}
+
+/// Moves `COMMIT` statements out of loops, such that the transaction is
+/// committed once after the outermost loop. `ROLLBACK` inside loops is part
+/// of the error handling and left to be reworked by hand.
+fn loop_commits(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    const NOTE: &str = "Commits once after the loop, a failure now rolls back all iterations";
+
+    let mut edits = Vec::new();
+    let mut loops = Vec::new();
+    for commit in find_loop_commits(&rule_ctx.root) {
+        edits.push(rule_ctx.remove(commit.statement.text_range()));
+        if !commit.committed_after && !loops.contains(&commit.outer_loop) {
+            let text = commit.statement.text().to_string();
+            edits.push(rule_ctx.insert_after(commit.outer_loop.text_range().end(), &text));
+            loops.push(commit.outer_loop);
+        }
+    }
+
+    edits
+        .into_iter()
+        .map(|edit| RuleMatch::from(edit).with_confidence(RuleConfidence::Medium, NOTE))
+        .collect()
+}
+
+fn number_conversions(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    find_number_conversions(&rule_ctx.root, &rule_ctx.index)
+        .into_iter()
+        .filter_map(|c| {
+            let m = RuleMatch::from((c.replacement?, c.location));
+            Some(m.with_confidence(RuleConfidence::Medium, &c.hint))
+        })
+        .collect()
+}
+
+/// Renames parameters named like a column, which PL/pgSQL rejects as
+/// ambiguous. This changes the signature of the routine.
+fn parameter_names(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    let prefix = rule_ctx.options.parameter_prefix.as_deref().unwrap_or("p_");
+    rename_conflicting_parameters(&rule_ctx.root, rule_ctx.ctx, prefix, &rule_ctx.index)
+        .into_iter()
+        .map(|edit| {
+            RuleMatch::from(edit).with_confidence(
+                RuleConfidence::Medium,
+                "Callers passing the parameter in named notation must use the new name",
+            )
+        })
+        .collect()
+}
+
+fn renames(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    find_renames(&rule_ctx.root, rule_ctx.ctx, &rule_ctx.index)
+        .into_iter()
+        .map(|rename| (rename.to, rename.location).into())
+        .collect()
+}
+
+fn security(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    let case = rule_ctx.options.keyword_case;
+    translate_invoker_rights(&rule_ctx.root, case, &rule_ctx.index)
+        .into_iter()
+        .map(RuleMatch::from)
+        .collect()
+}
+
+/// Makes the select list of `SELECT .. INTO` statements match their targets.
+/// Oracle rejects such statements, so the intended columns are a guess.
+fn select_into(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    align_select_lists(&rule_ctx.root, rule_ctx.ctx, &rule_ctx.index)
+        .into_iter()
+        .map(|edit| {
+            RuleMatch::from(edit).with_confidence(
+                RuleConfidence::Low,
+                "Keeps what PostgreSQL does at runtime, check which columns were meant",
+            )
+        })
+        .collect()
+}
+
+/// Points out `SQLCODE`, whose numeric Oracle codes cannot be mapped to
+/// `SQLSTATE` automatically.
+fn sqlcode(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    find_sqlcode_references(&rule_ctx.root, &rule_ctx.index)
+        .into_iter()
+        .map(|edit| {
+            RuleMatch::from(edit).hint_only(
+                "Compare SQLSTATE against PostgreSQL error codes, e.g. '23505' for ORA-00001",
+            )
+        })
+        .collect()
+}
+
+fn terminator(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    normalize_terminator(&rule_ctx.root, &rule_ctx.index)
+        .into_iter()
+        .map(RuleMatch::from)
+        .collect()
+}
+
+/// Declares the volatility of a function, see [`DboFunctionVolatility`].
+/// It replaces `DETERMINISTIC`, or is inserted before the `IS` of the body,
+/// where PostgreSQL takes it before the `AS`.
+fn volatility(rule_ctx: &RuleContext) -> Vec<RuleMatch> {
+    let Some(body) = rule_ctx.root.function().and_then(|f| f.body()) else {
+        return Vec::new();
+    };
+    let Some(routine) = routine(&rule_ctx.root) else {
+        return Vec::new();
+    };
+
+    let volatility = classify_volatility(&body);
+    let keyword =
+        attribute_case(&routine, rule_ctx.options.keyword_case).apply(volatility.keyword());
+    let edit = match header_keyword(&routine, "deterministic") {
+        Some(deterministic) => Some((
+            keyword,
+            SourceLocation::new(deterministic.text_range(), &rule_ctx.index),
+        )),
+        None => body_keyword(&routine)
+            .map(|is| rule_ctx.insert_before(is.text_range().start(), &keyword)),
+    };
+
+    edit.map(|edit| match volatility {
+        DboFunctionVolatility::Volatile => RuleMatch::from(edit),
+        _ => RuleMatch::from(edit).with_confidence(
+            RuleConfidence::Medium,
+            "Derived from the body, make sure it depends on nothing else",
+        ),
+    })
+    .into_iter()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use crate::analyzer::{analyze, DboAnalyzeOptions};
+    use crate::KeywordCase;
+
+    use super::*;
+
+    const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE notify(p_grade VARCHAR2) IS
+BEGIN
+    CASE p_grade
+        WHEN 'A' THEN INSERT INTO emp_log (line) VALUES ('top' || CHR(10));
+    END CASE;
+END notify;
+"#;
+
+    fn ctx() -> DboAnalyzeContext {
+        DboAnalyzeContext::default()
+            .with_renames(HashMap::from([("emp_log".into(), "audit_log".into())]))
+    }
+
+    #[test]
+    fn test_apply_safe_syntax_rules() {
+        let filter = RuleFilter {
+            categories: vec![RuleCategory::Syntax],
+            ..Default::default()
+        };
+        let result = apply_rules_filtered(DboType::Procedure, INPUT, &filter, &ctx());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let application = result.unwrap();
+        assert_eq!(
+            application
+                .applied
+                .iter()
+                .map(|e| e.rule.as_str())
+                .collect::<Vec<_>>(),
+            vec!["renames", "chr_escapes"]
+        );
+        assert_eq!(
+            application
+                .hints
+                .iter()
+                .map(|e| e.rule.as_str())
+                .collect::<Vec<_>>(),
+            vec!["case_else"]
+        );
+        assert_eq!(application.applied[0].confidence, RuleConfidence::High);
+        assert_eq!(application.applied[0].note, None);
+        assert_eq!(application.hints[0].confidence, RuleConfidence::Medium);
+        assert_eq!(
+            application.hints[0].note.as_deref(),
+            Some("Unmatched values no longer raise CASE_NOT_FOUND")
+        );
+        assert!(application
+            .sql
+            .contains("INSERT INTO audit_log (line) VALUES (E'top\\n');\n    END CASE;"));
+    }
+
+    #[test]
+    fn test_apply_rules_by_id_prefix() {
+        let filter = RuleFilter {
+            ids: vec!["case".to_string(), "chr".to_string()],
+            ..Default::default()
+        };
+        let result = apply_rules_filtered(DboType::Procedure, INPUT, &filter, &ctx());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let application = result.unwrap();
+        assert_eq!(application.applied.len(), 2);
+        assert_eq!(application.hints[0].rule, "renames");
+        assert!(application.sql.contains("INSERT INTO emp_log"));
+        assert!(application.sql.contains("ELSE NULL;\n    END CASE;"));
+
+        let everything =
+            apply_rules_filtered(DboType::Procedure, INPUT, &RuleFilter::default(), &ctx());
+        assert_eq!(everything.unwrap().hints, vec![]);
+    }
+
+    #[test]
+    fn test_apply_rules_by_severity() {
+        let filter = RuleFilter {
+            severities: vec![RuleSeverity::Error],
+            ..Default::default()
+        };
+        let result = apply_rules_filtered(DboType::Procedure, INPUT, &filter, &ctx());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let application = result.unwrap();
+        assert_eq!(
+            application
+                .applied
+                .iter()
+                .map(|e| (e.rule.as_str(), e.severity))
+                .collect::<Vec<_>>(),
+            vec![("renames", RuleSeverity::Error)]
+        );
+        assert_eq!(
+            application
+                .hints
+                .iter()
+                .map(|e| (e.rule.as_str(), e.severity))
+                .collect::<Vec<_>>(),
+            vec![
+                ("case_else", RuleSeverity::Warning),
+                ("chr_escapes", RuleSeverity::Info)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edits_keep_indentation() {
+        const INPUT: &str = "BEGIN\n    IF l_done THEN\n        NULL;\n    END IF; RETURN;\nEND;";
+        let parse = crate::parse_block(INPUT).unwrap();
+        let rule_ctx = RuleContext {
+            sql: INPUT,
+            root: cast_to_root(&parse).unwrap(),
+            index: LineIndex::new(INPUT),
+            ctx: &DboAnalyzeContext::default(),
+            options: &DboAnalyzeOptions::default(),
+            target_version: TargetVersion::default(),
+        };
+
+        let offset = |text: &str| TextSize::from(INPUT.find(text).unwrap() as u32);
+        assert_eq!(rule_ctx.indent_at(offset("NULL")), "        ");
+        assert_eq!(
+            rule_ctx
+                .insert_before(offset("END IF"), "ELSE\n    l_done := TRUE;")
+                .0,
+            "ELSE\n        l_done := TRUE;\n    "
+        );
+        assert_eq!(
+            rule_ctx.insert_before(offset("RETURN"), "COMMIT;").0,
+            "COMMIT; "
+        );
+        assert_eq!(
+            rule_ctx
+                .insert_after(offset("NULL;") + TextSize::from(5), "COMMIT;")
+                .0,
+            "\n        COMMIT;"
+        );
+        assert_eq!(
+            rule_ctx
+                .insert_after(offset("END IF;") + TextSize::from(7), "COMMIT;")
+                .0,
+            " COMMIT;"
+        );
+
+        let range = |text: &str| TextRange::at(offset(text), TextSize::of(text));
+        assert_eq!(
+            rule_ctx.text(&rule_ctx.remove(range("NULL;")).1),
+            "        NULL;\n"
+        );
+        assert_eq!(
+            rule_ctx.text(&rule_ctx.remove(range("RETURN;")).1),
+            "RETURN;"
+        );
+    }
+
+    #[test]
+    fn test_apply_rule_batch() {
+        let steps = [
+            RuleFilter {
+                ids: vec!["renames".to_string()],
+                ..Default::default()
+            },
+            RuleFilter {
+                ids: vec!["chr".to_string()],
+                ..Default::default()
+            },
+        ];
+
+        let batch = apply_rule_batch(DboType::Procedure, INPUT, &steps, &ctx());
+        assert_eq!(batch.failed_step, None, "{batch:#?}");
+        assert_eq!(batch.steps.len(), 2);
+        assert!(batch
+            .sql
+            .contains("INSERT INTO audit_log (line) VALUES (E'top\\n');"));
+
+        let ctx = ctx().with_options(DboAnalyzeOptions {
+            keyword_case: Some(KeywordCase::Lower),
+            ..Default::default()
+        });
+        let input = INPUT.replace("OR REPLACE", "EDITIONABLE");
+        let steps = [
+            steps[0].clone(),
+            RuleFilter {
+                ids: vec!["header".to_string(), "keyword".to_string()],
+                ..Default::default()
+            },
+        ];
+        let batch = apply_rule_batch(DboType::Procedure, &input, &steps, &ctx);
+        assert_eq!(batch.sql, input);
+        assert_eq!(batch.steps, vec![]);
+        assert_eq!(batch.failed_step, Some(1));
+        assert!(batch
+            .error
+            .is_some_and(|e| e.starts_with("Edit ")
+                && e.ends_with(" of header on line 1 overlaps another edit")));
+    }
+
+    #[test]
+    fn test_rules_share_locations_with_analysis() {
+        assert!(RULES.windows(2).all(|pair| pair[0].id < pair[1].id));
+
+        let ctx = ctx().with_options(DboAnalyzeOptions {
+            excerpt_lines: Some(0),
+            ..Default::default()
+        });
+        let result = apply_rules_filtered(DboType::Procedure, INPUT, &RuleFilter::default(), &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+
+        let meta_data = analyze(DboType::Procedure, INPUT, &ctx).unwrap();
+        let application = result.unwrap();
+        let rename = application.applied.iter().find(|e| e.rule == "renames");
+        assert_eq!(rename.unwrap().location, meta_data.renames[0].location);
+        assert!(meta_data.renames[0].location.excerpt.is_some());
+    }
+
+    #[test]
+    fn test_apply_keyword_case() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE Touch(p_id NUMBER) IS
+BEGIN
+    UPDATE emp SET name = 'NOT NULL' WHERE id = p_id AND Name IS NOT NULL;
+END Touch;
+"#;
+        let filter = RuleFilter {
+            ids: vec!["keyword".to_string()],
+            ..Default::default()
+        };
+        let unset = apply_rules_filtered(DboType::Procedure, INPUT, &filter, &ctx());
+        assert_eq!(unset.unwrap().sql, INPUT);
+
+        let ctx = ctx().with_options(DboAnalyzeOptions {
+            keyword_case: Some(KeywordCase::Lower),
+            ..Default::default()
+        });
+        let result = apply_rules_filtered(DboType::Procedure, INPUT, &filter, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result.unwrap().sql,
+            r#"
+create or replace procedure Touch(p_id number) is
+begin
+    update emp set name = 'NOT NULL' where id = p_id and Name is not null;
+end Touch;
+"#
+        );
+    }
+
+    #[test]
+    fn test_apply_volatility() {
+        let filter = RuleFilter {
+            ids: vec!["volatility".to_string()],
+            ..Default::default()
+        };
+        let apply = |sql: &str| {
+            let result = apply_rules_filtered(DboType::Function, sql, &filter, &ctx());
+            assert!(result.is_ok(), "{result:#?}");
+            result.unwrap().sql
+        };
+
+        assert_eq!(
+            apply("CREATE FUNCTION twice(p NUMBER) RETURN NUMBER IS BEGIN RETURN p * 2; END twice;\n/\n"),
+            "CREATE FUNCTION twice(p NUMBER) RETURN NUMBER IMMUTABLE IS BEGIN RETURN p * 2; END twice;\n/\n"
+        );
+        assert_eq!(
+            apply("create function total return number is begin return pkg.g_total; end;"),
+            "create function total return number stable is begin return pkg.g_total; end;"
+        );
+        assert_eq!(
+            apply("CREATE FUNCTION next_id RETURN NUMBER\nIS\nBEGIN\n    RETURN ids.NEXTVAL;\nEND next_id;"),
+            "CREATE FUNCTION next_id RETURN NUMBER\nVOLATILE\nIS\nBEGIN\n    RETURN ids.NEXTVAL;\nEND next_id;"
+        );
+        assert_eq!(
+            apply("CREATE FUNCTION twice(p NUMBER) RETURN NUMBER DETERMINISTIC IS BEGIN RETURN p * 2; END;"),
+            "CREATE FUNCTION twice(p NUMBER) RETURN NUMBER IMMUTABLE IS BEGIN RETURN p * 2; END;"
+        );
+    }
+
+    #[test]
+    fn test_hints_have_stable_order_and_ids() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE notify IS
+BEGIN
+    INSERT INTO emp_log (line) VALUES ('a' || CHR(10));
+    INSERT INTO emp_log (line) VALUES ('a' || CHR(10));
+END notify;
+"#;
+        let filter = RuleFilter {
+            ids: vec!["terminator".to_string()],
+            ..Default::default()
+        };
+        let hints = |sql: &str| {
+            let result = apply_rules_filtered(DboType::Procedure, sql, &filter, &ctx());
+            assert!(result.is_ok(), "{result:#?}");
+            result.unwrap().hints
+        };
+
+        let before = hints(INPUT);
+        assert_eq!(
+            before
+                .iter()
+                .map(|e| (e.rule.as_str(), e.location.start.line))
+                .collect::<Vec<_>>(),
+            vec![
+                ("chr_escapes", 3),
+                ("chr_escapes", 4),
+                ("renames", 3),
+                ("renames", 4)
+            ]
+        );
+        assert_eq!(before[0].id.len(), 16);
+        assert_eq!(before[1].id, format!("{}-2", before[0].id));
+        assert_ne!(before[0].id, before[2].id);
+
+        let after = hints(&INPUT.replace("BEGIN\n", "BEGIN\n    NULL;\n"));
+        assert_eq!(
+            after.iter().map(|e| &e.id).collect::<Vec<_>>(),
+            before.iter().map(|e| &e.id).collect::<Vec<_>>()
+        );
+        assert_ne!(after[0].location, before[0].location);
+    }
+}
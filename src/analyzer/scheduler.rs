@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects jobs created through `DBMS_SCHEDULER` and `DBMS_JOB`, including
+//! an analysis of the PL/SQL code they run.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::profile::Profiler;
+use crate::analyzer::{analyze_root, DboAnalyzeContext, DboMetaData};
+use crate::ast::{Argument, AstNode, FunctionInvocation, Root};
+use crate::parser::parse_block;
+use crate::util::{LineIndex, SourceLocation};
+
+/// The package used to create a job.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboJobApi {
+    /// `DBMS_SCHEDULER.CREATE_JOB`
+    Scheduler,
+    /// The deprecated `DBMS_JOB.SUBMIT`
+    Job,
+}
+
+/// What a job runs, which decides how it can be migrated. Jobs running SQL
+/// or PL/SQL fit pg_cron, executables need pgAgent.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboJobKind {
+    PlsqlBlock,
+    StoredProcedure,
+    Executable,
+    /// Any other job type, or a job running a named program.
+    Other,
+}
+
+/// A job created by the analyzed object.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboScheduledJob {
+    pub api: DboJobApi,
+    pub kind: DboJobKind,
+    /// The job name, if given as a literal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub name: Option<String>,
+    /// The job action, i.e. the PL/SQL code, procedure or executable to run.
+    /// Literals are unquoted, anything else is kept as written.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub action: Option<String>,
+    /// The repeat interval, interval expression or schedule name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub schedule: Option<String>,
+    /// The analysis of a literal PL/SQL action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub analysis: Option<Box<DboMetaData>>,
+    pub location: SourceLocation,
+}
+
+/// Finds all job creations in source order. Literal PL/SQL actions are
+/// analyzed as anonymous blocks, with locations relative to the action.
+pub(super) fn find_scheduled_jobs(
+    root: &Root,
+    ctx: &DboAnalyzeContext,
+    index: &LineIndex,
+) -> Vec<DboScheduledJob> {
+    root.syntax()
+        .descendants()
+        .filter_map(FunctionInvocation::cast)
+        .filter_map(|call| {
+            let name = call.ident()?.syntax().text().to_string().to_lowercase();
+            let api = if name.ends_with("dbms_scheduler.create_job") {
+                DboJobApi::Scheduler
+            } else if name.ends_with("dbms_job.submit") {
+                DboJobApi::Job
+            } else {
+                return None;
+            };
+
+            let arguments = call.arguments().unwrap_or_default();
+            let arg = |name: &str, position: usize| {
+                argument(&arguments, name, position).map(|a| a.value())
+            };
+
+            let mut job = match api {
+                DboJobApi::Scheduler => {
+                    let kind = match arg("job_type", 1).and_then(|t| unquote(&t)) {
+                        Some(t) if t.eq_ignore_ascii_case("plsql_block") => DboJobKind::PlsqlBlock,
+                        Some(t) if t.eq_ignore_ascii_case("stored_procedure") => {
+                            DboJobKind::StoredProcedure
+                        }
+                        Some(t) if t.eq_ignore_ascii_case("executable") => DboJobKind::Executable,
+                        _ => DboJobKind::Other,
+                    };
+                    let action = (kind != DboJobKind::Other)
+                        .then(|| arg("job_action", 2))
+                        .flatten();
+
+                    DboScheduledJob {
+                        api,
+                        kind,
+                        name: arg("job_name", 0).and_then(|n| unquote(&n)),
+                        action,
+                        schedule: argument(&arguments, "repeat_interval", 5)
+                            .or_else(|| argument(&arguments, "schedule_name", usize::MAX))
+                            .map(|a| a.value()),
+                        analysis: None,
+                        location: SourceLocation::new(call.syntax().text_range(), index),
+                    }
+                }
+                DboJobApi::Job => DboScheduledJob {
+                    api,
+                    kind: DboJobKind::PlsqlBlock,
+                    name: None,
+                    action: arg("what", 1),
+                    schedule: arg("interval", 3),
+                    analysis: None,
+                    location: SourceLocation::new(call.syntax().text_range(), index),
+                },
+            };
+
+            if job.kind == DboJobKind::PlsqlBlock {
+                if let Some(code) = job.action.as_deref().and_then(unquote) {
+                    job.analysis = analyze_block(&code, ctx).map(Box::new);
+                }
+            }
+            job.action = job.action.map(|a| unquote(&a).unwrap_or(a));
+
+            Some(job)
+        })
+        .collect()
+}
+
+/// Returns the argument passed for the parameter `name` in named notation or
+/// at `position` in positional notation.
+fn argument<'a>(arguments: &'a [Argument], name: &str, position: usize) -> Option<&'a Argument> {
+    arguments
+        .iter()
+        .find(|a| a.name().is_some_and(|n| n.eq_ignore_ascii_case(name)))
+        .or_else(|| arguments.get(position).filter(|a| a.name().is_none()))
+}
+
+/// Returns the content of a string literal, `None` for other expressions.
+fn unquote(value: &str) -> Option<String> {
+    let content = value.strip_prefix('\'')?.strip_suffix('\'')?;
+    Some(content.replace("''", "'"))
+}
+
+/// Analyzes the PL/SQL code of a job. `DBMS_JOB` also accepts bare
+/// statements, which are wrapped into a block.
+fn analyze_block(code: &str, ctx: &DboAnalyzeContext) -> Option<DboMetaData> {
+    let trimmed = code.trim_start().to_lowercase();
+    let code = if trimmed.starts_with("begin") || trimmed.starts_with("declare") {
+        code.to_owned()
+    } else {
+        format!("BEGIN\n{code}\nEND;")
+    };
+
+    let root = Root::cast(parse_block(&code).ok()?.syntax())?;
+    analyze_root(
+        root,
+        &code,
+        ctx,
+        |_, _| Ok(DboMetaData::default()),
+        &mut Profiler::disabled(),
+    )
+    .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::parse_procedure;
+
+    use super::*;
+
+    #[test]
+    fn test_find_scheduled_jobs() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE setup_jobs IS
+    l_job NUMBER;
+BEGIN
+    DBMS_SCHEDULER.CREATE_JOB(
+        job_name        => 'purge_orders',
+        job_type        => 'PLSQL_BLOCK',
+        job_action      => 'BEGIN DELETE FROM orders; log_purge(''orders''); END;',
+        repeat_interval => 'FREQ=DAILY; BYHOUR=2',
+        enabled         => TRUE);
+    DBMS_SCHEDULER.CREATE_JOB('backup', 'EXECUTABLE', '/usr/bin/backup.sh');
+    DBMS_JOB.SUBMIT(l_job, 'refresh_stats;', SYSDATE, 'SYSDATE + 1');
+END setup_jobs;
+"#;
+        let parse = parse_procedure(INPUT).unwrap();
+        assert!(parse.errors.is_empty(), "{:?}", parse.errors);
+
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let jobs = result.unwrap().scheduled_jobs;
+        assert_eq!(jobs.len(), 3);
+
+        assert_eq!(jobs[0].api, DboJobApi::Scheduler);
+        assert_eq!(jobs[0].kind, DboJobKind::PlsqlBlock);
+        assert_eq!(jobs[0].name.as_deref(), Some("purge_orders"));
+        assert_eq!(
+            jobs[0].action.as_deref(),
+            Some("BEGIN DELETE FROM orders; log_purge('orders'); END;")
+        );
+        assert_eq!(jobs[0].schedule.as_deref(), Some("'FREQ=DAILY; BYHOUR=2'"));
+        assert_eq!(jobs[0].location.start.line, 4);
+        let analysis = jobs[0].analysis.as_ref().unwrap();
+        assert_eq!(analysis.unfiltered_dml.len(), 1);
+        assert_eq!(analysis.unfiltered_dml[0].table, "orders");
+
+        assert_eq!(jobs[1].kind, DboJobKind::Executable);
+        assert_eq!(jobs[1].name.as_deref(), Some("backup"));
+        assert_eq!(jobs[1].action.as_deref(), Some("/usr/bin/backup.sh"));
+        assert_eq!(jobs[1].analysis, None);
+
+        assert_eq!(jobs[2].api, DboJobApi::Job);
+        assert_eq!(jobs[2].kind, DboJobKind::PlsqlBlock);
+        assert_eq!(jobs[2].action.as_deref(), Some("refresh_stats;"));
+        assert_eq!(jobs[2].schedule.as_deref(), Some("'SYSDATE + 1'"));
+        assert!(jobs[2].analysis.is_some());
+    }
+}
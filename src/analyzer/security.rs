@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Maps the Oracle invoker rights clause to the PostgreSQL security mode.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::attributes::routine;
+use crate::ast::{AstNode, InvokerRightsClause, Root};
+use crate::util::{KeywordCase, LineIndex, SourceLocation};
+
+/// The PostgreSQL `SECURITY` mode of a function or procedure.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboSecurity {
+    /// `SECURITY DEFINER`, translated from `AUTHID DEFINER`.
+    Definer,
+    /// `SECURITY INVOKER`, translated from `AUTHID CURRENT_USER`.
+    Invoker,
+}
+
+impl DboSecurity {
+    /// Returns the attribute declaring the mode, in lowercase.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Self::Definer => "security definer",
+            Self::Invoker => "security invoker",
+        }
+    }
+}
+
+/// Maps an `AUTHID` clause to the matching security mode. Returns [`None`] if
+/// there is no clause, in which case the PostgreSQL default applies.
+pub(super) fn map_invoker_rights(clause: Option<InvokerRightsClause>) -> Option<DboSecurity> {
+    clause.map(|c| {
+        if c.is_current_user() {
+            DboSecurity::Invoker
+        } else {
+            DboSecurity::Definer
+        }
+    })
+}
+
+/// Returns the edit replacing the `AUTHID` clause of a function or procedure
+/// by its security mode in `case`, or in the case of `AUTHID` otherwise.
+/// PostgreSQL takes the mode before the `AS` of the body, just where Oracle
+/// takes the clause before its `IS`.
+pub(super) fn translate_invoker_rights(
+    root: &Root,
+    case: Option<KeywordCase>,
+    index: &LineIndex,
+) -> Option<(String, SourceLocation)> {
+    let clause = routine(root)?
+        .first_child()
+        .and_then(|header| header.children().find_map(InvokerRightsClause::cast))?;
+
+    let case = case
+        .or_else(|| {
+            clause
+                .syntax()
+                .first_token()
+                .and_then(|t| KeywordCase::of(t.text()))
+        })
+        .unwrap_or_default();
+    let location = SourceLocation::new(clause.syntax().text_range(), index);
+    let security = map_invoker_rights(Some(clause))?;
+    Some((case.apply(security.keyword()), location))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, apply_rules_filtered, DboType, RuleFilter};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_map_invoker_rights() {
+        let security = |typ, sql: &str| {
+            let result = analyze(typ, sql, &DboAnalyzeContext::default()).unwrap();
+            result
+                .procedure
+                .map(|p| p.security)
+                .or_else(|| result.function.map(|f| f.security))
+                .unwrap()
+        };
+
+        assert_eq!(
+            security(
+                DboType::Procedure,
+                "CREATE PROCEDURE p AUTHID CURRENT_USER IS BEGIN NULL; END p;"
+            ),
+            Some(DboSecurity::Invoker)
+        );
+        assert_eq!(
+            security(
+                DboType::Function,
+                "CREATE FUNCTION f RETURN NUMBER AUTHID DEFINER IS BEGIN RETURN 1; END f;"
+            ),
+            Some(DboSecurity::Definer)
+        );
+        assert_eq!(
+            security(
+                DboType::Procedure,
+                "CREATE PROCEDURE p IS BEGIN NULL; END p;"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_translate_invoker_rights() {
+        let filter = RuleFilter {
+            ids: vec!["security".to_string()],
+            ..Default::default()
+        };
+        let apply = |typ, sql: &str| {
+            let result = apply_rules_filtered(typ, sql, &filter, &DboAnalyzeContext::default());
+            assert!(result.is_ok(), "{result:#?}");
+            result.unwrap().sql
+        };
+
+        assert_eq!(
+            apply(
+                DboType::Procedure,
+                "CREATE PROCEDURE p AUTHID CURRENT_USER IS BEGIN NULL; END p;"
+            ),
+            "CREATE PROCEDURE p SECURITY INVOKER IS BEGIN NULL; END p;"
+        );
+        assert_eq!(
+            apply(
+                DboType::Function,
+                "create function f return number\n    authid definer\nis\nbegin\n    return 1;\nend f;\n/\n"
+            ),
+            "create function f return number\n    security definer\nis\nbegin\n    return 1;\nend f;\n/\n"
+        );
+        assert_eq!(
+            apply(
+                DboType::Procedure,
+                "CREATE PROCEDURE p IS BEGIN NULL; END p;"
+            ),
+            "CREATE PROCEDURE p IS BEGIN NULL; END p;"
+        );
+    }
+}
@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Checks that `SELECT .. INTO` statements have a target for every column.
+
+use rowan::TextRange;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::DboAnalyzeContext;
+use crate::ast::{AstNode, IdentGroup, Root, SelectStmt};
+use crate::util::{LineIndex, SourceLocation};
+use crate::SqlIdent;
+use source_gen::syntax::SyntaxKind;
+
+/// A `SELECT .. INTO` statement whose number of target variables differs
+/// from the number of selected columns. Oracle rejects these when compiling,
+/// PostgreSQL only when the statement is executed.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboSelectIntoMismatch {
+    pub columns: usize,
+    pub variables: usize,
+    pub location: SourceLocation,
+}
+
+/// A `SELECT .. INTO` statement with more than one target, which does not
+/// match its select list.
+struct Mismatch {
+    select: SelectStmt,
+    columns: usize,
+    variables: usize,
+}
+
+/// Finds all `SELECT .. INTO` and `SELECT .. BULK COLLECT INTO` statements
+/// with more than one target, which do not match the select list, in source
+/// order. A single target may be a record and is thus not checked. The
+/// columns of `SELECT *` are looked up in the context, if it selects from a
+/// single table.
+pub(super) fn find_select_into_mismatches(
+    root: &Root,
+    ctx: &DboAnalyzeContext,
+    index: &LineIndex,
+) -> Vec<DboSelectIntoMismatch> {
+    mismatches(root, ctx)
+        .into_iter()
+        .map(|m| DboSelectIntoMismatch {
+            columns: m.columns,
+            variables: m.variables,
+            location: SourceLocation::new(m.select.syntax().text_range(), index),
+        })
+        .collect()
+}
+
+/// Returns the edits making the select list of each mismatch match its
+/// targets, like PostgreSQL does when executing it: surplus targets are set
+/// to `NULL`, and surplus columns are dropped. Columns of `SELECT *` cannot
+/// be dropped and are left alone.
+pub(super) fn align_select_lists(
+    root: &Root,
+    ctx: &DboAnalyzeContext,
+    index: &LineIndex,
+) -> Vec<(String, SourceLocation)> {
+    mismatches(root, ctx)
+        .into_iter()
+        .filter_map(|m| {
+            let columns = m
+                .select
+                .select_clause()
+                .map(|clause| clause.columns())
+                .unwrap_or_default();
+
+            if m.variables > m.columns {
+                let end = match columns.last() {
+                    Some(column) => column.syntax().text_range().end(),
+                    None => m
+                        .select
+                        .syntax()
+                        .children_with_tokens()
+                        .find(|it| it.kind() == SyntaxKind::Asterisk)?
+                        .text_range()
+                        .end(),
+                };
+                let nulls = ", NULL".repeat(m.variables - m.columns);
+                return Some((nulls, SourceLocation::new(TextRange::empty(end), index)));
+            }
+
+            let start = columns.get(m.variables.checked_sub(1)?)?.syntax();
+            let end = columns.last()?.syntax();
+            let range = TextRange::new(start.text_range().end(), end.text_range().end());
+            Some((String::new(), SourceLocation::new(range, index)))
+        })
+        .collect()
+}
+
+fn mismatches(root: &Root, ctx: &DboAnalyzeContext) -> Vec<Mismatch> {
+    root.syntax()
+        .descendants()
+        .filter_map(SelectStmt::cast)
+        .filter_map(|select| {
+            let variables = select
+                .syntax()
+                .children()
+                .find(|node| {
+                    matches!(
+                        node.kind(),
+                        SyntaxKind::IntoClause | SyntaxKind::BulkIntoClause
+                    )
+                })?
+                .children()
+                .filter_map(IdentGroup::cast)
+                .count();
+            if variables < 2 {
+                return None;
+            }
+
+            let columns = column_count(&select, ctx)?;
+            (columns != variables).then_some(Mismatch {
+                select,
+                columns,
+                variables,
+            })
+        })
+        .collect()
+}
+
+/// Returns the number of selected columns, if known.
+fn column_count(select: &SelectStmt, ctx: &DboAnalyzeContext) -> Option<usize> {
+    let is_wildcard = select
+        .syntax()
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .any(|t| t.kind() == SyntaxKind::Asterisk);
+
+    if is_wildcard {
+        let [table] = select.tables().try_into().ok()?;
+        let name = table.last()?.text();
+        return ctx
+            .table(&SqlIdent::from(name.as_str()))
+            .map(|table| table.column_count());
+    }
+
+    let columns = select.select_clause()?.columns();
+    // `t.*` expands to an unknown number of columns
+    if columns
+        .iter()
+        .any(|c| c.syntax().text().to_string().trim_end().ends_with('*'))
+    {
+        return None;
+    }
+
+    Some(columns.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{
+        analyze, apply_rules_filtered, DboColumnType, DboTable, DboTableColumn, DboType, RuleFilter,
+    };
+    use crate::parse_procedure;
+
+    use super::*;
+
+    #[test]
+    fn test_find_select_into_mismatches() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE load_emp(p_id NUMBER) IS
+    r_emp emp%ROWTYPE;
+    v_id NUMBER;
+    v_name VARCHAR2(100);
+    l_ids id_list;
+    l_names name_list;
+BEGIN
+    SELECT * INTO r_emp FROM emp WHERE id = p_id;
+    SELECT id, first_name, last_name INTO v_id, v_name FROM emp WHERE id = p_id;
+    SELECT id, first_name INTO v_id, v_name FROM emp WHERE id = p_id;
+    SELECT * INTO v_id, v_name FROM emp WHERE id = p_id;
+    SELECT id BULK COLLECT INTO l_ids, l_names FROM emp;
+END load_emp;
+"#;
+        let parse = parse_procedure(INPUT).unwrap();
+        assert!(parse.errors.is_empty(), "{:?}", parse.errors);
+
+        let column = || DboTableColumn::new(DboColumnType::Text);
+        let ctx = DboAnalyzeContext::new(HashMap::from([(
+            "emp".into(),
+            DboTable::new(HashMap::from([
+                ("id".into(), column()),
+                ("first_name".into(), column()),
+                ("last_name".into(), column()),
+            ])),
+        )]));
+
+        let result = analyze(DboType::Procedure, INPUT, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+
+        let mismatches = result
+            .unwrap()
+            .select_into_mismatches
+            .into_iter()
+            .map(|m| (m.columns, m.variables, m.location.start.line))
+            .collect::<Vec<_>>();
+        assert_eq!(mismatches, vec![(3, 2, 9), (3, 2, 11), (1, 2, 12)]);
+    }
+
+    #[test]
+    fn test_align_select_lists() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE load_emp(p_id NUMBER) IS
+    v_id NUMBER;
+    v_name VARCHAR2(100);
+    v_dept NUMBER;
+BEGIN
+    SELECT id, first_name, last_name INTO v_id, v_name FROM emp WHERE id = p_id;
+    SELECT id INTO v_id, v_name FROM emp WHERE id = p_id;
+    SELECT * INTO v_id, v_name, v_dept FROM emp WHERE id = p_id;
+    SELECT * INTO v_id FROM emp WHERE id = p_id;
+END load_emp;
+"#;
+        let column = || DboTableColumn::new(DboColumnType::Text);
+        let ctx = DboAnalyzeContext::new(HashMap::from([(
+            "emp".into(),
+            DboTable::new(HashMap::from([
+                ("id".into(), column()),
+                ("first_name".into(), column()),
+            ])),
+        )]));
+        let filter = RuleFilter {
+            ids: vec!["select_into".to_string()],
+            ..Default::default()
+        };
+
+        let result = apply_rules_filtered(DboType::Procedure, INPUT, &filter, &ctx);
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result.unwrap().sql,
+            r#"
+CREATE OR REPLACE PROCEDURE load_emp(p_id NUMBER) IS
+    v_id NUMBER;
+    v_name VARCHAR2(100);
+    v_dept NUMBER;
+BEGIN
+    SELECT id, first_name INTO v_id, v_name FROM emp WHERE id = p_id;
+    SELECT id, NULL INTO v_id, v_name FROM emp WHERE id = p_id;
+    SELECT *, NULL INTO v_id, v_name, v_dept FROM emp WHERE id = p_id;
+    SELECT * INTO v_id FROM emp WHERE id = p_id;
+END load_emp;
+"#
+        );
+    }
+}
@@ -0,0 +1,532 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements an analyzer keeping its context between calls.
+
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ptr;
+use std::rc::Rc;
+
+use rowan::{GreenNode, GreenNodeData};
+
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+use wasm_bindgen::prelude::*;
+
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+use crate::analyzer::{report_progress, DboBatchProgressCallback};
+
+use crate::analyzer::rules::{apply_edits, find_rule_edits, into_hints};
+use crate::analyzer::summary::summarize_parse;
+use crate::analyzer::{
+    analyze_batch_with_progress, analyze_root, apply_rule_batch, cast_to_root, parse_object,
+    AnalyzeError, AnalyzeFn, DboAnalyzeBatch, DboAnalyzeBatchResult, DboAnalyzeContext,
+    DboAnalyzeOptions, DboBatchProgress, DboMetaData, DboSummary, DboType, Profiler, Rule,
+    RuleApplication, RuleBatch, RuleEdit, RuleFilter, TargetVersion,
+};
+use crate::parser::Parse;
+
+/// Holds a [`DboAnalyzeContext`] for many analyses. From JS, this avoids
+/// passing the context, which may describe thousands of tables, across the
+/// WASM boundary on every call.
+#[cfg_attr(any(target_arch = "wasm32", target_arch = "wasm64"), wasm_bindgen)]
+#[derive(Clone, Debug, Default)]
+pub struct Analyzer {
+    ctx: DboAnalyzeContext,
+    /// The object parsed last, such that e.g. [`Analyzer::analyze()`] and
+    /// [`Analyzer::hints()`] of the same object parse it only once.
+    last_parse: RefCell<Option<CachedParse>>,
+    /// The edits of all rules on the tree found last, which stay the same
+    /// as long as the tree and the context do.
+    last_edits: RefCell<Option<CachedEdits>>,
+}
+
+type RuleEdits = Rc<[(&'static Rule, RuleEdit)]>;
+
+/// The edits of all rules on a tree, keyed by the type and a hash of the
+/// source it was parsed from. Only if those match, the trees are compared,
+/// which is cheap if it is the same tree and walks it otherwise. Changing
+/// the context clears it.
+#[derive(Clone, Debug)]
+struct CachedEdits {
+    typ: DboType,
+    hash: u64,
+    green: GreenNode,
+    edits: RuleEdits,
+}
+
+#[derive(Clone, Debug)]
+struct CachedParse {
+    typ: DboType,
+    sql: String,
+    parse: Rc<Parse>,
+    analyze_fn: AnalyzeFn,
+}
+
+impl Analyzer {
+    pub fn new(ctx: DboAnalyzeContext) -> Self {
+        Self {
+            ctx,
+            last_parse: RefCell::default(),
+            last_edits: RefCell::default(),
+        }
+    }
+
+    pub fn context(&self) -> &DboAnalyzeContext {
+        &self.ctx
+    }
+
+    /// Replaces the context used by all following analyses.
+    pub fn set_context(&mut self, ctx: DboAnalyzeContext) {
+        self.ctx = ctx;
+        self.last_parse.take();
+        self.last_edits.take();
+    }
+
+    pub fn target_version(&self) -> TargetVersion {
+        self.ctx.options().target_version
+    }
+
+    /// Sets the PostgreSQL version the rules write code for, keeping the
+    /// rest of the context.
+    pub fn set_target_version(&mut self, target_version: TargetVersion) {
+        let options = DboAnalyzeOptions {
+            target_version,
+            ..self.ctx.options().clone()
+        };
+        self.ctx = std::mem::take(&mut self.ctx).with_options(options);
+        self.last_edits.take();
+    }
+
+    /// Same as [`analyze()`], using the held context.
+    ///
+    /// [`analyze()`]: super::analyze
+    pub fn analyze(&self, typ: DboType, sql: &str) -> Result<DboMetaData, AnalyzeError> {
+        let (parse, analyze_fn) = self.parse(typ, sql)?;
+        analyze_root(
+            cast_to_root(&parse)?,
+            sql,
+            &self.ctx,
+            analyze_fn,
+            &mut Profiler::disabled(),
+        )
+    }
+
+    /// Same as [`analyze_summary()`], using the held context.
+    ///
+    /// [`analyze_summary()`]: super::analyze_summary
+    pub fn analyze_summary(&self, typ: DboType, sql: &str) -> Result<DboSummary, AnalyzeError> {
+        let (parse, _) = self.parse(typ, sql)?;
+        let edits = self.rule_edits(typ, &parse, sql)?;
+        summarize_parse(typ, &parse, &edits, sql, &self.ctx)
+    }
+
+    /// Returns the edits of all rules, ordered by rule id, then in source
+    /// order.
+    pub fn hints(&self, typ: DboType, sql: &str) -> Result<Vec<RuleEdit>, AnalyzeError> {
+        let (parse, _) = self.parse(typ, sql)?;
+        Ok(into_hints(self.rule_edits(typ, &parse, sql)?.to_vec()))
+    }
+
+    /// Same as [`analyze_batch_with_progress()`], using the held context.
+    pub fn analyze_batch(
+        &self,
+        batch: &DboAnalyzeBatch,
+        interval: usize,
+        on_progress: impl FnMut(DboBatchProgress),
+    ) -> DboAnalyzeBatchResult {
+        analyze_batch_with_progress(batch, &self.ctx, interval, on_progress)
+    }
+
+    /// Same as [`apply_rules_filtered()`], using the held context.
+    ///
+    /// [`apply_rules_filtered()`]: super::apply_rules_filtered
+    pub fn apply_rules(
+        &self,
+        typ: DboType,
+        sql: &str,
+        filter: &RuleFilter,
+    ) -> Result<RuleApplication, AnalyzeError> {
+        let (parse, _) = self.parse(typ, sql)?;
+        Ok(apply_edits(
+            sql,
+            self.rule_edits(typ, &parse, sql)?.to_vec(),
+            filter,
+        ))
+    }
+
+    /// Applies the rules whose ids start with `id`, leaving the others as
+    /// hints.
+    pub fn apply_rule(
+        &self,
+        typ: DboType,
+        sql: &str,
+        id: &str,
+    ) -> Result<RuleApplication, AnalyzeError> {
+        let filter = RuleFilter {
+            ids: vec![id.to_string()],
+            ..Default::default()
+        };
+        self.apply_rules(typ, sql, &filter)
+    }
+
+    /// Applies every rule, including those changing the behavior.
+    pub fn apply_all(&self, typ: DboType, sql: &str) -> Result<RuleApplication, AnalyzeError> {
+        self.apply_rules(typ, sql, &RuleFilter::default())
+    }
+
+    /// Same as [`apply_rule_batch()`], using the held context.
+    ///
+    /// [`apply_rule_batch()`]: super::apply_rule_batch
+    pub fn apply_rule_batch(&self, typ: DboType, sql: &str, steps: &[RuleFilter]) -> RuleBatch {
+        apply_rule_batch(typ, sql, steps, &self.ctx)
+    }
+
+    /// Parses the object, or returns the last parse if it is the same.
+    fn parse(&self, typ: DboType, sql: &str) -> Result<(Rc<Parse>, AnalyzeFn), AnalyzeError> {
+        let mut last_parse = self.last_parse.borrow_mut();
+        if let Some(cached) = last_parse
+            .as_ref()
+            .filter(|cached| cached.typ == typ && cached.sql == sql)
+        {
+            return Ok((cached.parse.clone(), cached.analyze_fn));
+        }
+
+        let (parse, analyze_fn) = parse_object(typ, sql, &self.ctx)?;
+        let parse = Rc::new(parse);
+        *last_parse = Some(CachedParse {
+            typ,
+            sql: sql.to_string(),
+            parse: parse.clone(),
+            analyze_fn,
+        });
+        Ok((parse, analyze_fn))
+    }
+
+    /// Finds the edits of all rules on `parse` of `sql`, or returns the last
+    /// ones if the tree is the same.
+    fn rule_edits(
+        &self,
+        typ: DboType,
+        parse: &Parse,
+        sql: &str,
+    ) -> Result<RuleEdits, AnalyzeError> {
+        let mut hasher = DefaultHasher::new();
+        sql.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let green = parse.syntax().green().into_owned();
+        let mut last_edits = self.last_edits.borrow_mut();
+        if let Some(cached) = last_edits.as_ref().filter(|cached| {
+            cached.typ == typ
+                && cached.hash == hash
+                && (ptr::eq::<GreenNodeData>(&*cached.green, &*green) || cached.green == green)
+        }) {
+            return Ok(cached.edits.clone());
+        }
+
+        let edits: RuleEdits = find_rule_edits(cast_to_root(parse)?, sql, &self.ctx).into();
+        *last_edits = Some(CachedEdits {
+            typ,
+            hash,
+            green,
+            edits: edits.clone(),
+        });
+        Ok(edits)
+    }
+}
+
+/// WASM exports of the [`Analyzer`] methods, see the free functions of the
+/// same names. Should _never_ be called from other Rust code.
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+#[wasm_bindgen(js_class = "Analyzer")]
+impl Analyzer {
+    #[wasm_bindgen(constructor)]
+    pub fn js_new(ctx: DboAnalyzeContext) -> Self {
+        Self::new(ctx)
+    }
+
+    #[wasm_bindgen(js_name = "setContext")]
+    pub fn js_set_context(&mut self, ctx: DboAnalyzeContext) {
+        self.set_context(ctx);
+    }
+
+    #[wasm_bindgen(js_name = "setTargetVersion")]
+    pub fn js_set_target_version(&mut self, target_version: TargetVersion) {
+        self.set_target_version(target_version);
+    }
+
+    #[wasm_bindgen(js_name = "analyze")]
+    pub fn js_analyze(&self, typ: DboType, sql: &str) -> Result<DboMetaData, JsValue> {
+        self.analyze(typ, sql)
+            .or_else(|err| Err(serde_wasm_bindgen::to_value(&err)?))
+    }
+
+    #[wasm_bindgen(js_name = "analyzeSummary")]
+    pub fn js_analyze_summary(&self, typ: DboType, sql: &str) -> Result<DboSummary, JsValue> {
+        self.analyze_summary(typ, sql)
+            .or_else(|err| Err(serde_wasm_bindgen::to_value(&err)?))
+    }
+
+    #[wasm_bindgen(js_name = "hints")]
+    pub fn js_hints(&self, typ: DboType, sql: &str) -> Result<JsValue, JsValue> {
+        let hints = self
+            .hints(typ, sql)
+            .or_else(|err| Err(serde_wasm_bindgen::to_value(&err)?))?;
+        Ok(serde_wasm_bindgen::to_value(&hints)?)
+    }
+
+    #[wasm_bindgen(js_name = "analyzeBatch")]
+    pub fn js_analyze_batch(
+        &self,
+        batch: DboAnalyzeBatch,
+        on_progress: Option<DboBatchProgressCallback>,
+        progress_interval: Option<u32>,
+    ) -> DboAnalyzeBatchResult {
+        let interval = progress_interval.unwrap_or(1) as usize;
+
+        self.analyze_batch(&batch, interval, |progress| {
+            report_progress(on_progress.as_ref(), progress)
+        })
+    }
+
+    #[wasm_bindgen(js_name = "applyRules")]
+    pub fn js_apply_rules(
+        &self,
+        typ: DboType,
+        sql: &str,
+        filter: RuleFilter,
+    ) -> Result<RuleApplication, JsValue> {
+        self.apply_rules(typ, sql, &filter)
+            .or_else(|err| Err(serde_wasm_bindgen::to_value(&err)?))
+    }
+
+    #[wasm_bindgen(js_name = "applyRule")]
+    pub fn js_apply_rule(
+        &self,
+        typ: DboType,
+        sql: &str,
+        id: &str,
+    ) -> Result<RuleApplication, JsValue> {
+        self.apply_rule(typ, sql, id)
+            .or_else(|err| Err(serde_wasm_bindgen::to_value(&err)?))
+    }
+
+    /// Takes the steps as an array of [`RuleFilter`]s.
+    #[wasm_bindgen(js_name = "applyRuleBatch")]
+    pub fn js_apply_rule_batch(
+        &self,
+        typ: DboType,
+        sql: &str,
+        steps: JsValue,
+    ) -> Result<RuleBatch, JsValue> {
+        let steps: Vec<RuleFilter> = serde_wasm_bindgen::from_value(steps)?;
+        Ok(self.apply_rule_batch(typ, sql, &steps))
+    }
+
+    #[wasm_bindgen(js_name = "applyAll")]
+    pub fn js_apply_all(&self, typ: DboType, sql: &str) -> Result<RuleApplication, JsValue> {
+        self.apply_all(typ, sql)
+            .or_else(|err| Err(serde_wasm_bindgen::to_value(&err)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboColumnType, DboTable, DboTableColumn};
+
+    use super::*;
+
+    #[test]
+    fn test_analyzer_keeps_context() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE raise_salary(sal NUMBER) IS
+BEGIN
+    UPDATE emp SET sal = sal * 1.1 WHERE id = 1;
+END raise_salary;
+"#;
+        let mut analyzer = Analyzer::default();
+        let result = analyzer.analyze(DboType::Procedure, INPUT);
+        assert!(result.is_ok(), "{result:#?}");
+        assert!(result.unwrap().parameter_conflicts.is_empty());
+
+        analyzer.set_context(DboAnalyzeContext::new(HashMap::from([(
+            "emp".into(),
+            DboTable::new(HashMap::from([(
+                "sal".into(),
+                DboTableColumn::new(DboColumnType::Integer),
+            )])),
+        )])));
+        let result = analyzer.analyze(DboType::Procedure, INPUT);
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(result.unwrap().parameter_conflicts.len(), 1);
+        assert_eq!(
+            analyzer.analyze(DboType::Procedure, INPUT),
+            analyze(DboType::Procedure, INPUT, analyzer.context())
+        );
+    }
+
+    #[test]
+    fn test_analyzer_applies_rules() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE log_line(p_text VARCHAR2) IS
+BEGIN
+    INSERT INTO emp_log (line) VALUES (p_text || CHR(10));
+END log_line;
+"#;
+        let analyzer = Analyzer::new(
+            DboAnalyzeContext::default()
+                .with_renames(HashMap::from([("emp_log".into(), "audit_log".into())])),
+        );
+
+        let result = analyzer.apply_rule(DboType::Procedure, INPUT, "renames");
+        assert!(result.is_ok(), "{result:#?}");
+        let application = result.unwrap();
+        assert!(application
+            .sql
+            .contains("INSERT INTO audit_log (line) VALUES (p_text || CHR(10));"));
+        assert_eq!(application.hints.len(), 1);
+
+        let result = analyzer.apply_all(DboType::Procedure, INPUT);
+        assert!(result.is_ok(), "{result:#?}");
+        let application = result.unwrap();
+        assert!(application
+            .sql
+            .contains("INSERT INTO audit_log (line) VALUES (p_text || E'\\n');"));
+        assert!(application.hints.is_empty());
+    }
+
+    #[test]
+    fn test_analyzer_hints_for_target_version() {
+        const INPUT: &str =
+            "CREATE TRIGGER stamp BEFORE INSERT ON orders FOR EACH ROW BEGIN NULL; END;";
+        let mut analyzer = Analyzer::default();
+        assert_eq!(analyzer.target_version(), TargetVersion::Pg16);
+
+        let result = analyzer.hints(DboType::Trigger, INPUT);
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(result.unwrap()[0].replacement, " OR REPLACE");
+        assert!(analyzer
+            .apply_all(DboType::Trigger, INPUT)
+            .unwrap()
+            .sql
+            .starts_with("CREATE OR REPLACE TRIGGER"));
+
+        analyzer.set_target_version(TargetVersion::Pg13);
+        assert_eq!(
+            analyzer.context().options().target_version,
+            TargetVersion::Pg13
+        );
+        assert_eq!(
+            analyzer.apply_all(DboType::Trigger, INPUT).unwrap().sql,
+            INPUT
+        );
+    }
+
+    #[test]
+    fn test_analyzer_caches_last_parse() {
+        const INPUT: &str = "BEGIN DELETE FROM orders; END;";
+        let mut analyzer = Analyzer::default();
+
+        let (first, _) = analyzer.parse(DboType::AnonymousBlock, INPUT).unwrap();
+        let (second, _) = analyzer.parse(DboType::AnonymousBlock, INPUT).unwrap();
+        assert!(Rc::ptr_eq(&first, &second));
+
+        let (other, _) = analyzer
+            .parse(DboType::AnonymousBlock, "BEGIN NULL; END;")
+            .unwrap();
+        assert!(!Rc::ptr_eq(&first, &other));
+
+        let (before, _) = analyzer.parse(DboType::AnonymousBlock, INPUT).unwrap();
+        analyzer.set_context(DboAnalyzeContext::default());
+        let (after, _) = analyzer.parse(DboType::AnonymousBlock, INPUT).unwrap();
+        assert!(!Rc::ptr_eq(&before, &after));
+    }
+
+    #[test]
+    fn test_analyzer_caches_rule_edits() {
+        const INPUT: &str = "BEGIN DELETE FROM orders; END;\n/";
+        let mut analyzer = Analyzer::default();
+        let (parse, _) = analyzer.parse(DboType::AnonymousBlock, INPUT).unwrap();
+
+        let first = analyzer
+            .rule_edits(DboType::AnonymousBlock, &parse, INPUT)
+            .unwrap();
+        let second = analyzer
+            .rule_edits(DboType::AnonymousBlock, &parse, INPUT)
+            .unwrap();
+        assert!(Rc::ptr_eq(&first, &second));
+        assert_eq!(first.len(), 1);
+
+        let reparsed = parse_object(DboType::AnonymousBlock, INPUT, analyzer.context())
+            .unwrap()
+            .0;
+        assert!(Rc::ptr_eq(
+            &first,
+            &analyzer
+                .rule_edits(DboType::AnonymousBlock, &reparsed, INPUT)
+                .unwrap()
+        ));
+
+        const OTHER: &str = "BEGIN DELETE FROM orders WHERE id = 1; END;\n/";
+        let (other, _) = analyzer.parse(DboType::AnonymousBlock, OTHER).unwrap();
+        assert!(!Rc::ptr_eq(
+            &first,
+            &analyzer
+                .rule_edits(DboType::AnonymousBlock, &other, OTHER)
+                .unwrap()
+        ));
+
+        analyzer.set_target_version(TargetVersion::Pg13);
+        assert!(!Rc::ptr_eq(
+            &first,
+            &analyzer
+                .rule_edits(DboType::AnonymousBlock, &parse, INPUT)
+                .unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_analyzer_applies_rules_to_all_objects() {
+        let analyzer = Analyzer::default();
+        let cases = [
+            (
+                DboType::Trigger,
+                "CREATE EDITIONABLE TRIGGER stamp BEFORE INSERT ON orders FOR EACH ROW BEGIN NULL; END;\n/",
+                "CREATE OR REPLACE TRIGGER stamp BEFORE INSERT ON orders FOR EACH ROW BEGIN NULL; END;",
+            ),
+            (
+                DboType::View,
+                "CREATE EDITIONABLE VIEW recent AS SELECT * FROM orders;\n/",
+                "CREATE OR REPLACE VIEW recent AS SELECT * FROM orders;",
+            ),
+            (
+                DboType::Package,
+                "CREATE EDITIONABLE PACKAGE billing AS PROCEDURE settle; END billing;\n/",
+                "CREATE OR REPLACE PACKAGE billing AS PROCEDURE settle; END billing;",
+            ),
+            (
+                DboType::AnonymousBlock,
+                "BEGIN UPDATE orders SET total = 0; END;\n/",
+                "BEGIN UPDATE orders SET total = 0; END;",
+            ),
+        ];
+
+        for (typ, input, expected) in cases {
+            let result = analyzer.apply_all(typ, input);
+            assert!(result.is_ok(), "{typ:?}: {result:#?}");
+            assert_eq!(result.unwrap().sql, expected, "{typ:?}");
+        }
+        assert!(!analyzer
+            .analyze(DboType::AnonymousBlock, "BEGIN DELETE FROM orders; END;")
+            .unwrap()
+            .unfiltered_dml
+            .is_empty());
+    }
+}
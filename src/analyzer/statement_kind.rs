@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Counts the PL/SQL statements of an object by their kind, e.g. to estimate
+//! the migration effort per construct.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, Root};
+use source_gen::syntax::{SyntaxElement, SyntaxKind, SyntaxNode};
+
+/// The kind of a PL/SQL statement.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboStatementKind {
+    /// `SELECT .. INTO`, including those starting with `WITH`.
+    SelectInto,
+    Insert,
+    Update,
+    Delete,
+    Assignment,
+    ProcedureCall,
+    If,
+    Case,
+    /// Any of `LOOP`, `WHILE .. LOOP` and `FOR .. LOOP`.
+    Loop,
+    Exit,
+    Continue,
+    /// A nested `BEGIN .. END` or `DECLARE .. BEGIN .. END` block.
+    Block,
+    Return,
+    Raise,
+    Null,
+    Open,
+    Fetch,
+    Close,
+    ExecuteImmediate,
+    Forall,
+    /// `COMMIT`, `ROLLBACK` or `SAVEPOINT`.
+    TransactionControl,
+    /// Statements not covered above, e.g. pragmas.
+    Other,
+}
+
+/// How often statements of a kind appear in an object.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboStatementCount {
+    pub kind: DboStatementKind,
+    pub count: usize,
+}
+
+/// Counts all statements by their kind, including those in nested blocks,
+/// branches, loops and exception handlers. Kinds which do not appear are left
+/// out, the rest is ordered as [`DboStatementKind`].
+pub(super) fn count_statement_kinds(root: &Root) -> Vec<DboStatementCount> {
+    let mut counts: Vec<DboStatementCount> = Vec::new();
+    for kind in root
+        .syntax()
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::BlockStatement)
+        .filter_map(|node| statement_kind(&node))
+    {
+        match counts.iter_mut().find(|c| c.kind == kind) {
+            Some(count) => count.count += 1,
+            None => counts.push(DboStatementCount { kind, count: 1 }),
+        }
+    }
+    counts.sort_by_key(|c| c.kind);
+    counts
+}
+
+/// Returns the kind of a `BlockStatement` from its first element. `IF`,
+/// `RETURN`, `NULL` and assignments have no node of their own.
+fn statement_kind(statement: &SyntaxNode) -> Option<DboStatementKind> {
+    let is_assignment = statement
+        .children_with_tokens()
+        .any(|it| it.kind() == SyntaxKind::Assign);
+    if is_assignment {
+        return Some(DboStatementKind::Assignment);
+    }
+
+    let first = statement.children_with_tokens().find(|it| {
+        !matches!(
+            it.kind(),
+            SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+        )
+    })?;
+    let kind = match first.kind() {
+        SyntaxKind::SelectStmt | SyntaxKind::WithClause => DboStatementKind::SelectInto,
+        SyntaxKind::InsertStmt => DboStatementKind::Insert,
+        SyntaxKind::UpdateStmt => DboStatementKind::Update,
+        SyntaxKind::DeleteStmt => DboStatementKind::Delete,
+        SyntaxKind::FunctionInvocation | SyntaxKind::IdentGroup => DboStatementKind::ProcedureCall,
+        SyntaxKind::CaseBlock => DboStatementKind::Case,
+        SyntaxKind::Loop => DboStatementKind::Loop,
+        SyntaxKind::ExitStmt => DboStatementKind::Exit,
+        SyntaxKind::ContinueStmt => DboStatementKind::Continue,
+        SyntaxKind::Block => DboStatementKind::Block,
+        SyntaxKind::RaiseStmt => DboStatementKind::Raise,
+        SyntaxKind::OpenStmt => DboStatementKind::Open,
+        SyntaxKind::FetchStmt => DboStatementKind::Fetch,
+        SyntaxKind::CloseStmt => DboStatementKind::Close,
+        SyntaxKind::ExecuteImmediateStmt => DboStatementKind::ExecuteImmediate,
+        SyntaxKind::ForallStmt => DboStatementKind::Forall,
+        SyntaxKind::CommitStmt | SyntaxKind::RollbackStmt | SyntaxKind::SavepointStmt => {
+            DboStatementKind::TransactionControl
+        }
+        SyntaxKind::Keyword => keyword_statement_kind(&first),
+        // Leftovers of statements the parser failed on
+        SyntaxKind::Error | SyntaxKind::Semicolon => return None,
+        _ => DboStatementKind::Other,
+    };
+    Some(kind)
+}
+
+fn keyword_statement_kind(keyword: &SyntaxElement) -> DboStatementKind {
+    let keyword = keyword
+        .as_token()
+        .map(|t| t.text().to_lowercase())
+        .unwrap_or_default();
+    match keyword.as_str() {
+        "if" => DboStatementKind::If,
+        "return" => DboStatementKind::Return,
+        "null" => DboStatementKind::Null,
+        _ => DboStatementKind::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_count_statement_kinds() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE settle(p_id NUMBER) IS
+    l_total NUMBER;
+BEGIN
+    SELECT SUM(amount) INTO l_total FROM invoices WHERE account_id = p_id;
+    IF l_total > 0 THEN
+        UPDATE accounts SET balance = balance - l_total WHERE id = p_id;
+        l_total := 0;
+    ELSE
+        FOR i IN 1..3 LOOP
+            notify(p_id, i);
+        END LOOP;
+    END IF;
+    BEGIN
+        DELETE FROM invoices WHERE account_id = p_id;
+        COMMIT;
+    EXCEPTION
+        WHEN OTHERS THEN
+            ROLLBACK;
+            RAISE;
+    END;
+END settle;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let counts = result
+            .unwrap()
+            .statement_counts
+            .into_iter()
+            .map(|c| (c.kind, c.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            counts,
+            vec![
+                (DboStatementKind::SelectInto, 1),
+                (DboStatementKind::Update, 1),
+                (DboStatementKind::Delete, 1),
+                (DboStatementKind::Assignment, 1),
+                (DboStatementKind::ProcedureCall, 1),
+                (DboStatementKind::If, 1),
+                (DboStatementKind::Loop, 1),
+                (DboStatementKind::Block, 1),
+                (DboStatementKind::Raise, 1),
+                (DboStatementKind::TransactionControl, 2),
+            ]
+        );
+    }
+}
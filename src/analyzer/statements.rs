@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Extracts the SQL statements embedded in PL/SQL code, e.g. to generate
+//! regression tests replaying them against the migrated schema.
+
+use rowan::TextRange;
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
+
+/// The kind of an embedded SQL statement.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboSqlStatementKind {
+    Select,
+    Insert,
+    Update,
+    Delete,
+}
+
+/// Returns all SQL statements embedded in the object in source order, with
+/// their text as written, without the terminating semicolon. Cursor queries
+/// are included, subqueries are part of their enclosing statement. Dynamic
+/// SQL of `EXECUTE IMMEDIATE` is not known until runtime and thus skipped.
+pub fn extract_sql_statements(root: &Root) -> Vec<(DboSqlStatementKind, String, SourceLocation)> {
+    let source = root.syntax().to_string();
+    let index = LineIndex::new(&source);
+
+    root.syntax()
+        .descendants()
+        .filter(|node| {
+            statement_kind(node).is_some()
+                && !node
+                    .ancestors()
+                    .skip(1)
+                    .any(|a| statement_kind(&a).is_some())
+        })
+        .filter_map(|node| {
+            let kind = statement_kind(&node)?;
+            let range = statement_range(&node)?;
+            Some((
+                kind,
+                source[range].to_string(),
+                SourceLocation::new(range, &index),
+            ))
+        })
+        .collect()
+}
+
+fn statement_kind(node: &SyntaxNode) -> Option<DboSqlStatementKind> {
+    match node.kind() {
+        SyntaxKind::SelectStmt => Some(DboSqlStatementKind::Select),
+        SyntaxKind::InsertStmt => Some(DboSqlStatementKind::Insert),
+        SyntaxKind::UpdateStmt => Some(DboSqlStatementKind::Update),
+        SyntaxKind::DeleteStmt => Some(DboSqlStatementKind::Delete),
+        _ => None,
+    }
+}
+
+/// Returns the range of the statement without surrounding trivia and the
+/// terminating semicolon.
+fn statement_range(node: &SyntaxNode) -> Option<TextRange> {
+    let mut tokens = node
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|t| {
+            !matches!(
+                t.kind(),
+                SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if tokens.last().map(|t| t.kind()) == Some(SyntaxKind::Semicolon) {
+        tokens.pop();
+    }
+
+    Some(
+        tokens
+            .first()?
+            .text_range()
+            .cover(tokens.last()?.text_range()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::parse_procedure;
+
+    use super::*;
+
+    #[test]
+    fn test_extract_sql_statements() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE close_account(p_id NUMBER) IS
+    CURSOR c_orders IS SELECT id FROM orders WHERE account_id = p_id;
+    v_balance NUMBER;
+BEGIN
+    SELECT balance INTO v_balance FROM accounts WHERE id = p_id;
+    DELETE FROM orders
+     WHERE account_id = p_id
+       AND id = (SELECT MAX(order_id) FROM invoices WHERE paid = 1);
+    UPDATE accounts SET closed = 1 WHERE id = p_id;
+    INSERT INTO audit_log (account_id) VALUES (p_id);
+END close_account;
+"#;
+        let parse = parse_procedure(INPUT).unwrap();
+        assert!(parse.errors.is_empty(), "{:?}", parse.errors);
+        let root = Root::cast(parse.syntax()).unwrap();
+
+        let statements = extract_sql_statements(&root)
+            .into_iter()
+            .map(|(kind, text, location)| (kind, text, location.start.line))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            statements,
+            vec![
+                (
+                    DboSqlStatementKind::Select,
+                    "SELECT id FROM orders WHERE account_id = p_id".to_string(),
+                    2
+                ),
+                (
+                    DboSqlStatementKind::Select,
+                    "SELECT balance INTO v_balance FROM accounts WHERE id = p_id".to_string(),
+                    5
+                ),
+                (
+                    DboSqlStatementKind::Delete,
+                    "DELETE FROM orders\n     WHERE account_id = p_id\n       AND id = (SELECT MAX(order_id) FROM invoices WHERE paid = 1)".to_string(),
+                    6
+                ),
+                (
+                    DboSqlStatementKind::Update,
+                    "UPDATE accounts SET closed = 1 WHERE id = p_id".to_string(),
+                    9
+                ),
+                (
+                    DboSqlStatementKind::Insert,
+                    "INSERT INTO audit_log (account_id) VALUES (p_id)".to_string(),
+                    10
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects subprograms defined in the declare section of other objects.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, Function, Procedure, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::SyntaxKind;
+
+/// The kind of a subprogram.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboSubprogramKind {
+    Function,
+    Procedure,
+}
+
+/// A function or procedure defined inside another object, which has to be
+/// lifted into a standalone PostgreSQL function.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboNestedSubprogram {
+    pub kind: DboSubprogramKind,
+    pub name: String,
+    /// The number of enclosing declare sections, starting at `1` for
+    /// subprograms declared directly in the top-level object.
+    pub depth: usize,
+    pub location: SourceLocation,
+}
+
+/// Finds all nested subprogram definitions, in source order. Forward
+/// declarations are skipped, as they are always followed by a definition.
+pub(super) fn find_nested_subprograms(root: &Root, index: &LineIndex) -> Vec<DboNestedSubprogram> {
+    root.syntax()
+        .descendants()
+        .filter(|node| node.parent().map(|p| p.kind()) == Some(SyntaxKind::DeclareSection))
+        .filter_map(|node| {
+            let (kind, name, body) = if let Some(function) = Function::cast(node.clone()) {
+                (
+                    DboSubprogramKind::Function,
+                    function.name(),
+                    function.body(),
+                )
+            } else if let Some(procedure) = Procedure::cast(node.clone()) {
+                (
+                    DboSubprogramKind::Procedure,
+                    procedure.name(),
+                    procedure.body(),
+                )
+            } else {
+                return None;
+            };
+
+            body.map(|_| DboNestedSubprogram {
+                kind,
+                name: name.unwrap_or_else(|| "<unknown>".to_string()),
+                depth: node
+                    .ancestors()
+                    .filter(|n| n.kind() == SyntaxKind::DeclareSection)
+                    .count(),
+                location: SourceLocation::new(node.text_range(), index),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_find_nested_subprograms() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE outer_proc IS
+    PROCEDURE log_it;
+    FUNCTION twice(i NUMBER) RETURN NUMBER IS
+        PROCEDURE inner_proc IS BEGIN NULL; END inner_proc;
+    BEGIN
+        RETURN i * 2;
+    END twice;
+    PROCEDURE log_it IS BEGIN NULL; END log_it;
+BEGIN
+    log_it;
+END outer_proc;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let nested = result
+            .unwrap()
+            .nested_subprograms
+            .into_iter()
+            .map(|s| (s.kind, s.name, s.depth, s.location.start.line))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            nested,
+            vec![
+                (DboSubprogramKind::Function, "twice".to_string(), 1, 3),
+                (DboSubprogramKind::Procedure, "inner_proc".to_string(), 2, 4),
+                (DboSubprogramKind::Procedure, "log_it".to_string(), 1, 8),
+            ]
+        );
+    }
+}
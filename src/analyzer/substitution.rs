@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects SQL*Plus substitution variables left in scripts.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::SyntaxKind;
+
+/// A SQL*Plus substitution variable, e.g. `&schema`, which SQL*Plus replaces
+/// textually before running the script. These are errors, as PostgreSQL
+/// runs the code as is, so they must become parameters or `psql` variables.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboSubstitutionVariable {
+    /// The lowercased name without the ampersands and the `.` ending it.
+    pub name: String,
+    /// Whether the variable is written as `&&name`, i.e. SQL*Plus prompts for
+    /// it only once per session.
+    pub is_persistent: bool,
+    pub location: SourceLocation,
+}
+
+/// Finds all uses of substitution variables, in source order.
+pub(super) fn find_substitution_variables(
+    root: &Root,
+    index: &LineIndex,
+) -> Vec<DboSubstitutionVariable> {
+    root.syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|t| t.kind() == SyntaxKind::SubstitutionVar)
+        .map(|token| DboSubstitutionVariable {
+            name: token
+                .text()
+                .trim_start_matches('&')
+                .trim_end_matches('.')
+                .to_lowercase(),
+            is_persistent: token.text().starts_with("&&"),
+            location: SourceLocation::new(token.text_range(), index),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    #[test]
+    fn test_find_substitution_variables() {
+        const INPUT: &str = r#"
+SELECT o.id, &&Amount_Col
+  FROM &owner..orders o, stage_&run_id.items i
+ WHERE o.created >= SYSDATE - &days
+   AND o.note <> 'R&D' AND i.order_id = o.id;
+"#;
+        let result = analyze(DboType::Query, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let meta_data = result.unwrap();
+        let variables = meta_data.substitution_variables;
+        assert_eq!(
+            variables
+                .iter()
+                .map(|v| (v.name.as_str(), v.is_persistent, v.location.start.line))
+                .collect::<Vec<_>>(),
+            vec![
+                ("amount_col", true, 1),
+                ("owner", false, 2),
+                ("run_id", false, 2),
+                ("days", false, 3)
+            ]
+        );
+        assert_eq!(
+            variables[1].location.end.col - variables[1].location.start.col,
+            7
+        );
+    }
+}
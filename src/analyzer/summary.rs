@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements a cheap analysis, which only counts what [`analyze()`] reports.
+//!
+//! [`analyze()`]: super::analyze
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::datatype::{find_precision_warnings, find_unresolved_packages};
+use crate::analyzer::dml::{find_record_dml, find_unfiltered_dml};
+use crate::analyzer::exception::find_swallowed_exceptions;
+use crate::analyzer::lines_of_code::count_object_lines;
+use crate::analyzer::materialized_view::analyze_materialized_view;
+use crate::analyzer::outer_join::find_unsafe_outer_joins;
+use crate::analyzer::param_conflict::find_parameter_conflicts;
+use crate::analyzer::rules::find_rule_edits;
+use crate::analyzer::select_into::find_select_into_mismatches;
+use crate::analyzer::statements::extract_sql_statements;
+use crate::analyzer::substitution::find_substitution_variables;
+use crate::analyzer::transaction::find_loop_transactions;
+use crate::analyzer::{
+    cast_to_root, parse_object, AnalyzeError, DboAnalyzeContext, DboType, Rule, RuleEdit,
+    RuleSeverity,
+};
+use crate::ast::{AstNode, Root};
+use crate::util::LineIndex;
+use crate::{Parse, ParseErrorType};
+use source_gen::syntax::SyntaxKind;
+
+/// The result of [`analyze_summary()`], e.g. for listing many objects.
+#[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboSummary {
+    /// The lines of the body of functions, procedures and triggers, or of the
+    /// whole object otherwise, counted as configured in the
+    /// [`DboAnalyzeOptions`].
+    ///
+    /// [`DboAnalyzeOptions`]: super::DboAnalyzeOptions
+    pub lines_of_code: usize,
+    /// The number of statements, including nested ones. Objects without a
+    /// body count their SQL statements.
+    pub statements: usize,
+    /// Findings which fail after the migration: parameters conflicting with
+    /// columns, `SELECT .. INTO` target count mismatches, DML taking a whole
+    /// row from a record, outer joins which cannot be rewritten as is and
+    /// SQL*Plus substitution variables.
+    pub errors: usize,
+    /// Findings which need a review: unfiltered DML, swallowed exceptions,
+    /// transaction control inside loops, datatypes losing precision and
+    /// parser warnings.
+    pub warnings: usize,
+    /// Constructs the parser or PostgreSQL does not support, including skipped
+    /// physical properties like `PARALLEL` or `NOLOGGING`.
+    pub unsupported_features: usize,
+    /// The edits of all rules, see [`apply_rules_filtered()`].
+    ///
+    /// [`apply_rules_filtered()`]: super::apply_rules_filtered
+    pub hints: DboHintCounts,
+}
+
+/// The number of rule edits per [`RuleSeverity`].
+#[derive(Tsify, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboHintCounts {
+    pub info: usize,
+    pub warning: usize,
+    pub error: usize,
+}
+
+/// Parses the object and counts its statements and findings, skipping the
+/// metadata [`analyze()`] builds, e.g. the call graph or required grants.
+///
+/// [`analyze()`]: super::analyze
+pub fn analyze_summary(
+    typ: DboType,
+    sql: &str,
+    ctx: &DboAnalyzeContext,
+) -> Result<DboSummary, AnalyzeError> {
+    let (parse, _) = parse_object(typ, sql, ctx)?;
+    let edits = find_rule_edits(cast_to_root(&parse)?, sql, ctx);
+    summarize_parse(typ, &parse, &edits, sql, ctx)
+}
+
+/// Same as [`analyze_summary()`] on an already parsed object and the edits
+/// of all rules on it.
+pub(super) fn summarize_parse(
+    typ: DboType,
+    parse: &Parse,
+    edits: &[(&Rule, RuleEdit)],
+    sql: &str,
+    ctx: &DboAnalyzeContext,
+) -> Result<DboSummary, AnalyzeError> {
+    let root = cast_to_root(parse)?;
+    let index = LineIndex::new(sql);
+
+    let errors = find_parameter_conflicts(&root, ctx, &index).len()
+        + find_select_into_mismatches(&root, ctx, &index).len()
+        + find_record_dml(&root, &index).len()
+        + find_unsafe_outer_joins(&root, &index).len()
+        + find_substitution_variables(&root, &index).len();
+    let warnings = find_unfiltered_dml(&root, &index).len()
+        + find_swallowed_exceptions(&root, &index).len()
+        + find_loop_transactions(&root, &index).len()
+        + find_precision_warnings(&root, ctx, &index).len()
+        + find_unresolved_packages(&root, ctx, &index).len()
+        + parse.errors.iter().filter(|e| e.typ.is_warning()).count();
+
+    let mut unsupported_features = parse
+        .errors
+        .iter()
+        .filter(|e| matches!(e.typ, ParseErrorType::Unimplemented(_)))
+        .count()
+        + root
+            .syntax()
+            .descendants()
+            .filter(|node| node.kind() == SyntaxKind::IgnoredClause)
+            .count();
+    if typ == DboType::MaterializedView {
+        unsupported_features += analyze_materialized_view(cast_to_root(parse)?, ctx)?
+            .materialized_view
+            .map_or(0, |view| view.unsupported_features.len());
+    }
+
+    let mut hints = DboHintCounts::default();
+    for (rule, _) in edits {
+        match rule.severity {
+            RuleSeverity::Info => hints.info += 1,
+            RuleSeverity::Warning => hints.warning += 1,
+            RuleSeverity::Error => hints.error += 1,
+        }
+    }
+
+    Ok(DboSummary {
+        lines_of_code: count_object_lines(&root).get(ctx.options().lines_of_code),
+        statements: statements(&root),
+        errors,
+        warnings,
+        unsupported_features,
+        hints,
+    })
+}
+
+/// WASM export of [`analyze_summary()`]. Should _never_ be called from other
+/// Rust code.
+#[cfg(any(target_arch = "wasm32", target_arch = "wasm64"))]
+#[wasm_bindgen(js_name = "analyzeSummary")]
+pub fn js_analyze_summary(
+    typ: DboType,
+    sql: &str,
+    ctx: DboAnalyzeContext,
+) -> Result<DboSummary, JsValue> {
+    analyze_summary(typ, sql, &ctx).or_else(|err| Err(serde_wasm_bindgen::to_value(&err)?))
+}
+
+fn statements(root: &Root) -> usize {
+    match root
+        .syntax()
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::BlockStatement)
+        .count()
+    {
+        0 => extract_sql_statements(root).len(),
+        count => count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_analyze_summary() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE purge(p_days NUMBER) IS
+    l_count NUMBER;
+BEGIN
+    SELECT COUNT(*) INTO l_count FROM orders;
+    DELETE FROM orders;
+EXCEPTION
+    WHEN OTHERS THEN
+        NULL;
+END purge_all;
+"#;
+        let result = analyze_summary(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result.unwrap(),
+            DboSummary {
+                lines_of_code: 8,
+                statements: 3,
+                errors: 0,
+                warnings: 3,
+                unsupported_features: 0,
+                hints: DboHintCounts {
+                    info: 0,
+                    warning: 0,
+                    error: 0,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_analyze_summary_materialized_view() {
+        const INPUT: &str = r#"
+CREATE MATERIALIZED VIEW sales_mv
+    NOLOGGING PARALLEL 4
+    REFRESH FAST ON COMMIT
+    ENABLE QUERY REWRITE
+AS SELECT region, SUM(amount) FROM sales GROUP BY region;
+"#;
+        let result = analyze_summary(
+            DboType::MaterializedView,
+            INPUT,
+            &DboAnalyzeContext::default(),
+        );
+        assert!(result.is_ok(), "{result:#?}");
+
+        let summary = result.unwrap();
+        assert_eq!(summary.lines_of_code, 5);
+        assert_eq!(summary.statements, 1);
+        assert_eq!(summary.unsupported_features, 5);
+        assert_eq!(
+            summary.hints,
+            DboHintCounts {
+                info: 0,
+                warning: 0,
+                error: 2,
+            }
+        );
+    }
+}
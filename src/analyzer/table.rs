@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements analysis of Oracle `CREATE TABLE .. AS SELECT` statements.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::analyzer::{AnalyzeError, DboAnalyzeContext, DboMetaData};
+use crate::ast::{AstNode, IgnoredClause, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::SyntaxKind;
+
+/// A physical property dropped for PostgreSQL, e.g. `PARALLEL 4`.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboDroppedOption {
+    /// The option as written.
+    pub option: String,
+    /// How to get a similar behavior in PostgreSQL, if at all.
+    pub hint: String,
+    pub location: SourceLocation,
+}
+
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboTableMetaData {
+    pub name: String,
+    /// The query the table is created from.
+    pub query: String,
+    pub dropped_options: Vec<DboDroppedOption>,
+    /// The statement without the dropped options, as run by PostgreSQL.
+    /// Left out if the statement could not be parsed completely, as parts of
+    /// it would be missing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    pub replacement: Option<String>,
+}
+
+pub(super) fn analyze_table(
+    root: Root,
+    ctx: &DboAnalyzeContext,
+) -> Result<DboMetaData, AnalyzeError> {
+    let table = root
+        .table()
+        .ok_or_else(|| AnalyzeError::ParseError("failed to find table".to_owned()))?;
+    let index =
+        LineIndex::new(&root.syntax().to_string()).with_excerpt_lines(ctx.options().excerpt_lines);
+
+    let dropped_options = table
+        .ignored_clauses()
+        .iter()
+        .map(|clause| DboDroppedOption {
+            option: clause.syntax().text().to_string(),
+            hint: dropped_option_hint(clause).to_string(),
+            location: SourceLocation::new(clause.syntax().text_range(), &index),
+        })
+        .collect();
+
+    let is_complete = root.trailing_content().is_none()
+        && !table
+            .syntax()
+            .descendants()
+            .any(|node| node.kind() == SyntaxKind::Error);
+
+    // Leave out the options along with the whitespace following them
+    let mut replacement = String::new();
+    let mut after_option = false;
+    for element in table.syntax().children_with_tokens() {
+        match element.kind() {
+            SyntaxKind::IgnoredClause => after_option = true,
+            SyntaxKind::Whitespace if after_option => {}
+            _ => {
+                after_option = false;
+                replacement.push_str(&element.to_string());
+            }
+        }
+    }
+
+    Ok(DboMetaData {
+        table: Some(DboTableMetaData {
+            name: table.name().unwrap_or_else(|| "<unknown>".to_string()),
+            query: table
+                .query()
+                .map(|q| q.text().trim().to_string())
+                .unwrap_or_default(),
+            dropped_options,
+            replacement: is_complete.then(|| replacement.trim().to_string()),
+        }),
+        ..Default::default()
+    })
+}
+
+fn dropped_option_hint(clause: &IgnoredClause) -> &'static str {
+    match clause.keyword().as_deref() {
+        Some("nologging") => {
+            "use `CREATE UNLOGGED TABLE` if the table need not survive a crash or a failover"
+        }
+        Some("parallel" | "noparallel") => {
+            "PostgreSQL plans parallel scans itself, see `max_parallel_workers_per_gather`"
+        }
+        Some("compress" | "nocompress") => "PostgreSQL compresses large values itself (TOAST)",
+        Some("pctfree") => "use the `fillfactor` storage parameter, i.e. 100 - `PCTFREE`",
+        Some("tablespace") => "add `TABLESPACE` again if the tablespace exists in PostgreSQL",
+        _ => "PostgreSQL has no equivalent, nothing to do",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, DboType};
+    use crate::DboAnalyzeContext;
+
+    #[test]
+    fn test_analyze_table() {
+        const INPUT: &str = r#"
+CREATE TABLE stage_orders
+    NOLOGGING
+    PARALLEL 8 COMPRESS FOR QUERY HIGH
+    STORAGE (INITIAL 64K)
+AS SELECT id, amount FROM orders WHERE created >= SYSDATE - 1;
+"#;
+        let result = analyze(DboType::Table, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let table = result.unwrap().table.unwrap();
+        assert_eq!(table.name, "stage_orders");
+        assert_eq!(
+            table.query,
+            "SELECT id, amount FROM orders WHERE created >= SYSDATE - 1;"
+        );
+        assert_eq!(
+            table
+                .dropped_options
+                .iter()
+                .map(|o| (o.option.as_str(), o.location.start.line))
+                .collect::<Vec<_>>(),
+            vec![
+                ("NOLOGGING", 2),
+                ("PARALLEL 8", 3),
+                ("COMPRESS FOR QUERY HIGH", 3),
+                ("STORAGE (INITIAL 64K)", 4),
+            ]
+        );
+        assert!(table.dropped_options[0].hint.contains("UNLOGGED"));
+        assert_eq!(
+            table.replacement.as_deref(),
+            Some("CREATE TABLE stage_orders\n    AS SELECT id, amount FROM orders WHERE created >= SYSDATE - 1;")
+        );
+    }
+
+    #[test]
+    fn test_analyze_table_with_compound_query() {
+        const INPUT: &str = r#"
+CREATE TABLE stage_ids NOLOGGING AS
+WITH recent AS (SELECT id FROM orders WHERE created >= SYSDATE - 1)
+SELECT id FROM recent UNION ALL SELECT id FROM returns;
+"#;
+        let result = analyze(DboType::Table, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let table = result.unwrap().table.unwrap();
+        assert_eq!(
+            table.query,
+            "WITH recent AS (SELECT id FROM orders WHERE created >= SYSDATE - 1)\n\
+             SELECT id FROM recent UNION ALL SELECT id FROM returns;"
+        );
+        assert_eq!(
+            table.replacement.as_deref(),
+            Some(
+                "CREATE TABLE stage_ids AS\n\
+                 WITH recent AS (SELECT id FROM orders WHERE created >= SYSDATE - 1)\n\
+                 SELECT id FROM recent UNION ALL SELECT id FROM returns;"
+            )
+        );
+    }
+
+    #[test]
+    fn test_analyze_table_with_trailing_content() {
+        const INPUT: &str = "CREATE TABLE t AS SELECT a FROM x; DROP TABLE x;";
+        let result = analyze(DboType::Table, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(result.unwrap().table.unwrap().replacement, None);
+    }
+}
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Normalizes how objects end, such that `psql` can run them one after
+//! another.
+
+use rowan::TextRange;
+
+use crate::ast::{AstNode, Root};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::{SyntaxKind, SyntaxToken};
+
+/// Returns the edits making the object end with exactly one semicolon, in
+/// source order. A missing semicolon is added after the last token, further
+/// semicolons and SQL*Plus slashes are removed along with the whitespace
+/// before them. Trailing whitespace and comments are kept.
+pub(super) fn normalize_terminator(
+    root: &Root,
+    index: &LineIndex,
+) -> Vec<(String, SourceLocation)> {
+    let mut tokens = root
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|t| {
+            !matches!(
+                t.kind(),
+                SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let code_end = tokens
+        .iter()
+        .rposition(|t| !matches!(t.kind(), SyntaxKind::Semicolon | SyntaxKind::Slash))
+        .map_or(0, |i| i + 1);
+    let Some(last_code) = code_end.checked_sub(1).map(|i| tokens[i].clone()) else {
+        return Vec::new();
+    };
+    let mut terminators = tokens.split_off(code_end);
+
+    let mut edits = Vec::new();
+    match terminators
+        .iter()
+        .position(|t| t.kind() == SyntaxKind::Semicolon)
+    {
+        Some(i) => {
+            terminators.remove(i);
+        }
+        None => edits.push((
+            ";".to_string(),
+            SourceLocation::new(TextRange::empty(last_code.text_range().end()), index),
+        )),
+    }
+    edits.extend(
+        terminators
+            .iter()
+            .map(|t| (String::new(), SourceLocation::new(removal(t), index))),
+    );
+    edits
+}
+
+/// Returns the range of `token` along with the whitespace before it, unless
+/// that ends an inline comment.
+fn removal(token: &SyntaxToken) -> TextRange {
+    match token.prev_token() {
+        Some(whitespace)
+            if whitespace.kind() == SyntaxKind::Whitespace
+                && whitespace
+                    .prev_token()
+                    .map_or(true, |t| t.kind() != SyntaxKind::InlineComment) =>
+        {
+            whitespace.text_range().cover(token.text_range())
+        }
+        _ => token.text_range(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{apply_rules_filtered, DboAnalyzeContext, DboType, RuleFilter};
+
+    fn apply_terminator(typ: DboType, sql: &str) -> String {
+        let filter = RuleFilter {
+            ids: vec!["terminator".to_string()],
+            ..Default::default()
+        };
+        let result = apply_rules_filtered(typ, sql, &filter, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+        result.unwrap().sql
+    }
+
+    #[test]
+    fn test_normalize_terminator() {
+        assert_eq!(
+            apply_terminator(
+                DboType::Procedure,
+                "CREATE PROCEDURE touch IS BEGIN NULL; END touch;\n/\n"
+            ),
+            "CREATE PROCEDURE touch IS BEGIN NULL; END touch;\n"
+        );
+        assert_eq!(
+            apply_terminator(
+                DboType::View,
+                "CREATE VIEW recent AS SELECT * FROM orders -- all of them\n/"
+            ),
+            "CREATE VIEW recent AS SELECT * FROM orders; -- all of them\n"
+        );
+        assert_eq!(
+            apply_terminator(
+                DboType::View,
+                "CREATE VIEW recent AS SELECT * FROM orders;\n/"
+            ),
+            "CREATE VIEW recent AS SELECT * FROM orders;"
+        );
+        assert_eq!(
+            apply_terminator(DboType::Query, "SELECT * FROM orders;\n"),
+            "SELECT * FROM orders;\n"
+        );
+    }
+}
@@ -0,0 +1,541 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Collects transaction control statements inside loops, and chooses
+//! procedures for objects ending transactions.
+
+use serde::{Deserialize, Serialize};
+use tsify::Tsify;
+use wasm_bindgen::prelude::*;
+
+use crate::ast::{AstNode, Function, FunctionHeader, Procedure, Root, Trigger};
+use crate::util::{LineIndex, SourceLocation};
+use source_gen::syntax::{SyntaxElement, SyntaxKind, SyntaxNode};
+
+/// The kind of a transaction control statement.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum DboTransactionControl {
+    Commit,
+    Rollback,
+}
+
+/// A `COMMIT` or `ROLLBACK` inside a loop, e.g. to commit every n rows.
+///
+/// PostgreSQL only allows this in procedures which are not called inside an
+/// explicit transaction, and each commit is costly. Such loops should rather
+/// be rewritten to set-based statements committed once, or to process a
+/// batch of rows per call.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboLoopTransaction {
+    pub kind: DboTransactionControl,
+    /// The enclosing function, procedure or trigger.
+    pub routine: String,
+    pub location: SourceLocation,
+    /// The location of the innermost loop containing the statement.
+    pub loop_location: SourceLocation,
+}
+
+/// A function or procedure ending transactions, which has to become a
+/// PostgreSQL procedure. Functions cannot `COMMIT` or `ROLLBACK` there, and
+/// procedures only if called with `CALL` outside of an explicit transaction.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct DboTransactionRoutine {
+    /// The `COMMIT` and `ROLLBACK` statements, in source order.
+    pub statements: Vec<SourceLocation>,
+    /// Whether the object is a function, which returns its result through
+    /// an `OUT` parameter as procedure.
+    pub is_function: bool,
+    /// The header of the procedure. For functions, `RETURN` and the
+    /// function-only attributes, e.g. `DETERMINISTIC`, are replaced by the
+    /// `OUT` parameter.
+    pub header: String,
+    pub header_location: SourceLocation,
+    /// The `RETURN` statements of a function, which have to assign the `OUT`
+    /// parameter and return without a value.
+    pub returns: Vec<SourceLocation>,
+    /// How callers have to be changed.
+    pub call_hint: String,
+}
+
+/// Finds all `COMMIT` and `ROLLBACK` statements inside loops of the same
+/// routine, in source order. `ROLLBACK TO SAVEPOINT` does not end the
+/// transaction and is skipped.
+pub(super) fn find_loop_transactions(root: &Root, index: &LineIndex) -> Vec<DboLoopTransaction> {
+    root.syntax()
+        .descendants()
+        .filter_map(|node| {
+            let kind = match node.kind() {
+                SyntaxKind::CommitStmt => DboTransactionControl::Commit,
+                SyntaxKind::RollbackStmt if !is_savepoint_rollback(&node) => {
+                    DboTransactionControl::Rollback
+                }
+                _ => return None,
+            };
+
+            let loop_node = node
+                .ancestors()
+                .take_while(|a| !matches!(a.kind(), SyntaxKind::Function | SyntaxKind::Procedure))
+                .find(|a| a.kind() == SyntaxKind::Loop)?;
+
+            Some(DboLoopTransaction {
+                kind,
+                routine: routine_name(&node),
+                location: SourceLocation::new(node.text_range(), index),
+                loop_location: SourceLocation::new(loop_node.text_range(), index),
+            })
+        })
+        .collect()
+}
+
+/// A `COMMIT` inside loops, see [`find_loop_commits()`].
+pub(super) struct LoopCommit {
+    pub(super) statement: SyntaxNode,
+    /// The outermost loop of the routine containing the statement.
+    pub(super) outer_loop: SyntaxNode,
+    /// Whether the outermost loop is directly followed by a `COMMIT`.
+    pub(super) committed_after: bool,
+}
+
+/// Finds all `COMMIT` statements inside loops of the same routine, in source
+/// order, along with the outermost loop, after which a single `COMMIT` ends
+/// the transaction once.
+pub(super) fn find_loop_commits(root: &Root) -> Vec<LoopCommit> {
+    root.syntax()
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::CommitStmt)
+        .filter_map(|statement| {
+            let outer_loop = statement
+                .ancestors()
+                .take_while(|a| !matches!(a.kind(), SyntaxKind::Function | SyntaxKind::Procedure))
+                .filter(|a| a.kind() == SyntaxKind::Loop)
+                .last()?;
+            let committed_after = outer_loop
+                .parent()
+                .and_then(|block_statement| block_statement.next_sibling())
+                .and_then(|next| next.first_child())
+                .is_some_and(|next| next.kind() == SyntaxKind::CommitStmt);
+
+            Some(LoopCommit {
+                statement,
+                outer_loop,
+                committed_after,
+            })
+        })
+        .collect()
+}
+
+/// Chooses a procedure for the function or procedure of `root` if it ends
+/// transactions, i.e. contains `COMMIT` or `ROLLBACK` but not only `ROLLBACK
+/// TO SAVEPOINT`.
+pub(super) fn find_transaction_routine(
+    root: &Root,
+    index: &LineIndex,
+) -> Option<DboTransactionRoutine> {
+    let statements = root
+        .syntax()
+        .descendants()
+        .filter(|node| match node.kind() {
+            SyntaxKind::CommitStmt => true,
+            SyntaxKind::RollbackStmt => !is_savepoint_rollback(node),
+            _ => false,
+        })
+        .map(|node| SourceLocation::new(node.text_range(), index))
+        .collect::<Vec<_>>();
+    if statements.is_empty() {
+        return None;
+    }
+
+    if let Some(procedure) = root.procedure() {
+        let header = procedure.header()?;
+        let name = procedure.name().unwrap_or_else(|| "<unknown>".to_string());
+        return Some(DboTransactionRoutine {
+            statements,
+            is_function: false,
+            header: header.syntax().text().to_string(),
+            header_location: SourceLocation::new(header.syntax().text_range(), index),
+            returns: Vec::new(),
+            call_hint: format!(
+                "Call `{name}` with `CALL` outside of an explicit transaction block, and not from \
+                 a function."
+            ),
+        });
+    }
+
+    let function = root.function()?;
+    let header = function.header()?;
+    let name = function.name().unwrap_or_else(|| "<unknown>".to_string());
+    let (procedure_header, result) = procedure_header(&header);
+    let returns = function
+        .body()
+        .map(|body| {
+            body.syntax()
+                .descendants()
+                .filter(|node| is_value_return(node, function.syntax()))
+                .map(|node| SourceLocation::new(node.text_range(), index))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(DboTransactionRoutine {
+        statements,
+        is_function: true,
+        header: procedure_header,
+        header_location: SourceLocation::new(header.syntax().text_range(), index),
+        returns,
+        call_hint: format!(
+            "Replace calls in expressions, e.g. `x := {name}(..)`, by `CALL {name}(.., x)` \
+             outside of an explicit transaction block, and not from a function. The result is \
+             returned through `{result}`."
+        ),
+    })
+}
+
+/// Rewrites a function header into a procedure header, returning it along
+/// with the name of the added `OUT` parameter.
+fn procedure_header(header: &FunctionHeader) -> (String, String) {
+    let params = header
+        .param_list()
+        .map(|list| list.params())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|param| param.name())
+        .map(|name| name.to_lowercase())
+        .collect::<Vec<_>>();
+    let mut result = "result".to_string();
+    while params.contains(&result) {
+        result.push('_');
+    }
+    let return_type = header
+        .return_type()
+        .map(|typ| typ.syntax().text().to_string())
+        .unwrap_or_default();
+    let out_param = format!("{result} OUT {return_type}");
+
+    let mut text = String::new();
+    let mut after_return = false;
+    for element in header.syntax().children_with_tokens() {
+        match element {
+            SyntaxElement::Token(t)
+                if t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("function") =>
+            {
+                text.push_str(if t.text() == "function" {
+                    "procedure"
+                } else {
+                    "PROCEDURE"
+                });
+            }
+            SyntaxElement::Token(t)
+                if t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("return") =>
+            {
+                text.truncate(text.trim_end().len());
+                after_return = true;
+            }
+            // Only `AUTHID` applies to procedures as well
+            SyntaxElement::Node(n)
+                if after_return && n.kind() == SyntaxKind::InvokerRightsClause =>
+            {
+                text.push(' ');
+                text.push_str(&n.text().to_string());
+            }
+            _ if after_return => {}
+            SyntaxElement::Node(n) if n.kind() == SyntaxKind::ParamList => {
+                // Everything up to the closing parenthesis of the list itself
+                let elements = n.children_with_tokens().collect::<Vec<_>>();
+                let end = elements
+                    .iter()
+                    .rposition(|it| it.kind() == SyntaxKind::RParen)
+                    .unwrap_or(elements.len());
+                let list = elements[..end]
+                    .iter()
+                    .map(|it| it.to_string())
+                    .collect::<String>();
+                text.push_str(&format!("{}, {out_param})", list.trim_end()));
+            }
+            SyntaxElement::Node(n) if n.kind() == SyntaxKind::IdentGroup => {
+                text.push_str(&n.text().to_string());
+                if header.param_list().is_none() {
+                    text.push_str(&format!("({out_param})"));
+                }
+            }
+            element => text.push_str(&element.to_string()),
+        }
+    }
+    (text.trim_end().to_string(), result)
+}
+
+/// Whether `node` is a `RETURN <expr>;` statement of `function` itself, not
+/// of a nested subprogram.
+fn is_value_return(node: &SyntaxNode, function: &SyntaxNode) -> bool {
+    let starts_with_return = node.first_token().is_some_and(|t| {
+        t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("return")
+    });
+
+    node.kind() == SyntaxKind::BlockStatement
+        && starts_with_return
+        && node.children().any(|n| n.kind() == SyntaxKind::Expression)
+        && node
+            .ancestors()
+            .find(|a| a.kind() == SyntaxKind::Function)
+            .as_ref()
+            == Some(function)
+}
+
+fn is_savepoint_rollback(node: &SyntaxNode) -> bool {
+    node.children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .any(|t| t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("to"))
+}
+
+fn routine_name(node: &SyntaxNode) -> String {
+    node.ancestors()
+        .find_map(|node| {
+            Function::cast(node.clone())
+                .and_then(|f| f.name())
+                .or_else(|| Procedure::cast(node.clone()).and_then(|p| p.name()))
+                .or_else(|| Trigger::cast(node).and_then(|t| t.name()))
+        })
+        .unwrap_or_else(|| "<unknown>".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::analyzer::{analyze, apply_rules_filtered, DboType, RuleFilter};
+    use crate::DboAnalyzeContext;
+
+    use super::*;
+
+    #[test]
+    fn test_find_loop_transactions() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE archive_orders IS
+    l_count NUMBER := 0;
+BEGIN
+    FOR r IN (SELECT id FROM orders) LOOP
+        INSERT INTO orders_archive (id) VALUES (r.id);
+        l_count := l_count + 1;
+        IF MOD(l_count, 1000) = 0 THEN
+            COMMIT;
+        END IF;
+    END LOOP;
+    WHILE l_count > 0 LOOP
+        SAVEPOINT before_delete;
+        DELETE FROM orders WHERE ROWNUM <= 100;
+        ROLLBACK TO SAVEPOINT before_delete;
+        ROLLBACK;
+        l_count := l_count - 100;
+    END LOOP;
+    COMMIT;
+END archive_orders;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let transactions = result
+            .unwrap()
+            .loop_transactions
+            .into_iter()
+            .map(|t| {
+                (
+                    t.kind,
+                    t.routine,
+                    t.location.start.line,
+                    t.loop_location.start.line,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            transactions,
+            vec![
+                (
+                    DboTransactionControl::Commit,
+                    "archive_orders".to_string(),
+                    8,
+                    4
+                ),
+                (
+                    DboTransactionControl::Rollback,
+                    "archive_orders".to_string(),
+                    15,
+                    11
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_move_loop_commits() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE archive_orders IS
+    l_count NUMBER := 0;
+BEGIN
+    FOR r IN (SELECT id FROM orders) LOOP
+        INSERT INTO orders_archive (id) VALUES (r.id);
+        l_count := l_count + 1;
+        IF MOD(l_count, 1000) = 0 THEN
+            COMMIT;
+        END IF;
+    END LOOP;
+    WHILE l_count > 0 LOOP
+        DELETE FROM orders WHERE ROWNUM <= 100;
+        commit;
+        l_count := l_count - 100;
+        LOOP
+            EXIT WHEN l_count > 0;
+            COMMIT;
+        END LOOP;
+    END LOOP;
+    COMMIT;
+END archive_orders;
+"#;
+        let filter = RuleFilter {
+            ids: vec!["loop_commits".to_string()],
+            ..Default::default()
+        };
+
+        let result = apply_rules_filtered(
+            DboType::Procedure,
+            INPUT,
+            &filter,
+            &DboAnalyzeContext::default(),
+        );
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result.unwrap().sql,
+            r#"
+CREATE OR REPLACE PROCEDURE archive_orders IS
+    l_count NUMBER := 0;
+BEGIN
+    FOR r IN (SELECT id FROM orders) LOOP
+        INSERT INTO orders_archive (id) VALUES (r.id);
+        l_count := l_count + 1;
+        IF MOD(l_count, 1000) = 0 THEN
+        END IF;
+    END LOOP;
+    COMMIT;
+    WHILE l_count > 0 LOOP
+        DELETE FROM orders WHERE ROWNUM <= 100;
+        l_count := l_count - 100;
+        LOOP
+            EXIT WHEN l_count > 0;
+        END LOOP;
+    END LOOP;
+    COMMIT;
+END archive_orders;
+"#
+        );
+    }
+
+    #[test]
+    fn test_find_transaction_routine_of_function() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE FUNCTION close_period(p_period IN NUMBER)
+    RETURN NUMBER DETERMINISTIC AUTHID DEFINER
+IS
+BEGIN
+    UPDATE periods SET closed = 1 WHERE id = p_period;
+    IF SQL%ROWCOUNT = 0 THEN
+        ROLLBACK;
+        RETURN 0;
+    END IF;
+    COMMIT;
+    RETURN 1;
+END close_period;
+"#;
+        let result = analyze(DboType::Function, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let routine = result.unwrap().transaction_routine.unwrap();
+        assert!(routine.is_function);
+        assert_eq!(
+            routine.header,
+            "CREATE OR REPLACE PROCEDURE close_period(p_period IN NUMBER, result OUT NUMBER) AUTHID DEFINER"
+        );
+        assert_eq!(
+            routine
+                .statements
+                .iter()
+                .map(|l| l.start.line)
+                .collect::<Vec<_>>(),
+            vec![7, 10]
+        );
+        assert_eq!(
+            routine
+                .returns
+                .iter()
+                .map(|l| l.start.line)
+                .collect::<Vec<_>>(),
+            vec![8, 11]
+        );
+        assert!(routine.call_hint.contains("CALL close_period(.., x)"));
+    }
+
+    #[test]
+    fn test_find_transaction_routine_of_procedure() {
+        const INPUT: &str = r#"
+CREATE OR REPLACE PROCEDURE purge_log(result NUMBER) IS
+BEGIN
+    SAVEPOINT before_purge;
+    DELETE FROM log_entries;
+    ROLLBACK TO SAVEPOINT before_purge;
+END purge_log;
+"#;
+        let result = analyze(DboType::Procedure, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(result.unwrap().transaction_routine, None);
+
+        let input = INPUT.replace("ROLLBACK TO SAVEPOINT before_purge", "COMMIT");
+        let result = analyze(DboType::Procedure, &input, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+
+        let routine = result.unwrap().transaction_routine.unwrap();
+        assert!(!routine.is_function);
+        assert_eq!(
+            routine.header,
+            "CREATE OR REPLACE PROCEDURE purge_log(result NUMBER)"
+        );
+        assert!(routine.returns.is_empty());
+    }
+
+    #[test]
+    fn test_procedure_header_without_parameters() {
+        const INPUT: &str = r#"
+CREATE FUNCTION next_batch RETURN PLS_INTEGER IS
+BEGIN
+    COMMIT;
+    RETURN 1;
+END;
+"#;
+        let result = analyze(DboType::Function, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result.unwrap().transaction_routine.unwrap().header,
+            "CREATE PROCEDURE next_batch(result OUT PLS_INTEGER)"
+        );
+    }
+
+    #[test]
+    fn test_procedure_header_with_sized_parameter() {
+        const INPUT: &str = r#"
+CREATE FUNCTION archive(p_note VARCHAR2(200) ) RETURN NUMBER IS
+BEGIN
+    COMMIT;
+    RETURN 1;
+END;
+"#;
+        let result = analyze(DboType::Function, INPUT, &DboAnalyzeContext::default());
+        assert!(result.is_ok(), "{result:#?}");
+        assert_eq!(
+            result.unwrap().transaction_routine.unwrap().header,
+            "CREATE PROCEDURE archive(p_note VARCHAR2(200), result OUT NUMBER)"
+        );
+    }
+}
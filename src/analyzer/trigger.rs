@@ -6,8 +6,9 @@ use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
 
-use crate::analyzer::{AnalyzeError, DboMetaData};
-use crate::ast::Root;
+use crate::analyzer::lines_of_code::count_lines;
+use crate::analyzer::{AnalyzeError, DboAnalyzeContext, DboMetaData};
+use crate::ast::{AstNode, Root};
 
 #[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[tsify(into_wasm_abi, from_wasm_abi)]
@@ -18,23 +19,25 @@ pub struct DboTriggerMetaData {
     pub lines_of_code: usize,
 }
 
-pub(super) fn analyze_trigger(root: Root) -> Result<DboMetaData, AnalyzeError> {
+pub(super) fn analyze_trigger(
+    root: Root,
+    ctx: &DboAnalyzeContext,
+) -> Result<DboMetaData, AnalyzeError> {
     let trigger = root
         .trigger()
         .ok_or_else(|| AnalyzeError::ParseError("failed to find trigger".to_owned()))?;
 
     let body = trigger
         .body()
-        .map(|b| b.text())
         .ok_or_else(|| AnalyzeError::ParseError("failed to find trigger body".to_owned()))?;
 
     let name = trigger.name().unwrap_or_else(|| "<unknown>".to_string());
-    let lines_of_code = body.matches('\n').count() + 1;
+    let lines_of_code = count_lines(body.syntax()).get(ctx.options().lines_of_code);
 
     Ok(DboMetaData {
         trigger: Some(DboTriggerMetaData {
             name,
-            body,
+            body: body.text(),
             lines_of_code,
         }),
         ..Default::default()
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 use wasm_bindgen::prelude::*;
 
-use crate::analyzer::{AnalyzeError, DboMetaData};
+use crate::analyzer::{AnalyzeError, DboAnalyzeContext, DboMetaData};
 use crate::ast::Root;
 
 #[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -16,7 +16,10 @@ pub struct DboViewMetaData {
     pub name: String,
 }
 
-pub(super) fn analyze_view(root: Root) -> Result<DboMetaData, AnalyzeError> {
+pub(super) fn analyze_view(
+    root: Root,
+    _ctx: &DboAnalyzeContext,
+) -> Result<DboMetaData, AnalyzeError> {
     let view = root
         .view()
         .ok_or_else(|| AnalyzeError::ParseError("failed to find view".to_owned()))?;
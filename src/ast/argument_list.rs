@@ -4,18 +4,22 @@
 
 //! Typed AST nodes for an argument list and its arguments.
 
-use crate::ast::AstNode;
+use crate::ast::{AstNode, IdentGroup};
+use source_gen::syntax::{SyntaxElement, SyntaxKind};
 
 use super::typed_syntax_node;
 
 typed_syntax_node!(ArgumentList, Argument);
 
 impl ArgumentList {
+    /// Returns the arguments in order, regardless of any comments between
+    /// them.
+    pub fn args(&self) -> impl Iterator<Item = Argument> {
+        self.syntax().children().filter_map(Argument::cast)
+    }
+
     pub fn arguments(&self) -> Vec<Argument> {
-        self.syntax()
-            .children()
-            .filter_map(Argument::cast)
-            .collect::<Vec<Argument>>()
+        self.args().collect::<Vec<Argument>>()
     }
 }
 
@@ -23,4 +27,103 @@ impl Argument {
     pub fn text(&self) -> String {
         self.syntax.text().to_string()
     }
+
+    /// Returns the parameter name of an argument in named notation, e.g.
+    /// `job_name` for `job_name => 'nightly'`.
+    pub fn name(&self) -> Option<String> {
+        let has_arrow = self
+            .syntax
+            .children_with_tokens()
+            .any(|it| it.kind() == SyntaxKind::FatArrow);
+        if !has_arrow {
+            return None;
+        }
+
+        self.syntax
+            .children()
+            .find_map(IdentGroup::cast)
+            .and_then(|ident| ident.name())
+    }
+
+    /// Returns the text of the passed value, without the parameter name of
+    /// named notation.
+    pub fn value(&self) -> String {
+        let is_named = self.name().is_some();
+        self.syntax
+            .children_with_tokens()
+            .skip_while(|it| is_named && it.kind() != SyntaxKind::FatArrow)
+            .skip(usize::from(is_named))
+            .map(|it| it.to_string())
+            .collect::<String>()
+            .trim()
+            .to_string()
+    }
+
+    /// Returns the passed expression, without the parameter name of named
+    /// notation and any whitespace or comments around it. Depending on the
+    /// argument, this is e.g. an `Expression` node or a literal token.
+    pub fn expression(&self) -> Option<SyntaxElement> {
+        let is_named = self.name().is_some();
+        self.syntax
+            .children_with_tokens()
+            .skip_while(|it| is_named && it.kind() != SyntaxKind::FatArrow)
+            .skip(usize::from(is_named))
+            .find(|it| {
+                !matches!(
+                    it.kind(),
+                    SyntaxKind::Whitespace
+                        | SyntaxKind::Comment
+                        | SyntaxKind::InlineComment
+                        | SyntaxKind::Hint
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::ast::{FunctionInvocation, Root};
+
+    use super::*;
+
+    #[test]
+    fn test_args_with_comments() {
+        const INPUT: &str = r#"
+CREATE PROCEDURE p IS
+BEGIN
+    x := f(/* first */ a, -- second
+        p_flag => /* default */ 1 + 2, 'c');
+END p;
+"#;
+        let parse = crate::parse_procedure(INPUT).unwrap();
+        let root = Root::cast(parse.syntax()).unwrap();
+        let list = root
+            .syntax()
+            .descendants()
+            .find_map(FunctionInvocation::cast)
+            .and_then(|f| f.syntax().children().find_map(ArgumentList::cast))
+            .unwrap();
+
+        let args = list
+            .args()
+            .map(|arg| {
+                let expression = arg.expression().unwrap();
+                (arg.name(), expression.kind(), expression.to_string())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            args,
+            vec![
+                (None, SyntaxKind::Expression, "a".to_string()),
+                (
+                    Some("p_flag".to_string()),
+                    SyntaxKind::Expression,
+                    "1 + 2".to_string()
+                ),
+                (None, SyntaxKind::QuotedLiteral, "'c'".to_string()),
+            ]
+        );
+    }
 }
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Typed AST nodes for `COMMENT ON` statements.
+
+use crate::ast::{AstNode, IdentGroup};
+use source_gen::syntax::SyntaxKind;
+
+use super::typed_syntax_node;
+
+typed_syntax_node!(CommentStmt);
+
+impl CommentStmt {
+    /// Returns the table, column or materialized view commented on.
+    pub fn object(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
+
+    /// Returns the comment as written, including its quotes.
+    pub fn comment(&self) -> Option<String> {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|t| t.kind() == SyntaxKind::QuotedLiteral)
+            .map(|t| t.text().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::Root;
+
+    use super::*;
+
+    #[test]
+    fn check_ast_node_to_comment() {
+        let result =
+            crate::parse_comment("COMMENT ON COLUMN hr.emp.sal IS 'Monthly, in EUR';").unwrap();
+        assert!(result.ok(), "{:#?}", result.errors);
+        let root = Root::cast(result.syntax()).unwrap();
+
+        let comment = root.comment().unwrap();
+        assert_eq!(
+            comment.object().and_then(|o| o.name()),
+            Some("hr.emp.sal".to_string())
+        );
+        assert_eq!(comment.comment(), Some("'Monthly, in EUR'".to_string()));
+    }
+}
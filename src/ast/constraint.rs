@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Typed AST nodes for table and view constraints.
+
+use crate::ast::{AstNode, IdentGroup};
+use source_gen::syntax::{SyntaxKind, SyntaxToken};
+
+use super::typed_syntax_node;
+
+typed_syntax_node!(Constraint, ReferencesClause, UsingIndexClause);
+
+impl Constraint {
+    /// Returns the name given with `CONSTRAINT`, if any.
+    pub fn name(&self) -> Option<String> {
+        let named = self
+            .keywords()
+            .first()
+            .is_some_and(|t| t.text().eq_ignore_ascii_case("constraint"));
+        if named {
+            self.syntax.children().find_map(IdentGroup::cast)?.name()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `REFERENCES` clause of a foreign key.
+    pub fn references(&self) -> Option<ReferencesClause> {
+        self.syntax.children().find_map(ReferencesClause::cast)
+    }
+
+    /// Returns the `USING INDEX` clause of a primary key or unique
+    /// constraint.
+    pub fn using_index(&self) -> Option<UsingIndexClause> {
+        self.syntax.children().find_map(UsingIndexClause::cast)
+    }
+
+    /// Whether the check may be deferred, i.e. `DEFERRABLE` but not
+    /// `NOT DEFERRABLE`.
+    pub fn is_deferrable(&self) -> bool {
+        let keywords = self.keywords();
+        keywords.iter().enumerate().any(|(i, t)| {
+            t.text().eq_ignore_ascii_case("deferrable")
+                && !(i > 0 && keywords[i - 1].text().eq_ignore_ascii_case("not"))
+        })
+    }
+
+    /// Whether the check is `INITIALLY DEFERRED`.
+    pub fn is_initially_deferred(&self) -> bool {
+        self.keywords().windows(2).any(|pair| {
+            pair[0].text().eq_ignore_ascii_case("initially")
+                && pair[1].text().eq_ignore_ascii_case("deferred")
+        })
+    }
+
+    /// Returns the keywords directly within the constraint, in source order.
+    fn keywords(&self) -> Vec<SyntaxToken> {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|t| t.kind() == SyntaxKind::Keyword)
+            .collect()
+    }
+}
+
+impl ReferencesClause {
+    /// Returns the referenced table.
+    pub fn table(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
+
+    /// Returns the referenced columns, which are empty for the primary key.
+    pub fn columns(&self) -> Vec<IdentGroup> {
+        self.syntax
+            .children()
+            .filter_map(IdentGroup::cast)
+            .skip(1)
+            .collect()
+    }
+
+    /// Returns the lowercased action after `ON DELETE`, i.e. `cascade` or
+    /// `set null`, if any.
+    pub fn on_delete(&self) -> Option<String> {
+        let keywords = self
+            .syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|t| t.kind() == SyntaxKind::Keyword)
+            .map(|t| t.text().to_lowercase())
+            .collect::<Vec<_>>();
+        let position = keywords.iter().position(|k| k == "delete")?;
+        Some(keywords[position + 1..].join(" ")).filter(|action| !action.is_empty())
+    }
+}
+
+impl UsingIndexClause {
+    /// Returns the existing index the constraint uses, if named.
+    pub fn index(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
+
+    /// Whether the index is created in place, i.e. `USING INDEX (CREATE INDEX
+    /// ..)`.
+    pub fn creates_index(&self) -> bool {
+        self.syntax
+            .children_with_tokens()
+            .any(|it| it.kind() == SyntaxKind::LParen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::Root;
+    use crate::grammar::parse_constraint;
+    use crate::parser::Parser;
+
+    use super::*;
+
+    fn constraint(input: &str) -> Constraint {
+        let mut parser = Parser::new(input);
+        parse_constraint(&mut parser);
+        let root = Root::cast(parser.build().syntax()).unwrap();
+        root.syntax().children().find_map(Constraint::cast).unwrap()
+    }
+
+    #[test]
+    fn check_ast_node_to_foreign_key() {
+        let constraint = constraint(
+            "CONSTRAINT fk_line FOREIGN KEY (order_id, line_no) REFERENCES order_lines (order_id, line_no) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED",
+        );
+        assert_eq!(constraint.name(), Some("fk_line".to_string()));
+        assert!(constraint.is_deferrable());
+        assert!(constraint.is_initially_deferred());
+        assert!(constraint.using_index().is_none());
+
+        let references = constraint.references().unwrap();
+        assert_eq!(
+            references.table().and_then(|t| t.name()),
+            Some("order_lines".to_string())
+        );
+        assert_eq!(
+            references
+                .columns()
+                .iter()
+                .filter_map(|c| c.name())
+                .collect::<Vec<_>>(),
+            vec!["order_id", "line_no"]
+        );
+        assert_eq!(references.on_delete(), Some("set null".to_string()));
+    }
+
+    #[test]
+    fn check_ast_node_to_using_index() {
+        let constraint =
+            constraint("PRIMARY KEY (id) NOT DEFERRABLE USING INDEX pk_orders_idx ENABLE");
+        assert_eq!(constraint.name(), None);
+        assert!(!constraint.is_deferrable());
+        assert!(constraint.references().is_none());
+
+        let using_index = constraint.using_index().unwrap();
+        assert_eq!(
+            using_index.index().and_then(|i| i.name()),
+            Some("pk_orders_idx".to_string())
+        );
+        assert!(!using_index.creates_index());
+    }
+}
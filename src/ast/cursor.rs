@@ -1,10 +1,136 @@
-// use crate::ast::AstNode;
+use crate::ast::{AstNode, Expression, IdentGroup, SelectStmt};
 use crate::typed_syntax_node;
+use source_gen::syntax::SyntaxKind;
 
-typed_syntax_node!(CursorStmt);
+typed_syntax_node!(
+    CloseStmt,
+    CursorAttribute,
+    CursorStmt,
+    FetchStmt,
+    OpenStmt,
+    RowtypeClause
+);
 
 impl CursorStmt {
-    // pub fn row_type(&self) -> Option<RowType> {}
+    /// Returns the name of the cursor.
+    pub fn name(&self) -> Option<String> {
+        self.syntax
+            .children()
+            .find_map(IdentGroup::cast)
+            .and_then(|ident| ident.name())
+    }
+
+    /// Returns the declared parameters as written, e.g. `p_id NUMBER`.
+    pub fn parameters(&self) -> Vec<String> {
+        self.syntax
+            .children()
+            .filter(|node| node.kind() == SyntaxKind::CursorParameterDeclarations)
+            .flat_map(|node| node.children())
+            .filter(|node| node.kind() == SyntaxKind::CursorParameterDeclaration)
+            .map(|node| node.text().to_string().trim().to_string())
+            .collect()
+    }
+
+    /// Returns the `RETURN` clause declaring the row type of the cursor.
+    pub fn return_type(&self) -> Option<RowtypeClause> {
+        self.syntax.children().find_map(RowtypeClause::cast)
+    }
+
+    /// Returns the query of the cursor, `None` if it is only declared.
+    pub fn query(&self) -> Option<SelectStmt> {
+        self.syntax.children().find_map(SelectStmt::cast)
+    }
+}
+
+impl CursorAttribute {
+    /// Returns the cursor, `SQL` for the implicit one.
+    pub fn cursor(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
+
+    /// Returns the name of the attribute, e.g. `BULK_EXCEPTIONS` for
+    /// `SQL%BULK_EXCEPTIONS(i).ERROR_INDEX`.
+    pub fn attribute(&self) -> Option<String> {
+        self.syntax
+            .children()
+            .nth(1)?
+            .first_token()
+            .map(|t| t.text().to_string())
+    }
+
+    /// Returns whether this is an attribute of the implicit `SQL` cursor.
+    pub fn is_implicit(&self) -> bool {
+        self.cursor()
+            .and_then(|c| c.name())
+            .is_some_and(|name| name.eq_ignore_ascii_case("sql"))
+    }
+}
+
+impl RowtypeClause {
+    /// Returns the referenced table, record type or variable.
+    pub fn reference(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
+
+    /// Returns whether the type is anchored using `%ROWTYPE`.
+    pub fn is_rowtype(&self) -> bool {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .any(|t| t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("rowtype"))
+    }
+}
+
+impl FetchStmt {
+    /// Returns the fetched cursor.
+    pub fn cursor(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
+
+    /// Returns whether the rows are fetched using `BULK COLLECT INTO`.
+    pub fn is_bulk(&self) -> bool {
+        self.syntax
+            .children()
+            .any(|node| node.kind() == SyntaxKind::BulkIntoClause)
+    }
+
+    /// Returns the `LIMIT` of a bulk fetch, if any.
+    pub fn limit(&self) -> Option<Expression> {
+        self.syntax.children().find_map(Expression::cast)
+    }
+
+    /// Returns the variables, records or collections fetched into.
+    pub fn targets(&self) -> Vec<IdentGroup> {
+        self.syntax
+            .children()
+            .find(|node| {
+                matches!(
+                    node.kind(),
+                    SyntaxKind::IntoClause | SyntaxKind::BulkIntoClause
+                )
+            })
+            .map(|node| node.children().filter_map(IdentGroup::cast).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl OpenStmt {
+    /// Returns the opened cursor.
+    pub fn cursor(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
+
+    /// Returns the query of an `OPEN .. FOR` statement.
+    pub fn query(&self) -> Option<SelectStmt> {
+        self.syntax.children().find_map(SelectStmt::cast)
+    }
+}
+
+impl CloseStmt {
+    /// Returns the closed cursor.
+    pub fn cursor(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
 }
 
 #[cfg(test)]
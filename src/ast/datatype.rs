@@ -6,12 +6,19 @@
 
 use crate::ast::IdentGroup;
 use rowan::ast::AstNode;
-use source_gen::syntax::SyntaxKind;
+use source_gen::syntax::{SyntaxKind, SyntaxToken};
 
 use super::typed_syntax_node;
 
 typed_syntax_node!(Datatype);
 
+/// The length semantics of a character datatype, e.g. `VARCHAR2(10 CHAR)`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CharSemantics {
+    Byte,
+    Char,
+}
+
 impl Datatype {
     /// Returns the identifiers referenced by the %TYPE attribute of the datatype.
     pub fn referenced_type(&self) -> Option<IdentGroup> {
@@ -29,4 +36,208 @@ impl Datatype {
             None => None,
         }
     }
+
+    /// Returns the lowercased name of the datatype without any size
+    /// specification, e.g. `long raw` or `timestamp with time zone`.
+    /// User-defined types and anchored types are returned as written,
+    /// without the `%TYPE` attribute.
+    pub fn name(&self) -> Option<String> {
+        let keywords = self.keywords();
+        if keywords.is_empty() {
+            return self
+                .syntax
+                .children()
+                .find_map(IdentGroup::cast)
+                .map(|ident| ident.syntax().text().to_string());
+        }
+
+        Some(
+            keywords
+                .iter()
+                .map(|t| t.text().to_lowercase())
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+
+    /// Returns the precision of a numeric datatype, e.g. `10` for
+    /// `NUMBER(10, 2)`, or the leading precision of `TIMESTAMP` and
+    /// `INTERVAL` types. `NUMBER(*, 2)` has no precision.
+    pub fn precision(&self) -> Option<u32> {
+        if self.is_character_type() {
+            return None;
+        }
+        self.size_arguments().first().copied().flatten()
+    }
+
+    /// Returns the scale of a numeric datatype, e.g. `2` for `NUMBER(10, 2)`.
+    pub fn scale(&self) -> Option<u32> {
+        if self.is_character_type() {
+            return None;
+        }
+        self.size_arguments().get(1).copied().flatten()
+    }
+
+    /// Returns the length of a character or binary datatype, e.g. `20` for
+    /// `VARCHAR2(20 CHAR)` or `RAW(16)`.
+    pub fn length(&self) -> Option<u32> {
+        if !self.is_character_type() {
+            return None;
+        }
+        self.size_arguments().first().copied().flatten()
+    }
+
+    /// Returns the explicitly given length semantics of a character datatype.
+    pub fn char_semantics(&self) -> Option<CharSemantics> {
+        self.size_tokens()
+            .into_iter()
+            .filter(|t| t.kind() == SyntaxKind::Keyword)
+            .find_map(|t| match t.text().to_lowercase().as_str() {
+                "byte" => Some(CharSemantics::Byte),
+                "char" => Some(CharSemantics::Char),
+                _ => None,
+            })
+    }
+
+    /// Whether the size specification of the datatype is a length, not a
+    /// precision.
+    fn is_character_type(&self) -> bool {
+        matches!(
+            self.keywords()
+                .first()
+                .map(|t| t.text().to_lowercase())
+                .as_deref(),
+            Some(
+                "char"
+                    | "character"
+                    | "national"
+                    | "nchar"
+                    | "nvarchar2"
+                    | "raw"
+                    | "string"
+                    | "urowid"
+                    | "varchar"
+                    | "varchar2"
+            )
+        )
+    }
+
+    /// Returns the keywords making up the name of the datatype, i.e. all
+    /// keywords outside of parentheses up to a `CHARACTER SET` clause.
+    fn keywords(&self) -> Vec<SyntaxToken> {
+        let mut keywords = Vec::new();
+        let mut depth = 0;
+
+        let tokens = self.tokens().collect::<Vec<_>>();
+        for (i, token) in tokens.iter().enumerate() {
+            match token.kind() {
+                SyntaxKind::LParen => depth += 1,
+                SyntaxKind::RParen => depth -= 1,
+                SyntaxKind::Keyword if depth == 0 => {
+                    let is_charset = token.text().eq_ignore_ascii_case("character")
+                        && tokens
+                            .get(i + 1)
+                            .is_some_and(|next| next.text().eq_ignore_ascii_case("set"));
+                    if is_charset {
+                        break;
+                    }
+                    keywords.push(token.clone());
+                }
+                _ => {}
+            }
+        }
+
+        keywords
+    }
+
+    /// Returns all direct child tokens except whitespace and comments.
+    fn tokens(&self) -> impl Iterator<Item = SyntaxToken> {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|t| {
+                !matches!(
+                    t.kind(),
+                    SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+                )
+            })
+    }
+
+    /// Returns the tokens within the first pair of parentheses.
+    fn size_tokens(&self) -> Vec<SyntaxToken> {
+        self.tokens()
+            .skip_while(|t| t.kind() != SyntaxKind::LParen)
+            .skip(1)
+            .take_while(|t| t.kind() != SyntaxKind::RParen)
+            .collect()
+    }
+
+    /// Returns the comma-separated arguments within the first pair of
+    /// parentheses, `None` for arguments which are not integers, e.g. `*`.
+    fn size_arguments(&self) -> Vec<Option<u32>> {
+        self.size_tokens()
+            .split(|t| t.kind() == SyntaxKind::Comma)
+            .map(|arg| {
+                arg.iter()
+                    .find(|t| t.kind() != SyntaxKind::Keyword)
+                    .filter(|t| t.kind() == SyntaxKind::Integer)
+                    .and_then(|t| t.text().parse().ok())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::ast::Root;
+
+    use super::*;
+
+    fn datatype(input: &str) -> Datatype {
+        let input = format!("CREATE FUNCTION f(p {input}) RETURN NUMBER IS BEGIN RETURN 1; END f;");
+        let result = crate::parse_function(&input).unwrap();
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+        Root::cast(result.syntax())
+            .and_then(|root| root.function()?.header()?.param_list())
+            .and_then(|list| list.params().first()?.datatype())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_datatype_size() {
+        let number = datatype("NUMBER(10, 2)");
+        assert_eq!(number.name(), Some("number".to_string()));
+        assert_eq!(number.precision(), Some(10));
+        assert_eq!(number.scale(), Some(2));
+        assert_eq!(number.length(), None);
+
+        let number = datatype("number(*, 0)");
+        assert_eq!(number.precision(), None);
+        assert_eq!(number.scale(), Some(0));
+
+        let varchar = datatype("VARCHAR2(20 CHAR)");
+        assert_eq!(varchar.name(), Some("varchar2".to_string()));
+        assert_eq!(varchar.length(), Some(20));
+        assert_eq!(varchar.precision(), None);
+        assert_eq!(varchar.char_semantics(), Some(CharSemantics::Char));
+
+        let nchar = datatype("national char varying (20)");
+        assert_eq!(nchar.name(), Some("national char varying".to_string()));
+        assert_eq!(nchar.length(), Some(20));
+        assert_eq!(nchar.char_semantics(), None);
+
+        let timestamp = datatype("timestamp(6) with local time zone");
+        assert_eq!(
+            timestamp.name(),
+            Some("timestamp with local time zone".to_string())
+        );
+        assert_eq!(timestamp.precision(), Some(6));
+
+        let anchored = datatype("emp.salary%type");
+        assert_eq!(anchored.name(), Some("emp.salary".to_string()));
+        assert_eq!(anchored.precision(), None);
+    }
 }
@@ -1,15 +1,64 @@
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
+
 use super::typed_syntax_node;
-use crate::{ast::AstNode, WhereClause};
+use crate::{ast::AstNode, IdentGroup, SelectStmt, WhereClause};
 
-typed_syntax_node!(DeleteStmt, UpdateStmt, SetClause);
+typed_syntax_node!(
+    DeleteStmt,
+    InsertStmt,
+    UpdateStmt,
+    SetClause,
+    AssignmentExpr,
+    RecordRow,
+    ReturningClause
+);
 
 impl DeleteStmt {
+    /// Returns the table the rows are deleted from.
+    pub fn table(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
+
     pub fn where_clause(&self) -> Option<WhereClause> {
         self.syntax.children().find_map(WhereClause::cast)
     }
+
+    pub fn returning_clause(&self) -> Option<ReturningClause> {
+        self.syntax.children().find_map(ReturningClause::cast)
+    }
+}
+
+impl InsertStmt {
+    /// Returns the table the rows are inserted into.
+    pub fn table(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
+
+    /// Returns the record of `INSERT INTO .. VALUES <record>`.
+    pub fn record_row(&self) -> Option<RecordRow> {
+        self.syntax.children().find_map(RecordRow::cast)
+    }
+
+    pub fn returning_clause(&self) -> Option<ReturningClause> {
+        self.syntax.children().find_map(ReturningClause::cast)
+    }
 }
 
 impl UpdateStmt {
+    /// Returns the table being updated.
+    pub fn table(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
+
+    /// Returns the alias of the updated table, e.g. `e` in `UPDATE emp e`.
+    pub fn alias(&self) -> Option<IdentGroup> {
+        self.syntax
+            .children()
+            .take_while(|node| node.kind() != SyntaxKind::SetClause)
+            .filter_map(IdentGroup::cast)
+            .nth(1)
+    }
+
     pub fn where_clause(&self) -> Option<WhereClause> {
         self.syntax.children().find_map(WhereClause::cast)
     }
@@ -17,11 +66,95 @@ impl UpdateStmt {
     pub fn set_clause(&self) -> Option<SetClause> {
         self.syntax.children().find_map(SetClause::cast)
     }
+
+    pub fn returning_clause(&self) -> Option<ReturningClause> {
+        self.syntax.children().find_map(ReturningClause::cast)
+    }
+
+    /// Returns the record of `UPDATE .. SET ROW = <record>`.
+    pub fn record_row(&self) -> Option<RecordRow> {
+        self.set_clause()?
+            .syntax
+            .children()
+            .find_map(RecordRow::cast)
+    }
+}
+
+impl SetClause {
+    /// Returns all `<column> = <value>` assignments in source order.
+    pub fn assignments(&self) -> Vec<AssignmentExpr> {
+        self.syntax
+            .children()
+            .filter_map(AssignmentExpr::cast)
+            .collect()
+    }
+}
+
+impl AssignmentExpr {
+    /// Returns the assigned columns, multiple ones for
+    /// `(<column>, ..) = (<subquery>)`.
+    pub fn columns(&self) -> Vec<IdentGroup> {
+        self.syntax
+            .children_with_tokens()
+            .take_while(|it| it.kind() != SyntaxKind::ComparisonOp)
+            .filter_map(|it| it.into_node())
+            .filter_map(IdentGroup::cast)
+            .collect()
+    }
+
+    /// Returns the assigned value, i.e. the node following the `=`.
+    pub fn value(&self) -> Option<SyntaxNode> {
+        self.syntax
+            .children_with_tokens()
+            .skip_while(|it| it.kind() != SyntaxKind::ComparisonOp)
+            .find_map(|it| it.into_node())
+    }
+
+    /// Returns the subquery if the whole value is one, e.g.
+    /// `= (SELECT ..)`.
+    pub fn query(&self) -> Option<SelectStmt> {
+        self.value().and_then(SelectStmt::cast)
+    }
+}
+
+impl RecordRow {
+    /// Returns the expression providing the record, as written.
+    pub fn record(&self) -> String {
+        self.syntax
+            .children()
+            .last()
+            .map(|node| node.text().to_string())
+            .unwrap_or_default()
+    }
+}
+
+impl ReturningClause {
+    /// Returns whether the rows are returned using `BULK COLLECT INTO`.
+    pub fn is_bulk(&self) -> bool {
+        self.target_clause()
+            .is_some_and(|node| node.kind() == SyntaxKind::BulkIntoClause)
+    }
+
+    /// Returns the variables or collections the values are returned into.
+    pub fn targets(&self) -> Vec<IdentGroup> {
+        self.target_clause()
+            .map(|node| node.children().filter_map(IdentGroup::cast).collect())
+            .unwrap_or_default()
+    }
+
+    fn target_clause(&self) -> Option<SyntaxNode> {
+        self.syntax.children().find(|node| {
+            matches!(
+                node.kind(),
+                SyntaxKind::IntoClause | SyntaxKind::BulkIntoClause
+            )
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::ast::{AstNode, Root};
+    use crate::ast::{AstNode, Root, UpdateStmt};
 
     #[test]
     fn check_ast_node_to_delete_stmt() {
@@ -38,4 +171,30 @@ mod tests {
         let where_clause = delete.where_clause();
         assert!(where_clause.is_some());
     }
+
+    #[test]
+    fn check_update_stmt_assignments() {
+        const INPUT: &str =
+            "UPDATE emp e SET (sal, grade) = (SELECT s.sal, s.grade FROM salaries s), bonus = 0;";
+
+        let result = crate::parse_dml(INPUT).unwrap();
+        let update = result.syntax().descendants().find_map(UpdateStmt::cast);
+        let update = update.unwrap();
+        assert_eq!(
+            update.table().and_then(|t| t.name()),
+            Some("emp".to_owned())
+        );
+        assert_eq!(update.alias().and_then(|t| t.name()), Some("e".to_owned()));
+
+        let assignments = update.set_clause().unwrap().assignments();
+        assert_eq!(assignments.len(), 2);
+        let columns = assignments[0]
+            .columns()
+            .iter()
+            .filter_map(|c| c.name())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec!["sal", "grade"]);
+        assert!(assignments[0].query().is_some());
+        assert!(assignments[1].query().is_none());
+    }
 }
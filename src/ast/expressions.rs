@@ -6,11 +6,11 @@
 
 use std::str::FromStr;
 
-use source_gen::syntax::{SyntaxNode, SyntaxToken};
+use source_gen::syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
 
 use super::typed_syntax_node;
 
-typed_syntax_node!(Expression);
+typed_syntax_node!(Expression, TypedLiteral);
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum ComparisonOpType {
@@ -24,6 +24,14 @@ pub enum ComparisonOpType {
     ILike,
 }
 
+/// The type prefix of a [`TypedLiteral`].
+#[derive(Debug, Eq, PartialEq)]
+pub enum TypedLiteralKind {
+    Date,
+    Interval,
+    Timestamp,
+}
+
 impl Expression {
     pub fn filter_tokens<F>(&self, filter: F) -> impl Iterator<Item = SyntaxToken>
     where
@@ -44,6 +52,48 @@ impl Expression {
     }
 }
 
+impl TypedLiteral {
+    /// Returns the type prefix of the literal.
+    pub fn kind(&self) -> Option<TypedLiteralKind> {
+        let keyword = self.keywords().next()?;
+        match keyword.text().to_lowercase().as_str() {
+            "date" => Some(TypedLiteralKind::Date),
+            "interval" => Some(TypedLiteralKind::Interval),
+            "timestamp" => Some(TypedLiteralKind::Timestamp),
+            _ => None,
+        }
+    }
+
+    /// Returns the contents of the quoted literal, without the quotes.
+    pub fn value(&self) -> Option<String> {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|t| t.kind() == SyntaxKind::QuotedLiteral)
+            .map(|t| {
+                let text = t.text();
+                text[1..text.len() - 1].replace("''", "'")
+            })
+    }
+
+    /// Returns the lowercased interval fields, e.g. `["day", "minute"]` for
+    /// `INTERVAL '4 5:12' DAY(3) TO MINUTE`.
+    pub fn interval_fields(&self) -> Vec<String> {
+        self.keywords()
+            .skip(1)
+            .map(|t| t.text().to_lowercase())
+            .filter(|t| t != "to")
+            .collect()
+    }
+
+    fn keywords(&self) -> impl Iterator<Item = SyntaxToken> {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|t| t.kind() == SyntaxKind::Keyword)
+    }
+}
+
 impl FromStr for ComparisonOpType {
     type Err = ();
 
@@ -61,3 +111,35 @@ impl FromStr for ComparisonOpType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::ast::AstNode;
+
+    use super::*;
+
+    #[test]
+    fn check_typed_literals() {
+        const INPUT: &str =
+            "SELECT DATE '2020-01-01', INTERVAL '4 5:12' DAY(3) TO MINUTE FROM dual";
+        let result = crate::parse_query(INPUT).unwrap();
+        assert!(result.ok(), "{:?}", result.errors);
+
+        let literals = result
+            .syntax()
+            .descendants()
+            .filter_map(TypedLiteral::cast)
+            .collect::<Vec<_>>();
+        assert_eq!(literals.len(), 2);
+
+        assert_eq!(literals[0].kind(), Some(TypedLiteralKind::Date));
+        assert_eq!(literals[0].value(), Some("2020-01-01".to_string()));
+        assert!(literals[0].interval_fields().is_empty());
+
+        assert_eq!(literals[1].kind(), Some(TypedLiteralKind::Interval));
+        assert_eq!(literals[1].value(), Some("4 5:12".to_string()));
+        assert_eq!(literals[1].interval_fields(), vec!["day", "minute"]);
+    }
+}
@@ -4,7 +4,8 @@
 
 //! Typed AST nodes for PL/SQL functions.
 
-use crate::ast::{AstNode, Block, IdentGroup, ParamList};
+use crate::ast::{AstNode, Block, CallSpec, Datatype, IdentGroup, InvokerRightsClause, ParamList};
+use source_gen::syntax::SyntaxKind;
 
 use super::typed_syntax_node;
 
@@ -24,6 +25,12 @@ impl Function {
     pub fn body(&self) -> Option<Block> {
         self.syntax.children().find_map(Block::cast)
     }
+
+    /// Returns the call specification, if the function is implemented
+    /// externally.
+    pub fn call_spec(&self) -> Option<CallSpec> {
+        self.syntax.children().find_map(CallSpec::cast)
+    }
 }
 
 impl FunctionHeader {
@@ -35,6 +42,47 @@ impl FunctionHeader {
     pub fn param_list(&self) -> Option<ParamList> {
         self.syntax.children().find_map(ParamList::cast)
     }
+
+    /// Returns the datatype after `RETURN`.
+    pub fn return_type(&self) -> Option<Datatype> {
+        self.syntax.children().find_map(Datatype::cast)
+    }
+
+    /// Returns whether the function is declared as `DETERMINISTIC`.
+    pub fn is_deterministic(&self) -> bool {
+        self.has_keyword("deterministic")
+    }
+
+    /// Returns whether the function is declared as `PIPELINED`.
+    pub fn is_pipelined(&self) -> bool {
+        self.has_keyword("pipelined")
+    }
+
+    /// Returns whether the function has a `PARALLEL_ENABLE` clause.
+    pub fn is_parallel_enabled(&self) -> bool {
+        self.has_child(SyntaxKind::ParallelEnableClause)
+    }
+
+    /// Returns whether the function has a `RESULT_CACHE` clause.
+    pub fn is_result_cached(&self) -> bool {
+        self.has_child(SyntaxKind::ResultCacheClause)
+    }
+
+    /// Returns the `AUTHID` clause, if any.
+    pub fn invoker_rights(&self) -> Option<InvokerRightsClause> {
+        self.syntax.children().find_map(InvokerRightsClause::cast)
+    }
+
+    fn has_keyword(&self, keyword: &str) -> bool {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .any(|t| t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case(keyword))
+    }
+
+    fn has_child(&self, kind: SyntaxKind) -> bool {
+        self.syntax.children().any(|n| n.kind() == kind)
+    }
 }
 
 #[cfg(test)]
@@ -6,10 +6,11 @@
 
 use crate::ast::{AstNode, IdentGroup};
 use crate::{Argument, ArgumentList};
+use source_gen::syntax::{SyntaxElement, SyntaxKind, SyntaxNode};
 
 use super::typed_syntax_node;
 
-typed_syntax_node!(FunctionInvocation);
+typed_syntax_node!(FunctionInvocation, MethodInvocation, FieldAccess);
 
 impl FunctionInvocation {
     /// Returns the name of the function.
@@ -26,6 +27,74 @@ impl FunctionInvocation {
     }
 }
 
+impl MethodInvocation {
+    /// Returns the name of the collection the method is called on, unless
+    /// it is called on the result of another call.
+    pub fn collection(&self) -> Option<IdentGroup> {
+        self.receiver().and_then(IdentGroup::cast)
+    }
+
+    /// Returns what the method is called on, e.g. the [`FunctionInvocation`]
+    /// `v.method(x)` for `v.method(x).other(y)`.
+    pub fn receiver(&self) -> Option<SyntaxNode> {
+        self.syntax.first_child()
+    }
+
+    /// Returns the lowercased name of the method, e.g. `count`, which is the
+    /// identifier following the dot.
+    pub fn method(&self) -> Option<String> {
+        let name = self
+            .syntax
+            .children_with_tokens()
+            .skip_while(|it| it.kind() != SyntaxKind::Dot)
+            .skip(1)
+            .find(|it| {
+                !matches!(
+                    it.kind(),
+                    SyntaxKind::Whitespace | SyntaxKind::Comment | SyntaxKind::InlineComment
+                )
+            })?;
+        match name {
+            SyntaxElement::Token(token) => Some(token.text().to_lowercase()),
+            SyntaxElement::Node(node) => IdentGroup::cast(node)?
+                .name()
+                .map(|name| name.to_lowercase()),
+        }
+    }
+
+    /// Whether this calls a collection method, e.g. `COUNT`, rather than a
+    /// member of an object type.
+    pub fn is_collection_method(&self) -> bool {
+        self.syntax
+            .children_with_tokens()
+            .any(|it| it.kind() == SyntaxKind::Keyword)
+    }
+
+    pub fn arguments(&self) -> Option<Vec<Argument>> {
+        self.syntax
+            .children()
+            .find_map(ArgumentList::cast)
+            .map(|l| l.arguments())
+    }
+}
+
+impl FieldAccess {
+    /// Returns what the attribute is accessed on, e.g. the
+    /// [`FunctionInvocation`] `v.method(x)` for `v.method(x).attr`.
+    pub fn receiver(&self) -> Option<SyntaxNode> {
+        self.syntax.first_child()
+    }
+
+    /// Returns the name of the accessed attribute.
+    pub fn field(&self) -> Option<String> {
+        self.syntax
+            .children()
+            .skip(1)
+            .find_map(IdentGroup::cast)?
+            .name()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ast::{ColumnExpr, Root};
@@ -100,4 +169,79 @@ mod tests {
             vec!["col1", "col2 + 1", "col3"]
         );
     }
+
+    #[test]
+    fn check_method_invocation() {
+        const INPUT: &str =
+            "CREATE PROCEDURE p IS BEGIN IF pkg.names.EXISTS(3) THEN NULL; END IF; END p;";
+        let result = crate::parse_procedure(INPUT).unwrap();
+        let method_invocation = result
+            .syntax()
+            .descendants()
+            .find_map(MethodInvocation::cast)
+            .unwrap();
+
+        assert_eq!(
+            method_invocation.collection().unwrap().name(),
+            Some("pkg.names".to_string())
+        );
+        assert_eq!(method_invocation.method(), Some("exists".to_string()));
+        assert!(method_invocation.is_collection_method());
+        assert_eq!(method_invocation.arguments().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn check_column_named_like_method() {
+        const INPUT: &str = r#"
+CREATE PROCEDURE p IS
+    TYPE t_ids IS TABLE OF NUMBER;
+    l_ids t_ids;
+BEGIN
+    SELECT t.count, x.first INTO l_count, l_first FROM t, x WHERE t.id = l_ids.COUNT;
+END p;
+"#;
+        let result = crate::parse_procedure(INPUT).unwrap();
+        let methods = result
+            .syntax()
+            .descendants()
+            .filter_map(MethodInvocation::cast)
+            .map(|m| m.collection().and_then(|c| c.name()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(methods, vec![Some("l_ids".to_string())]);
+    }
+
+    #[test]
+    fn check_chained_member_access() {
+        const INPUT: &str = "SELECT v.method(1).attr FROM DUAL";
+        let result = crate::parse_query(INPUT).unwrap();
+        let field_access = result
+            .syntax()
+            .descendants()
+            .find_map(FieldAccess::cast)
+            .unwrap();
+
+        assert_eq!(field_access.field(), Some("attr".to_string()));
+        let receiver = field_access.receiver().and_then(FunctionInvocation::cast);
+        assert_eq!(
+            receiver.and_then(|f| f.ident()).and_then(|i| i.name()),
+            Some("v.method".to_string())
+        );
+    }
+
+    #[test]
+    fn check_chained_method_name() {
+        const INPUT: &str = "SELECT v.method(1).Other(2) FROM DUAL";
+        let result = crate::parse_query(INPUT).unwrap();
+        let method_invocation = result
+            .syntax()
+            .descendants()
+            .find_map(MethodInvocation::cast)
+            .unwrap();
+
+        assert_eq!(method_invocation.method(), Some("other".to_string()));
+        assert!(!method_invocation.is_collection_method());
+        assert_eq!(method_invocation.collection(), None);
+        assert_eq!(method_invocation.arguments().unwrap().len(), 1);
+    }
 }
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Typed AST nodes for PL/SQL loops and `FORALL` statements.
+
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
+
+use super::typed_syntax_node;
+use crate::ast::{AstNode, Expression, IdentGroup, Query};
+
+typed_syntax_node!(BasicLoop, ExitStmt, ForallStmt, ForLoop);
+
+impl BasicLoop {
+    /// Returns the statements of the loop body.
+    pub fn statements(&self) -> impl Iterator<Item = SyntaxNode> {
+        self.syntax
+            .children()
+            .filter(|node| node.kind() == SyntaxKind::BlockStatement)
+    }
+}
+
+impl ExitStmt {
+    /// Returns the condition of `EXIT WHEN`, if any.
+    pub fn condition(&self) -> Option<Expression> {
+        self.syntax.children().find_map(Expression::cast)
+    }
+}
+
+impl ForLoop {
+    /// Returns the loop index or record, e.g. `rec` in `FOR rec IN ..`.
+    pub fn iterand(&self) -> Option<IdentGroup> {
+        self.iterator()?.children().find_map(IdentGroup::cast)
+    }
+
+    /// Returns the inline query of a cursor `FOR` loop including its `WITH`
+    /// clause, e.g. `FOR rec IN (SELECT ..) LOOP`. Loops over a declared
+    /// cursor or over `VALUES OF` a query have none.
+    pub fn query(&self) -> Option<Query> {
+        let control = self
+            .iterator()?
+            .children()
+            .filter(|node| node.kind() == SyntaxKind::IterationControl)
+            .find(|node| {
+                node.first_token()
+                    .is_some_and(|t| t.kind() == SyntaxKind::LParen)
+            })?;
+        Query::first_child(&control)
+    }
+
+    /// Returns the statements of the loop body.
+    pub fn statements(&self) -> impl Iterator<Item = SyntaxNode> {
+        self.syntax
+            .children()
+            .filter(|node| node.kind() == SyntaxKind::BlockStatement)
+    }
+
+    fn iterator(&self) -> Option<SyntaxNode> {
+        self.syntax
+            .children()
+            .find(|node| node.kind() == SyntaxKind::Iterator)
+    }
+}
+
+impl ForallStmt {
+    /// Returns the index, e.g. `i` in `FORALL i IN ..`.
+    pub fn index(&self) -> Option<IdentGroup> {
+        self.syntax.children().find_map(IdentGroup::cast)
+    }
+
+    /// Returns whether `SAVE EXCEPTIONS` is given, continuing after failed
+    /// rows and raising a single error at the end.
+    pub fn saves_exceptions(&self) -> bool {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .any(|t| t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("save"))
+    }
+
+    /// Returns the DML statement run for every index.
+    pub fn statement(&self) -> Option<SyntaxNode> {
+        self.syntax.children().find(|node| {
+            matches!(
+                node.kind(),
+                SyntaxKind::InsertStmt
+                    | SyntaxKind::UpdateStmt
+                    | SyntaxKind::DeleteStmt
+                    | SyntaxKind::ExecuteImmediateStmt
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::ast::{AstNode, ForLoop, Root};
+
+    #[test]
+    fn check_ast_node_to_for_loop() {
+        const INPUT: &str = r#"
+BEGIN
+    FOR rec IN (WITH o AS (SELECT id FROM orders) SELECT id FROM o) LOOP
+        NULL;
+        NULL;
+    END LOOP;
+    FOR i IN 1..10 LOOP
+        NULL;
+    END LOOP;
+END;
+"#;
+        let result = crate::parse_block(INPUT).unwrap();
+        assert!(result.ok(), "{:#?}", result.errors);
+        let root = Root::cast(result.syntax()).unwrap();
+
+        let loops = root
+            .syntax()
+            .descendants()
+            .filter_map(ForLoop::cast)
+            .collect::<Vec<_>>();
+        assert_eq!(loops.len(), 2);
+
+        assert_eq!(
+            loops[0].iterand().and_then(|i| i.name()),
+            Some("rec".into())
+        );
+        assert_eq!(
+            loops[0].query().map(|q| q.text()),
+            Some("WITH o AS (SELECT id FROM orders) SELECT id FROM o".into())
+        );
+        assert_eq!(loops[0].statements().count(), 2);
+
+        assert_eq!(loops[1].iterand().and_then(|i| i.name()), Some("i".into()));
+        assert_eq!(loops[1].query(), None);
+    }
+}
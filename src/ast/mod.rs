@@ -5,31 +5,43 @@
 
 //! Implements a typed AST for PL/SQL.
 
-use cursor::CursorStmt;
+pub use cursor::{CloseStmt, CursorAttribute, CursorStmt, FetchStmt, OpenStmt, RowtypeClause};
 pub use rowan::ast::AstNode;
 
 pub use argument_list::*;
+pub use comment::*;
+pub use constraint::*;
 pub use datatype::*;
 pub use dml::*;
 pub use expressions::*;
 pub use function::*;
 pub use function_invocation::*;
+pub use loops::*;
+pub use package::*;
+pub use pragma::*;
 pub use procedure::*;
 pub use query::*;
+pub use table::*;
 pub use trigger::*;
 pub use view::*;
 
-use source_gen::syntax::{SyntaxKind, SyntaxToken};
+use source_gen::syntax::{SyntaxKind, SyntaxNode, SyntaxToken};
 
 mod argument_list;
+mod comment;
+mod constraint;
 mod cursor;
 mod datatype;
 mod dml;
 mod expressions;
 mod function;
 mod function_invocation;
+mod loops;
+mod package;
+mod pragma;
 mod procedure;
 mod query;
+mod table;
 mod trigger;
 mod view;
 
@@ -85,6 +97,7 @@ macro_rules! typed_syntax_token {
 // Needed so that submodules can import [`typed_syntax_node`] and
 // [`typed_syntax_token`]
 /// as `super::typed_syntax_{node,token}`.
+#[allow(unused_imports)]
 pub(crate) use {typed_syntax, typed_syntax_node, typed_syntax_token};
 
 /// Represents a interface for typed AST tokens, akin to [`AstNode`].
@@ -111,7 +124,17 @@ pub trait AstToken {
     }
 }
 
-typed_syntax_node!(Root, IdentGroup, ParamList, Param, Block);
+typed_syntax_node!(
+    Root,
+    IdentGroup,
+    ParamList,
+    Param,
+    Block,
+    CallSpec,
+    CaseBlock,
+    InvokerRightsClause,
+    TrailingContent
+);
 typed_syntax_token!(ComparisonOp, Ident);
 
 impl Root {
@@ -119,6 +142,15 @@ impl Root {
         self.syntax.children().find_map(DeleteStmt::cast)
     }
 
+    /// Finds the (next) anonymous block in this root node.
+    pub fn block(&self) -> Option<Block> {
+        self.syntax.children().find_map(Block::cast)
+    }
+
+    pub fn comment(&self) -> Option<CommentStmt> {
+        self.syntax.children().find_map(CommentStmt::cast)
+    }
+
     pub fn cursor(&self) -> Option<CursorStmt> {
         self.syntax.children().find_map(CursorStmt::cast)
     }
@@ -128,6 +160,16 @@ impl Root {
         self.syntax.children().find_map(Function::cast)
     }
 
+    /// Finds the (next) materialized view in this root node.
+    pub fn materialized_view(&self) -> Option<MaterializedView> {
+        self.syntax.children().find_map(MaterializedView::cast)
+    }
+
+    /// Finds the (next) package specification or body in this root node.
+    pub fn package(&self) -> Option<Package> {
+        self.syntax.children().find_map(Package::cast)
+    }
+
     /// Finds the (next) procedure in this root node.
     pub fn procedure(&self) -> Option<Procedure> {
         self.syntax.children().find_map(Procedure::cast)
@@ -138,6 +180,12 @@ impl Root {
         self.syntax.children().find_map(SelectStmt::cast)
     }
 
+    /// Finds the (next) `CREATE TABLE .. AS SELECT` statement in this root
+    /// node.
+    pub fn table(&self) -> Option<Table> {
+        self.syntax.children().find_map(Table::cast)
+    }
+
     /// Finds the (next) trigger query in this root node.
     pub fn trigger(&self) -> Option<Trigger> {
         self.syntax.children().find_map(Trigger::cast)
@@ -147,9 +195,103 @@ impl Root {
     pub fn view(&self) -> Option<View> {
         self.syntax.children().find_map(View::cast)
     }
+
+    /// Returns the tokens following the parsed object, if any.
+    pub fn trailing_content(&self) -> Option<TrailingContent> {
+        self.syntax.children().find_map(TrailingContent::cast)
+    }
+
+    /// Returns all top-level objects of this root node, in source order.
+    pub fn objects(&self) -> impl Iterator<Item = DboObject> {
+        self.syntax.children().filter_map(DboObject::cast)
+    }
+}
+
+/// Any typed object which may appear at the top level of a [`Root`] node.
+#[derive(Debug, Eq, PartialEq)]
+pub enum DboObject {
+    /// An anonymous block.
+    Block(Block),
+    Comment(CommentStmt),
+    Cursor(CursorStmt),
+    Delete(DeleteStmt),
+    Function(Function),
+    Insert(InsertStmt),
+    MaterializedView(MaterializedView),
+    Package(Package),
+    Procedure(Procedure),
+    Query(SelectStmt),
+    Table(Table),
+    Trigger(Trigger),
+    Update(UpdateStmt),
+    View(View),
+}
+
+impl AstNode for DboObject {
+    type Language = source_gen::syntax::SqlProcedureLang;
+
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            SyntaxKind::Block
+                | SyntaxKind::CommentStmt
+                | SyntaxKind::CursorStmt
+                | SyntaxKind::DeleteStmt
+                | SyntaxKind::Function
+                | SyntaxKind::InsertStmt
+                | SyntaxKind::MaterializedView
+                | SyntaxKind::Package
+                | SyntaxKind::Procedure
+                | SyntaxKind::SelectStmt
+                | SyntaxKind::Table
+                | SyntaxKind::Trigger
+                | SyntaxKind::UpdateStmt
+                | SyntaxKind::View
+        )
+    }
+
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        Some(match syntax.kind() {
+            SyntaxKind::Block => Self::Block(Block { syntax }),
+            SyntaxKind::CommentStmt => Self::Comment(CommentStmt { syntax }),
+            SyntaxKind::CursorStmt => Self::Cursor(CursorStmt { syntax }),
+            SyntaxKind::DeleteStmt => Self::Delete(DeleteStmt { syntax }),
+            SyntaxKind::Function => Self::Function(Function { syntax }),
+            SyntaxKind::InsertStmt => Self::Insert(InsertStmt { syntax }),
+            SyntaxKind::MaterializedView => Self::MaterializedView(MaterializedView { syntax }),
+            SyntaxKind::Package => Self::Package(Package { syntax }),
+            SyntaxKind::Procedure => Self::Procedure(Procedure { syntax }),
+            SyntaxKind::SelectStmt => Self::Query(SelectStmt { syntax }),
+            SyntaxKind::Table => Self::Table(Table { syntax }),
+            SyntaxKind::Trigger => Self::Trigger(Trigger { syntax }),
+            SyntaxKind::UpdateStmt => Self::Update(UpdateStmt { syntax }),
+            SyntaxKind::View => Self::View(View { syntax }),
+            _ => return None,
+        })
+    }
+
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::Block(it) => it.syntax(),
+            Self::Comment(it) => it.syntax(),
+            Self::Cursor(it) => it.syntax(),
+            Self::Delete(it) => it.syntax(),
+            Self::Function(it) => it.syntax(),
+            Self::Insert(it) => it.syntax(),
+            Self::MaterializedView(it) => it.syntax(),
+            Self::Package(it) => it.syntax(),
+            Self::Procedure(it) => it.syntax(),
+            Self::Query(it) => it.syntax(),
+            Self::Table(it) => it.syntax(),
+            Self::Trigger(it) => it.syntax(),
+            Self::Update(it) => it.syntax(),
+            Self::View(it) => it.syntax(),
+        }
+    }
 }
 
 impl IdentGroup {
+    /// Returns the full, possibly qualified name, e.g. `hr.employees`.
     pub fn name(&self) -> Option<String> {
         self.syntax
             .children_with_tokens()
@@ -159,13 +301,40 @@ impl IdentGroup {
             .collect()
     }
 
-    // TODO: implement a `last_nth` method
-    pub fn nth(&self, n: usize) -> Option<Ident> {
+    /// Returns all identifier segments, e.g. `hr` and `employees` for
+    /// `hr.employees`.
+    pub fn segments(&self) -> impl Iterator<Item = Ident> {
         self.syntax
             .children_with_tokens()
             .filter_map(|t| t.into_token())
             .filter_map(Ident::cast)
-            .nth(n)
+    }
+
+    pub fn nth(&self, n: usize) -> Option<Ident> {
+        self.segments().nth(n)
+    }
+
+    /// Returns the `n`th segment counted from the end, starting at `0` for
+    /// the last one.
+    pub fn last_nth(&self, n: usize) -> Option<Ident> {
+        let segments = self.segments().collect::<Vec<_>>();
+        let index = segments.len().checked_sub(n + 1)?;
+        segments.into_iter().nth(index)
+    }
+
+    /// Returns the last segment, i.e. the unqualified name.
+    pub fn last(&self) -> Option<Ident> {
+        self.segments().last()
+    }
+
+    /// Returns all segments but the last one, e.g. `hr` for
+    /// `hr.employees`.
+    pub fn qualifier(&self) -> Option<String> {
+        let segments = self.segments().map(|i| i.text()).collect::<Vec<_>>();
+        match segments.split_last() {
+            Some((_, qualifier)) if !qualifier.is_empty() => Some(qualifier.join(".")),
+            _ => None,
+        }
     }
 }
 
@@ -183,13 +352,8 @@ impl ParamList {
 }
 
 impl Param {
-    #[allow(unused)]
     pub fn name(&self) -> Option<String> {
-        self.syntax
-            .children_with_tokens()
-            .filter_map(|it| it.into_token())
-            .find_map(Ident::cast)
-            .map(|id| id.text())
+        self.syntax.children().find_map(IdentGroup::cast)?.name()
     }
 
     pub fn datatype(&self) -> Option<Datatype> {
@@ -206,3 +370,123 @@ impl Block {
         self.syntax.text().to_string()
     }
 }
+
+impl CaseBlock {
+    /// Returns whether an `ELSE` branch is given.
+    pub fn has_else(&self) -> bool {
+        self.keywords()
+            .any(|t| t.text().eq_ignore_ascii_case("else"))
+    }
+
+    /// Returns the `END` of `END CASE`.
+    pub fn end_keyword(&self) -> Option<SyntaxToken> {
+        self.keywords()
+            .filter(|t| t.text().eq_ignore_ascii_case("end"))
+            .last()
+    }
+
+    fn keywords(&self) -> impl Iterator<Item = SyntaxToken> {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|t| t.kind() == SyntaxKind::Keyword)
+    }
+}
+
+impl InvokerRightsClause {
+    /// Returns whether the clause is `AUTHID CURRENT_USER`, as opposed to
+    /// `AUTHID DEFINER`.
+    pub fn is_current_user(&self) -> bool {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .any(|t| t.text().eq_ignore_ascii_case("current_user"))
+    }
+}
+
+impl TrailingContent {
+    pub fn text(&self) -> String {
+        self.syntax.text().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_ident_group_segments() {
+        let result = crate::parse_dml("DELETE FROM hr.employees;").unwrap();
+        let root = Root::cast(result.syntax()).unwrap();
+        let table = root.dml().and_then(|d| d.table()).unwrap();
+
+        let segments = table.segments().map(|i| i.text()).collect::<Vec<_>>();
+        assert_eq!(segments, vec!["hr", "employees"]);
+        assert_eq!(table.name(), Some("hr.employees".to_string()));
+        assert_eq!(
+            table.last().map(|i| i.text()),
+            Some("employees".to_string())
+        );
+        assert_eq!(table.last_nth(1).map(|i| i.text()), Some("hr".to_string()));
+        assert_eq!(table.last_nth(2).map(|i| i.text()), None);
+        assert_eq!(table.qualifier(), Some("hr".to_string()));
+
+        let result = crate::parse_dml("DELETE FROM employees;").unwrap();
+        let root = Root::cast(result.syntax()).unwrap();
+        let table = root.dml().and_then(|d| d.table()).unwrap();
+        assert_eq!(table.qualifier(), None);
+    }
+
+    #[test]
+    fn check_root_objects() {
+        const INPUT: &str = r#"
+            CREATE OR REPLACE FUNCTION answer RETURN NUMBER
+            IS
+            BEGIN
+                RETURN 42;
+            END answer;
+        "#;
+        let result = crate::parse_function(INPUT).unwrap();
+        let root = Root::cast(result.syntax()).unwrap();
+
+        let objects = root.objects().collect::<Vec<_>>();
+        assert_eq!(objects.len(), 1);
+        match &objects[0] {
+            DboObject::Function(function) => {
+                assert_eq!(function.name(), Some("answer".to_string()))
+            }
+            other => panic!("expected function, got {other:?}"),
+        }
+
+        let result = crate::parse_dml("UPDATE emp SET salary = 0;").unwrap();
+        let root = Root::cast(result.syntax()).unwrap();
+        assert!(matches!(
+            root.objects().collect::<Vec<_>>().as_slice(),
+            [DboObject::Update(_)]
+        ));
+
+        let package = "CREATE PACKAGE p AS PROCEDURE a; END p;";
+        let view = "CREATE MATERIALIZED VIEW mv AS SELECT 1 FROM dual;";
+        let table = "CREATE TABLE t AS SELECT 1 FROM dual;";
+        let comment = "COMMENT ON TABLE t IS 'Test';";
+        let insert = "INSERT INTO t VALUES (1);";
+        let block = "BEGIN\n  UPDATE t SET n = 0;\nEND;";
+        for (result, expected) in [
+            (crate::parse_block(block), "Block"),
+            (crate::parse_package(package), "Package"),
+            (crate::parse_materialized_view(view), "MaterializedView"),
+            (crate::parse_table(table), "Table"),
+            (crate::parse_comment(comment), "Comment"),
+            (crate::parse_dml(insert), "Insert"),
+        ] {
+            let result = result.unwrap();
+            let root = Root::cast(result.syntax()).unwrap();
+            let objects = root.objects().collect::<Vec<_>>();
+            assert_eq!(objects.len(), 1, "{expected}");
+            assert!(
+                format!("{:?}", objects[0]).starts_with(expected),
+                "{objects:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Typed AST nodes for PL/SQL packages.
+
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
+
+use crate::ast::{AstNode, Function, IdentGroup, Procedure};
+
+use super::typed_syntax_node;
+
+typed_syntax_node!(Package);
+
+impl Package {
+    /// Returns the name of the package.
+    pub fn name(&self) -> Option<String> {
+        self.syntax.children().find_map(IdentGroup::cast)?.name()
+    }
+
+    /// Returns whether this is a package body, as opposed to a package
+    /// specification.
+    pub fn is_body(&self) -> bool {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .take_while(|t| t.kind() != SyntaxKind::Ident)
+            .any(|t| t.kind() == SyntaxKind::Keyword && t.text().eq_ignore_ascii_case("body"))
+    }
+
+    /// Returns all functions declared in the package, in source order.
+    pub fn functions(&self) -> impl Iterator<Item = Function> {
+        self.declarations().filter_map(Function::cast)
+    }
+
+    /// Returns all procedures declared in the package, in source order.
+    pub fn procedures(&self) -> impl Iterator<Item = Procedure> {
+        self.declarations().filter_map(Procedure::cast)
+    }
+
+    fn declarations(&self) -> impl Iterator<Item = SyntaxNode> {
+        self.syntax
+            .children()
+            .filter(|node| node.kind() == SyntaxKind::DeclareSection)
+            .flat_map(|section| section.children())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::Root;
+
+    use super::*;
+
+    #[test]
+    fn check_ast_node_to_package() {
+        const INPUT: &str = include_str!("../../tests/package/util_spec.ora.sql");
+
+        let result = crate::parse_package(INPUT).unwrap();
+        let root = Root::cast(result.syntax()).unwrap();
+
+        let package = root.package().unwrap();
+        assert_eq!(package.name(), Some("northwind.util".to_string()));
+        assert!(!package.is_body());
+        assert_eq!(package.functions().count(), 0);
+        assert_eq!(
+            package
+                .procedures()
+                .filter_map(|p| p.name())
+                .collect::<Vec<_>>(),
+            vec!["print".to_string()]
+        );
+    }
+}
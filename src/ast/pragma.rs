@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Typed AST nodes for PL/SQL pragmas.
+
+use crate::ast::{Argument, ArgumentList, AstNode, IdentGroup};
+
+use super::typed_syntax_node;
+
+typed_syntax_node!(PragmaStmt);
+
+impl PragmaStmt {
+    /// Returns the name of the pragma, e.g. `INLINE`, as written.
+    pub fn name(&self) -> Option<String> {
+        self.syntax.children().find_map(IdentGroup::cast)?.name()
+    }
+
+    /// Returns the arguments in order, which are empty for pragmas like
+    /// `AUTONOMOUS_TRANSACTION`.
+    pub fn arguments(&self) -> Vec<Argument> {
+        self.syntax
+            .children()
+            .find_map(ArgumentList::cast)
+            .map(|list| list.arguments())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::Root;
+
+    use super::*;
+
+    #[test]
+    fn check_ast_node_to_pragma() {
+        const INPUT: &str = r#"
+CREATE PROCEDURE p IS
+    PRAGMA AUTONOMOUS_TRANSACTION;
+BEGIN
+    PRAGMA INLINE(calc, 'YES');
+    calc(1);
+END p;
+"#;
+        let result = crate::parse_procedure(INPUT).unwrap();
+        assert!(result.ok(), "{:#?}", result.errors);
+        let root = Root::cast(result.syntax()).unwrap();
+
+        let pragmas = root
+            .syntax()
+            .descendants()
+            .filter_map(PragmaStmt::cast)
+            .collect::<Vec<_>>();
+        assert_eq!(pragmas.len(), 2);
+        assert_eq!(
+            pragmas[0].name(),
+            Some("AUTONOMOUS_TRANSACTION".to_string())
+        );
+        assert!(pragmas[0].arguments().is_empty());
+        assert_eq!(pragmas[1].name(), Some("INLINE".to_string()));
+        assert_eq!(
+            pragmas[1]
+                .arguments()
+                .iter()
+                .map(|a| a.text())
+                .collect::<Vec<_>>(),
+            vec!["calc", "'YES'"]
+        );
+    }
+}
@@ -5,7 +5,7 @@
 
 //! Typed AST nodes for PL/SQL procedures.
 
-use crate::ast::{AstNode, Block, IdentGroup, ParamList};
+use crate::ast::{AstNode, Block, CallSpec, IdentGroup, InvokerRightsClause, ParamList};
 
 use super::typed_syntax_node;
 
@@ -25,6 +25,12 @@ impl Procedure {
     pub fn body(&self) -> Option<Block> {
         self.syntax.children().find_map(Block::cast)
     }
+
+    /// Returns the call specification, if the procedure is implemented
+    /// externally.
+    pub fn call_spec(&self) -> Option<CallSpec> {
+        self.syntax.children().find_map(CallSpec::cast)
+    }
 }
 
 impl ProcedureHeader {
@@ -36,6 +42,11 @@ impl ProcedureHeader {
     pub fn param_list(&self) -> Option<ParamList> {
         self.syntax.children().find_map(ParamList::cast)
     }
+
+    /// Returns the `AUTHID` clause, if any.
+    pub fn invoker_rights(&self) -> Option<InvokerRightsClause> {
+        self.syntax.children().find_map(InvokerRightsClause::cast)
+    }
 }
 
 #[cfg(test)]
@@ -4,15 +4,76 @@
 
 //! Typed AST nodes for PL/SQL procedures.
 
+use rowan::TextRange;
+
 use crate::ast::AstNode;
+use source_gen::syntax::{SyntaxElement, SyntaxKind, SyntaxNode};
 
 use super::typed_syntax_node;
-use super::Expression;
+use super::{Expression, IdentGroup};
+
+typed_syntax_node!(
+    SelectClause,
+    SelectStmt,
+    ColumnExpr,
+    WhereClause,
+    CrossOuterApplyClause,
+    InlineView,
+    WithClause
+);
+
+/// A complete query, i.e. a `SELECT` including the `WITH` clause preceding
+/// it. The parser places both next to each other, hence this is no node of
+/// its own.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Query {
+    with_clause: Option<WithClause>,
+    select: SelectStmt,
+}
+
+impl Query {
+    /// Returns the first query among the children of `parent`.
+    pub(crate) fn first_child(parent: &SyntaxNode) -> Option<Self> {
+        let select = parent.children().find_map(SelectStmt::cast)?;
+        let with_clause = select.syntax().prev_sibling().and_then(WithClause::cast);
+        Some(Self {
+            with_clause,
+            select,
+        })
+    }
 
-typed_syntax_node!(SelectClause, SelectStmt, ColumnExpr, WhereClause);
+    pub fn with_clause(&self) -> Option<&WithClause> {
+        self.with_clause.as_ref()
+    }
+
+    /// Returns the `SELECT` following the `WITH` clause.
+    pub fn select(&self) -> &SelectStmt {
+        &self.select
+    }
+
+    pub fn text_range(&self) -> TextRange {
+        let end = self.select.syntax().text_range();
+        match &self.with_clause {
+            Some(with_clause) => with_clause.syntax().text_range().cover(end),
+            None => end,
+        }
+    }
+
+    /// Returns the text of the whole query, including the `WITH` clause.
+    pub fn text(&self) -> String {
+        let range = self.text_range();
+        self.select
+            .syntax()
+            .parent()
+            .map(|parent| {
+                let offset = parent.text_range().start();
+                parent.text().slice(range - offset).to_string()
+            })
+            .unwrap_or_else(|| self.select.syntax().text().to_string())
+    }
+}
 
 impl SelectStmt {
-    #[allow(unused)]
     pub fn select_clause(&self) -> Option<SelectClause> {
         self.syntax.children().find_map(SelectClause::cast)
     }
@@ -20,6 +81,222 @@ impl SelectStmt {
     pub fn where_clause(&self) -> Option<WhereClause> {
         self.syntax.children().find_map(WhereClause::cast)
     }
+
+    /// Returns the tables listed in the `FROM` clause in source order,
+    /// including joined tables but not the ones of subqueries.
+    pub fn tables(&self) -> Vec<IdentGroup> {
+        self.from_items()
+            .into_iter()
+            .filter_map(|item| IdentGroup::cast(item.reference))
+            .collect()
+    }
+
+    /// Returns the tables, subqueries and collections listed in the `FROM`
+    /// clause with their aliases in source order, including joined ones but
+    /// not the ones of subqueries.
+    pub fn from_items(&self) -> Vec<FromItem> {
+        let is_join_clause = |node: &SyntaxNode| {
+            matches!(
+                node.kind(),
+                SyntaxKind::CrossJoinClause
+                    | SyntaxKind::CrossOuterApplyClause
+                    | SyntaxKind::InnerJoinClause
+                    | SyntaxKind::NaturalJoinClause
+                    | SyntaxKind::OuterJoinClause
+            ) && node
+                .ancestors()
+                .find(|a| a.kind() == SyntaxKind::SelectStmt)
+                == Some(self.syntax.clone())
+        };
+
+        let mut items = std::iter::once(self.syntax.clone())
+            .chain(self.syntax.descendants().filter(is_join_clause))
+            .flat_map(|node| {
+                let mut in_from_list = false;
+                let mut expect_table = false;
+                let mut items = Vec::new();
+                let mut current: Option<FromItem> = None;
+
+                // Trivia is skipped. An item ends at the next token or node
+                // which is neither its alias nor its column aliases.
+                for element in node.children_with_tokens() {
+                    match element {
+                        SyntaxElement::Token(t) if t.kind() == SyntaxKind::Keyword => {
+                            let keyword = t.text().to_lowercase();
+                            if keyword == "as" {
+                                continue;
+                            }
+                            in_from_list = keyword == "from";
+                            expect_table = in_from_list || keyword == "join" || keyword == "apply";
+                            items.extend(current.take());
+                        }
+                        SyntaxElement::Token(t)
+                            if matches!(
+                                t.kind(),
+                                SyntaxKind::Whitespace
+                                    | SyntaxKind::Comment
+                                    | SyntaxKind::InlineComment
+                            ) => {}
+                        SyntaxElement::Token(t) => {
+                            if t.kind() == SyntaxKind::Comma {
+                                expect_table = in_from_list;
+                            }
+                            items.extend(current.take());
+                        }
+                        SyntaxElement::Node(n) if expect_table => {
+                            items.extend(current.take());
+                            current = Some(FromItem {
+                                reference: n,
+                                alias: None,
+                                column_aliases: Vec::new(),
+                            });
+                            expect_table = false;
+                        }
+                        SyntaxElement::Node(n) => match current.as_mut() {
+                            Some(item)
+                                if item.alias.is_none() && item.column_aliases.is_empty() =>
+                            {
+                                item.alias = IdentGroup::cast(n);
+                                if item.alias.is_none() {
+                                    items.extend(current.take());
+                                }
+                            }
+                            Some(item) if n.kind() == SyntaxKind::ColumnAliasList => {
+                                item.column_aliases =
+                                    n.children().filter_map(IdentGroup::cast).collect();
+                                items.extend(current.take());
+                            }
+                            _ => items.extend(current.take()),
+                        },
+                    }
+                }
+
+                items.extend(current);
+                items
+            })
+            .collect::<Vec<_>>();
+
+        items.sort_by_key(|item| item.reference.text_range().start());
+        items
+    }
+}
+
+/// A table, subquery or collection expression of a `FROM` list or join.
+#[derive(Debug, Eq, PartialEq)]
+pub struct FromItem {
+    reference: SyntaxNode,
+    alias: Option<IdentGroup>,
+    column_aliases: Vec<IdentGroup>,
+}
+
+impl FromItem {
+    /// Returns the referenced table, if any.
+    pub fn table(&self) -> Option<IdentGroup> {
+        IdentGroup::cast(self.reference.clone())
+    }
+
+    /// Returns the referenced subquery, if any.
+    pub fn inline_view(&self) -> Option<InlineView> {
+        InlineView::cast(self.reference.clone())
+    }
+
+    /// Returns the table, subquery or collection expression as written.
+    pub fn reference(&self) -> &SyntaxNode {
+        &self.reference
+    }
+
+    pub fn alias(&self) -> Option<&IdentGroup> {
+        self.alias.as_ref()
+    }
+
+    /// Returns the column aliases, e.g. `a` and `b` of `(SELECT ..) x (a, b)`.
+    pub fn column_aliases(&self) -> &[IdentGroup] {
+        &self.column_aliases
+    }
+
+    /// Returns the name the columns of the item are qualified with, i.e. its
+    /// alias or otherwise the last component of the table name.
+    pub fn qualifier(&self) -> Option<String> {
+        let ident = match &self.alias {
+            Some(alias) => alias.last(),
+            None => self.table()?.last(),
+        };
+        ident.map(|ident| ident.text())
+    }
+}
+
+impl CrossOuterApplyClause {
+    /// Returns whether this is an `OUTER APPLY`, which keeps the rows of the
+    /// left side without a match.
+    pub fn is_outer(&self) -> bool {
+        self.syntax
+            .first_token()
+            .is_some_and(|t| t.text().eq_ignore_ascii_case("outer"))
+    }
+
+    /// Returns the `LATERAL` join PostgreSQL uses instead, e.g.
+    /// `LEFT JOIN LATERAL (SELECT ..) d ON true` for
+    /// `OUTER APPLY (SELECT ..) d`. Plain tables need no `LATERAL`.
+    pub fn to_lateral_join(&self) -> String {
+        let applied = self
+            .syntax
+            .children_with_tokens()
+            .skip_while(|it| {
+                !it.as_token()
+                    .is_some_and(|t| t.text().eq_ignore_ascii_case("apply"))
+            })
+            .skip(1)
+            .map(|it| it.to_string())
+            .collect::<String>();
+        let is_table = self
+            .syntax
+            .children()
+            .next()
+            .is_some_and(|n| n.kind() == SyntaxKind::IdentGroup);
+        let lateral = if is_table { "" } else { "LATERAL " };
+
+        if self.is_outer() {
+            format!("LEFT JOIN {lateral}{} ON true", applied.trim())
+        } else {
+            format!("CROSS JOIN {lateral}{}", applied.trim())
+        }
+    }
+}
+
+impl InlineView {
+    /// Returns whether the subquery is `LATERAL`, i.e. may reference the
+    /// tables preceding it.
+    pub fn is_lateral(&self) -> bool {
+        self.syntax
+            .first_token()
+            .is_some_and(|t| t.text().eq_ignore_ascii_case("lateral"))
+    }
+
+    pub fn query(&self) -> Option<SelectStmt> {
+        self.syntax.children().find_map(SelectStmt::cast)
+    }
+}
+
+impl SelectClause {
+    /// Returns the selected columns in source order.
+    pub fn columns(&self) -> Vec<ColumnExpr> {
+        self.syntax
+            .children()
+            .filter_map(ColumnExpr::cast)
+            .collect()
+    }
+
+    /// Whether duplicate rows are removed, i.e. `DISTINCT` or its Oracle
+    /// synonym `UNIQUE`.
+    pub fn is_distinct(&self) -> bool {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find(|t| t.kind() == SyntaxKind::Keyword)
+            .is_some_and(|t| {
+                t.text().eq_ignore_ascii_case("distinct") || t.text().eq_ignore_ascii_case("unique")
+            })
+    }
 }
 
 impl WhereClause {
@@ -33,10 +310,112 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use crate::ast::{ComparisonOpType, Root};
-    use source_gen::syntax::SyntaxKind;
 
     use super::*;
 
+    #[test]
+    fn check_select_stmt_tables() {
+        const INPUT: &str = "SELECT e.name FROM emp e JOIN dept d ON e.dept_id = d.id, locations";
+        let result = crate::parse_query(INPUT).unwrap();
+        let select = Root::cast(result.syntax()).unwrap().query().unwrap();
+
+        let tables = select
+            .tables()
+            .into_iter()
+            .filter_map(|t| t.name())
+            .collect::<Vec<_>>();
+        assert_eq!(tables, vec!["emp", "dept", "locations"]);
+    }
+
+    #[test]
+    fn check_select_stmt_tables_with_aliases() {
+        const INPUT: &str = "SELECT * FROM hr.emp e, dept d WHERE e.dept_id = d.id";
+        let result = crate::parse_query(INPUT).unwrap();
+        let select = Root::cast(result.syntax()).unwrap().query().unwrap();
+
+        let tables = select
+            .tables()
+            .into_iter()
+            .filter_map(|t| t.name())
+            .collect::<Vec<_>>();
+        assert_eq!(tables, vec!["hr.emp", "dept"]);
+    }
+
+    #[test]
+    fn check_select_clause_is_distinct() {
+        let is_distinct = |input: &str| {
+            let result = crate::parse_query(input).unwrap();
+            assert!(result.ok(), "{:#?}", result.errors);
+            let select = Root::cast(result.syntax()).unwrap().query().unwrap();
+            select.select_clause().is_some_and(|c| c.is_distinct())
+        };
+
+        assert!(is_distinct("SELECT DISTINCT dept_id FROM emp"));
+        assert!(is_distinct("SELECT unique dept_id, job FROM emp"));
+        assert!(is_distinct("SELECT DISTINCT * FROM emp"));
+        assert!(!is_distinct("SELECT ALL dept_id FROM emp"));
+        assert!(!is_distinct("SELECT dept_id FROM emp"));
+    }
+
+    #[test]
+    fn check_select_stmt_from_items() {
+        const INPUT: &str = r#"SELECT * FROM hr.emp AS e, (SELECT 1, 2 FROM dual) x (a, b)
+            LEFT JOIN dept ON dept.id = x.a"#;
+        let result = crate::parse_query(INPUT).unwrap();
+        assert!(result.ok(), "{:#?}", result.errors);
+        let select = Root::cast(result.syntax()).unwrap().query().unwrap();
+
+        let items = select.from_items();
+        assert_eq!(items.len(), 3);
+        assert_eq!(
+            items[0].table().and_then(|t| t.name()),
+            Some("hr.emp".into())
+        );
+        assert_eq!(items[0].qualifier(), Some("e".into()));
+
+        assert!(items[1].inline_view().is_some());
+        assert_eq!(items[1].qualifier(), Some("x".into()));
+        let columns = items[1]
+            .column_aliases()
+            .iter()
+            .filter_map(|c| c.name())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec!["a", "b"]);
+
+        assert!(items[2].alias().is_none());
+        assert_eq!(items[2].qualifier(), Some("dept".into()));
+    }
+
+    #[test]
+    fn check_cross_outer_apply_to_lateral_join() {
+        const INPUT: &str = r#"SELECT * FROM emp e
+            OUTER APPLY (SELECT * FROM dept d WHERE d.id = e.dept_id) d
+            CROSS APPLY locations"#;
+        let result = crate::parse_query(INPUT).unwrap();
+        let select = Root::cast(result.syntax()).unwrap().query().unwrap();
+
+        let joins = select
+            .syntax()
+            .descendants()
+            .filter_map(CrossOuterApplyClause::cast)
+            .map(|apply| apply.to_lateral_join())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            joins,
+            vec![
+                "LEFT JOIN LATERAL (SELECT * FROM dept d WHERE d.id = e.dept_id) d ON true",
+                "CROSS JOIN locations",
+            ]
+        );
+
+        let tables = select
+            .tables()
+            .into_iter()
+            .filter_map(|t| t.name())
+            .collect::<Vec<_>>();
+        assert_eq!(tables, vec!["emp", "locations"]);
+    }
+
     #[test]
     fn check_ast_node_to_select_stmt() {
         const INPUT: &str = include_str!("../../tests/dql/select_left_join.ora.sql");
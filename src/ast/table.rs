@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Typed AST nodes for tables created from a query.
+
+use crate::ast::{AstNode, IdentGroup, Query};
+
+use super::typed_syntax_node;
+
+typed_syntax_node!(Table, IgnoredClause);
+
+impl Table {
+    /// Returns the name of the table.
+    pub fn name(&self) -> Option<String> {
+        self.syntax.children().find_map(IdentGroup::cast)?.name()
+    }
+
+    /// Returns the skipped physical properties, e.g. `NOLOGGING`.
+    pub fn ignored_clauses(&self) -> Vec<IgnoredClause> {
+        self.syntax
+            .children()
+            .filter_map(IgnoredClause::cast)
+            .collect()
+    }
+
+    /// Returns the query the table is created from, including its `WITH`
+    /// clause.
+    pub fn query(&self) -> Option<Query> {
+        Query::first_child(&self.syntax)
+    }
+}
+
+impl IgnoredClause {
+    /// Returns the lowercased keyword the clause starts with, e.g.
+    /// `parallel`.
+    pub fn keyword(&self) -> Option<String> {
+        self.syntax.first_token().map(|t| t.text().to_lowercase())
+    }
+}
@@ -5,6 +5,7 @@
 //! Typed AST nodes for PL/SQL triggers.
 
 use crate::ast::{AstNode, Block, IdentGroup};
+use source_gen::syntax::{SyntaxElement, SyntaxKind};
 
 use super::typed_syntax_node;
 
@@ -31,6 +32,31 @@ impl TriggerHeader {
     pub fn identifier(&self) -> Option<IdentGroup> {
         self.syntax.children().find_map(IdentGroup::cast)
     }
+
+    /// Returns the lowercased correlation names of the rows, i.e. `old`,
+    /// `new` and `parent` unless renamed with `REFERENCING`.
+    pub fn correlation_names(&self) -> Vec<String> {
+        let mut names = ["old", "new", "parent"].map(str::to_owned).to_vec();
+        let mut renamed: Option<usize> = None;
+
+        for element in self.syntax.children_with_tokens() {
+            match element {
+                SyntaxElement::Token(t) if t.kind() == SyntaxKind::Keyword => {
+                    let keyword = t.text().to_lowercase();
+                    if keyword != "as" {
+                        renamed = names.iter().position(|n| *n == keyword);
+                    }
+                }
+                SyntaxElement::Node(n) => {
+                    if let Some(index) = renamed.take() {
+                        names[index] = n.text().to_string().to_lowercase();
+                    }
+                }
+                _ => {}
+            }
+        }
+        names
+    }
 }
 
 #[cfg(test)]
@@ -53,4 +79,23 @@ mod tests {
             Some("store.after_trigger".to_string())
         );
     }
+
+    #[test]
+    fn check_trigger_correlation_names() {
+        const INPUT: &str = r#"
+CREATE TRIGGER audit_sal
+    BEFORE UPDATE ON emp
+    REFERENCING OLD AS prev NEW AS cur
+    FOR EACH ROW
+BEGIN
+    :cur.changed := SYSDATE;
+END;
+"#;
+        let result = crate::parse_trigger(INPUT).unwrap();
+        let trigger = Root::cast(result.syntax()).unwrap().trigger().unwrap();
+        assert_eq!(
+            trigger.header().unwrap().correlation_names(),
+            vec!["prev", "cur", "parent"]
+        );
+    }
 }
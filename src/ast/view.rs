@@ -4,11 +4,12 @@
 
 //! Typed AST nodes for PL/SQL views.
 
-use crate::ast::{AstNode, IdentGroup};
+use crate::ast::{AstNode, IdentGroup, SelectStmt};
+use source_gen::syntax::{SyntaxKind, SyntaxNode};
 
 use super::typed_syntax_node;
 
-typed_syntax_node!(View);
+typed_syntax_node!(View, MaterializedView, RefreshClause);
 
 impl View {
     /// Returns the name of the view.
@@ -17,6 +18,80 @@ impl View {
     }
 }
 
+impl MaterializedView {
+    /// Returns the name of the materialized view.
+    pub fn name(&self) -> Option<String> {
+        self.syntax.children().find_map(IdentGroup::cast)?.name()
+    }
+
+    /// Returns the `REFRESH` or `NEVER REFRESH` clause, if any.
+    pub fn refresh_clause(&self) -> Option<RefreshClause> {
+        self.syntax.children().find_map(RefreshClause::cast)
+    }
+
+    /// Returns whether the view is populated on creation (`BUILD IMMEDIATE`,
+    /// the default) instead of on the first refresh (`BUILD DEFERRED`).
+    pub fn is_build_deferred(&self) -> bool {
+        keywords(&self.syntax)
+            .windows(2)
+            .any(|w| w[0] == "build" && w[1] == "deferred")
+    }
+
+    /// Returns whether `ENABLE QUERY REWRITE` is given.
+    pub fn is_query_rewrite_enabled(&self) -> bool {
+        keywords(&self.syntax).iter().any(|k| k == "enable")
+    }
+
+    /// Returns the query defining the materialized view.
+    pub fn query(&self) -> Option<SelectStmt> {
+        self.syntax.children().find_map(SelectStmt::cast)
+    }
+}
+
+impl RefreshClause {
+    /// Returns whether this is a `NEVER REFRESH` clause.
+    pub fn is_never(&self) -> bool {
+        self.keywords().first().map(String::as_str) == Some("never")
+    }
+
+    /// Returns the lowercased refresh method, i.e. `fast`, `complete` or
+    /// `force`, if given.
+    pub fn method(&self) -> Option<String> {
+        self.keywords()
+            .into_iter()
+            .find(|k| ["fast", "complete", "force"].contains(&k.as_str()))
+    }
+
+    /// Returns the lowercased refresh mode, i.e. `demand`, `commit` or
+    /// `statement`, if given.
+    pub fn mode(&self) -> Option<String> {
+        let keywords = self.keywords();
+        keywords
+            .windows(2)
+            .find(|w| w[0] == "on")
+            .map(|w| w[1].clone())
+    }
+
+    /// Returns whether the refresh is scheduled using `START WITH` or
+    /// `NEXT`.
+    pub fn is_scheduled(&self) -> bool {
+        self.keywords().iter().any(|k| k == "start" || k == "next")
+    }
+
+    fn keywords(&self) -> Vec<String> {
+        keywords(&self.syntax)
+    }
+}
+
+/// Returns the lowercased keywords directly below `node`.
+fn keywords(node: &SyntaxNode) -> Vec<String> {
+    node.children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Keyword)
+        .map(|t| t.text().to_lowercase())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ast::Root;
@@ -6,22 +6,38 @@
 
 use crate::grammar::declare_section::parse_declare_section;
 use crate::grammar::{
-    opt_expr, opt_function_invocation, parse_expr, parse_ident, parse_insert, parse_query,
+    opt_end_label, opt_expr, opt_function_invocation, opt_method_invocation, parse_expr,
+    parse_ident, parse_insert, parse_member_accesses, parse_query,
 };
 use crate::parser::{safe_loop, Parser};
-use crate::ParseErrorType;
+use crate::{ParseErrorType, SqlIdent};
 use source_gen::lexer::TokenKind;
 use source_gen::syntax::SyntaxKind;
 use source_gen::T;
 
-use super::commit::parse_commit;
-use super::loops::{parse_continue_stmt, parse_exit_stmt, parse_loop};
-use super::{parse_cte, parse_cursor, parse_dml, parse_execute_immediate, parse_raise_stmt};
+use super::commit::{parse_commit, parse_rollback, parse_savepoint};
+use super::loops::{parse_continue_stmt, parse_exit_stmt, parse_forall, parse_loop};
+use super::{
+    at_cursor_stmt, parse_close, parse_cte, parse_cursor, parse_dml, parse_execute_immediate,
+    parse_fetch, parse_open, parse_pragma, parse_raise_stmt,
+};
 
 /// Parses a complete block.
 pub fn parse_block(p: &mut Parser) {
+    parse_named_block(p, None);
+}
+
+/// Parses a complete block, which is the body of the subprogram `name` if
+/// given. The label after `END` has to match the block label or the name,
+/// otherwise a warning is emitted.
+pub(super) fn parse_named_block(p: &mut Parser, name: Option<&str>) {
     p.start(SyntaxKind::Block);
 
+    let label = p
+        .at(T![loop_label])
+        .then(|| p.current_text().trim_matches(&['<', '>'][..]).to_owned());
+    p.eat(T![loop_label]);
+
     let checkpoint = p.checkpoint();
     if p.eat(T![declare]) || p.current() != T![begin] {
         parse_declare_section(p, Some(checkpoint));
@@ -31,39 +47,116 @@ pub fn parse_block(p: &mut Parser) {
 
     safe_loop!(p, {
         parse_stmt(p);
-        if p.at(T![end]) {
+        if p.at(T![exception]) || p.at(T![end]) {
             break;
         }
     });
 
+    if p.at(T![exception]) {
+        parse_exception_section(p);
+    }
+
     p.expect(T![end]);
-    parse_ident(p, 0..1);
+    if let Some(expected) = label.as_deref().or(name) {
+        check_end_label(p, expected);
+    }
+    opt_end_label(p);
     p.expect(T![;]);
 
     p.finish();
 }
 
+/// Warns if the identifier after `END` is present and differs from `expected`.
+fn check_end_label(p: &mut Parser, expected: &str) {
+    if !p.current().is_ident() {
+        return;
+    }
+
+    let found = p.current_text().to_owned();
+    if SqlIdent::from(expected) != SqlIdent::from(found.as_str()) {
+        p.error(ParseErrorType::MismatchedEndLabel(
+            expected.to_owned(),
+            found,
+        ));
+    }
+}
+
+/// Parses the `EXCEPTION` section of a block, up to the closing `END`.
+pub(super) fn parse_exception_section(p: &mut Parser) {
+    p.start(SyntaxKind::ExceptionSection);
+    p.expect(T![exception]);
+
+    safe_loop!(p, {
+        parse_exception_handler(p);
+        if !p.at(T![when]) {
+            break;
+        }
+    });
+
+    p.finish();
+}
+
+/// Parses a single `WHEN <exception> [OR <exception>..] THEN <stmts>` handler.
+fn parse_exception_handler(p: &mut Parser) {
+    p.start(SyntaxKind::ExceptionHandler);
+    p.expect(T![when]);
+
+    if !p.eat(T![others]) {
+        safe_loop!(p, {
+            parse_ident(p, 1..2);
+            if !p.eat(T![or]) {
+                break;
+            }
+        });
+    }
+
+    p.expect(T![then]);
+
+    safe_loop!(p, {
+        parse_stmt(p);
+        if p.at(T![when]) || p.at(T![end]) {
+            break;
+        }
+    });
+
+    p.finish();
+}
+
 pub(super) fn parse_stmt(p: &mut Parser) {
     p.start(SyntaxKind::BlockStatement);
 
+    if p.eat_unterminated_stmt() {
+        p.finish();
+        return;
+    }
+
     match p.current() {
+        T![case] => parse_case_stmt(p),
         T![continue] => parse_continue_stmt(p),
         T![cursor] => parse_cursor(p),
         T![with] => parse_cte(p),
         T![declare] | T![begin] => parse_block(p),
         T![execute] => parse_execute_immediate(p),
         T![exit] => parse_exit_stmt(p),
+        T![fetch] => parse_fetch(p),
+        T![forall] => parse_forall(p),
         T![if] => parse_if_stmt(p),
         T![insert] => parse_insert(p),
-        T![loop] | T![loop_label] => {
+        T![loop_label] if matches!(p.nth(1), Some(T![begin] | T![declare])) => parse_block(p),
+        T![for] | T![loop] | T![loop_label] | T![while] => {
             parse_loop(p);
         }
         T![null] => parse_null_stmt(p),
+        T![pragma] => parse_pragma(p),
         T![return] => parse_return_stmt(p),
         T![select] => parse_query(p, true),
         T![raise] => parse_raise_stmt(p),
         T![delete] | T![update] => parse_dml(p),
         T![commit] => parse_commit(p),
+        T![rollback] => parse_rollback(p),
+        T![savepoint] => parse_savepoint(p),
+        T![unquoted_ident] if at_cursor_stmt(p, "open") => parse_open(p),
+        T![unquoted_ident] if at_cursor_stmt(p, "close") => parse_close(p),
         current_token => {
             if !(opt_assignment_stmt(p) || opt_procedure_call(p)) {
                 p.error(ParseErrorType::ExpectedStatement(current_token));
@@ -117,6 +210,48 @@ fn parse_if_stmt(p: &mut Parser) {
     p.expect(T![;]);
 }
 
+/// Parses a simple or searched `CASE` statement, e.g.
+/// `CASE grade WHEN 'A' THEN .. ELSE .. END CASE;`.
+fn parse_case_stmt(p: &mut Parser) {
+    p.start(SyntaxKind::CaseBlock);
+    p.expect(T![case]);
+    if !p.at(T![when]) {
+        parse_expr(p);
+    }
+
+    safe_loop!(p, {
+        p.expect(T![when]);
+        parse_expr(p);
+        p.expect(T![then]);
+
+        safe_loop!(p, {
+            parse_stmt(p);
+            if [T![when], T![else], T![end]].contains(&p.current()) {
+                break;
+            }
+        });
+
+        if !p.at(T![when]) {
+            break;
+        }
+    });
+
+    if p.eat(T![else]) {
+        safe_loop!(p, {
+            parse_stmt(p);
+            if p.at(T![end]) {
+                break;
+            }
+        });
+    }
+
+    p.expect(T![end]);
+    p.expect(T![case]);
+    opt_end_label(p);
+    p.expect(T![;]);
+    p.finish();
+}
+
 fn parse_null_stmt(p: &mut Parser) {
     p.expect(T![null]);
     p.expect(T![;]);
@@ -129,7 +264,9 @@ fn parse_return_stmt(p: &mut Parser) {
 }
 
 fn opt_procedure_call(p: &mut Parser) -> bool {
-    if opt_function_invocation(p) {
+    let checkpoint = p.checkpoint();
+    if opt_method_invocation(p) || opt_function_invocation(p) {
+        parse_member_accesses(p, checkpoint);
         p.expect(T![;]);
         true
     } else {
@@ -167,7 +304,9 @@ mod tests {
     use expect_test::expect;
 
     use crate::ParseError;
-    use crate::ParseErrorType::{ExpectedStatement, ExpectedToken};
+    use crate::ParseErrorType::{
+        ExpectedStatement, ExpectedToken, MismatchedEndLabel, UnterminatedLiteral,
+    };
     use source_gen::lexer::TokenKind::{IntoKw, UnquotedIdent};
 
     use super::super::tests::{check, parse};
@@ -192,6 +331,34 @@ Root@0..14
         );
     }
 
+    #[test]
+    fn test_block_with_unterminated_literal() {
+        check(
+            parse("BEGIN\n  log('oops);\n  NULL;\nEND;", parse_block),
+            expect![[r#"
+Root@0..32
+  Block@0..32
+    Keyword@0..5 "BEGIN"
+    Whitespace@5..8 "\n  "
+    BlockStatement@8..19
+      Ident@8..11 "log"
+      LParen@11..12 "("
+      Error@12..19 "'oops);"
+    Whitespace@19..22 "\n  "
+    BlockStatement@22..27
+      Keyword@22..26 "NULL"
+      Semicolon@26..27 ";"
+    Whitespace@27..28 "\n"
+    Keyword@28..31 "END"
+    Semicolon@31..32 ";"
+"#]],
+            vec![ParseError::new(
+                UnterminatedLiteral("'oops);".to_owned()),
+                12..19,
+            )],
+        );
+    }
+
     #[test]
     fn test_block_with_null_stmt() {
         check(
@@ -212,6 +379,233 @@ Root@0..16
         );
     }
 
+    #[test]
+    fn test_block_with_for_and_while_loops() {
+        check(
+            parse(
+                r#"BEGIN FOR i IN 1..2 LOOP NULL; END LOOP; WHILE x LOOP NULL; END LOOP; END;"#,
+                parse_block,
+            ),
+            expect![[r#"
+Root@0..74
+  Block@0..74
+    Keyword@0..5 "BEGIN"
+    Whitespace@5..6 " "
+    BlockStatement@6..40
+      Loop@6..40
+        ForLoop@6..39
+          Keyword@6..9 "FOR"
+          Whitespace@9..10 " "
+          Iterator@10..19
+            IdentGroup@10..11
+              Ident@10..11 "i"
+            Whitespace@11..12 " "
+            Keyword@12..14 "IN"
+            Whitespace@14..15 " "
+            IterationControl@15..19
+              IterRange@15..19 "1..2"
+          Whitespace@19..20 " "
+          Keyword@20..24 "LOOP"
+          Whitespace@24..25 " "
+          BlockStatement@25..30
+            Keyword@25..29 "NULL"
+            Semicolon@29..30 ";"
+          Whitespace@30..31 " "
+          Keyword@31..34 "END"
+          Whitespace@34..35 " "
+          Keyword@35..39 "LOOP"
+        Semicolon@39..40 ";"
+    Whitespace@40..41 " "
+    BlockStatement@41..69
+      Loop@41..69
+        WhileLoop@41..68
+          Keyword@41..46 "WHILE"
+          Whitespace@46..47 " "
+          IdentGroup@47..48
+            Ident@47..48 "x"
+          Whitespace@48..49 " "
+          Keyword@49..53 "LOOP"
+          Whitespace@53..54 " "
+          BlockStatement@54..59
+            Keyword@54..58 "NULL"
+            Semicolon@58..59 ";"
+          Whitespace@59..60 " "
+          Keyword@60..63 "END"
+          Whitespace@63..64 " "
+          Keyword@64..68 "LOOP"
+        Semicolon@68..69 ";"
+    Whitespace@69..70 " "
+    Keyword@70..73 "END"
+    Semicolon@73..74 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_labeled_nested_blocks() {
+        check(
+            parse(
+                r#"BEGIN <<outer>> DECLARE x NUMBER; BEGIN <<inner>> BEGIN NULL; END Inner; END outer; END;"#,
+                parse_block,
+            ),
+            expect![[r#"
+Root@0..88
+  Block@0..88
+    Keyword@0..5 "BEGIN"
+    Whitespace@5..6 " "
+    BlockStatement@6..83
+      Block@6..83
+        Ident@6..15 "<<outer>>"
+        Whitespace@15..16 " "
+        DeclareSection@16..33
+          Keyword@16..23 "DECLARE"
+          Whitespace@23..24 " "
+          IdentGroup@24..25
+            Ident@24..25 "x"
+          Whitespace@25..26 " "
+          Datatype@26..32
+            Keyword@26..32 "NUMBER"
+          Semicolon@32..33 ";"
+        Whitespace@33..34 " "
+        Keyword@34..39 "BEGIN"
+        Whitespace@39..40 " "
+        BlockStatement@40..72
+          Block@40..72
+            Ident@40..49 "<<inner>>"
+            Whitespace@49..50 " "
+            Keyword@50..55 "BEGIN"
+            Whitespace@55..56 " "
+            BlockStatement@56..61
+              Keyword@56..60 "NULL"
+              Semicolon@60..61 ";"
+            Whitespace@61..62 " "
+            Keyword@62..65 "END"
+            Whitespace@65..66 " "
+            IdentGroup@66..71
+              Ident@66..71 "Inner"
+            Semicolon@71..72 ";"
+        Whitespace@72..73 " "
+        Keyword@73..76 "END"
+        Whitespace@76..77 " "
+        IdentGroup@77..82
+          Ident@77..82 "outer"
+        Semicolon@82..83 ";"
+    Whitespace@83..84 " "
+    Keyword@84..87 "END"
+    Semicolon@87..88 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_block_with_mismatched_end_label() {
+        check(
+            parse(r#"<<outer>> BEGIN NULL; END inner;"#, parse_block),
+            expect![[r#"
+Root@0..32
+  Block@0..32
+    Ident@0..9 "<<outer>>"
+    Whitespace@9..10 " "
+    Keyword@10..15 "BEGIN"
+    Whitespace@15..16 " "
+    BlockStatement@16..21
+      Keyword@16..20 "NULL"
+      Semicolon@20..21 ";"
+    Whitespace@21..22 " "
+    Keyword@22..25 "END"
+    Whitespace@25..26 " "
+    IdentGroup@26..31
+      Ident@26..31 "inner"
+    Semicolon@31..32 ";"
+"#]],
+            vec![ParseError::new(
+                MismatchedEndLabel("outer".to_owned(), "inner".to_owned()),
+                26..31,
+            )],
+        );
+    }
+
+    #[test]
+    fn test_block_with_exception_section() {
+        check(
+            parse(
+                r#"BEGIN
+    NULL;
+EXCEPTION
+    WHEN no_data_found OR too_many_rows THEN
+        NULL;
+    WHEN OTHERS THEN
+        log_error(SQLCODE, SQLERRM);
+        RAISE;
+END;"#,
+                parse_block,
+            ),
+            expect![[r#"
+Root@0..162
+  Block@0..162
+    Keyword@0..5 "BEGIN"
+    Whitespace@5..10 "\n    "
+    BlockStatement@10..15
+      Keyword@10..14 "NULL"
+      Semicolon@14..15 ";"
+    Whitespace@15..16 "\n"
+    ExceptionSection@16..157
+      Keyword@16..25 "EXCEPTION"
+      Whitespace@25..30 "\n    "
+      ExceptionHandler@30..84
+        Keyword@30..34 "WHEN"
+        Whitespace@34..35 " "
+        IdentGroup@35..48
+          Ident@35..48 "no_data_found"
+        Whitespace@48..49 " "
+        Keyword@49..51 "OR"
+        Whitespace@51..52 " "
+        IdentGroup@52..65
+          Ident@52..65 "too_many_rows"
+        Whitespace@65..66 " "
+        Keyword@66..70 "THEN"
+        Whitespace@70..79 "\n        "
+        BlockStatement@79..84
+          Keyword@79..83 "NULL"
+          Semicolon@83..84 ";"
+      Whitespace@84..89 "\n    "
+      ExceptionHandler@89..157
+        Keyword@89..93 "WHEN"
+        Whitespace@93..94 " "
+        Keyword@94..100 "OTHERS"
+        Whitespace@100..101 " "
+        Keyword@101..105 "THEN"
+        Whitespace@105..114 "\n        "
+        BlockStatement@114..142
+          FunctionInvocation@114..141
+            IdentGroup@114..123
+              Ident@114..123 "log_error"
+            LParen@123..124 "("
+            ArgumentList@124..140
+              Argument@124..131
+                Expression@124..131
+                  Keyword@124..131 "SQLCODE"
+              Comma@131..132 ","
+              Whitespace@132..133 " "
+              Argument@133..140
+                Keyword@133..140 "SQLERRM"
+            RParen@140..141 ")"
+          Semicolon@141..142 ";"
+        Whitespace@142..151 "\n        "
+        BlockStatement@151..157
+          RaiseStmt@151..157
+            Keyword@151..156 "RAISE"
+            Semicolon@156..157 ";"
+    Whitespace@157..158 "\n"
+    Keyword@158..161 "END"
+    Semicolon@161..162 ";"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn test_exhaustive_block() {
         check(
@@ -243,7 +637,7 @@ END log_last_login_fuzzy;"#,
 Root@0..520
   Whitespace@0..1 "\n"
   Block@1..520
-    DeclareSection@1..45
+    DeclareSection@1..44
       Keyword@1..8 "DECLARE"
       Whitespace@8..13 "\n    "
       IdentGroup@13..29
@@ -255,45 +649,45 @@ Root@0..520
         Integer@39..42 "100"
         RParen@42..43 ")"
       Semicolon@43..44 ";"
-      Whitespace@44..45 "\n"
+    Whitespace@44..45 "\n"
     Keyword@45..50 "BEGIN"
     Whitespace@50..55 "\n    "
     InlineComment@55..69 "-- SELECT INTO"
     Whitespace@69..74 "\n    "
-    BlockStatement@74..177
+    BlockStatement@74..152
       SelectStmt@74..152
         Keyword@74..80 "SELECT"
         Whitespace@80..81 " "
-        SelectClause@81..120
-          ColumnExpr@81..120
-            Expression@81..120
-              Expression@81..99
+        SelectClause@81..118
+          ColumnExpr@81..118
+            Expression@81..118
+              Expression@81..98
                 QuotedLiteral@81..89 "'name: '"
                 Whitespace@89..90 " "
                 Concat@90..92 "||"
                 Whitespace@92..93 " "
                 IdentGroup@93..98
                   Ident@93..98 "dummy"
-                Whitespace@98..99 " "
+              Whitespace@98..99 " "
               Concat@99..101 "||"
               Whitespace@101..102 " "
               QuotedLiteral@102..118 "', last login: '"
-              Whitespace@118..120 "  "
-        IntoClause@120..142
+        Whitespace@118..120 "  "
+        IntoClause@120..141
           Keyword@120..124 "INTO"
           Whitespace@124..125 " "
           IdentGroup@125..141
             Ident@125..141 "formatted_output"
-          Whitespace@141..142 " "
+        Whitespace@141..142 " "
         Keyword@142..146 "FROM"
         Whitespace@146..147 " "
         IdentGroup@147..151
           Ident@147..151 "DUAL"
         Semicolon@151..152 ";"
-      Whitespace@152..157 "\n    "
-      InlineComment@157..172 "-- Nested block"
-      Whitespace@172..177 "\n    "
-    BlockStatement@177..220
+    Whitespace@152..157 "\n    "
+    InlineComment@157..172 "-- Nested block"
+    Whitespace@172..177 "\n    "
+    BlockStatement@177..193
       Block@177..193
         Keyword@177..182 "BEGIN"
         Whitespace@182..183 " "
@@ -303,9 +697,9 @@ Root@0..520
         Whitespace@188..189 " "
         Keyword@189..192 "END"
         Semicolon@192..193 ";"
-      Whitespace@193..198 "\n    "
-      InlineComment@198..215 "-- Procedure call"
-      Whitespace@215..220 "\n    "
+    Whitespace@193..198 "\n    "
+    InlineComment@198..215 "-- Procedure call"
+    Whitespace@215..220 "\n    "
     BlockStatement@220..259
       FunctionInvocation@220..258
         IdentGroup@220..240
@@ -407,20 +801,20 @@ Root@0..31
   Block@0..31
     Keyword@0..5 "BEGIN"
     Whitespace@5..6 " "
-    BlockStatement@6..26
+    BlockStatement@6..25
       SelectStmt@6..25
         Keyword@6..12 "SELECT"
         Whitespace@12..13 " "
-        SelectClause@13..15
-          ColumnExpr@13..15
+        SelectClause@13..14
+          ColumnExpr@13..14
             Integer@13..14 "1"
-            Whitespace@14..15 " "
+        Whitespace@14..15 " "
         Keyword@15..19 "FROM"
         Whitespace@19..20 " "
         IdentGroup@20..24
           Ident@20..24 "dual"
         Semicolon@24..25 ";"
-      Whitespace@25..26 " "
+    Whitespace@25..26 " "
     Keyword@26..29 "END"
     Whitespace@29..30 " "
     Semicolon@30..31 ";"
@@ -493,6 +887,147 @@ Root@0..124
     Whitespace@107..120 "\n            "
     Keyword@120..123 "END"
     Semicolon@123..124 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_case_stmt() {
+        check(
+            parse(
+                r#"BEGIN
+    CASE grade
+        WHEN 'A' THEN award(1);
+        WHEN 'B' THEN NULL;
+        ELSE RAISE no_grade;
+    END CASE;
+    CASE WHEN score > 90 THEN x := 1; END CASE;
+END;"#,
+                parse_block,
+            ),
+            expect![[r#"
+Root@0..176
+  Block@0..176
+    Keyword@0..5 "BEGIN"
+    Whitespace@5..10 "\n    "
+    BlockStatement@10..123
+      CaseBlock@10..123
+        Keyword@10..14 "CASE"
+        Whitespace@14..15 " "
+        IdentGroup@15..20
+          Ident@15..20 "grade"
+        Whitespace@20..29 "\n        "
+        Keyword@29..33 "WHEN"
+        Whitespace@33..34 " "
+        QuotedLiteral@34..37 "'A'"
+        Whitespace@37..38 " "
+        Keyword@38..42 "THEN"
+        Whitespace@42..43 " "
+        BlockStatement@43..52
+          FunctionInvocation@43..51
+            IdentGroup@43..48
+              Ident@43..48 "award"
+            LParen@48..49 "("
+            ArgumentList@49..50
+              Argument@49..50
+                Integer@49..50 "1"
+            RParen@50..51 ")"
+          Semicolon@51..52 ";"
+        Whitespace@52..61 "\n        "
+        Keyword@61..65 "WHEN"
+        Whitespace@65..66 " "
+        QuotedLiteral@66..69 "'B'"
+        Whitespace@69..70 " "
+        Keyword@70..74 "THEN"
+        Whitespace@74..75 " "
+        BlockStatement@75..80
+          Keyword@75..79 "NULL"
+          Semicolon@79..80 ";"
+        Whitespace@80..89 "\n        "
+        Keyword@89..93 "ELSE"
+        Whitespace@93..94 " "
+        BlockStatement@94..109
+          RaiseStmt@94..109
+            Keyword@94..99 "RAISE"
+            Whitespace@99..100 " "
+            IdentGroup@100..108
+              Ident@100..108 "no_grade"
+            Semicolon@108..109 ";"
+        Whitespace@109..114 "\n    "
+        Keyword@114..117 "END"
+        Whitespace@117..118 " "
+        Keyword@118..122 "CASE"
+        Semicolon@122..123 ";"
+    Whitespace@123..128 "\n    "
+    BlockStatement@128..171
+      CaseBlock@128..171
+        Keyword@128..132 "CASE"
+        Whitespace@132..133 " "
+        Keyword@133..137 "WHEN"
+        Whitespace@137..138 " "
+        Expression@138..148
+          IdentGroup@138..143
+            Ident@138..143 "score"
+          Whitespace@143..144 " "
+          ComparisonOp@144..145 ">"
+          Whitespace@145..146 " "
+          Integer@146..148 "90"
+        Whitespace@148..149 " "
+        Keyword@149..153 "THEN"
+        Whitespace@153..154 " "
+        BlockStatement@154..161
+          IdentGroup@154..155
+            Ident@154..155 "x"
+          Whitespace@155..156 " "
+          Assign@156..158 ":="
+          Whitespace@158..159 " "
+          Expression@159..160
+            Integer@159..160 "1"
+          Semicolon@160..161 ";"
+        Whitespace@161..162 " "
+        Keyword@162..165 "END"
+        Whitespace@165..166 " "
+        Keyword@166..170 "CASE"
+        Semicolon@170..171 ";"
+    Whitespace@171..172 "\n"
+    Keyword@172..175 "END"
+    Semicolon@175..176 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_comments_between_statements_belong_to_block() {
+        check(
+            parse(
+                "BEGIN\n  -- reset\n  x := 1; /* done */\nEND; /* end of\nblock */",
+                parse_block,
+            ),
+            expect![[r#"
+Root@0..61
+  Block@0..42
+    Keyword@0..5 "BEGIN"
+    Whitespace@5..8 "\n  "
+    InlineComment@8..16 "-- reset"
+    Whitespace@16..19 "\n  "
+    BlockStatement@19..26
+      IdentGroup@19..20
+        Ident@19..20 "x"
+      Whitespace@20..21 " "
+      Assign@21..23 ":="
+      Whitespace@23..24 " "
+      Expression@24..25
+        Integer@24..25 "1"
+      Semicolon@25..26 ";"
+    Whitespace@26..27 " "
+    Comment@27..37 "/* done */"
+    Whitespace@37..38 "\n"
+    Keyword@38..41 "END"
+    Semicolon@41..42 ";"
+  Whitespace@42..43 " "
+  Comment@43..61 "/* end of\nblock */"
 "#]],
             vec![],
         );
@@ -9,6 +9,7 @@ use crate::grammar::{parse_expr, parse_ident};
 use crate::parser::{safe_loop, Parser};
 use crate::ParseErrorType;
 use source_gen::lexer::TokenKind;
+use source_gen::syntax::SyntaxKind;
 use source_gen::T;
 
 /// Attempts to parse a call_spec if applicable
@@ -23,6 +24,8 @@ pub(crate) fn opt_call_spec(p: &mut Parser) -> bool {
 
 /// Parses a call specification
 pub(super) fn parse_call_spec(p: &mut Parser) {
+    p.start(SyntaxKind::CallSpec);
+
     match p.current() {
         T![language] => match p.nth(1) {
             Some(T![java]) => {
@@ -42,6 +45,8 @@ pub(super) fn parse_call_spec(p: &mut Parser) {
             T![external],
         ])),
     }
+
+    p.finish();
 }
 
 fn parse_javascript_declaration(p: &mut Parser) {
@@ -169,14 +174,15 @@ mod tests {
             parse(INPUT, parse_call_spec),
             expect![[r#"
 Root@0..54
-  Keyword@0..8 "LANGUAGE"
-  Whitespace@8..9 " "
-  Keyword@9..13 "JAVA"
-  Whitespace@13..14 " "
-  Keyword@14..18 "NAME"
-  Whitespace@18..19 " "
-  Expression@19..54
-    QuotedLiteral@19..54 "'Adjuster.raiseSalary ..."
+  CallSpec@0..54
+    Keyword@0..8 "LANGUAGE"
+    Whitespace@8..9 " "
+    Keyword@9..13 "JAVA"
+    Whitespace@13..14 " "
+    Keyword@14..18 "NAME"
+    Whitespace@18..19 " "
+    Expression@19..54
+      QuotedLiteral@19..54 "'Adjuster.raiseSalary ..."
 "#]],
             vec![],
         );
@@ -190,15 +196,16 @@ Root@0..54
             parse(INPUT, parse_call_spec),
             expect![[r#"
 Root@0..34
-  Keyword@0..3 "MLE"
-  Whitespace@3..4 " "
-  Keyword@4..12 "LANGUAGE"
-  Whitespace@12..13 " "
-  IdentGroup@13..23
-    Ident@13..23 "JAVASCRIPT"
-  Whitespace@23..24 " "
-  Expression@24..34
-    QuotedLiteral@24..34 "'return 1'"
+  CallSpec@0..34
+    Keyword@0..3 "MLE"
+    Whitespace@3..4 " "
+    Keyword@4..12 "LANGUAGE"
+    Whitespace@12..13 " "
+    IdentGroup@13..23
+      Ident@13..23 "JAVASCRIPT"
+    Whitespace@23..24 " "
+    Expression@24..34
+      QuotedLiteral@24..34 "'return 1'"
 "#]],
             vec![],
         );
@@ -212,17 +219,18 @@ Root@0..34
             parse(INPUT, parse_call_spec),
             expect![[r#"
 Root@0..55
-  Keyword@0..3 "MLE"
-  Whitespace@3..4 " "
-  Keyword@4..10 "MODULE"
-  Whitespace@10..11 " "
-  IdentGroup@11..21
-    Ident@11..21 "custom_mod"
-  Whitespace@21..22 " "
-  Keyword@22..31 "SIGNATURE"
-  Whitespace@31..32 " "
-  Expression@32..55
-    QuotedLiteral@32..55 "'mod_func(Out<number>)'"
+  CallSpec@0..55
+    Keyword@0..3 "MLE"
+    Whitespace@3..4 " "
+    Keyword@4..10 "MODULE"
+    Whitespace@10..11 " "
+    IdentGroup@11..21
+      Ident@11..21 "custom_mod"
+    Whitespace@21..22 " "
+    Keyword@22..31 "SIGNATURE"
+    Whitespace@31..32 " "
+    Expression@32..55
+      QuotedLiteral@32..55 "'mod_func(Out<number>)'"
 "#]],
             vec![],
         );
@@ -83,33 +83,33 @@ Root@0..77
   CaseStmt@0..77
     Keyword@0..4 "CASE"
     Whitespace@4..5 " "
-    SimpleCaseExpression@5..60
+    SimpleCaseExpression@5..59
       IdentGroup@5..17
         Ident@5..17 "credit_limit"
       Whitespace@17..18 " "
       Keyword@18..22 "WHEN"
       Whitespace@22..23 " "
-      ComparissonExpression@23..27
+      ComparissonExpression@23..26
         Integer@23..26 "100"
-        Whitespace@26..27 " "
+      Whitespace@26..27 " "
       Keyword@27..31 "THEN"
       Whitespace@31..32 " "
       QuotedLiteral@32..37 "'Low'"
       Whitespace@37..38 "\n"
       Keyword@38..42 "WHEN"
       Whitespace@42..43 " "
-      ComparissonExpression@43..48
+      ComparissonExpression@43..47
         Integer@43..47 "5000"
-        Whitespace@47..48 " "
+      Whitespace@47..48 " "
       Keyword@48..52 "THEN"
       Whitespace@52..53 " "
       QuotedLiteral@53..59 "'High'"
-      Whitespace@59..60 "\n"
-    ElseExpression@60..74
+    Whitespace@59..60 "\n"
+    ElseExpression@60..73
       Keyword@60..64 "ELSE"
       Whitespace@64..65 " "
       QuotedLiteral@65..73 "'Medium'"
-      Whitespace@73..74 " "
+    Whitespace@73..74 " "
     Keyword@74..77 "END"
 "#]],
             vec![],
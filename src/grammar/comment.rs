@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements parsing of `COMMENT ON` statements from a token tree.
+
+use crate::grammar::parse_ident;
+use crate::parser::Parser;
+use crate::ParseErrorType;
+use source_gen::lexer::TokenKind;
+use source_gen::syntax::SyntaxKind;
+use source_gen::T;
+
+/// Parses a `COMMENT ON { TABLE | COLUMN | MATERIALIZED VIEW } ... IS '...'`
+/// statement.
+pub(crate) fn parse_comment_stmt(p: &mut Parser) {
+    p.start(SyntaxKind::CommentStmt);
+    p.expect(T![comment]);
+    p.expect(T![on]);
+
+    match p.current() {
+        T![table] => {
+            p.bump_any();
+            parse_ident(p, 1..2);
+        }
+        T![materialized] => {
+            p.bump_any();
+            p.expect(T![view]);
+            parse_ident(p, 1..2);
+        }
+        T![unquoted_ident] if p.current_text().eq_ignore_ascii_case("column") => {
+            p.bump_any_map(SyntaxKind::Keyword);
+            parse_ident(p, 2..3);
+        }
+        _ => p.error(ParseErrorType::ExpectedOneOfTokens(vec![
+            T![table],
+            T![materialized],
+        ])),
+    }
+
+    p.expect(T![is]);
+    p.expect(T![quoted_literal]);
+    p.eat(T![;]);
+    p.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::grammar::tests::{check, parse};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_comment_on_table() {
+        check(
+            parse(
+                "COMMENT ON TABLE hr.employees IS 'All employees, including contractors';",
+                parse_comment_stmt,
+            ),
+            expect![[r#"
+Root@0..72
+  CommentStmt@0..72
+    Keyword@0..7 "COMMENT"
+    Whitespace@7..8 " "
+    Keyword@8..10 "ON"
+    Whitespace@10..11 " "
+    Keyword@11..16 "TABLE"
+    Whitespace@16..17 " "
+    IdentGroup@17..29
+      Ident@17..19 "hr"
+      Dot@19..20 "."
+      Ident@20..29 "employees"
+    Whitespace@29..30 " "
+    Keyword@30..32 "IS"
+    Whitespace@32..33 " "
+    QuotedLiteral@33..71 "'All employees, inclu ..."
+    Semicolon@71..72 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_comment_on_column() {
+        check(
+            parse(
+                "COMMENT ON COLUMN employees.salary IS 'Monthly salary in EUR'",
+                parse_comment_stmt,
+            ),
+            expect![[r#"
+Root@0..61
+  CommentStmt@0..61
+    Keyword@0..7 "COMMENT"
+    Whitespace@7..8 " "
+    Keyword@8..10 "ON"
+    Whitespace@10..11 " "
+    Keyword@11..17 "COLUMN"
+    Whitespace@17..18 " "
+    IdentGroup@18..34
+      Ident@18..27 "employees"
+      Dot@27..28 "."
+      Ident@28..34 "salary"
+    Whitespace@34..35 " "
+    Keyword@35..37 "IS"
+    Whitespace@37..38 " "
+    QuotedLiteral@38..61 "'Monthly salary in EUR'"
+"#]],
+            vec![],
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use super::parse_ident;
 use crate::parser::Parser;
 use source_gen::{lexer::TokenKind, syntax::SyntaxKind, T};
 
@@ -15,6 +16,28 @@ pub(crate) fn parse_commit(p: &mut Parser) {
     p.finish();
 }
 
+pub(crate) fn parse_rollback(p: &mut Parser) {
+    p.start(SyntaxKind::RollbackStmt);
+    p.expect(T![rollback]);
+    p.eat(T![work]);
+    if p.eat(T![to]) {
+        p.eat(T![savepoint]);
+        parse_ident(p, 1..1);
+    } else if p.at(T![force]) {
+        parse_force(p);
+    }
+    p.eat(T![;]);
+    p.finish();
+}
+
+pub(crate) fn parse_savepoint(p: &mut Parser) {
+    p.start(SyntaxKind::SavepointStmt);
+    p.expect(T![savepoint]);
+    parse_ident(p, 1..1);
+    p.eat(T![;]);
+    p.finish();
+}
+
 fn parse_force(p: &mut Parser) {
     p.expect(T![force]);
     p.expect(T![quoted_literal]);
@@ -43,7 +66,7 @@ mod tests {
 
     use crate::grammar::tests::{check, parse};
 
-    use super::parse_commit;
+    use super::{parse_commit, parse_rollback};
 
     #[test]
     fn parse_simple_commit() {
@@ -135,6 +158,29 @@ Root@0..30
     Whitespace@19..20 " "
     Keyword@20..29 "IMMEDIATE"
     Semicolon@29..30 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn parse_rollback_to_savepoint() {
+        check(
+            parse("ROLLBACK WORK TO SAVEPOINT before_update;", parse_rollback),
+            expect![[r#"
+Root@0..41
+  RollbackStmt@0..41
+    Keyword@0..8 "ROLLBACK"
+    Whitespace@8..9 " "
+    Keyword@9..13 "WORK"
+    Whitespace@13..14 " "
+    Keyword@14..16 "TO"
+    Whitespace@16..17 " "
+    Keyword@17..26 "SAVEPOINT"
+    Whitespace@26..27 " "
+    IdentGroup@27..40
+      Ident@27..40 "before_update"
+    Semicolon@40..41 ";"
 "#]],
             vec![],
         );
@@ -4,6 +4,10 @@
 
 //! Implements parsing of constraints from a token tree.
 
+use crate::grammar::physical_properties::{
+    at_ignored_clause, parse_ignored_clause, skip_parenthesized,
+};
+
 use super::*;
 
 #[allow(unused)]
@@ -206,6 +210,7 @@ fn parse_out_of_line_ref_constraint(p: &mut Parser) {
 }
 
 fn parse_references_clause(p: &mut Parser) {
+    p.start(SyntaxKind::ReferencesClause);
     p.expect(T![references]);
     parse_ident(p, 1..2);
 
@@ -227,6 +232,8 @@ fn parse_references_clause(p: &mut Parser) {
             ])),
         }
     }
+
+    p.finish();
 }
 
 fn opt_parse_constraint_state(p: &mut Parser) {
@@ -252,7 +259,7 @@ fn opt_parse_constraint_state(p: &mut Parser) {
 
     p.eat_one_of(&[T![rely], T![norely]]);
     if p.at(T![using]) {
-        // TODO: using_index_clause
+        parse_using_index_clause(p);
     }
     p.eat_one_of(&[T![enable], T![disable]]);
     p.eat_one_of(&[T![validate], T![novalidate]]);
@@ -262,6 +269,36 @@ fn opt_parse_constraint_state(p: &mut Parser) {
     }
 }
 
+/// Parses a `USING INDEX` clause, which either names an existing index,
+/// creates one or only gives the physical properties of the created index.
+fn parse_using_index_clause(p: &mut Parser) {
+    p.start(SyntaxKind::UsingIndexClause);
+    p.expect(T![using]);
+    p.expect(T![index]);
+
+    if p.at(T!["("]) {
+        skip_parenthesized(p);
+    } else if p.current().is_ident()
+        && !at_ignored_clause(p)
+        && ![
+            T![enable],
+            T![disable],
+            T![validate],
+            T![novalidate],
+            T![exceptions],
+        ]
+        .contains(&p.current())
+    {
+        parse_ident(p, 1..2);
+    }
+
+    while at_ignored_clause(p) {
+        parse_ignored_clause(p);
+    }
+
+    p.finish();
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::expect;
@@ -347,14 +384,15 @@ Root@0..49
     IdentGroup@11..21
       Ident@11..21 "fk_storeid"
     Whitespace@21..22 " "
-    Keyword@22..32 "REFERENCES"
-    Whitespace@32..33 " "
-    IdentGroup@33..39
-      Ident@33..39 "stores"
-    LParen@39..40 "("
-    IdentGroup@40..48
-      Ident@40..48 "store_id"
-    RParen@48..49 ")"
+    ReferencesClause@22..49
+      Keyword@22..32 "REFERENCES"
+      Whitespace@32..33 " "
+      IdentGroup@33..39
+        Ident@33..39 "stores"
+      LParen@39..40 "("
+      IdentGroup@40..48
+        Ident@40..48 "store_id"
+      RParen@48..49 ")"
 "#]],
             vec![],
         );
@@ -370,20 +408,21 @@ Root@0..49
             expect![[r#"
 Root@0..45
   Constraint@0..45
-    Keyword@0..10 "REFERENCES"
-    Whitespace@10..11 " "
-    IdentGroup@11..17
-      Ident@11..17 "stores"
-    LParen@17..18 "("
-    IdentGroup@18..26
-      Ident@18..26 "store_id"
-    RParen@26..27 ")"
-    Whitespace@27..28 " "
-    Keyword@28..30 "ON"
-    Whitespace@30..31 " "
-    Keyword@31..37 "DELETE"
-    Whitespace@37..38 " "
-    Keyword@38..45 "CASCADE"
+    ReferencesClause@0..45
+      Keyword@0..10 "REFERENCES"
+      Whitespace@10..11 " "
+      IdentGroup@11..17
+        Ident@11..17 "stores"
+      LParen@17..18 "("
+      IdentGroup@18..26
+        Ident@18..26 "store_id"
+      RParen@26..27 ")"
+      Whitespace@27..28 " "
+      Keyword@28..30 "ON"
+      Whitespace@30..31 " "
+      Keyword@31..37 "DELETE"
+      Whitespace@37..38 " "
+      Keyword@38..45 "CASCADE"
 "#]],
             vec![],
         );
@@ -458,6 +497,157 @@ Root@0..47
     Keyword@28..36 "DEFERRED"
     Whitespace@36..37 " "
     Keyword@37..47 "DEFERRABLE"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn parse_composite_foreign_key_constraint() {
+        check(
+            parse(
+                "CONSTRAINT fk_line FOREIGN KEY (order_id, line_no) REFERENCES order_lines (order_id, line_no) ON DELETE SET NULL DEFERRABLE INITIALLY DEFERRED",
+                parse_constraint,
+            ),
+            expect![[r#"
+Root@0..142
+  Constraint@0..142
+    Keyword@0..10 "CONSTRAINT"
+    Whitespace@10..11 " "
+    IdentGroup@11..18
+      Ident@11..18 "fk_line"
+    Whitespace@18..19 " "
+    Keyword@19..26 "FOREIGN"
+    Whitespace@26..27 " "
+    Keyword@27..30 "KEY"
+    Whitespace@30..31 " "
+    LParen@31..32 "("
+    IdentGroup@32..40
+      Ident@32..40 "order_id"
+    Comma@40..41 ","
+    Whitespace@41..42 " "
+    IdentGroup@42..49
+      Ident@42..49 "line_no"
+    RParen@49..50 ")"
+    Whitespace@50..51 " "
+    ReferencesClause@51..112
+      Keyword@51..61 "REFERENCES"
+      Whitespace@61..62 " "
+      IdentGroup@62..73
+        Ident@62..73 "order_lines"
+      Whitespace@73..74 " "
+      LParen@74..75 "("
+      IdentGroup@75..83
+        Ident@75..83 "order_id"
+      Comma@83..84 ","
+      Whitespace@84..85 " "
+      IdentGroup@85..92
+        Ident@85..92 "line_no"
+      RParen@92..93 ")"
+      Whitespace@93..94 " "
+      Keyword@94..96 "ON"
+      Whitespace@96..97 " "
+      Keyword@97..103 "DELETE"
+      Whitespace@103..104 " "
+      Keyword@104..107 "SET"
+      Whitespace@107..108 " "
+      Keyword@108..112 "NULL"
+    Whitespace@112..113 " "
+    Keyword@113..123 "DEFERRABLE"
+    Whitespace@123..124 " "
+    Keyword@124..133 "INITIALLY"
+    Whitespace@133..134 " "
+    Keyword@134..142 "DEFERRED"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn parse_using_index_constraint() {
+        check(
+            parse(
+                "CONSTRAINT pk_orders PRIMARY KEY (id) USING INDEX pk_orders_idx TABLESPACE idx ENABLE",
+                parse_constraint,
+            ),
+            expect![[r#"
+Root@0..85
+  Constraint@0..85
+    Keyword@0..10 "CONSTRAINT"
+    Whitespace@10..11 " "
+    IdentGroup@11..20
+      Ident@11..20 "pk_orders"
+    Whitespace@20..21 " "
+    Keyword@21..28 "PRIMARY"
+    Whitespace@28..29 " "
+    Keyword@29..32 "KEY"
+    Whitespace@32..33 " "
+    LParen@33..34 "("
+    IdentGroup@34..36
+      Ident@34..36 "id"
+    RParen@36..37 ")"
+    Whitespace@37..38 " "
+    UsingIndexClause@38..78
+      Keyword@38..43 "USING"
+      Whitespace@43..44 " "
+      Keyword@44..49 "INDEX"
+      Whitespace@49..50 " "
+      IdentGroup@50..63
+        Ident@50..63 "pk_orders_idx"
+      Whitespace@63..64 " "
+      IgnoredClause@64..78
+        Keyword@64..74 "TABLESPACE"
+        Whitespace@74..75 " "
+        IdentGroup@75..78
+          Ident@75..78 "idx"
+    Whitespace@78..79 " "
+    Keyword@79..85 "ENABLE"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn parse_using_index_create_constraint() {
+        check(
+            parse(
+                "UNIQUE (code) USING INDEX (CREATE UNIQUE INDEX code_idx ON items (code)) NOVALIDATE",
+                parse_constraint,
+            ),
+            expect![[r#"
+Root@0..83
+  Constraint@0..83
+    Keyword@0..6 "UNIQUE"
+    Whitespace@6..7 " "
+    LParen@7..8 "("
+    IdentGroup@8..12
+      Ident@8..12 "code"
+    RParen@12..13 ")"
+    Whitespace@13..14 " "
+    UsingIndexClause@14..72
+      Keyword@14..19 "USING"
+      Whitespace@19..20 " "
+      Keyword@20..25 "INDEX"
+      Whitespace@25..26 " "
+      LParen@26..27 "("
+      Keyword@27..33 "CREATE"
+      Whitespace@33..34 " "
+      Keyword@34..40 "UNIQUE"
+      Whitespace@40..41 " "
+      Keyword@41..46 "INDEX"
+      Whitespace@46..47 " "
+      Ident@47..55 "code_idx"
+      Whitespace@55..56 " "
+      Keyword@56..58 "ON"
+      Whitespace@58..59 " "
+      Ident@59..64 "items"
+      Whitespace@64..65 " "
+      LParen@65..66 "("
+      Ident@66..70 "code"
+      RParen@70..71 ")"
+      RParen@71..72 ")"
+    Whitespace@72..73 " "
+    Keyword@73..83 "NOVALIDATE"
 "#]],
             vec![],
         );
@@ -1,7 +1,10 @@
 use crate::{safe_loop, Parser};
 use source_gen::{lexer::TokenKind, syntax::SyntaxKind, T};
 
-use super::{parse_datatype, parse_expr, parse_ident, parse_query};
+use super::{
+    parse_argument_list, parse_bulk_into_clause, parse_datatype, parse_expr, parse_ident,
+    parse_ident_or_function_invocation, parse_into_clause, parse_query,
+};
 
 /// Railroad diagram 🚆 https://docs.oracle.com/en/database/oracle/oracle-database/19/lnpls/explicit-cursor-declaration-and-definition.html
 pub fn parse_cursor(p: &mut Parser) {
@@ -21,6 +24,71 @@ pub fn parse_cursor(p: &mut Parser) {
     p.finish();
 }
 
+/// Parses a `FETCH` statement, either into variables or into collections
+/// using `BULK COLLECT INTO`, optionally with a `LIMIT`.
+pub(crate) fn parse_fetch(p: &mut Parser) {
+    p.start(SyntaxKind::FetchStmt);
+    p.expect(T![fetch]);
+    if !p.eat(T![bind_var]) {
+        parse_ident(p, 1..1);
+    }
+    if p.at(T![bulk]) {
+        parse_bulk_into_clause(p);
+        if p.eat(T![limit]) {
+            parse_expr(p);
+        }
+    } else {
+        parse_into_clause(p, true);
+    }
+    p.eat(T![;]);
+    p.finish();
+}
+
+/// Parses an attribute of an explicit or the implicit `SQL` cursor, e.g.
+/// `c_emp%NOTFOUND` or `SQL%BULK_EXCEPTIONS(i).ERROR_INDEX`.
+pub(crate) fn parse_cursor_attribute(p: &mut Parser) {
+    p.start(SyntaxKind::CursorAttribute);
+    parse_ident(p, 1..1);
+    p.expect(T![%]);
+    parse_ident_or_function_invocation(p);
+    p.finish();
+}
+
+/// Returns whether the parser is at an `OPEN` or `CLOSE` statement, as
+/// opposed to a call of a procedure named alike.
+pub(crate) fn at_cursor_stmt(p: &mut Parser, keyword: &str) -> bool {
+    p.current_text().eq_ignore_ascii_case(keyword) && p.nth(1).is_some_and(|t| t.is_ident())
+}
+
+/// Parses an `OPEN` statement of an explicit cursor with its arguments or of
+/// a cursor variable `FOR` a query.
+pub(crate) fn parse_open(p: &mut Parser) {
+    p.start(SyntaxKind::OpenStmt);
+    p.bump_any_map(SyntaxKind::Keyword);
+    if !p.eat(T![bind_var]) {
+        parse_ident(p, 1..1);
+    }
+    if p.at(T!["("]) {
+        parse_argument_list(p);
+    }
+    if p.eat(T![for]) {
+        parse_query(p, false);
+    }
+    p.eat(T![;]);
+    p.finish();
+}
+
+/// Parses a `CLOSE` statement.
+pub(crate) fn parse_close(p: &mut Parser) {
+    p.start(SyntaxKind::CloseStmt);
+    p.bump_any_map(SyntaxKind::Keyword);
+    if !p.eat(T![bind_var]) {
+        parse_ident(p, 1..1);
+    }
+    p.expect(T![;]);
+    p.finish();
+}
+
 fn parse_cursor_param_declarations(p: &mut Parser) {
     p.start(SyntaxKind::CursorParameterDeclarations);
     p.expect(T!["("]);
@@ -65,11 +133,11 @@ mod tests {
     use expect_test::expect;
 
     use crate::grammar::{
-        parse_block,
+        parse_block, parse_expr,
         tests::{check, parse},
     };
 
-    use super::parse_cursor;
+    use super::{parse_cursor, parse_fetch};
 
     #[test]
     fn test_explicit_cursor_declaration_and_definition() {
@@ -94,7 +162,7 @@ END;",
             expect![[r#"
 Root@0..395
   Block@0..395
-    DeclareSection@0..377
+    DeclareSection@0..372
       Keyword@0..7 "DECLARE"
       Whitespace@7..10 "\n  "
       CursorStmt@10..47
@@ -114,7 +182,7 @@ Root@0..395
       Whitespace@47..51 "    "
       InlineComment@51..64 "-- Declare c1"
       Whitespace@64..69 "\n \n  "
-      CursorStmt@69..219
+      CursorStmt@69..213
         Keyword@69..75 "CURSOR"
         Whitespace@75..76 " "
         IdentGroup@76..78
@@ -127,7 +195,7 @@ Root@0..395
         SelectStmt@139..213
           Keyword@139..145 "SELECT"
           Whitespace@145..146 " "
-          SelectClause@146..174
+          SelectClause@146..173
             ColumnExpr@146..157
               Expression@146..157
                 IdentGroup@146..157
@@ -140,10 +208,10 @@ Root@0..395
                   Ident@159..165 "job_id"
             Comma@165..166 ","
             Whitespace@166..167 " "
-            ColumnExpr@167..174
+            ColumnExpr@167..173
               IdentGroup@167..173
                 Ident@167..173 "salary"
-              Whitespace@173..174 " "
+          Whitespace@173..174 " "
           Keyword@174..178 "FROM"
           Whitespace@178..179 " "
           IdentGroup@179..188
@@ -160,8 +228,8 @@ Root@0..395
               Whitespace@207..208 " "
               Integer@208..212 "2000"
           Semicolon@212..213 ";"
-        Whitespace@213..219 " \n \n  "
-      CursorStmt@219..377
+      Whitespace@213..219 " \n \n  "
+      CursorStmt@219..372
         Keyword@219..225 "CURSOR"
         Whitespace@225..226 " "
         IdentGroup@226..228
@@ -202,7 +270,7 @@ Root@0..395
               Whitespace@367..368 " "
               Integer@368..371 "110"
           Semicolon@371..372 ";"
-        Whitespace@372..377 "\n \n  "
+    Whitespace@372..377 "\n \n  "
     Keyword@377..382 "BEGIN"
     Whitespace@382..385 "\n  "
     BlockStatement@385..390
@@ -258,7 +326,7 @@ Root@0..175
     SelectStmt@47..175
       Keyword@47..53 "SELECT"
       Whitespace@53..54 " "
-      SelectClause@54..112
+      SelectClause@54..107
         ColumnExpr@54..63
           Expression@54..63
             IdentGroup@54..63
@@ -271,7 +339,7 @@ Root@0..175
               Ident@65..75 "first_name"
         Comma@75..76 ","
         Whitespace@76..77 " "
-        ColumnExpr@77..112
+        ColumnExpr@77..107
           LParen@77..78 "("
           Expression@78..94
             IdentGroup@78..84
@@ -285,7 +353,7 @@ Root@0..175
           Whitespace@95..96 " "
           Alias@96..107
             Ident@96..107 "overpayment"
-          Whitespace@107..112 "\n    "
+      Whitespace@107..112 "\n    "
       Keyword@112..116 "FROM"
       Whitespace@116..117 " "
       IdentGroup@117..126
@@ -295,7 +363,7 @@ Root@0..175
         Keyword@131..136 "WHERE"
         Whitespace@136..137 " "
         Expression@137..174
-          Expression@137..154
+          Expression@137..149
             IdentGroup@137..143
               Ident@137..143 "job_id"
             Whitespace@143..144 " "
@@ -303,7 +371,7 @@ Root@0..175
             Whitespace@145..146 " "
             IdentGroup@146..149
               Ident@146..149 "job"
-            Whitespace@149..154 "\n    "
+          Whitespace@149..154 "\n    "
           LogicOp@154..157 "AND"
           Whitespace@157..158 " "
           Expression@158..174
@@ -348,9 +416,9 @@ Root@0..269
         IdentGroup@10..18
           Ident@10..18 "location"
         Whitespace@18..19 " "
-        Datatype@19..26
+        Datatype@19..25
           Keyword@19..25 "NUMBER"
-          Whitespace@25..26 " "
+        Whitespace@25..26 " "
         Keyword@26..33 "DEFAULT"
         Whitespace@33..34 " "
         Integer@34..38 "1700"
@@ -361,7 +429,7 @@ Root@0..269
     SelectStmt@47..269
       Keyword@47..53 "SELECT"
       Whitespace@53..54 " "
-      SelectClause@54..121
+      SelectClause@54..116
         ColumnExpr@54..69
           Expression@54..69
             IdentGroup@54..69
@@ -376,10 +444,10 @@ Root@0..269
             Ident@92..99 "manager"
         Comma@99..100 ","
         Whitespace@100..112 "\n           "
-        ColumnExpr@112..121
+        ColumnExpr@112..116
           IdentGroup@112..116
             Ident@112..116 "city"
-          Whitespace@116..121 "\n    "
+      Whitespace@116..121 "\n    "
       Keyword@121..125 "FROM"
       Whitespace@125..126 " "
       IdentGroup@126..137
@@ -397,8 +465,8 @@ Root@0..269
         Keyword@164..169 "WHERE"
         Whitespace@169..170 " "
         Expression@170..268
-          Expression@170..235
-            Expression@170..199
+          Expression@170..228
+            Expression@170..192
               IdentGroup@170..181
                 Ident@170..181 "location_id"
               Whitespace@181..182 " "
@@ -406,10 +474,10 @@ Root@0..269
               Whitespace@183..184 " "
               IdentGroup@184..192
                 Ident@184..192 "location"
-              Whitespace@192..199 "\n      "
+            Whitespace@192..199 "\n      "
             LogicOp@199..202 "AND"
             Whitespace@202..203 " "
-            Expression@203..235
+            Expression@203..228
               IdentGroup@203..214
                 Ident@203..214 "location_id"
               Whitespace@214..215 " "
@@ -417,7 +485,7 @@ Root@0..269
               Whitespace@216..217 " "
               IdentGroup@217..228
                 Ident@217..228 "location_id"
-              Whitespace@228..235 "\n      "
+          Whitespace@228..235 "\n      "
           LogicOp@235..238 "AND"
           Whitespace@238..239 " "
           Expression@239..268
@@ -457,9 +525,9 @@ Root@0..57
         Whitespace@15..16 " "
         Keyword@16..18 "IN"
         Whitespace@18..19 " "
-        Datatype@19..23
+        Datatype@19..22
           Keyword@19..22 "int"
-          Whitespace@22..23 " "
+        Whitespace@22..23 " "
         Keyword@23..30 "DEFAULT"
         Whitespace@30..31 " "
         Expression@31..36
@@ -504,9 +572,9 @@ Root@0..52
         Whitespace@15..16 " "
         Keyword@16..18 "IN"
         Whitespace@18..19 " "
-        Datatype@19..23
+        Datatype@19..22
           Keyword@19..22 "int"
-          Whitespace@22..23 " "
+        Whitespace@22..23 " "
         Assign@23..25 ":="
         Whitespace@25..26 " "
         Expression@26..31
@@ -523,6 +591,198 @@ Root@0..52
       Percentage@43..44 "%"
       Keyword@44..51 "ROWTYPE"
     Semicolon@51..52 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_fetch_bulk_collect_limit() {
+        check(
+            parse(
+                "FETCH c_emp BULK COLLECT INTO l_ids, l_names LIMIT 100;",
+                parse_fetch,
+            ),
+            expect![[r#"
+Root@0..55
+  FetchStmt@0..55
+    Keyword@0..5 "FETCH"
+    Whitespace@5..6 " "
+    IdentGroup@6..11
+      Ident@6..11 "c_emp"
+    Whitespace@11..12 " "
+    BulkIntoClause@12..44
+      Keyword@12..16 "BULK"
+      Whitespace@16..17 " "
+      Keyword@17..24 "COLLECT"
+      Whitespace@24..25 " "
+      Keyword@25..29 "INTO"
+      Whitespace@29..30 " "
+      IdentGroup@30..35
+        Ident@30..35 "l_ids"
+      Comma@35..36 ","
+      Whitespace@36..37 " "
+      IdentGroup@37..44
+        Ident@37..44 "l_names"
+    Whitespace@44..45 " "
+    Keyword@45..50 "LIMIT"
+    Whitespace@50..51 " "
+    Expression@51..54
+      Integer@51..54 "100"
+    Semicolon@54..55 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_fetch_into() {
+        check(
+            parse("FETCH c_emp INTO l_id, l_name;", parse_fetch),
+            expect![[r#"
+Root@0..30
+  FetchStmt@0..30
+    Keyword@0..5 "FETCH"
+    Whitespace@5..6 " "
+    IdentGroup@6..11
+      Ident@6..11 "c_emp"
+    Whitespace@11..12 " "
+    IntoClause@12..29
+      Keyword@12..16 "INTO"
+      Whitespace@16..17 " "
+      IdentGroup@17..21
+        Ident@17..21 "l_id"
+      Comma@21..22 ","
+      Whitespace@22..23 " "
+      IdentGroup@23..29
+        Ident@23..29 "l_name"
+    Semicolon@29..30 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_open_and_close_cursor() {
+        check(
+            parse(
+                "BEGIN OPEN c_emp(10); OPEN :rc FOR SELECT 1 FROM dual; IF c_emp%ISOPEN THEN CLOSE c_emp; END IF; END;",
+                parse_block,
+            ),
+            expect![[r#"
+Root@0..101
+  Block@0..101
+    Keyword@0..5 "BEGIN"
+    Whitespace@5..6 " "
+    BlockStatement@6..21
+      OpenStmt@6..21
+        Keyword@6..10 "OPEN"
+        Whitespace@10..11 " "
+        IdentGroup@11..16
+          Ident@11..16 "c_emp"
+        LParen@16..17 "("
+        ArgumentList@17..19
+          Argument@17..19
+            Integer@17..19 "10"
+        RParen@19..20 ")"
+        Semicolon@20..21 ";"
+    Whitespace@21..22 " "
+    BlockStatement@22..54
+      OpenStmt@22..54
+        Keyword@22..26 "OPEN"
+        Whitespace@26..27 " "
+        BindVar@27..30 ":rc"
+        Whitespace@30..31 " "
+        Keyword@31..34 "FOR"
+        Whitespace@34..35 " "
+        SelectStmt@35..54
+          Keyword@35..41 "SELECT"
+          Whitespace@41..42 " "
+          SelectClause@42..43
+            ColumnExpr@42..43
+              Integer@42..43 "1"
+          Whitespace@43..44 " "
+          Keyword@44..48 "FROM"
+          Whitespace@48..49 " "
+          IdentGroup@49..53
+            Ident@49..53 "dual"
+          Semicolon@53..54 ";"
+    Whitespace@54..55 " "
+    BlockStatement@55..96
+      Keyword@55..57 "IF"
+      Whitespace@57..58 " "
+      CursorAttribute@58..70
+        IdentGroup@58..63
+          Ident@58..63 "c_emp"
+        Percentage@63..64 "%"
+        IdentGroup@64..70
+          Ident@64..70 "ISOPEN"
+      Whitespace@70..71 " "
+      Keyword@71..75 "THEN"
+      Whitespace@75..76 " "
+      BlockStatement@76..88
+        CloseStmt@76..88
+          Keyword@76..81 "CLOSE"
+          Whitespace@81..82 " "
+          IdentGroup@82..87
+            Ident@82..87 "c_emp"
+          Semicolon@87..88 ";"
+      Whitespace@88..89 " "
+      Keyword@89..92 "END"
+      Whitespace@92..93 " "
+      Keyword@93..95 "IF"
+      Semicolon@95..96 ";"
+    Whitespace@96..97 " "
+    Keyword@97..100 "END"
+    Semicolon@100..101 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_cursor_attributes() {
+        check(
+            parse(
+                "SQL%BULK_EXCEPTIONS(i).ERROR_INDEX + SQL%BULK_ROWCOUNT(i)",
+                parse_expr,
+            ),
+            expect![[r#"
+Root@0..57
+  Expression@0..57
+    CursorAttribute@0..34
+      IdentGroup@0..3
+        Ident@0..3 "SQL"
+      Percentage@3..4 "%"
+      FieldAccess@4..34
+        FunctionInvocation@4..22
+          IdentGroup@4..19
+            Ident@4..19 "BULK_EXCEPTIONS"
+          LParen@19..20 "("
+          ArgumentList@20..21
+            Argument@20..21
+              IdentGroup@20..21
+                Ident@20..21 "i"
+          RParen@21..22 ")"
+        Dot@22..23 "."
+        IdentGroup@23..34
+          Ident@23..34 "ERROR_INDEX"
+    Whitespace@34..35 " "
+    ArithmeticOp@35..36 "+"
+    Whitespace@36..37 " "
+    CursorAttribute@37..57
+      IdentGroup@37..40
+        Ident@37..40 "SQL"
+      Percentage@40..41 "%"
+      FunctionInvocation@41..57
+        IdentGroup@41..54
+          Ident@41..54 "BULK_ROWCOUNT"
+        LParen@54..55 "("
+        ArgumentList@55..56
+          Argument@55..56
+            IdentGroup@55..56
+              Ident@55..56 "i"
+        RParen@56..57 ")"
 "#]],
             vec![],
         );
@@ -9,7 +9,7 @@ use rowan::Checkpoint;
 
 use crate::grammar::{
     opt_function_invocation, parse_cursor, parse_datatype, parse_expr, parse_function, parse_ident,
-    parse_procedure,
+    parse_pragma, parse_procedure,
 };
 use crate::parser::{safe_loop, Parser};
 use crate::ParseErrorType;
@@ -32,13 +32,14 @@ pub(super) fn parse_declare_section(p: &mut Parser, checkpoint: Option<Checkpoin
             T![procedure] => parse_procedure(p, true),
             T![type] => parse_type_definition(p),
             T![subtype] => parse_subtype_definition(p),
+            T![pragma] => parse_pragma(p),
             _ => parse_item_declaration(p),
         }
 
         match p.current() {
             // while the docs don't specify it anywhere, `BEGIN` and `END` may not be used as an identifier here
             T![begin] | T![end] => break,
-            T![cursor] | T![function] | T![procedure] | T![type] | T![subtype] => {}
+            T![cursor] | T![function] | T![procedure] | T![type] | T![subtype] | T![pragma] => {}
             token if token.is_ident() => {}
             _ => break,
         }
@@ -49,13 +50,29 @@ pub(super) fn parse_declare_section(p: &mut Parser, checkpoint: Option<Checkpoin
 
 fn parse_type_definition(p: &mut Parser) {
     p.expect(T!(type));
+    let name = p.current_text().to_owned();
     parse_ident(p, 1..1);
     p.expect(T![is]);
 
+    if matches!(
+        p.current(),
+        T![table] | T![varray] | T![varying] | T![array]
+    ) {
+        p.add_collection(&name);
+    }
+
     match p.current() {
         // collection type
-        T![table] => parse_assoc_array_type_def(p),
-        T![varray] | T![varying] | T![array] => parse_varray_type_def(p),
+        T![table] => {
+            p.start(SyntaxKind::CollectionTypeDef);
+            parse_assoc_array_type_def(p);
+            p.finish();
+        }
+        T![varray] | T![varying] | T![array] => {
+            p.start(SyntaxKind::CollectionTypeDef);
+            parse_varray_type_def(p);
+            p.finish();
+        }
         // record type
         T![record] => parse_record_type_definition(p),
         // ref cursor
@@ -78,7 +95,7 @@ fn parse_type_definition(p: &mut Parser) {
 fn parse_assoc_array_type_def(p: &mut Parser) {
     p.expect(T![table]);
     p.expect(T![of]);
-    parse_ident(p, 1..1);
+    parse_datatype(p);
 
     if p.eat(T![not]) {
         p.expect(T![null]);
@@ -92,7 +109,7 @@ fn parse_assoc_array_type_def(p: &mut Parser) {
                 p.bump_any();
                 p.expect(T!["("]);
                 p.expect(T![int_literal]);
-                p.expect(T!["("]);
+                p.expect(T![")"]);
             }
             _ => parse_rowtype(p),
         }
@@ -206,6 +223,7 @@ fn parse_subtype_definition(p: &mut Parser) {
 }
 
 fn parse_item_declaration(p: &mut Parser) {
+    let name = p.current_text().to_owned();
     parse_ident(p, 1..1);
 
     match p.current() {
@@ -223,6 +241,9 @@ fn parse_item_declaration(p: &mut Parser) {
         }
         T![exception] => p.bump_any(),
         _ => {
+            if p.is_collection(p.nth_text(0).unwrap_or_default()) {
+                p.add_collection(&name);
+            }
             parse_datatype(p);
 
             if p.eat(T![:=]) && !opt_function_invocation(p) {
@@ -270,9 +291,9 @@ Root@0..97
         IdentGroup@27..30
           Ident@27..30 "age"
         Whitespace@30..31 " "
-        Datatype@31..38
+        Datatype@31..37
           Keyword@31..37 "number"
-          Whitespace@37..38 " "
+        Whitespace@37..38 " "
         Keyword@38..45 "DEFAULT"
         Whitespace@45..46 " "
         Integer@46..48 "18"
@@ -283,11 +304,11 @@ Root@0..97
     SelectStmt@65..97
       Keyword@65..71 "SELECT"
       Whitespace@71..72 " "
-      SelectClause@72..83
-        ColumnExpr@72..83
+      SelectClause@72..82
+        ColumnExpr@72..82
           IdentGroup@72..82
             Ident@72..82 "first_name"
-          Whitespace@82..83 " "
+      Whitespace@82..83 " "
       Keyword@83..87 "FROM"
       Whitespace@87..88 " "
       IdentGroup@88..96
@@ -313,24 +334,113 @@ Root@0..61
     Whitespace@16..17 " "
     Keyword@17..19 "IS"
     Whitespace@19..20 " "
-    Keyword@20..25 "TABLE"
-    Whitespace@25..26 " "
-    Keyword@26..28 "OF"
-    Whitespace@28..29 " "
-    IdentGroup@29..39
-      Ident@29..39 "table_name"
-    Whitespace@39..40 " "
-    Keyword@40..45 "INDEX"
-    Whitespace@45..46 " "
-    Keyword@46..48 "BY"
-    Whitespace@48..49 " "
-    Keyword@49..60 "PLS_INTEGER"
+    CollectionTypeDef@20..60
+      Keyword@20..25 "TABLE"
+      Whitespace@25..26 " "
+      Keyword@26..28 "OF"
+      Whitespace@28..29 " "
+      Datatype@29..40
+        IdentGroup@29..39
+          Ident@29..39 "table_name"
+        Whitespace@39..40 " "
+      Keyword@40..45 "INDEX"
+      Whitespace@45..46 " "
+      Keyword@46..48 "BY"
+      Whitespace@48..49 " "
+      Keyword@49..60 "PLS_INTEGER"
     Semicolon@60..61 ";"
 "#]],
             vec![],
         );
     }
 
+    #[test]
+    fn test_collection_type_definitions() {
+        const INPUT: &str = "TYPE names IS TABLE OF VARCHAR2(30) INDEX BY VARCHAR2(10);
+TYPE name_list IS TABLE OF VARCHAR2(30) NOT NULL;
+TYPE name_array IS VARRAY(10) OF VARCHAR2(30);";
+        check(
+            parse(INPUT, |p| parse_declare_section(p, None)),
+            expect![[r#"
+Root@0..155
+  DeclareSection@0..155
+    Keyword@0..4 "TYPE"
+    Whitespace@4..5 " "
+    IdentGroup@5..10
+      Ident@5..10 "names"
+    Whitespace@10..11 " "
+    Keyword@11..13 "IS"
+    Whitespace@13..14 " "
+    CollectionTypeDef@14..57
+      Keyword@14..19 "TABLE"
+      Whitespace@19..20 " "
+      Keyword@20..22 "OF"
+      Whitespace@22..23 " "
+      Datatype@23..35
+        Keyword@23..31 "VARCHAR2"
+        LParen@31..32 "("
+        Integer@32..34 "30"
+        RParen@34..35 ")"
+      Whitespace@35..36 " "
+      Keyword@36..41 "INDEX"
+      Whitespace@41..42 " "
+      Keyword@42..44 "BY"
+      Whitespace@44..45 " "
+      Keyword@45..53 "VARCHAR2"
+      LParen@53..54 "("
+      Integer@54..56 "10"
+      RParen@56..57 ")"
+    Semicolon@57..58 ";"
+    Whitespace@58..59 "\n"
+    Keyword@59..63 "TYPE"
+    Whitespace@63..64 " "
+    IdentGroup@64..73
+      Ident@64..73 "name_list"
+    Whitespace@73..74 " "
+    Keyword@74..76 "IS"
+    Whitespace@76..77 " "
+    CollectionTypeDef@77..107
+      Keyword@77..82 "TABLE"
+      Whitespace@82..83 " "
+      Keyword@83..85 "OF"
+      Whitespace@85..86 " "
+      Datatype@86..98
+        Keyword@86..94 "VARCHAR2"
+        LParen@94..95 "("
+        Integer@95..97 "30"
+        RParen@97..98 ")"
+      Whitespace@98..99 " "
+      Keyword@99..102 "NOT"
+      Whitespace@102..103 " "
+      Keyword@103..107 "NULL"
+    Semicolon@107..108 ";"
+    Whitespace@108..109 "\n"
+    Keyword@109..113 "TYPE"
+    Whitespace@113..114 " "
+    IdentGroup@114..124
+      Ident@114..124 "name_array"
+    Whitespace@124..125 " "
+    Keyword@125..127 "IS"
+    Whitespace@127..128 " "
+    CollectionTypeDef@128..154
+      Keyword@128..134 "VARRAY"
+      LParen@134..135 "("
+      Integer@135..137 "10"
+      RParen@137..138 ")"
+      Whitespace@138..139 " "
+      Keyword@139..141 "OF"
+      Whitespace@141..142 " "
+      Datatype@142..154
+        Keyword@142..150 "VARCHAR2"
+        LParen@150..151 "("
+        Integer@151..153 "30"
+        RParen@153..154 ")"
+    Semicolon@154..155 ";"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn test_item_declarations() {
         const INPUT: &str = r#"
@@ -360,9 +470,9 @@ Root@0..156
     IdentGroup@42..45
       Ident@42..45 "p_2"
     Whitespace@45..46 " "
-    Datatype@46..53
+    Datatype@46..52
       Keyword@46..52 "NUMBER"
-      Whitespace@52..53 " "
+    Whitespace@52..53 " "
     Assign@53..55 ":="
     Whitespace@55..56 " "
     Expression@56..58
@@ -402,6 +512,112 @@ Root@0..156
         );
     }
 
+    #[test]
+    fn test_package_qualified_item_declarations() {
+        const INPUT: &str = r#"
+            p_1 my_pkg.rec_type;
+            p_2 my_schema.my_pkg.g_var%TYPE;"#;
+        check(
+            parse(INPUT, |p| parse_declare_section(p, None)),
+            expect![[r#"
+Root@0..78
+  Whitespace@0..13 "\n            "
+  DeclareSection@13..78
+    IdentGroup@13..16
+      Ident@13..16 "p_1"
+    Whitespace@16..17 " "
+    Datatype@17..32
+      IdentGroup@17..32
+        Ident@17..23 "my_pkg"
+        Dot@23..24 "."
+        Ident@24..32 "rec_type"
+    Semicolon@32..33 ";"
+    Whitespace@33..46 "\n            "
+    IdentGroup@46..49
+      Ident@46..49 "p_2"
+    Whitespace@49..50 " "
+    Datatype@50..77
+      IdentGroup@50..72
+        Ident@50..59 "my_schema"
+        Dot@59..60 "."
+        Ident@60..66 "my_pkg"
+        Dot@66..67 "."
+        Ident@67..72 "g_var"
+      TypeAttribute@72..77
+        Percentage@72..73 "%"
+        Keyword@73..77 "TYPE"
+    Semicolon@77..78 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_forward_declaration() {
+        const INPUT: &str = r#"
+PROCEDURE log_it;
+FUNCTION twice (i NUMBER) RETURN NUMBER;
+PROCEDURE log_it IS BEGIN NULL; END;"#;
+        check(
+            parse(INPUT, |p| parse_declare_section(p, None)),
+            expect![[r#"
+Root@0..96
+  Whitespace@0..1 "\n"
+  DeclareSection@1..96
+    Procedure@1..18
+      ProcedureHeader@1..17
+        Keyword@1..10 "PROCEDURE"
+        Whitespace@10..11 " "
+        IdentGroup@11..17
+          Ident@11..17 "log_it"
+      Semicolon@17..18 ";"
+    Whitespace@18..19 "\n"
+    Function@19..59
+      FunctionHeader@19..58
+        Keyword@19..27 "FUNCTION"
+        Whitespace@27..28 " "
+        IdentGroup@28..33
+          Ident@28..33 "twice"
+        Whitespace@33..34 " "
+        ParamList@34..44
+          LParen@34..35 "("
+          Param@35..43
+            IdentGroup@35..36
+              Ident@35..36 "i"
+            Whitespace@36..37 " "
+            Datatype@37..43
+              Keyword@37..43 "NUMBER"
+          RParen@43..44 ")"
+        Whitespace@44..45 " "
+        Keyword@45..51 "RETURN"
+        Whitespace@51..52 " "
+        Datatype@52..58
+          Keyword@52..58 "NUMBER"
+      Semicolon@58..59 ";"
+    Whitespace@59..60 "\n"
+    Procedure@60..96
+      ProcedureHeader@60..76
+        Keyword@60..69 "PROCEDURE"
+        Whitespace@69..70 " "
+        IdentGroup@70..76
+          Ident@70..76 "log_it"
+      Whitespace@76..77 " "
+      Keyword@77..79 "IS"
+      Whitespace@79..80 " "
+      Block@80..96
+        Keyword@80..85 "BEGIN"
+        Whitespace@85..86 " "
+        BlockStatement@86..91
+          Keyword@86..90 "NULL"
+          Semicolon@90..91 ";"
+        Whitespace@91..92 " "
+        Keyword@92..95 "END"
+        Semicolon@95..96 ";"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn test_nested_procedure() {
         const INPUT: &str = r#"
@@ -416,7 +632,7 @@ Root@0..62
   Whitespace@0..1 "\n"
   DeclareSection@1..62
     Procedure@1..62
-      ProcedureHeader@1..39
+      ProcedureHeader@1..38
         Keyword@1..10 "PROCEDURE"
         Whitespace@10..11 " "
         IdentGroup@11..20
@@ -435,7 +651,7 @@ Root@0..62
             Datatype@31..37
               Keyword@31..37 "NUMBER"
           RParen@37..38 ")"
-        Whitespace@38..39 " "
+      Whitespace@38..39 " "
       Keyword@39..41 "IS"
       Whitespace@41..42 "\n"
       Block@42..62
@@ -1,4 +1,7 @@
-use super::{parse_expr, parse_ident, parse_where_clause};
+use super::{
+    parse_bulk_into_clause, parse_expr, parse_ident, parse_insert, parse_into_clause,
+    parse_where_clause,
+};
 use crate::parser::Parser;
 use crate::safe_loop;
 use source_gen::lexer::TokenKind;
@@ -6,49 +9,118 @@ use source_gen::syntax::SyntaxKind;
 use source_gen::T;
 
 pub(crate) fn parse_dml(p: &mut Parser) {
-    if p.at(T![delete]) {
-        parse_delete(p);
-    } else {
-        parse_update(p);
+    match p.current() {
+        T![delete] => parse_delete(p),
+        T![insert] => parse_insert(p),
+        _ => parse_update(p),
     }
 }
 
 pub(crate) fn parse_delete(p: &mut Parser) {
     p.start(SyntaxKind::DeleteStmt);
+    p.enter_sql();
     p.expect(T![delete]);
     p.expect(T![from]);
     parse_ident(p, 1..2);
-    parse_where_clause(p);
+    parse_ident(p, 0..1);
+    opt_dml_where_clause(p);
+    opt_returning_clause(p);
     p.eat(T![;]);
+    p.leave_sql();
     p.finish();
 }
 
 pub(crate) fn parse_update(p: &mut Parser) {
     p.start(SyntaxKind::UpdateStmt);
+    p.enter_sql();
     p.expect(T![update]);
     parse_ident(p, 1..2);
+    parse_ident(p, 0..1);
     parse_set_clause(p);
-    parse_where_clause(p);
+    opt_dml_where_clause(p);
+    opt_returning_clause(p);
     p.eat(T![;]);
+    p.leave_sql();
     p.finish();
 }
 
 pub(crate) fn parse_set_clause(p: &mut Parser) {
     p.start(SyntaxKind::SetClause);
     p.expect(T![set]);
+    if p.at(T![row]) && p.nth(1) == Some(T![=]) {
+        // PL/SQL allows updating a whole row from a record
+        p.start(SyntaxKind::RecordRow);
+        p.bump(T![row]);
+        p.bump(T![=]);
+        parse_expr(p);
+        p.finish();
+    } else {
+        safe_loop!(p, {
+            parse_assignment(p);
+            if !p.eat(T![,]) {
+                break;
+            }
+        });
+    }
+    p.finish()
+}
+
+/// Parses the optional WHERE clause of a DML statement, including the
+/// `WHERE CURRENT OF <cursor>` form.
+fn opt_dml_where_clause(p: &mut Parser) {
+    if !p.at(T![where]) {
+        return;
+    }
+
+    if p.nth(1) == Some(T![current]) && p.nth(2) == Some(T![of]) {
+        p.start(SyntaxKind::WhereClause);
+        p.bump(T![where]);
+        p.bump(T![current]);
+        p.bump(T![of]);
+        parse_ident(p, 1..1);
+        p.finish();
+    } else {
+        parse_where_clause(p);
+    }
+}
+
+/// Parses the optional `RETURNING .. INTO` clause of an INSERT, UPDATE or
+/// DELETE statement, with `BULK COLLECT INTO` collections for multiple rows.
+pub(crate) fn opt_returning_clause(p: &mut Parser) {
+    if !p.at(T![return]) && !p.at(T![returning]) {
+        return;
+    }
+
+    p.start(SyntaxKind::ReturningClause);
+    p.bump_any();
     safe_loop!(p, {
-        parse_assignment(p);
-        if p.at(T![where]) {
+        parse_expr(p);
+        if !p.eat(T![,]) {
             break;
         }
-        p.eat(T![,]);
     });
-    p.finish()
+    if p.at(T![bulk]) {
+        parse_bulk_into_clause(p);
+    } else {
+        parse_into_clause(p, true);
+    }
+    p.finish();
 }
 
 fn parse_assignment(p: &mut Parser) {
     p.start(SyntaxKind::AssignmentExpr);
-    parse_ident(p, 1..1);
+    if p.eat(T!["("]) {
+        // Multiple columns set from a single subquery
+        safe_loop!(p, {
+            parse_ident(p, 1..2);
+            if !p.eat(T![,]) {
+                break;
+            }
+        });
+        p.expect(T![")"]);
+    } else {
+        parse_ident(p, 1..2);
+    }
     p.expect(T![=]);
     parse_expr(p);
     p.finish()
@@ -90,6 +162,183 @@ Root@0..34
         );
     }
 
+    #[test]
+    fn test_parse_delete_returning_bulk_collect() {
+        check(
+            parse(
+                "DELETE FROM emp WHERE dept = 1 RETURNING id BULK COLLECT INTO ids;",
+                parse_dml,
+            ),
+            expect![[r#"
+Root@0..66
+  DeleteStmt@0..66
+    Keyword@0..6 "DELETE"
+    Whitespace@6..7 " "
+    Keyword@7..11 "FROM"
+    Whitespace@11..12 " "
+    IdentGroup@12..15
+      Ident@12..15 "emp"
+    Whitespace@15..16 " "
+    WhereClause@16..30
+      Keyword@16..21 "WHERE"
+      Whitespace@21..22 " "
+      Expression@22..30
+        IdentGroup@22..26
+          Ident@22..26 "dept"
+        Whitespace@26..27 " "
+        ComparisonOp@27..28 "="
+        Whitespace@28..29 " "
+        Integer@29..30 "1"
+    Whitespace@30..31 " "
+    ReturningClause@31..65
+      Keyword@31..40 "RETURNING"
+      Whitespace@40..41 " "
+      IdentGroup@41..43
+        Ident@41..43 "id"
+      Whitespace@43..44 " "
+      BulkIntoClause@44..65
+        Keyword@44..48 "BULK"
+        Whitespace@48..49 " "
+        Keyword@49..56 "COLLECT"
+        Whitespace@56..57 " "
+        Keyword@57..61 "INTO"
+        Whitespace@61..62 " "
+        IdentGroup@62..65
+          Ident@62..65 "ids"
+    Semicolon@65..66 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_update_returning_into() {
+        check(
+            parse(
+                "UPDATE emp SET sal = sal * 2 RETURN sal, id INTO l_sal, :id;",
+                parse_dml,
+            ),
+            expect![[r#"
+Root@0..60
+  UpdateStmt@0..60
+    Keyword@0..6 "UPDATE"
+    Whitespace@6..7 " "
+    IdentGroup@7..10
+      Ident@7..10 "emp"
+    Whitespace@10..11 " "
+    SetClause@11..28
+      Keyword@11..14 "SET"
+      Whitespace@14..15 " "
+      AssignmentExpr@15..28
+        IdentGroup@15..18
+          Ident@15..18 "sal"
+        Whitespace@18..19 " "
+        ComparisonOp@19..20 "="
+        Whitespace@20..21 " "
+        Expression@21..28
+          IdentGroup@21..24
+            Ident@21..24 "sal"
+          Whitespace@24..25 " "
+          ArithmeticOp@25..26 "*"
+          Whitespace@26..27 " "
+          Integer@27..28 "2"
+    Whitespace@28..29 " "
+    ReturningClause@29..59
+      Keyword@29..35 "RETURN"
+      Whitespace@35..36 " "
+      Expression@36..39
+        IdentGroup@36..39
+          Ident@36..39 "sal"
+      Comma@39..40 ","
+      Whitespace@40..41 " "
+      IdentGroup@41..43
+        Ident@41..43 "id"
+      Whitespace@43..44 " "
+      IntoClause@44..59
+        Keyword@44..48 "INTO"
+        Whitespace@48..49 " "
+        IdentGroup@49..54
+          Ident@49..54 "l_sal"
+        Comma@54..55 ","
+        Whitespace@55..56 " "
+        IdentGroup@56..59
+          BindVar@56..59 ":id"
+    Semicolon@59..60 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_delete_without_where() {
+        check(
+            parse("DELETE FROM emp;", parse_dml),
+            expect![[r#"
+Root@0..16
+  DeleteStmt@0..16
+    Keyword@0..6 "DELETE"
+    Whitespace@6..7 " "
+    Keyword@7..11 "FROM"
+    Whitespace@11..12 " "
+    IdentGroup@12..15
+      Ident@12..15 "emp"
+    Semicolon@15..16 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_update_where_current_of() {
+        check(
+            parse(
+                "UPDATE emp SET salary = 1, bonus = 2 WHERE CURRENT OF emp_cur;",
+                parse_dml,
+            ),
+            expect![[r#"
+Root@0..62
+  UpdateStmt@0..62
+    Keyword@0..6 "UPDATE"
+    Whitespace@6..7 " "
+    IdentGroup@7..10
+      Ident@7..10 "emp"
+    Whitespace@10..11 " "
+    SetClause@11..36
+      Keyword@11..14 "SET"
+      Whitespace@14..15 " "
+      AssignmentExpr@15..25
+        IdentGroup@15..21
+          Ident@15..21 "salary"
+        Whitespace@21..22 " "
+        ComparisonOp@22..23 "="
+        Whitespace@23..24 " "
+        Expression@24..25
+          Integer@24..25 "1"
+      Comma@25..26 ","
+      Whitespace@26..27 " "
+      AssignmentExpr@27..36
+        IdentGroup@27..32
+          Ident@27..32 "bonus"
+        Whitespace@32..33 " "
+        ComparisonOp@33..34 "="
+        Whitespace@34..35 " "
+        Integer@35..36 "2"
+    Whitespace@36..37 " "
+    WhereClause@37..61
+      Keyword@37..42 "WHERE"
+      Whitespace@42..43 " "
+      Keyword@43..50 "CURRENT"
+      Whitespace@50..51 " "
+      Keyword@51..53 "OF"
+      Whitespace@53..54 " "
+      IdentGroup@54..61
+        Ident@54..61 "emp_cur"
+    Semicolon@61..62 ";"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn test_parse_simple_update() {
         check(
@@ -105,21 +354,21 @@ Root@0..60
     IdentGroup@7..10
       Ident@7..10 "emp"
     Whitespace@10..11 " "
-    SetClause@11..33
+    SetClause@11..32
       Keyword@11..14 "SET"
       Whitespace@14..15 " "
-      AssignmentExpr@15..33
+      AssignmentExpr@15..32
         IdentGroup@15..21
           Ident@15..21 "salary"
         Whitespace@21..22 " "
         ComparisonOp@22..23 "="
         Whitespace@23..24 " "
-        Expression@24..33
+        Expression@24..32
           IdentGroup@24..30
             Ident@24..30 "salary"
           ArithmeticOp@30..31 "*"
           Integer@31..32 "2"
-          Whitespace@32..33 " "
+    Whitespace@32..33 " "
     WhereClause@33..59
       Keyword@33..38 "WHERE"
       Whitespace@38..39 " "
@@ -130,6 +379,192 @@ Root@0..60
         IdentGroup@53..59
           Ident@53..59 "Jeremy"
     Semicolon@59..60 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_update_with_keyword_columns() {
+        check(
+            parse(
+                "UPDATE emp e SET e.end = 1, type = 2 WHERE e.level > 0;",
+                parse_update,
+            ),
+            expect![[r#"
+Root@0..55
+  UpdateStmt@0..55
+    Keyword@0..6 "UPDATE"
+    Whitespace@6..7 " "
+    IdentGroup@7..10
+      Ident@7..10 "emp"
+    Whitespace@10..11 " "
+    IdentGroup@11..12
+      Ident@11..12 "e"
+    Whitespace@12..13 " "
+    SetClause@13..36
+      Keyword@13..16 "SET"
+      Whitespace@16..17 " "
+      AssignmentExpr@17..26
+        IdentGroup@17..22
+          Ident@17..18 "e"
+          Dot@18..19 "."
+          Ident@19..22 "end"
+        Whitespace@22..23 " "
+        ComparisonOp@23..24 "="
+        Whitespace@24..25 " "
+        Expression@25..26
+          Integer@25..26 "1"
+      Comma@26..27 ","
+      Whitespace@27..28 " "
+      AssignmentExpr@28..36
+        IdentGroup@28..32
+          Ident@28..32 "type"
+        Whitespace@32..33 " "
+        ComparisonOp@33..34 "="
+        Whitespace@34..35 " "
+        Integer@35..36 "2"
+    Whitespace@36..37 " "
+    WhereClause@37..54
+      Keyword@37..42 "WHERE"
+      Whitespace@42..43 " "
+      Expression@43..54
+        IdentGroup@43..50
+          Ident@43..44 "e"
+          Dot@44..45 "."
+          Ident@45..50 "level"
+        Whitespace@50..51 " "
+        ComparisonOp@51..52 ">"
+        Whitespace@52..53 " "
+        Integer@53..54 "0"
+    Semicolon@54..55 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_update_set_row() {
+        check(
+            parse(
+                "UPDATE emp SET ROW = l_emp WHERE id = l_emp.id;",
+                parse_update,
+            ),
+            expect![[r#"
+Root@0..47
+  UpdateStmt@0..47
+    Keyword@0..6 "UPDATE"
+    Whitespace@6..7 " "
+    IdentGroup@7..10
+      Ident@7..10 "emp"
+    Whitespace@10..11 " "
+    SetClause@11..26
+      Keyword@11..14 "SET"
+      Whitespace@14..15 " "
+      RecordRow@15..26
+        Keyword@15..18 "ROW"
+        Whitespace@18..19 " "
+        ComparisonOp@19..20 "="
+        Whitespace@20..21 " "
+        IdentGroup@21..26
+          Ident@21..26 "l_emp"
+    Whitespace@26..27 " "
+    WhereClause@27..46
+      Keyword@27..32 "WHERE"
+      Whitespace@32..33 " "
+      Expression@33..46
+        IdentGroup@33..35
+          Ident@33..35 "id"
+        Whitespace@35..36 " "
+        ComparisonOp@36..37 "="
+        Whitespace@37..38 " "
+        IdentGroup@38..46
+          Ident@38..43 "l_emp"
+          Dot@43..44 "."
+          Ident@44..46 "id"
+    Semicolon@46..47 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_update_set_multiple_columns() {
+        check(
+            parse(
+                "UPDATE emp e SET (sal, grade) = (SELECT s.sal, s.grade FROM salaries s WHERE s.id = e.id);",
+                parse_update,
+            ),
+            expect![[r#"
+Root@0..90
+  UpdateStmt@0..90
+    Keyword@0..6 "UPDATE"
+    Whitespace@6..7 " "
+    IdentGroup@7..10
+      Ident@7..10 "emp"
+    Whitespace@10..11 " "
+    IdentGroup@11..12
+      Ident@11..12 "e"
+    Whitespace@12..13 " "
+    SetClause@13..89
+      Keyword@13..16 "SET"
+      Whitespace@16..17 " "
+      AssignmentExpr@17..89
+        LParen@17..18 "("
+        IdentGroup@18..21
+          Ident@18..21 "sal"
+        Comma@21..22 ","
+        Whitespace@22..23 " "
+        IdentGroup@23..28
+          Ident@23..28 "grade"
+        RParen@28..29 ")"
+        Whitespace@29..30 " "
+        ComparisonOp@30..31 "="
+        Whitespace@31..32 " "
+        LParen@32..33 "("
+        SelectStmt@33..88
+          Keyword@33..39 "SELECT"
+          Whitespace@39..40 " "
+          SelectClause@40..54
+            ColumnExpr@40..45
+              Expression@40..45
+                IdentGroup@40..45
+                  Ident@40..41 "s"
+                  Dot@41..42 "."
+                  Ident@42..45 "sal"
+            Comma@45..46 ","
+            Whitespace@46..47 " "
+            ColumnExpr@47..54
+              IdentGroup@47..54
+                Ident@47..48 "s"
+                Dot@48..49 "."
+                Ident@49..54 "grade"
+          Whitespace@54..55 " "
+          Keyword@55..59 "FROM"
+          Whitespace@59..60 " "
+          IdentGroup@60..68
+            Ident@60..68 "salaries"
+          Whitespace@68..69 " "
+          IdentGroup@69..70
+            Ident@69..70 "s"
+          Whitespace@70..71 " "
+          WhereClause@71..88
+            Keyword@71..76 "WHERE"
+            Whitespace@76..77 " "
+            Expression@77..88
+              IdentGroup@77..81
+                Ident@77..78 "s"
+                Dot@78..79 "."
+                Ident@79..81 "id"
+              Whitespace@81..82 " "
+              ComparisonOp@82..83 "="
+              Whitespace@83..84 " "
+              IdentGroup@84..88
+                Ident@84..85 "e"
+                Dot@85..86 "."
+                Ident@86..88 "id"
+        RParen@88..89 ")"
+    Semicolon@89..90 ";"
 "#]],
             vec![],
         );
@@ -57,7 +57,7 @@ fn parse_return_into_clause(p: &mut Parser) {
     p.finish();
 }
 
-fn parse_bulk_into_clause(p: &mut Parser) {
+pub(crate) fn parse_bulk_into_clause(p: &mut Parser) {
     p.start(SyntaxKind::BulkIntoClause);
     p.expect(T![bulk]);
     p.expect(T![collect]);
@@ -66,10 +66,9 @@ fn parse_bulk_into_clause(p: &mut Parser) {
         if !p.eat(T![bind_var]) {
             parse_ident(p, 1..1);
         }
-        if [T![using], T![;]].contains(&p.current()) {
+        if !p.eat(T![,]) {
             break;
         }
-        p.expect(T![,]);
     });
     p.finish();
 }
@@ -137,7 +136,7 @@ END;"#,
             expect![[r#"
 Root@0..992
   Block@0..992
-    DeclareSection@0..275
+    DeclareSection@0..274
       Keyword@0..7 "DECLARE"
       Whitespace@7..11 "\n   "
       IdentGroup@11..19
@@ -163,12 +162,12 @@ Root@0..992
       IdentGroup@71..77
         Ident@71..77 "emp_id"
       Whitespace@77..83 "      "
-      Datatype@83..93
+      Datatype@83..92
         Keyword@83..89 "NUMBER"
         LParen@89..90 "("
         Integer@90..91 "4"
         RParen@91..92 ")"
-        Whitespace@92..93 " "
+      Whitespace@92..93 " "
       Assign@93..95 ":="
       Whitespace@95..96 " "
       Expression@96..100
@@ -190,12 +189,12 @@ Root@0..992
       IdentGroup@133..140
         Ident@133..140 "dept_id"
       Whitespace@140..145 "     "
-      Datatype@145..155
+      Datatype@145..154
         Keyword@145..151 "NUMBER"
         LParen@151..152 "("
         Integer@152..153 "2"
         RParen@153..154 ")"
-        Whitespace@154..155 " "
+      Whitespace@154..155 " "
       Assign@155..157 ":="
       Whitespace@157..158 " "
       Expression@158..160
@@ -205,12 +204,12 @@ Root@0..992
       IdentGroup@165..174
         Ident@165..174 "dept_name"
       Whitespace@174..177 "   "
-      Datatype@177..190
+      Datatype@177..189
         Keyword@177..185 "VARCHAR2"
         LParen@185..186 "("
         Integer@186..188 "14"
         RParen@188..189 ")"
-        Whitespace@189..190 " "
+      Whitespace@189..190 " "
       Assign@190..192 ":="
       Whitespace@192..193 " "
       Expression@193..204
@@ -220,12 +219,12 @@ Root@0..992
       IdentGroup@209..217
         Ident@209..217 "location"
       Whitespace@217..221 "    "
-      Datatype@221..234
+      Datatype@221..233
         Keyword@221..229 "VARCHAR2"
         LParen@229..230 "("
         Integer@230..232 "13"
         RParen@232..233 ")"
-        Whitespace@233..234 " "
+      Whitespace@233..234 " "
       Assign@234..236 ":="
       Whitespace@236..237 " "
       Expression@237..245
@@ -242,10 +241,10 @@ Root@0..992
           Percentage@265..266 "%"
           Keyword@266..273 "ROWTYPE"
       Semicolon@273..274 ";"
-      Whitespace@274..275 "\n"
+    Whitespace@274..275 "\n"
     Keyword@275..280 "BEGIN"
     Whitespace@280..284 "\n   "
-    BlockStatement@284..351
+    BlockStatement@284..347
       ExecuteImmediateStmt@284..347
         Keyword@284..291 "EXECUTE"
         Whitespace@291..292 " "
@@ -253,7 +252,7 @@ Root@0..992
         Whitespace@301..302 " "
         QuotedLiteral@302..346 "'CREATE TABLE bonus ( ..."
         Semicolon@346..347 ";"
-      Whitespace@347..351 "\n   "
+    Whitespace@347..351 "\n   "
     BlockStatement@351..402
       IdentGroup@351..359
         Ident@351..359 "sql_stmt"
@@ -264,7 +263,7 @@ Root@0..992
         QuotedLiteral@363..401 "'INSERT INTO dept VAL ..."
       Semicolon@401..402 ";"
     Whitespace@402..406 "\n   "
-    BlockStatement@406..472
+    BlockStatement@406..468
       ExecuteImmediateStmt@406..468
         Keyword@406..413 "EXECUTE"
         Whitespace@413..414 " "
@@ -290,7 +289,7 @@ Root@0..992
             IdentGroup@459..467
               Ident@459..467 "location"
         Semicolon@467..468 ";"
-      Whitespace@468..472 "\n   "
+    Whitespace@468..472 "\n   "
     BlockStatement@472..522
       IdentGroup@472..480
         Ident@472..480 "sql_stmt"
@@ -301,7 +300,7 @@ Root@0..992
         QuotedLiteral@484..521 "'SELECT * FROM emp WH ..."
       Semicolon@521..522 ";"
     Whitespace@522..526 "\n   "
-    BlockStatement@526..583
+    BlockStatement@526..579
       ExecuteImmediateStmt@526..579
         Keyword@526..533 "EXECUTE"
         Whitespace@533..534 " "
@@ -310,12 +309,12 @@ Root@0..992
         IdentGroup@544..552
           Ident@544..552 "sql_stmt"
         Whitespace@552..553 " "
-        IntoClause@553..566
+        IntoClause@553..565
           Keyword@553..557 "INTO"
           Whitespace@557..558 " "
           IdentGroup@558..565
             Ident@558..565 "emp_rec"
-          Whitespace@565..566 " "
+        Whitespace@565..566 " "
         UsingClause@566..578
           Keyword@566..571 "USING"
           Whitespace@571..572 " "
@@ -323,7 +322,7 @@ Root@0..992
             IdentGroup@572..578
               Ident@572..578 "emp_id"
         Semicolon@578..579 ";"
-      Whitespace@579..583 "\n   "
+    Whitespace@579..583 "\n   "
     BlockStatement@583..644
       IdentGroup@583..594
         Ident@583..594 "plsql_block"
@@ -334,7 +333,7 @@ Root@0..992
         QuotedLiteral@598..643 "'BEGIN emp_pkg.raise_ ..."
       Semicolon@643..644 ";"
     Whitespace@644..648 "\n   "
-    BlockStatement@648..698
+    BlockStatement@648..694
       ExecuteImmediateStmt@648..694
         Keyword@648..655 "EXECUTE"
         Whitespace@655..656 " "
@@ -353,7 +352,7 @@ Root@0..992
           Expression@690..693
             Integer@690..693 "500"
         Semicolon@693..694 ";"
-      Whitespace@694..698 "\n   "
+    Whitespace@694..698 "\n   "
     BlockStatement@698..783
       IdentGroup@698..706
         Ident@698..706 "sql_stmt"
@@ -364,7 +363,7 @@ Root@0..992
         QuotedLiteral@710..782 "'UPDATE emp SET sal = ..."
       Semicolon@782..783 ";"
     Whitespace@783..787 "\n   "
-    BlockStatement@787..853
+    BlockStatement@787..849
       ExecuteImmediateStmt@787..849
         Keyword@787..794 "EXECUTE"
         Whitespace@794..795 " "
@@ -373,12 +372,12 @@ Root@0..992
         IdentGroup@805..813
           Ident@805..813 "sql_stmt"
         Whitespace@813..814 " "
-        UsingClause@814..827
+        UsingClause@814..826
           Keyword@814..819 "USING"
           Whitespace@819..820 " "
           IdentGroup@820..826
             Ident@820..826 "emp_id"
-          Whitespace@826..827 " "
+        Whitespace@826..827 " "
         ReturnIntoClause@827..848
           Keyword@827..836 "RETURNING"
           Whitespace@836..837 " "
@@ -388,8 +387,8 @@ Root@0..992
             IdentGroup@842..848
               Ident@842..848 "salary"
         Semicolon@848..849 ";"
-      Whitespace@849..853 "\n   "
-    BlockStatement@853..934
+    Whitespace@849..853 "\n   "
+    BlockStatement@853..930
       ExecuteImmediateStmt@853..930
         Keyword@853..860 "EXECUTE"
         Whitespace@860..861 " "
@@ -404,8 +403,8 @@ Root@0..992
             IdentGroup@922..929
               Ident@922..929 "dept_id"
         Semicolon@929..930 ";"
-      Whitespace@930..934 "\n   "
-    BlockStatement@934..988
+    Whitespace@930..934 "\n   "
+    BlockStatement@934..987
       ExecuteImmediateStmt@934..987
         Keyword@934..941 "EXECUTE"
         Whitespace@941..942 " "
@@ -413,7 +412,7 @@ Root@0..992
         Whitespace@951..952 " "
         QuotedLiteral@952..986 "'ALTER SESSION SET SQ ..."
         Semicolon@986..987 ";"
-      Whitespace@987..988 "\n"
+    Whitespace@987..988 "\n"
     Keyword@988..991 "END"
     Semicolon@991..992 ";"
 "#]],
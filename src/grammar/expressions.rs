@@ -10,7 +10,9 @@
 
 use rowan::Checkpoint;
 
-use crate::grammar::{parse_ident, parse_ident_or_function_invocation};
+use crate::grammar::{
+    parse_cursor_attribute, parse_ident, parse_ident_or_function_invocation, parse_query,
+};
 use crate::parser::{safe_loop, Parser};
 use crate::ParseErrorType;
 use source_gen::lexer::TokenKind;
@@ -32,6 +34,15 @@ pub(crate) fn parse_expr(p: &mut Parser) {
     }
 }
 
+/// Parses an expression binding tighter than `AND`, e.g. the bounds of
+/// `BETWEEN <lower> AND <upper>`.
+pub(crate) fn parse_bound_expr(p: &mut Parser) {
+    if let Err(err) = expr_bp(p, 4) {
+        p.error(err);
+        p.bump_any();
+    }
+}
+
 /// # Arguments
 ///
 /// * `p`: Mutable reference to the parser instance
@@ -52,6 +63,11 @@ fn expr_bp(p: &mut Parser, min_bp: u8) -> Result<(), ParseErrorType> {
 
     let token = p.current();
     match token {
+        T![exists] if p.nth(1) == Some(T!["("]) && p.nth(2) == Some(T![select]) => {
+            p.bump_any_map(SyntaxKind::Keyword);
+            parse_subquery(p);
+            add_expr_node(p, checkpoint, None);
+        }
         token
             if (token.is_ident() || token.is_literal())
                 // reserved identifiers
@@ -71,6 +87,16 @@ fn expr_bp(p: &mut Parser, min_bp: u8) -> Result<(), ParseErrorType> {
                 .contains(&token) =>
         {
             match token {
+                // Builtin error information, only valid inside exception handlers
+                T![sqlcode] | T![sqlerrm] if p.nth(1) != Some(T!["("]) => {
+                    p.bump_any();
+                }
+                T![date] | T![interval] | T![timestamp] if p.nth(1) == Some(T![quoted_literal]) => {
+                    parse_typed_literal(p);
+                }
+                token if token.is_ident() && p.nth(1) == Some(T![%]) => {
+                    parse_cursor_attribute(p);
+                }
                 token if token.is_ident() => {
                     parse_ident_or_function_invocation(p);
                 }
@@ -86,6 +112,8 @@ fn expr_bp(p: &mut Parser, min_bp: u8) -> Result<(), ParseErrorType> {
             }
             p.eat(T![(+)]);
         }
+        // Scalar subquery
+        T!["("] if p.nth(1) == Some(T![select]) => parse_subquery(p),
         T!["("] => {
             p.bump_any();
             expr_bp(p, 0)?;
@@ -123,6 +151,7 @@ fn expr_bp(p: &mut Parser, min_bp: u8) -> Result<(), ParseErrorType> {
 
     while !p.at(T![;]) && !p.at(T![EOF]) {
         p.eat(T![not]);
+        p.split_sign();
         let op = p.current();
 
         if let Some(operator) = postfix_bp(op) {
@@ -135,6 +164,10 @@ fn expr_bp(p: &mut Parser, min_bp: u8) -> Result<(), ParseErrorType> {
                 None => p.bump_any(),
             }
 
+            if let Some(cb) = operator.callback {
+                cb(p, operator.bp + 1);
+            }
+
             add_expr_node(p, checkpoint, None);
             continue;
         }
@@ -213,6 +246,7 @@ fn prefix_bp(op: TokenKind) -> Option<Operator> {
 
 fn postfix_bp(op: TokenKind) -> Option<Operator> {
     Some(match op {
+        T![is] => Operator::new_with_cb(7, Some(&is_cond)),
         T![!] => Operator::new_plain(19),
         _ => return None,
     })
@@ -238,13 +272,81 @@ fn infix_bp(op: TokenKind) -> Option<Operator> {
     })
 }
 
+/// Parses a `DATE`, `TIMESTAMP` or `INTERVAL` literal, e.g.
+/// `INTERVAL '4 5:12' DAY(3) TO MINUTE`.
+fn parse_typed_literal(p: &mut Parser) {
+    p.start(SyntaxKind::TypedLiteral);
+    let is_interval = p.at(T![interval]);
+    p.bump_any();
+    p.expect(T![quoted_literal]);
+
+    if is_interval {
+        parse_interval_field(p);
+        if p.nth(0) == Some(T![to]) {
+            p.bump_any();
+            parse_interval_field(p);
+        }
+    }
+
+    p.finish();
+}
+
+/// Parses a single interval field with an optional precision, e.g. `DAY(3)`.
+/// `HOUR` and `MINUTE` are not reserved, hence matched by their text.
+fn parse_interval_field(p: &mut Parser) {
+    const FIELDS: &[&str] = &["year", "month", "day", "hour", "minute", "second"];
+
+    if !FIELDS
+        .iter()
+        .any(|f| p.current_text().eq_ignore_ascii_case(f))
+    {
+        p.error(ParseErrorType::ExpectedOneOfTokens(vec![
+            T![year],
+            T![month],
+            T![day],
+            T![second],
+        ]));
+        return;
+    }
+    p.bump_any_map(SyntaxKind::Keyword);
+
+    if p.nth(0) == Some(T!["("]) {
+        p.bump_any();
+        p.expect(T![int_literal]);
+        if p.eat(T![,]) {
+            p.expect(T![int_literal]);
+        }
+        p.expect(T![")"]);
+    }
+}
+
+/// Parses the remainder of `IS [NOT] NULL` and `IS [NOT] A SET`
+fn is_cond(p: &mut Parser, _min_bp: u8) {
+    p.eat(T![not]);
+
+    match p.current() {
+        T![null] => p.bump_any(),
+        T![unquoted_ident] if p.current_text().eq_ignore_ascii_case("a") => {
+            p.bump_any_map(SyntaxKind::Keyword);
+            p.expect(T![set]);
+        }
+        _ => p.error(ParseErrorType::ExpectedToken(T![null])),
+    }
+}
+
 fn between_cond(p: &mut Parser, min_bp: u8) {
+    p.eat(T![symmetric]);
     let _ = expr_bp(p, min_bp);
     p.expect(T![and]);
     let _ = expr_bp(p, min_bp);
 }
 
 fn in_cond(p: &mut Parser, min_bp: u8) {
+    if p.at(T!["("]) && p.nth(1) == Some(T![select]) {
+        parse_subquery(p);
+        return;
+    }
+
     p.expect(T!["("]);
 
     safe_loop!(p, {
@@ -257,6 +359,16 @@ fn in_cond(p: &mut Parser, min_bp: u8) {
     p.expect(T![")"]);
 }
 
+/// Parses a parenthesized subquery, e.g. of `EXISTS (SELECT ..)` or
+/// `IN (SELECT ..)`.
+fn parse_subquery(p: &mut Parser) {
+    p.bump_any();
+    parse_query(p, false);
+    if !p.expect(T![")"]) {
+        p.error(ParseErrorType::UnbalancedParens);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::expect;
@@ -361,16 +473,16 @@ Root@0..11
             expect![[r#"
 Root@0..22
   Expression@0..22
-    Expression@0..10
+    Expression@0..9
       LogicOp@0..3 "NOT"
       Whitespace@3..4 " "
-      Expression@4..10
+      Expression@4..9
         Integer@4..5 "1"
         Whitespace@5..6 " "
         ComparisonOp@6..7 ">"
         Whitespace@7..8 " "
         Integer@8..9 "2"
-        Whitespace@9..10 " "
+    Whitespace@9..10 " "
     LogicOp@10..13 "AND"
     Whitespace@13..14 " "
     Expression@14..22
@@ -419,6 +531,43 @@ Root@0..8
         );
     }
 
+    #[test]
+    fn test_parse_concat_across_lines_with_comments() {
+        check(
+            parse(
+                "'a' -- first\n  || b /* x */\n  || c -- count\n  -1",
+                parse_expr,
+            ),
+            expect![[r#"
+Root@0..48
+  Expression@0..48
+    Expression@0..19
+      QuotedLiteral@0..3 "'a'"
+      Whitespace@3..4 " "
+      InlineComment@4..12 "-- first"
+      Whitespace@12..15 "\n  "
+      Concat@15..17 "||"
+      Whitespace@17..18 " "
+      IdentGroup@18..19
+        Ident@18..19 "b"
+    Whitespace@19..20 " "
+    Comment@20..27 "/* x */"
+    Whitespace@27..30 "\n  "
+    Concat@30..32 "||"
+    Whitespace@32..33 " "
+    Expression@33..48
+      IdentGroup@33..34
+        Ident@33..34 "c"
+      Whitespace@34..35 " "
+      InlineComment@35..43 "-- count"
+      Whitespace@43..46 "\n  "
+      ArithmeticOp@46..47 "-"
+      Integer@47..48 "1"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn test_parse_between_expr() {
         check(
@@ -457,6 +606,157 @@ Root@0..34
         );
     }
 
+    #[test]
+    fn test_parse_between_symmetric_expr() {
+        check(
+            parse("x between symmetric 10 and 1", parse_expr),
+            expect![[r#"
+Root@0..28
+  Expression@0..28
+    IdentGroup@0..1
+      Ident@0..1 "x"
+    Whitespace@1..2 " "
+    Keyword@2..9 "between"
+    Whitespace@9..10 " "
+    Keyword@10..19 "symmetric"
+    Whitespace@19..20 " "
+    Integer@20..22 "10"
+    Whitespace@22..23 " "
+    Keyword@23..26 "and"
+    Whitespace@26..27 " "
+    Integer@27..28 "1"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_is_null_expr() {
+        check(
+            parse("x + 1 is not null and y is null", parse_expr),
+            expect![[r#"
+Root@0..31
+  Expression@0..31
+    Expression@0..17
+      Expression@0..5
+        IdentGroup@0..1
+          Ident@0..1 "x"
+        Whitespace@1..2 " "
+        ArithmeticOp@2..3 "+"
+        Whitespace@3..4 " "
+        Integer@4..5 "1"
+      Whitespace@5..6 " "
+      Keyword@6..8 "is"
+      Whitespace@8..9 " "
+      Keyword@9..12 "not"
+      Whitespace@12..13 " "
+      Keyword@13..17 "null"
+    Whitespace@17..18 " "
+    LogicOp@18..21 "and"
+    Whitespace@21..22 " "
+    Expression@22..31
+      IdentGroup@22..23
+        Ident@22..23 "y"
+      Whitespace@23..24 " "
+      Keyword@24..26 "is"
+      Whitespace@26..27 " "
+      Keyword@27..31 "null"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_is_a_set_expr() {
+        check(
+            parse("not nested_tab is a set", parse_expr),
+            expect![[r#"
+Root@0..23
+  Expression@0..23
+    LogicOp@0..3 "not"
+    Whitespace@3..4 " "
+    Expression@4..23
+      IdentGroup@4..14
+        Ident@4..14 "nested_tab"
+      Whitespace@14..15 " "
+      Keyword@15..17 "is"
+      Whitespace@17..18 " "
+      Keyword@18..19 "a"
+      Whitespace@19..20 " "
+      Keyword@20..23 "set"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_datetime_literals() {
+        check(
+            parse(
+                "hired BETWEEN DATE '2020-01-01' AND TIMESTAMP '2020-12-31 23:59:59'",
+                parse_expr,
+            ),
+            expect![[r#"
+Root@0..67
+  Expression@0..67
+    IdentGroup@0..5
+      Ident@0..5 "hired"
+    Whitespace@5..6 " "
+    Keyword@6..13 "BETWEEN"
+    Whitespace@13..14 " "
+    TypedLiteral@14..31
+      Keyword@14..18 "DATE"
+      Whitespace@18..19 " "
+      QuotedLiteral@19..31 "'2020-01-01'"
+    Whitespace@31..32 " "
+    Keyword@32..35 "AND"
+    Whitespace@35..36 " "
+    TypedLiteral@36..67
+      Keyword@36..45 "TIMESTAMP"
+      Whitespace@45..46 " "
+      QuotedLiteral@46..67 "'2020-12-31 23:59:59'"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_interval_literals() {
+        check(
+            parse(
+                "INTERVAL '4 5:12' DAY(3) TO minute + INTERVAL '1' day",
+                parse_expr,
+            ),
+            expect![[r#"
+Root@0..53
+  Expression@0..53
+    TypedLiteral@0..34
+      Keyword@0..8 "INTERVAL"
+      Whitespace@8..9 " "
+      QuotedLiteral@9..17 "'4 5:12'"
+      Whitespace@17..18 " "
+      Keyword@18..21 "DAY"
+      LParen@21..22 "("
+      Integer@22..23 "3"
+      RParen@23..24 ")"
+      Whitespace@24..25 " "
+      Keyword@25..27 "TO"
+      Whitespace@27..28 " "
+      Keyword@28..34 "minute"
+    Whitespace@34..35 " "
+    ArithmeticOp@35..36 "+"
+    Whitespace@36..37 " "
+    TypedLiteral@37..53
+      Keyword@37..45 "INTERVAL"
+      Whitespace@45..46 " "
+      QuotedLiteral@46..49 "'1'"
+      Whitespace@49..50 " "
+      Keyword@50..53 "day"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn test_parse_in_condition() {
         check(
@@ -585,23 +885,23 @@ Root@0..9
             expect![[r#"
 Root@0..17
   Expression@0..17
-    Expression@0..14
+    Expression@0..13
       Integer@0..1 "1"
       Whitespace@1..2 " "
       ArithmeticOp@2..3 "+"
       Whitespace@3..4 " "
-      Expression@4..14
-        Expression@4..10
+      Expression@4..13
+        Expression@4..9
           Integer@4..5 "2"
           Whitespace@5..6 " "
           ArithmeticOp@6..7 "*"
           Whitespace@7..8 " "
           Integer@8..9 "3"
-          Whitespace@9..10 " "
+        Whitespace@9..10 " "
         ArithmeticOp@10..11 "/"
         Whitespace@11..12 " "
         Integer@12..13 "4"
-        Whitespace@13..14 " "
+    Whitespace@13..14 " "
     ArithmeticOp@14..15 "-"
     Whitespace@15..16 " "
     Integer@16..17 "5"
@@ -655,7 +955,7 @@ Root@0..7
             expect![[r#"
 Root@0..15
   Expression@0..15
-    Expression@0..12
+    Expression@0..11
       IdentGroup@0..1
         Ident@0..1 "a"
       Whitespace@1..2 " "
@@ -669,7 +969,7 @@ Root@0..15
         Whitespace@8..9 " "
         Integer@9..10 "2"
       RParen@10..11 ")"
-      Whitespace@11..12 " "
+    Whitespace@11..12 " "
     ArithmeticOp@12..13 "/"
     Whitespace@13..14 " "
     IdentGroup@14..15
@@ -720,39 +1020,39 @@ Root@0..17
             expect![[r#"
 Root@0..75
   Expression@0..75
-    Expression@0..8
+    Expression@0..7
       IdentGroup@0..1
         Ident@0..1 "a"
       Whitespace@1..2 " "
       ComparisonOp@2..3 "<"
       Whitespace@3..4 " "
       Integer@4..7 "100"
-      Whitespace@7..8 " "
+    Whitespace@7..8 " "
     LogicOp@8..11 "AND"
     Whitespace@11..12 " "
     LParen@12..13 "("
     Expression@13..74
-      Expression@13..21
+      Expression@13..20
         Integer@13..15 "10"
         Whitespace@15..16 " "
         ComparisonOp@16..18 "<>"
         Whitespace@18..19 " "
         IdentGroup@19..20
           Ident@19..20 "b"
-        Whitespace@20..21 " "
+      Whitespace@20..21 " "
       LogicOp@21..23 "OR"
       Whitespace@23..24 " "
       Expression@24..74
         LParen@24..25 "("
         Expression@25..48
-          Expression@25..35
+          Expression@25..34
             IdentGroup@25..26
               Ident@25..26 "c"
             Whitespace@26..27 " "
             ComparisonOp@27..28 "="
             Whitespace@28..29 " "
             QuotedLiteral@29..34 "'foo'"
-            Whitespace@34..35 " "
+          Whitespace@34..35 " "
           LogicOp@35..38 "AND"
           Whitespace@38..39 " "
           Expression@39..48
@@ -865,27 +1165,84 @@ Root@0..64
   CaseStmt@0..64
     Keyword@0..4 "CASE"
     Whitespace@4..5 " "
-    SimpleCaseExpression@5..49
+    SimpleCaseExpression@5..44
       IdentGroup@5..15
         Ident@5..15 "country_id"
       Whitespace@15..20 "\n    "
       Keyword@20..24 "WHEN"
       Whitespace@24..25 " "
-      ComparissonExpression@25..34
+      ComparissonExpression@25..29
         QuotedLiteral@25..29 "'US'"
-        Whitespace@29..34 "\n    "
+      Whitespace@29..34 "\n    "
       Keyword@34..38 "THEN"
       Whitespace@38..39 " "
       IdentGroup@39..44
         Ident@39..44 "state"
-      Whitespace@44..49 "\n    "
-    ElseExpression@49..61
+    Whitespace@44..49 "\n    "
+    ElseExpression@49..58
       Keyword@49..53 "ELSE"
       Whitespace@53..54 " "
       IdentGroup@54..58
         Ident@54..58 "city"
-      Whitespace@58..61 "\n  "
+    Whitespace@58..61 "\n  "
     Keyword@61..64 "END"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_subquery_conditions() {
+        check(
+            parse(
+                "NOT EXISTS (SELECT 1 FROM a) AND b IN (SELECT c FROM d)",
+                parse_expr,
+            ),
+            expect![[r#"
+Root@0..55
+  Expression@0..55
+    Expression@0..28
+      LogicOp@0..3 "NOT"
+      Whitespace@3..4 " "
+      Expression@4..28
+        Keyword@4..10 "EXISTS"
+        Whitespace@10..11 " "
+        LParen@11..12 "("
+        SelectStmt@12..27
+          Keyword@12..18 "SELECT"
+          Whitespace@18..19 " "
+          SelectClause@19..20
+            ColumnExpr@19..20
+              Integer@19..20 "1"
+          Whitespace@20..21 " "
+          Keyword@21..25 "FROM"
+          Whitespace@25..26 " "
+          IdentGroup@26..27
+            Ident@26..27 "a"
+        RParen@27..28 ")"
+    Whitespace@28..29 " "
+    LogicOp@29..32 "AND"
+    Whitespace@32..33 " "
+    Expression@33..55
+      IdentGroup@33..34
+        Ident@33..34 "b"
+      Whitespace@34..35 " "
+      Keyword@35..37 "IN"
+      Whitespace@37..38 " "
+      LParen@38..39 "("
+      SelectStmt@39..54
+        Keyword@39..45 "SELECT"
+        Whitespace@45..46 " "
+        SelectClause@46..47
+          ColumnExpr@46..47
+            IdentGroup@46..47
+              Ident@46..47 "c"
+        Whitespace@47..48 " "
+        Keyword@48..52 "FROM"
+        Whitespace@52..53 " "
+        IdentGroup@53..54
+          Ident@53..54 "d"
+      RParen@54..55 ")"
 "#]],
             vec![],
         );
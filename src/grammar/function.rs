@@ -5,6 +5,10 @@
 //! Implements parsing of functions from a token tree.
 
 use crate::grammar::call_spec::opt_call_spec;
+use crate::grammar::udt::{
+    parse_accessible_by_clause, parse_invoker_rights_clause, parse_parallel_enable_clause,
+    parse_result_cache_clause, parse_sharing_clause,
+};
 use crate::parser::Parser;
 use source_gen::lexer::TokenKind;
 use source_gen::syntax::SyntaxKind;
@@ -14,8 +18,14 @@ use super::*;
 /// Parses a complete function.
 pub fn parse_function(p: &mut Parser, is_nested: bool) {
     p.start(SyntaxKind::Function);
+    let name = peek_name_after(p, T![function]);
     parse_header(p, is_nested);
-    parse_body(p);
+
+    // Nested subprograms may be forward-declared without a body
+    if !(is_nested && p.eat(T![;])) {
+        parse_body(p, name.as_deref());
+    }
+
     p.finish();
 }
 
@@ -35,6 +45,9 @@ fn parse_header(p: &mut Parser, is_nested: bool) {
     p.expect(T![function]);
 
     parse_ident(p, 1..2);
+    if p.at(T![sharing]) {
+        parse_sharing_clause(p);
+    }
 
     parse_param_list(p);
     parse_return_type(p);
@@ -49,17 +62,28 @@ fn parse_return_type(p: &mut Parser) {
     }
 }
 
+/// Parses the properties between the return type and the body, see
+/// <https://docs.oracle.com/en/database/oracle/oracle-database/21/lnpls/CREATE-FUNCTION-statement.html>.
 fn parse_attributes(p: &mut Parser) {
-    p.eat(T![deterministic]);
+    safe_loop!(p, {
+        match p.current() {
+            T![deterministic] | T![pipelined] => p.bump_any(),
+            T![accessible] => parse_accessible_by_clause(p),
+            T![authid] => parse_invoker_rights_clause(p),
+            T![parallel_enable] => parse_parallel_enable_clause(p),
+            T![result_cache] => parse_result_cache_clause(p),
+            _ => break,
+        }
+    });
 }
 
 /// Parses the body of a function.
-fn parse_body(p: &mut Parser) {
+fn parse_body(p: &mut Parser, name: Option<&str>) {
     p.expect_one_of(&[T![is], T![as]]);
     p.eat(T!["$$"]);
 
     if !opt_call_spec(p) {
-        parse_block(p);
+        parse_named_block(p, name);
     }
 
     p.eat(T!["$$"]);
@@ -140,11 +164,11 @@ Root@0..145
     ParamList@37..145
       LParen@37..38 "("
       Whitespace@38..40 "  "
-      Param@40..92
+      Param@40..86
         IdentGroup@40..48
           Ident@40..48 "p_emp_id"
         Whitespace@48..58 "          "
-        Datatype@58..92
+        Datatype@58..86
           IdentGroup@58..81
             Ident@58..69 "job_history"
             Dot@69..70 "."
@@ -152,14 +176,14 @@ Root@0..145
           TypeAttribute@81..86
             Percentage@81..82 "%"
             Keyword@82..86 "type"
-          Whitespace@86..92 "\n     "
+      Whitespace@86..92 "\n     "
       Comma@92..93 ","
       Whitespace@93..94 " "
-      Param@94..144
+      Param@94..139
         IdentGroup@94..106
           Ident@94..106 "p_start_date"
         Whitespace@106..112 "      "
-        Datatype@112..144
+        Datatype@112..139
           IdentGroup@112..134
             Ident@112..123 "job_history"
             Dot@123..124 "."
@@ -167,17 +191,76 @@ Root@0..145
           TypeAttribute@134..139
             Percentage@134..135 "%"
             Keyword@135..139 "type"
-          Whitespace@139..144 "\n    "
+      Whitespace@139..144 "\n    "
       RParen@144..145 ")"
 "#]],
             vec![],
         );
     }
 
+    #[test]
+    fn test_parse_header_with_attributes() {
+        const INPUT: &str = r#"CREATE FUNCTION get_rate(p_cur VARCHAR2 DEFAULT 'EUR')
+RETURN NUMBER
+AUTHID DEFINER DETERMINISTIC PARALLEL_ENABLE RESULT_CACHE RELIES_ON (rates)"#;
+        check(
+            parse(INPUT, |p| parse_header(p, false)),
+            expect![[r#"
+Root@0..144
+  FunctionHeader@0..144
+    Keyword@0..6 "CREATE"
+    Whitespace@6..7 " "
+    Keyword@7..15 "FUNCTION"
+    Whitespace@15..16 " "
+    IdentGroup@16..24
+      Ident@16..24 "get_rate"
+    ParamList@24..54
+      LParen@24..25 "("
+      Param@25..53
+        IdentGroup@25..30
+          Ident@25..30 "p_cur"
+        Whitespace@30..31 " "
+        Datatype@31..39
+          Keyword@31..39 "VARCHAR2"
+        Whitespace@39..40 " "
+        Keyword@40..47 "DEFAULT"
+        Whitespace@47..48 " "
+        QuotedLiteral@48..53 "'EUR'"
+      RParen@53..54 ")"
+    Whitespace@54..55 "\n"
+    Keyword@55..61 "RETURN"
+    Whitespace@61..62 " "
+    Datatype@62..68
+      Keyword@62..68 "NUMBER"
+    Whitespace@68..69 "\n"
+    InvokerRightsClause@69..83
+      Keyword@69..75 "AUTHID"
+      Whitespace@75..76 " "
+      Keyword@76..83 "DEFINER"
+    Whitespace@83..84 " "
+    Keyword@84..97 "DETERMINISTIC"
+    Whitespace@97..98 " "
+    ParallelEnableClause@98..113
+      Keyword@98..113 "PARALLEL_ENABLE"
+    Whitespace@113..114 " "
+    ResultCacheClause@114..144
+      Keyword@114..126 "RESULT_CACHE"
+      Whitespace@126..127 " "
+      Keyword@127..136 "RELIES_ON"
+      Whitespace@136..137 " "
+      LParen@137..138 "("
+      IdentGroup@138..143
+        Ident@138..143 "rates"
+      RParen@143..144 ")"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn test_parse_body() {
         check(
-            parse(r#"IS BEGIN NULL; END hello;"#, parse_body),
+            parse(r#"IS BEGIN NULL; END hello;"#, |p| parse_body(p, None)),
             expect![[r#"
 Root@0..25
   Keyword@0..2 "IS"
@@ -209,8 +292,8 @@ Root@0..25
 Root@0..171
   InlineComment@0..73 "-- test: ignore EDITI ..."
   Whitespace@73..74 "\n"
-  Function@74..171
-    FunctionHeader@74..146
+  Function@74..170
+    FunctionHeader@74..145
       Keyword@74..80 "CREATE"
       Whitespace@80..81 " "
       Keyword@81..83 "OR"
@@ -226,9 +309,9 @@ Root@0..171
       Whitespace@131..132 "\n"
       Keyword@132..138 "RETURN"
       Whitespace@138..139 " "
-      Datatype@139..146
+      Datatype@139..145
         Keyword@139..145 "number"
-        Whitespace@145..146 " "
+    Whitespace@145..146 " "
     Keyword@146..148 "IS"
     Whitespace@148..149 "\n"
     Block@149..170
@@ -243,7 +326,7 @@ Root@0..171
       Whitespace@165..166 "\n"
       Keyword@166..169 "END"
       Semicolon@169..170 ";"
-    Whitespace@170..171 "\n"
+  Whitespace@170..171 "\n"
 "#]],
             vec![],
         );
@@ -260,8 +343,8 @@ Root@0..171
 Root@0..180
   InlineComment@0..76 "-- test: ignore NONED ..."
   Whitespace@76..77 "\n"
-  Function@77..180
-    FunctionHeader@77..155
+  Function@77..179
+    FunctionHeader@77..154
       Keyword@77..83 "CREATE"
       Whitespace@83..84 " "
       Keyword@84..86 "OR"
@@ -277,9 +360,9 @@ Root@0..180
       Whitespace@140..141 "\n"
       Keyword@141..147 "RETURN"
       Whitespace@147..148 " "
-      Datatype@148..155
+      Datatype@148..154
         Keyword@148..154 "number"
-        Whitespace@154..155 " "
+    Whitespace@154..155 " "
     Keyword@155..157 "IS"
     Whitespace@157..158 "\n"
     Block@158..179
@@ -294,7 +377,7 @@ Root@0..180
       Whitespace@174..175 "\n"
       Keyword@175..178 "END"
       Semicolon@178..179 ";"
-    Whitespace@179..180 "\n"
+  Whitespace@179..180 "\n"
 "#]],
             vec![],
         );
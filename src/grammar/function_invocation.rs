@@ -7,6 +7,7 @@
 
 use crate::grammar::{parse_expr, parse_ident};
 use crate::parser::{safe_loop, Parser};
+use rowan::Checkpoint;
 use source_gen::lexer::TokenKind;
 use source_gen::syntax::SyntaxKind;
 use source_gen::T;
@@ -32,9 +33,94 @@ pub(crate) fn opt_function_invocation(p: &mut Parser) -> bool {
     false
 }
 
+/// Built-in methods of collection types.
+const COLLECTION_METHODS: &[&str] = &[
+    "count", "delete", "exists", "extend", "first", "last", "limit", "next", "prior", "trim",
+];
+
+/// Checks if the `n`th token names a collection method invoked on the
+/// collection named by the token before the dot. Within SQL statements, this
+/// is only the case for collections declared in the parsed source, as e.g.
+/// `t.count` refers to a column otherwise.
+fn at_collection_method(p: &Parser, n: usize) -> bool {
+    let is_method = p
+        .nth_text(n)
+        .is_some_and(|text| COLLECTION_METHODS.contains(&text.to_lowercase().as_str()));
+    let is_collection = !p.in_sql()
+        || n.checked_sub(2)
+            .and_then(|m| p.nth_text(m))
+            .is_some_and(|name| p.is_collection(name));
+
+    is_method && is_collection
+}
+
+/// Looks ahead and parses a collection method call like `tab.COUNT` or
+/// `pkg.tab.EXISTS(i)` if applicable
+pub(crate) fn opt_method_invocation(p: &mut Parser) -> bool {
+    let mut segments = 0;
+    while segments < 2
+        && p.nth(segments * 2).map(|t| t.is_ident()).unwrap_or(false)
+        && p.nth(segments * 2 + 1) == Some(T![.])
+    {
+        segments += 1;
+        if at_collection_method(p, segments * 2) {
+            parse_method_invocation(p, segments as u8);
+            return true;
+        }
+    }
+    false
+}
+
+/// Parses a collection method call, with `segments` being the number of
+/// identifiers naming the collection.
+fn parse_method_invocation(p: &mut Parser, segments: u8) {
+    p.start(SyntaxKind::MethodInvocation);
+    parse_ident(p, 1..segments);
+    p.expect(T![.]);
+    p.bump_any_map(SyntaxKind::Keyword);
+
+    if p.nth(0) == Some(T!["("]) {
+        parse_argument_list(p);
+    }
+    p.finish();
+}
+
+/// Parses attribute accesses and method calls chained to the expression
+/// started at `checkpoint`, e.g. `.y` and `.z(1)` in `v.method(x).y.z(1)`.
+/// Each one wraps everything before it.
+pub(crate) fn parse_member_accesses(p: &mut Parser, checkpoint: Checkpoint) {
+    while p.at(T![.]) && p.nth(1).is_some_and(|t| t.is_ident()) {
+        let is_collection_method = at_collection_method(p, 1);
+
+        if is_collection_method || p.nth(2) == Some(T!["("]) {
+            p.start_node_at(checkpoint, SyntaxKind::MethodInvocation);
+            p.bump(T![.]);
+            if is_collection_method {
+                p.bump_any_map(SyntaxKind::Keyword);
+            } else {
+                parse_ident(p, 1..1);
+            }
+            if p.at(T!["("]) {
+                parse_argument_list(p);
+            }
+        } else {
+            p.start_node_at(checkpoint, SyntaxKind::FieldAccess);
+            p.bump(T![.]);
+            parse_ident(p, 1..1);
+        }
+        p.finish();
+    }
+}
+
 pub(crate) fn parse_function_invocation(p: &mut Parser) {
     p.start(SyntaxKind::FunctionInvocation);
     parse_ident(p, 1..2);
+    parse_argument_list(p);
+    p.finish();
+}
+
+/// Parses the parenthesized arguments of a function or method call.
+pub(crate) fn parse_argument_list(p: &mut Parser) {
     p.expect(T!["("]);
 
     if !p.at(T![")"]) {
@@ -49,6 +135,11 @@ pub(crate) fn parse_function_invocation(p: &mut Parser) {
                 }
                 _ => {
                     p.start(SyntaxKind::Argument);
+                    // Named notation, e.g. `job_name => 'nightly'`
+                    if p.current().is_ident() && p.nth(1) == Some(T![=>]) {
+                        parse_ident(p, 1..1);
+                        p.bump(T![=>]);
+                    }
                     parse_expr(p);
                     p.finish();
                 }
@@ -59,7 +150,6 @@ pub(crate) fn parse_function_invocation(p: &mut Parser) {
     }
 
     p.expect(T![")"]);
-    p.finish();
 }
 
 #[cfg(test)]
@@ -69,6 +159,79 @@ mod tests {
     use super::super::tests::{check, parse};
     use super::*;
 
+    #[test]
+    fn parse_method_call_without_params() {
+        check(
+            parse("pkg.names.COUNT", |p| {
+                opt_method_invocation(p);
+            }),
+            expect![[r#"
+Root@0..15
+  MethodInvocation@0..15
+    IdentGroup@0..9
+      Ident@0..3 "pkg"
+      Dot@3..4 "."
+      Ident@4..9 "names"
+    Dot@9..10 "."
+    Keyword@10..15 "COUNT"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn parse_method_call_with_params() {
+        check(
+            parse("names.exists(i + 1)", |p| {
+                opt_method_invocation(p);
+            }),
+            expect![[r#"
+Root@0..19
+  MethodInvocation@0..19
+    IdentGroup@0..5
+      Ident@0..5 "names"
+    Dot@5..6 "."
+    Keyword@6..12 "exists"
+    LParen@12..13 "("
+    ArgumentList@13..18
+      Argument@13..18
+        Expression@13..18
+          IdentGroup@13..14
+            Ident@13..14 "i"
+          Whitespace@14..15 " "
+          ArithmeticOp@15..16 "+"
+          Whitespace@16..17 " "
+          Integer@17..18 "1"
+    RParen@18..19 ")"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn parse_qualified_ident_and_method_call() {
+        check(
+            parse("emp.counter + names.last", parse_expr),
+            expect![[r#"
+Root@0..24
+  Expression@0..24
+    IdentGroup@0..11
+      Ident@0..3 "emp"
+      Dot@3..4 "."
+      Ident@4..11 "counter"
+    Whitespace@11..12 " "
+    ArithmeticOp@12..13 "+"
+    Whitespace@13..14 " "
+    MethodInvocation@14..24
+      IdentGroup@14..19
+        Ident@14..19 "names"
+      Dot@19..20 "."
+      Keyword@20..24 "last"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn parse_function_call_without_params() {
         check(
@@ -169,6 +332,91 @@ Root@0..24
             Whitespace@21..22 " "
             Integer@22..23 "2"
     RParen@23..24 ")"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn parse_function_call_with_named_args() {
+        check(
+            parse(
+                "dbms_job.submit(job, what => 'log(''it'');', next_date => SYSDATE)",
+                parse_function_invocation,
+            ),
+            expect![[r#"
+Root@0..66
+  FunctionInvocation@0..66
+    IdentGroup@0..15
+      Ident@0..8 "dbms_job"
+      Dot@8..9 "."
+      Ident@9..15 "submit"
+    LParen@15..16 "("
+    ArgumentList@16..65
+      Argument@16..19
+        Expression@16..19
+          IdentGroup@16..19
+            Ident@16..19 "job"
+      Comma@19..20 ","
+      Whitespace@20..21 " "
+      Argument@21..43
+        IdentGroup@21..25
+          Ident@21..25 "what"
+        Whitespace@25..26 " "
+        FatArrow@26..28 "=>"
+        Whitespace@28..29 " "
+        Expression@29..43
+          QuotedLiteral@29..43 "'log(''it'');'"
+      Comma@43..44 ","
+      Whitespace@44..45 " "
+      Argument@45..65
+        IdentGroup@45..54
+          Ident@45..54 "next_date"
+        Whitespace@54..55 " "
+        FatArrow@55..57 "=>"
+        Whitespace@57..58 " "
+        IdentGroup@58..65
+          Ident@58..65 "SYSDATE"
+    RParen@65..66 ")"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn parse_chained_method_calls() {
+        check(
+            parse("v.method(x).other(1).attr", |p| {
+                let checkpoint = p.checkpoint();
+                opt_function_invocation(p);
+                parse_member_accesses(p, checkpoint);
+            }),
+            expect![[r#"
+Root@0..25
+  FieldAccess@0..25
+    MethodInvocation@0..20
+      FunctionInvocation@0..11
+        IdentGroup@0..8
+          Ident@0..1 "v"
+          Dot@1..2 "."
+          Ident@2..8 "method"
+        LParen@8..9 "("
+        ArgumentList@9..10
+          Argument@9..10
+            IdentGroup@9..10
+              Ident@9..10 "x"
+        RParen@10..11 ")"
+      Dot@11..12 "."
+      IdentGroup@12..17
+        Ident@12..17 "other"
+      LParen@17..18 "("
+      ArgumentList@18..19
+        Argument@18..19
+          Integer@18..19 "1"
+      RParen@19..20 ")"
+    Dot@20..21 "."
+    IdentGroup@21..25
+      Ident@21..25 "attr"
 "#]],
             vec![],
         );
@@ -1,10 +1,13 @@
 use crate::{
-    grammar::{parse_execute_immediate, parse_expr, parse_query, parse_stmt},
-    safe_loop, Parser,
+    grammar::{
+        parse_bound_expr, parse_cte, parse_dml, parse_execute_immediate, parse_expr, parse_insert,
+        parse_query, parse_stmt,
+    },
+    safe_loop, ParseErrorType, Parser,
 };
 use source_gen::{lexer::TokenKind, syntax::SyntaxKind, T};
 
-use super::{opt_expr, opt_parse_datatype, parse_ident};
+use super::{opt_end_label, opt_expr, opt_parse_datatype, parse_ident};
 
 pub(crate) fn parse_loop(p: &mut Parser) {
     p.start(SyntaxKind::Loop);
@@ -36,7 +39,7 @@ fn parse_basic_loop(p: &mut Parser) {
     });
     p.expect(T![end]);
     p.expect(T![loop]);
-    parse_ident(p, 0..1);
+    opt_end_label(p);
     p.finish();
 }
 
@@ -54,7 +57,7 @@ fn parse_for_loop(p: &mut Parser) {
     });
     p.expect(T![end]);
     p.expect(T![loop]);
-    parse_ident(p, 0..1);
+    opt_end_label(p);
     p.finish();
 }
 
@@ -72,7 +75,7 @@ fn parse_while_loop(p: &mut Parser) {
     });
     p.expect(T![end]);
     p.expect(T![loop]);
-    parse_ident(p, 0..1);
+    opt_end_label(p);
     p.finish();
 }
 
@@ -115,7 +118,9 @@ fn parse_iteration_control(p: &mut Parser) {
             p.expect_one_of(&[T![values], T![indices], T![pairs]]);
             p.expect(T![of]);
             if p.eat(T!["("]) {
-                if [T![with], T![select]].contains(&p.current()) {
+                if p.at(T![with]) {
+                    parse_cte(p);
+                } else if p.at(T![select]) {
                     parse_query(p, false);
                 } else if p.at(T![execute]) {
                     parse_execute_immediate(p);
@@ -132,7 +137,9 @@ fn parse_iteration_control(p: &mut Parser) {
             if p.eat(T![repeat]) {
                 parse_expr(p);
             } else if p.eat(T!["("]) {
-                if [T![with], T![select]].contains(&p.current()) {
+                if p.at(T![with]) {
+                    parse_cte(p);
+                } else if p.at(T![select]) {
                     parse_query(p, false);
                 } else if p.at(T![ref]) {
                     p.eat(T![ref]);
@@ -144,10 +151,7 @@ fn parse_iteration_control(p: &mut Parser) {
                 }
                 p.expect(T![")"]);
             } else {
-                parse_expr(p);
-                if p.eat(T![..]) {
-                    parse_expr(p);
-                }
+                opt_range(p);
                 if p.eat(T![by]) {
                     parse_expr(p);
                 }
@@ -164,6 +168,84 @@ fn parse_pred_clause_seq(p: &mut Parser) {
     }
 }
 
+/// Parses a `FORALL` statement, running a single DML statement once per
+/// index of its bounds.
+///
+/// Railroad diagram 🚆 https://docs.oracle.com/en/database/oracle/oracle-database/19/lnpls/FORALL-statement.html
+pub(crate) fn parse_forall(p: &mut Parser) {
+    p.start(SyntaxKind::ForallStmt);
+    p.expect(T![forall]);
+    parse_ident(p, 1..1);
+    p.expect(T![in]);
+    parse_bounds_clause(p);
+    if p.eat(T![save]) {
+        p.expect(T![exceptions]);
+    }
+
+    match p.current() {
+        T![insert] => parse_insert(p),
+        T![delete] | T![update] => parse_dml(p),
+        T![execute] => parse_execute_immediate(p),
+        _ => p.error(ParseErrorType::ExpectedOneOfTokens(vec![
+            T![insert],
+            T![update],
+            T![delete],
+            T![execute],
+        ])),
+    }
+    p.finish();
+}
+
+fn parse_bounds_clause(p: &mut Parser) {
+    p.start(SyntaxKind::IterationControl);
+    match p.current() {
+        T![indices] => {
+            p.bump_any();
+            p.expect(T![of]);
+            parse_ident(p, 1..2);
+            if p.eat(T![between]) {
+                parse_bound_expr(p);
+                p.expect(T![and]);
+                parse_bound_expr(p);
+            }
+        }
+        T![values] => {
+            p.bump_any();
+            p.expect(T![of]);
+            parse_ident(p, 1..2);
+        }
+        _ => {
+            if !opt_range(p) {
+                p.error(ParseErrorType::ExpectedToken(T![..]));
+            }
+        }
+    }
+    p.finish();
+}
+
+/// Parses an expression, optionally followed by `.. <upper>`. Returns
+/// whether it was a range.
+fn opt_range(p: &mut Parser) -> bool {
+    // The lexer merges `..` with adjacent integer bounds into a single
+    // token, e.g. `1..10` or `1 ..`
+    if !p.at(T![iter_range]) {
+        parse_expr(p);
+    }
+    if !p.at(T![iter_range]) && !p.at(T![..]) {
+        return false;
+    }
+
+    let has_upper = p
+        .current_text()
+        .trim_end()
+        .ends_with(|c: char| c.is_ascii_digit());
+    p.bump_any();
+    if !has_upper {
+        parse_expr(p);
+    }
+    true
+}
+
 pub(crate) fn parse_exit_stmt(p: &mut Parser) {
     p.start(SyntaxKind::ExitStmt);
     p.expect(T![exit]);
@@ -193,7 +275,7 @@ mod tests {
 
     use crate::grammar::tests::{check, parse};
 
-    use super::parse_loop;
+    use super::{parse_forall, parse_loop};
 
     #[test]
     fn test_parse_simple_loop() {
@@ -231,21 +313,21 @@ Root@0..98
       BlockStatement@41..86
         Keyword@41..43 "IF"
         Whitespace@43..44 " "
-        Expression@44..58
+        Expression@44..57
           IdentGroup@44..53
             Ident@44..53 "l_counter"
           Whitespace@53..54 " "
           ComparisonOp@54..55 ">"
           Whitespace@55..56 " "
           Integer@56..57 "3"
-          Whitespace@57..58 " "
+        Whitespace@57..58 " "
         Keyword@58..62 "THEN"
         Whitespace@62..69 "\n      "
-        BlockStatement@69..79
+        BlockStatement@69..74
           ExitStmt@69..74
             Keyword@69..73 "EXIT"
             Semicolon@73..74 ";"
-          Whitespace@74..79 "\n    "
+        Whitespace@74..79 "\n    "
         Keyword@79..82 "END"
         Whitespace@82..83 " "
         Keyword@83..85 "IF"
@@ -302,7 +384,7 @@ Root@0..357
           Integer@39..40 "1"
         Semicolon@40..41 ";"
       Whitespace@41..46 "\n    "
-      BlockStatement@46..84
+      BlockStatement@46..75
         ExitStmt@46..75
           Keyword@46..50 "EXIT"
           Whitespace@50..51 " "
@@ -318,7 +400,7 @@ Root@0..357
             Whitespace@72..73 " "
             Integer@73..74 "2"
           Semicolon@74..75 ";"
-        Whitespace@75..84 "    \n    "
+      Whitespace@75..84 "    \n    "
       BlockStatement@84..130
         FunctionInvocation@84..129
           IdentGroup@84..104
@@ -350,7 +432,7 @@ Root@0..357
           Integer@169..170 "0"
         Semicolon@170..171 ";"
       Whitespace@171..178 "\n      "
-      BlockStatement@178..337
+      BlockStatement@178..334
         Loop@178..334
           Ident@178..192 "<<inner_loop>>"
           Whitespace@192..193 " "
@@ -372,7 +454,7 @@ Root@0..357
                 Integer@217..218 "1"
               Semicolon@218..219 ";"
             Whitespace@219..226 "\n      "
-            BlockStatement@226..262
+            BlockStatement@226..255
               ExitStmt@226..255
                 Keyword@226..230 "EXIT"
                 Whitespace@230..231 " "
@@ -388,7 +470,7 @@ Root@0..357
                   Whitespace@252..253 " "
                   Integer@253..254 "3"
                 Semicolon@254..255 ";"
-              Whitespace@255..262 "\n      "
+            Whitespace@255..262 "\n      "
             BlockStatement@262..309
               FunctionInvocation@262..308
                 IdentGroup@262..282
@@ -415,7 +497,7 @@ Root@0..357
             IdentGroup@323..333
               Ident@323..333 "inner_loop"
           Semicolon@333..334 ";"
-        Whitespace@334..337 "\n  "
+      Whitespace@334..337 "\n  "
       Keyword@337..340 "END"
       Whitespace@340..341 " "
       Keyword@341..345 "LOOP"
@@ -445,15 +527,15 @@ Root@0..80
     ForLoop@0..78
       Keyword@0..3 "FOR"
       Whitespace@3..4 " "
-      Iterator@4..24
+      Iterator@4..21
         IdentGroup@4..13
           Ident@4..13 "l_counter"
         Whitespace@13..14 " "
         Keyword@14..16 "IN"
         Whitespace@16..17 " "
-        IterationControl@17..24
+        IterationControl@17..21
           IterRange@17..21 "1..5"
-          Whitespace@21..24 "\n  "
+      Whitespace@21..24 "\n  "
       Keyword@24..28 "LOOP"
       Whitespace@28..33 "\n    "
       BlockStatement@33..67
@@ -464,11 +546,11 @@ Root@0..80
             Ident@45..53 "PUT_LINE"
           LParen@53..54 "("
           Whitespace@54..55 " "
-          ArgumentList@55..65
-            Argument@55..65
+          ArgumentList@55..64
+            Argument@55..64
               IdentGroup@55..64
                 Ident@55..64 "l_counter"
-              Whitespace@64..65 " "
+          Whitespace@64..65 " "
           RParen@65..66 ")"
         Semicolon@66..67 ";"
       Whitespace@67..70 "\n  "
@@ -498,19 +580,19 @@ Root@0..93
     ForLoop@0..92
       Keyword@0..3 "FOR"
       Whitespace@3..4 " "
-      Iterator@4..32
+      Iterator@4..27
         IdentGroup@4..13
           Ident@4..13 "l_counter"
         Whitespace@13..14 " "
         Keyword@14..16 "IN"
         Whitespace@16..17 " "
-        IterationControl@17..32
+        IterationControl@17..27
           IterRange@17..22 "1..10"
           Whitespace@22..23 " "
           Keyword@23..25 "BY"
           Whitespace@25..26 " "
           Integer@26..27 "2"
-          Whitespace@27..32 "\n    "
+      Whitespace@27..32 "\n    "
       Keyword@32..36 "LOOP"
       Whitespace@36..45 "\n        "
       BlockStatement@45..79
@@ -521,11 +603,11 @@ Root@0..93
             Ident@57..65 "PUT_LINE"
           LParen@65..66 "("
           Whitespace@66..67 " "
-          ArgumentList@67..77
-            Argument@67..77
+          ArgumentList@67..76
+            Argument@67..76
               IdentGroup@67..76
                 Ident@67..76 "l_counter"
-              Whitespace@76..77 " "
+          Whitespace@76..77 " "
           RParen@77..78 ")"
         Semicolon@78..79 ";"
       Whitespace@79..84 "\n    "
@@ -554,7 +636,7 @@ Root@0..87
     ForLoop@0..86
       Keyword@0..3 "FOR"
       Whitespace@3..4 " "
-      Iterator@4..32
+      Iterator@4..29
         IdentGroup@4..13
           Ident@4..13 "l_counter"
         Whitespace@13..14 " "
@@ -562,9 +644,9 @@ Root@0..87
         Whitespace@16..17 " "
         Keyword@17..24 "REVERSE"
         Whitespace@24..25 " "
-        IterationControl@25..32
+        IterationControl@25..29
           IterRange@25..29 "1..3"
-          Whitespace@29..32 "\n  "
+      Whitespace@29..32 "\n  "
       Keyword@32..36 "LOOP"
       Whitespace@36..41 "\n    "
       BlockStatement@41..75
@@ -575,11 +657,11 @@ Root@0..87
             Ident@53..61 "PUT_LINE"
           LParen@61..62 "("
           Whitespace@62..63 " "
-          ArgumentList@63..73
-            Argument@63..73
+          ArgumentList@63..72
+            Argument@63..72
               IdentGroup@63..72
                 Ident@63..72 "l_counter"
-              Whitespace@72..73 " "
+          Whitespace@72..73 " "
           RParen@73..74 ")"
         Semicolon@74..75 ";"
       Whitespace@75..78 "\n  "
@@ -592,6 +674,80 @@ Root@0..87
         );
     }
 
+    #[test]
+    fn test_cursor_for_loop_with_cte() {
+        check(
+            parse(
+                "FOR rec IN (WITH o AS (SELECT id FROM orders) SELECT id FROM o) LOOP NULL; END LOOP;",
+                parse_loop,
+            ),
+            expect![[r#"
+Root@0..84
+  Loop@0..84
+    ForLoop@0..83
+      Keyword@0..3 "FOR"
+      Whitespace@3..4 " "
+      Iterator@4..63
+        IdentGroup@4..7
+          Ident@4..7 "rec"
+        Whitespace@7..8 " "
+        Keyword@8..10 "IN"
+        Whitespace@10..11 " "
+        IterationControl@11..63
+          LParen@11..12 "("
+          WithClause@12..45
+            Keyword@12..16 "WITH"
+            Whitespace@16..17 " "
+            SubqueryFactoringClause@17..45
+              IdentGroup@17..18
+                Ident@17..18 "o"
+              Whitespace@18..19 " "
+              Keyword@19..21 "AS"
+              Whitespace@21..22 " "
+              LParen@22..23 "("
+              SelectStmt@23..44
+                Keyword@23..29 "SELECT"
+                Whitespace@29..30 " "
+                SelectClause@30..32
+                  ColumnExpr@30..32
+                    IdentGroup@30..32
+                      Ident@30..32 "id"
+                Whitespace@32..33 " "
+                Keyword@33..37 "FROM"
+                Whitespace@37..38 " "
+                IdentGroup@38..44
+                  Ident@38..44 "orders"
+              RParen@44..45 ")"
+          Whitespace@45..46 " "
+          SelectStmt@46..62
+            Keyword@46..52 "SELECT"
+            Whitespace@52..53 " "
+            SelectClause@53..55
+              ColumnExpr@53..55
+                IdentGroup@53..55
+                  Ident@53..55 "id"
+            Whitespace@55..56 " "
+            Keyword@56..60 "FROM"
+            Whitespace@60..61 " "
+            IdentGroup@61..62
+              Ident@61..62 "o"
+          RParen@62..63 ")"
+      Whitespace@63..64 " "
+      Keyword@64..68 "LOOP"
+      Whitespace@68..69 " "
+      BlockStatement@69..74
+        Keyword@69..73 "NULL"
+        Semicolon@73..74 ";"
+      Whitespace@74..75 " "
+      Keyword@75..78 "END"
+      Whitespace@78..79 " "
+      Keyword@79..83 "LOOP"
+    Semicolon@83..84 ";"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn test_while_loop() {
         check(
@@ -609,14 +765,14 @@ Root@0..126
     WhileLoop@0..125
       Keyword@0..5 "WHILE"
       Whitespace@5..6 " "
-      Expression@6..23
+      Expression@6..20
         IdentGroup@6..15
           Ident@6..15 "n_counter"
         Whitespace@15..16 " "
         ComparisonOp@16..18 "<="
         Whitespace@18..19 " "
         Integer@19..20 "5"
-        Whitespace@20..23 "\n  "
+      Whitespace@20..23 "\n  "
       Keyword@23..27 "LOOP"
       Whitespace@27..32 "\n    "
       BlockStatement@32..82
@@ -627,16 +783,16 @@ Root@0..126
             Ident@44..52 "PUT_LINE"
           LParen@52..53 "("
           Whitespace@53..54 " "
-          ArgumentList@54..80
-            Argument@54..80
-              Expression@54..80
+          ArgumentList@54..79
+            Argument@54..79
+              Expression@54..79
                 QuotedLiteral@54..66 "'Counter : '"
                 Whitespace@66..67 " "
                 Concat@67..69 "||"
                 Whitespace@69..70 " "
                 IdentGroup@70..79
                   Ident@70..79 "n_counter"
-                Whitespace@79..80 " "
+          Whitespace@79..80 " "
           RParen@80..81 ")"
         Semicolon@81..82 ";"
       Whitespace@82..87 "\n    "
@@ -683,20 +839,20 @@ Root@0..146
     ForLoop@0..145
       Keyword@0..3 "FOR"
       Whitespace@3..4 " "
-      Iterator@4..25
+      Iterator@4..22
         IdentGroup@4..11
           Ident@4..11 "n_index"
         Whitespace@11..12 " "
         Keyword@12..14 "IN"
         Whitespace@14..15 " "
-        IterationControl@15..25
+        IterationControl@15..22
           IterRange@15..22 "1 .. 10"
-          Whitespace@22..25 "\n  "
+      Whitespace@22..25 "\n  "
       Keyword@25..29 "LOOP"
       Whitespace@29..34 "\n    "
       InlineComment@34..54 "-- skip even numbers"
       Whitespace@54..59 "\n    "
-      BlockStatement@59..102
+      BlockStatement@59..97
         ContinueStmt@59..97
           Keyword@59..67 "CONTINUE"
           Whitespace@67..70 "\n  "
@@ -708,23 +864,23 @@ Root@0..146
                 Ident@75..78 "MOD"
               LParen@78..79 "("
               Whitespace@79..80 " "
-              ArgumentList@80..91
+              ArgumentList@80..90
                 Argument@80..87
                   Expression@80..87
                     IdentGroup@80..87
                       Ident@80..87 "n_index"
                 Comma@87..88 ","
                 Whitespace@88..89 " "
-                Argument@89..91
+                Argument@89..90
                   Integer@89..90 "2"
-                  Whitespace@90..91 " "
+              Whitespace@90..91 " "
               RParen@91..92 ")"
             Whitespace@92..93 " "
             ComparisonOp@93..94 "="
             Whitespace@94..95 " "
             Integer@95..96 "0"
           Semicolon@96..97 ";"
-        Whitespace@97..102 "\n    "
+      Whitespace@97..102 "\n    "
       BlockStatement@102..134
         FunctionInvocation@102..133
           IdentGroup@102..122
@@ -733,11 +889,11 @@ Root@0..146
             Ident@114..122 "PUT_LINE"
           LParen@122..123 "("
           Whitespace@123..124 " "
-          ArgumentList@124..132
-            Argument@124..132
+          ArgumentList@124..131
+            Argument@124..131
               IdentGroup@124..131
                 Ident@124..131 "n_index"
-              Whitespace@131..132 " "
+          Whitespace@131..132 " "
           RParen@132..133 ")"
         Semicolon@133..134 ";"
       Whitespace@134..137 "\n  "
@@ -745,6 +901,189 @@ Root@0..146
       Whitespace@140..141 " "
       Keyword@141..145 "LOOP"
     Semicolon@145..146 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_for_loop_with_expression_bound() {
+        check(
+            parse("FOR i IN 1 .. l_ids.COUNT LOOP NULL; END LOOP;", parse_loop),
+            expect![[r#"
+Root@0..46
+  Loop@0..46
+    ForLoop@0..45
+      Keyword@0..3 "FOR"
+      Whitespace@3..4 " "
+      Iterator@4..25
+        IdentGroup@4..5
+          Ident@4..5 "i"
+        Whitespace@5..6 " "
+        Keyword@6..8 "IN"
+        Whitespace@8..9 " "
+        IterationControl@9..25
+          IterRange@9..14 "1 .. "
+          MethodInvocation@14..25
+            IdentGroup@14..19
+              Ident@14..19 "l_ids"
+            Dot@19..20 "."
+            Keyword@20..25 "COUNT"
+      Whitespace@25..26 " "
+      Keyword@26..30 "LOOP"
+      Whitespace@30..31 " "
+      BlockStatement@31..36
+        Keyword@31..35 "NULL"
+        Semicolon@35..36 ";"
+      Whitespace@36..37 " "
+      Keyword@37..40 "END"
+      Whitespace@40..41 " "
+      Keyword@41..45 "LOOP"
+    Semicolon@45..46 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_forall() {
+        check(
+            parse(
+                "FORALL i IN l_ids.FIRST..l_ids.LAST SAVE EXCEPTIONS
+    UPDATE emp SET sal = l_sal(i) WHERE id = l_ids(i);",
+                parse_forall,
+            ),
+            expect![[r#"
+Root@0..106
+  ForallStmt@0..106
+    Keyword@0..6 "FORALL"
+    Whitespace@6..7 " "
+    IdentGroup@7..8
+      Ident@7..8 "i"
+    Whitespace@8..9 " "
+    Keyword@9..11 "IN"
+    Whitespace@11..12 " "
+    IterationControl@12..35
+      MethodInvocation@12..23
+        IdentGroup@12..17
+          Ident@12..17 "l_ids"
+        Dot@17..18 "."
+        Keyword@18..23 "FIRST"
+      IterRange@23..25 ".."
+      MethodInvocation@25..35
+        IdentGroup@25..30
+          Ident@25..30 "l_ids"
+        Dot@30..31 "."
+        Keyword@31..35 "LAST"
+    Whitespace@35..36 " "
+    Keyword@36..40 "SAVE"
+    Whitespace@40..41 " "
+    Keyword@41..51 "EXCEPTIONS"
+    Whitespace@51..56 "\n    "
+    UpdateStmt@56..106
+      Keyword@56..62 "UPDATE"
+      Whitespace@62..63 " "
+      IdentGroup@63..66
+        Ident@63..66 "emp"
+      Whitespace@66..67 " "
+      SetClause@67..85
+        Keyword@67..70 "SET"
+        Whitespace@70..71 " "
+        AssignmentExpr@71..85
+          IdentGroup@71..74
+            Ident@71..74 "sal"
+          Whitespace@74..75 " "
+          ComparisonOp@75..76 "="
+          Whitespace@76..77 " "
+          FunctionInvocation@77..85
+            IdentGroup@77..82
+              Ident@77..82 "l_sal"
+            LParen@82..83 "("
+            ArgumentList@83..84
+              Argument@83..84
+                IdentGroup@83..84
+                  Ident@83..84 "i"
+            RParen@84..85 ")"
+      Whitespace@85..86 " "
+      WhereClause@86..105
+        Keyword@86..91 "WHERE"
+        Whitespace@91..92 " "
+        Expression@92..105
+          IdentGroup@92..94
+            Ident@92..94 "id"
+          Whitespace@94..95 " "
+          ComparisonOp@95..96 "="
+          Whitespace@96..97 " "
+          FunctionInvocation@97..105
+            IdentGroup@97..102
+              Ident@97..102 "l_ids"
+            LParen@102..103 "("
+            ArgumentList@103..104
+              Argument@103..104
+                IdentGroup@103..104
+                  Ident@103..104 "i"
+            RParen@104..105 ")"
+      Semicolon@105..106 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_forall_indices_of() {
+        check(
+            parse(
+                "FORALL i IN INDICES OF l_ids BETWEEN 1 AND 10 INSERT INTO t VALUES (l_ids(i));",
+                parse_forall,
+            ),
+            expect![[r#"
+Root@0..78
+  ForallStmt@0..78
+    Keyword@0..6 "FORALL"
+    Whitespace@6..7 " "
+    IdentGroup@7..8
+      Ident@7..8 "i"
+    Whitespace@8..9 " "
+    Keyword@9..11 "IN"
+    Whitespace@11..12 " "
+    IterationControl@12..45
+      Keyword@12..19 "INDICES"
+      Whitespace@19..20 " "
+      Keyword@20..22 "OF"
+      Whitespace@22..23 " "
+      IdentGroup@23..28
+        Ident@23..28 "l_ids"
+      Whitespace@28..29 " "
+      Keyword@29..36 "BETWEEN"
+      Whitespace@36..37 " "
+      Integer@37..38 "1"
+      Whitespace@38..39 " "
+      Keyword@39..42 "AND"
+      Whitespace@42..43 " "
+      Integer@43..45 "10"
+    Whitespace@45..46 " "
+    InsertStmt@46..78
+      Keyword@46..52 "INSERT"
+      Whitespace@52..53 " "
+      Keyword@53..57 "INTO"
+      Whitespace@57..58 " "
+      IdentGroup@58..59
+        Ident@58..59 "t"
+      Whitespace@59..60 " "
+      Keyword@60..66 "VALUES"
+      Whitespace@66..67 " "
+      LParen@67..68 "("
+      FunctionInvocation@68..76
+        IdentGroup@68..73
+          Ident@68..73 "l_ids"
+        LParen@73..74 "("
+        ArgumentList@74..75
+          Argument@74..75
+            IdentGroup@74..75
+              Ident@74..75 "i"
+        RParen@75..76 ")"
+      RParen@76..77 ")"
+      Semicolon@77..78 ";"
 "#]],
             vec![],
         );
@@ -0,0 +1,395 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements parsing of materialized views from a token tree.
+
+use crate::grammar::physical_properties::{at_ignored_clause, parse_ignored_clause};
+use crate::parser::Parser;
+use crate::ParseErrorType;
+use source_gen::lexer::TokenKind;
+use source_gen::syntax::SyntaxKind;
+
+use super::*;
+
+/// Parses a complete materialized view. Known physical properties are skipped,
+/// any others are flagged as unimplemented.
+/// Refer to https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/CREATE-MATERIALIZED-VIEW.html
+pub(crate) fn parse_materialized_view(p: &mut Parser) {
+    p.start(SyntaxKind::MaterializedView);
+
+    p.expect(T![create]);
+    p.expect(T![materialized]);
+    p.expect(T![view]);
+
+    if p.eat(T![if]) {
+        p.expect(T![not]);
+        p.expect(T![exists]);
+    }
+
+    parse_ident(p, 1..2);
+
+    if p.at(T!["("]) {
+        parse_column_list(p);
+    }
+
+    safe_loop!(p, {
+        match p.current() {
+            T![as] | T![EOF] => break,
+            T![on]
+                if p.nth_text(1)
+                    .is_some_and(|t| t.eq_ignore_ascii_case("prebuilt")) =>
+            {
+                p.bump_any();
+                p.bump_any_map(SyntaxKind::Keyword);
+                p.expect(T![table]);
+            }
+            T![enable] | T![disable] => parse_query_rewrite_clause(p),
+            _ if at_word(p, "build") => {
+                p.bump_any_map(SyntaxKind::Keyword);
+                p.expect_one_of(&[T![immediate], T![deferred]]);
+            }
+            _ if at_word(p, "refresh") || at_word(p, "never") => parse_refresh_clause(p),
+            _ if at_ignored_clause(p) => parse_ignored_clause(p),
+            _ => parse_physical_properties(p),
+        }
+    });
+
+    p.expect(T![as]);
+    if p.at(T![with]) {
+        parse_cte(p);
+    } else {
+        parse_query(p, false);
+    }
+
+    p.finish();
+}
+
+/// Parses `NEVER REFRESH` or `REFRESH` followed by any combination of the
+/// refresh method, mode and schedule.
+fn parse_refresh_clause(p: &mut Parser) {
+    p.start(SyntaxKind::RefreshClause);
+
+    if eat_word(p, "never") {
+        expect_word(p, "refresh");
+        p.finish();
+        return;
+    }
+
+    expect_word(p, "refresh");
+    safe_loop!(p, {
+        match p.current() {
+            T![force] => p.bump_any(),
+            T![on] => {
+                p.bump_any();
+                if !(p.eat(T![commit]) || eat_word(p, "demand") || eat_word(p, "statement")) {
+                    p.error(ParseErrorType::ExpectedOneOfTokens(vec![T![commit]]));
+                }
+            }
+            T![start] => {
+                p.bump_any();
+                p.expect(T![with]);
+                parse_expr(p);
+            }
+            T![with] => {
+                p.bump_any();
+                if p.eat(T![primary]) {
+                    p.expect(T![key]);
+                } else {
+                    p.expect(T![rowid]);
+                }
+            }
+            _ if eat_word(p, "fast") || eat_word(p, "complete") => {}
+            _ if eat_word(p, "next") => parse_expr(p),
+            _ => break,
+        }
+    });
+
+    p.finish();
+}
+
+/// Parses `{ ENABLE | DISABLE } [ ON QUERY COMPUTATION ] QUERY REWRITE`.
+fn parse_query_rewrite_clause(p: &mut Parser) {
+    p.expect_one_of(&[T![enable], T![disable]]);
+    if p.eat(T![on]) {
+        expect_word(p, "query");
+        expect_word(p, "computation");
+    }
+    expect_word(p, "query");
+    expect_word(p, "rewrite");
+}
+
+/// Skips unknown physical properties, e.g. `INMEMORY`, up to the next known
+/// clause, as they have no meaning for PostgreSQL anyway.
+fn parse_physical_properties(p: &mut Parser) {
+    p.start(SyntaxKind::UnsupportedClause);
+    p.error(ParseErrorType::Unimplemented(
+        "materialized view physical properties".to_string(),
+    ));
+
+    while !matches!(p.current(), T![as] | T![enable] | T![disable] | T![EOF])
+        && !["build", "never", "refresh"].iter().any(|w| at_word(p, w))
+        && !at_ignored_clause(p)
+    {
+        p.bump_any();
+    }
+
+    p.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::ParseError;
+
+    use super::super::tests::{check, parse};
+    use super::*;
+
+    #[test]
+    fn test_parse_materialized_view() {
+        check(
+            parse(
+                r#"CREATE MATERIALIZED VIEW sales_mv
+BUILD IMMEDIATE
+REFRESH FAST ON COMMIT WITH PRIMARY KEY
+ENABLE QUERY REWRITE
+AS SELECT region, SUM(amount) total FROM sales GROUP BY region;"#,
+                parse_materialized_view,
+            ),
+            expect![[r#"
+Root@0..174
+  MaterializedView@0..174
+    Keyword@0..6 "CREATE"
+    Whitespace@6..7 " "
+    Keyword@7..19 "MATERIALIZED"
+    Whitespace@19..20 " "
+    Keyword@20..24 "VIEW"
+    Whitespace@24..25 " "
+    IdentGroup@25..33
+      Ident@25..33 "sales_mv"
+    Whitespace@33..34 "\n"
+    Keyword@34..39 "BUILD"
+    Whitespace@39..40 " "
+    Keyword@40..49 "IMMEDIATE"
+    Whitespace@49..50 "\n"
+    RefreshClause@50..89
+      Keyword@50..57 "REFRESH"
+      Whitespace@57..58 " "
+      Keyword@58..62 "FAST"
+      Whitespace@62..63 " "
+      Keyword@63..65 "ON"
+      Whitespace@65..66 " "
+      Keyword@66..72 "COMMIT"
+      Whitespace@72..73 " "
+      Keyword@73..77 "WITH"
+      Whitespace@77..78 " "
+      Keyword@78..85 "PRIMARY"
+      Whitespace@85..86 " "
+      Keyword@86..89 "KEY"
+    Whitespace@89..90 "\n"
+    Keyword@90..96 "ENABLE"
+    Whitespace@96..97 " "
+    Keyword@97..102 "QUERY"
+    Whitespace@102..103 " "
+    Keyword@103..110 "REWRITE"
+    Whitespace@110..111 "\n"
+    Keyword@111..113 "AS"
+    Whitespace@113..114 " "
+    SelectStmt@114..174
+      Keyword@114..120 "SELECT"
+      Whitespace@120..121 " "
+      SelectClause@121..146
+        ColumnExpr@121..127
+          Expression@121..127
+            IdentGroup@121..127
+              Ident@121..127 "region"
+        Comma@127..128 ","
+        Whitespace@128..129 " "
+        ColumnExpr@129..146
+          FunctionInvocation@129..140
+            IdentGroup@129..132
+              Ident@129..132 "SUM"
+            LParen@132..133 "("
+            ArgumentList@133..139
+              Argument@133..139
+                IdentGroup@133..139
+                  Ident@133..139 "amount"
+            RParen@139..140 ")"
+          Whitespace@140..141 " "
+          Alias@141..146
+            Ident@141..146 "total"
+      Whitespace@146..147 " "
+      Keyword@147..151 "FROM"
+      Whitespace@151..152 " "
+      IdentGroup@152..157
+        Ident@152..157 "sales"
+      Whitespace@157..158 " "
+      GroupByClause@158..173
+        Keyword@158..163 "GROUP"
+        Whitespace@163..164 " "
+        Keyword@164..166 "BY"
+        Whitespace@166..167 " "
+        Expression@167..173
+          IdentGroup@167..173
+            Ident@167..173 "region"
+      Semicolon@173..174 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_materialized_view_with_schedule() {
+        check(
+            parse(
+                "CREATE MATERIALIZED VIEW hr.emp_mv (id, name) REFRESH COMPLETE START WITH SYSDATE NEXT SYSDATE + 1 AS SELECT id, name FROM emp",
+                parse_materialized_view,
+            ),
+            expect![[r#"
+Root@0..126
+  MaterializedView@0..126
+    Keyword@0..6 "CREATE"
+    Whitespace@6..7 " "
+    Keyword@7..19 "MATERIALIZED"
+    Whitespace@19..20 " "
+    Keyword@20..24 "VIEW"
+    Whitespace@24..25 " "
+    IdentGroup@25..34
+      Ident@25..27 "hr"
+      Dot@27..28 "."
+      Ident@28..34 "emp_mv"
+    Whitespace@34..35 " "
+    LParen@35..36 "("
+    IdentGroup@36..38
+      Ident@36..38 "id"
+    Comma@38..39 ","
+    Whitespace@39..40 " "
+    IdentGroup@40..44
+      Ident@40..44 "name"
+    RParen@44..45 ")"
+    Whitespace@45..46 " "
+    RefreshClause@46..98
+      Keyword@46..53 "REFRESH"
+      Whitespace@53..54 " "
+      Keyword@54..62 "COMPLETE"
+      Whitespace@62..63 " "
+      Keyword@63..68 "START"
+      Whitespace@68..69 " "
+      Keyword@69..73 "WITH"
+      Whitespace@73..74 " "
+      IdentGroup@74..81
+        Ident@74..81 "SYSDATE"
+      Whitespace@81..82 " "
+      Keyword@82..86 "NEXT"
+      Whitespace@86..87 " "
+      Expression@87..98
+        IdentGroup@87..94
+          Ident@87..94 "SYSDATE"
+        Whitespace@94..95 " "
+        ArithmeticOp@95..96 "+"
+        Whitespace@96..97 " "
+        Integer@97..98 "1"
+    Whitespace@98..99 " "
+    Keyword@99..101 "AS"
+    Whitespace@101..102 " "
+    SelectStmt@102..126
+      Keyword@102..108 "SELECT"
+      Whitespace@108..109 " "
+      SelectClause@109..117
+        ColumnExpr@109..111
+          Expression@109..111
+            IdentGroup@109..111
+              Ident@109..111 "id"
+        Comma@111..112 ","
+        Whitespace@112..113 " "
+        ColumnExpr@113..117
+          IdentGroup@113..117
+            Ident@113..117 "name"
+      Whitespace@117..118 " "
+      Keyword@118..122 "FROM"
+      Whitespace@122..123 " "
+      IdentGroup@123..126
+        Ident@123..126 "emp"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_materialized_view_with_physical_properties() {
+        check(
+            parse(
+                "CREATE MATERIALIZED VIEW sales_mv PCTFREE 0 TABLESPACE users STORAGE (INITIAL 1M NEXT 1M) NOLOGGING PARALLEL 4 INMEMORY AS SELECT * FROM sales",
+                parse_materialized_view,
+            ),
+            expect![[r#"
+Root@0..142
+  MaterializedView@0..142
+    Keyword@0..6 "CREATE"
+    Whitespace@6..7 " "
+    Keyword@7..19 "MATERIALIZED"
+    Whitespace@19..20 " "
+    Keyword@20..24 "VIEW"
+    Whitespace@24..25 " "
+    IdentGroup@25..33
+      Ident@25..33 "sales_mv"
+    Whitespace@33..34 " "
+    IgnoredClause@34..43
+      Keyword@34..41 "PCTFREE"
+      Whitespace@41..42 " "
+      Integer@42..43 "0"
+    Whitespace@43..44 " "
+    IgnoredClause@44..60
+      Keyword@44..54 "TABLESPACE"
+      Whitespace@54..55 " "
+      IdentGroup@55..60
+        Ident@55..60 "users"
+    Whitespace@60..61 " "
+    IgnoredClause@61..89
+      Keyword@61..68 "STORAGE"
+      Whitespace@68..69 " "
+      LParen@69..70 "("
+      Ident@70..77 "INITIAL"
+      Whitespace@77..78 " "
+      Integer@78..79 "1"
+      Ident@79..80 "M"
+      Whitespace@80..81 " "
+      Ident@81..85 "NEXT"
+      Whitespace@85..86 " "
+      Integer@86..87 "1"
+      Ident@87..88 "M"
+      RParen@88..89 ")"
+    Whitespace@89..90 " "
+    IgnoredClause@90..99
+      Keyword@90..99 "NOLOGGING"
+    Whitespace@99..100 " "
+    IgnoredClause@100..110
+      Keyword@100..108 "PARALLEL"
+      Whitespace@108..109 " "
+      Integer@109..110 "4"
+    Whitespace@110..111 " "
+    UnsupportedClause@111..119
+      Ident@111..119 "INMEMORY"
+    Whitespace@119..120 " "
+    Keyword@120..122 "AS"
+    Whitespace@122..123 " "
+    SelectStmt@123..142
+      Keyword@123..129 "SELECT"
+      Whitespace@129..130 " "
+      Asterisk@130..131 "*"
+      Whitespace@131..132 " "
+      Keyword@132..136 "FROM"
+      Whitespace@136..137 " "
+      IdentGroup@137..142
+        Ident@137..142 "sales"
+"#]],
+            vec![ParseError::new(
+                ParseErrorType::Unimplemented(
+                    "materialized view physical properties".to_string(),
+                ),
+                111..119,
+            )],
+        );
+    }
+}
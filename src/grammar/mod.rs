@@ -8,6 +8,7 @@
 use std::ops::Range;
 
 pub(crate) use block::*;
+pub(crate) use comment::*;
 pub(crate) use constraint::*;
 pub(crate) use cursor::*;
 pub(crate) use datatype::*;
@@ -16,10 +17,13 @@ pub(crate) use execute_immediate::*;
 pub(crate) use expressions::*;
 pub(crate) use function::*;
 pub(crate) use function_invocation::*;
+pub(crate) use materialized_view::*;
 pub(crate) use package::*;
+pub(crate) use pragma::*;
 pub(crate) use procedure::*;
 pub(crate) use query::*;
 pub(crate) use raise::*;
+pub(crate) use table::*;
 pub(crate) use trigger::*;
 pub(crate) use view::*;
 
@@ -32,6 +36,7 @@ use source_gen::T;
 mod block;
 mod call_spec;
 mod case;
+mod comment;
 mod commit;
 mod constraint;
 mod cursor;
@@ -44,11 +49,15 @@ mod expressions;
 mod function;
 mod function_invocation;
 mod loops;
+mod materialized_view;
 mod package;
+mod physical_properties;
+mod pragma;
 mod procedure;
 mod query;
 mod raise;
 mod sequence;
+mod table;
 mod trigger;
 mod udt;
 mod view;
@@ -110,7 +119,7 @@ fn parse_param(p: &mut Parser) {
 ///
 /// * `p`: The parser struct
 /// * `expected_components`: A range of the minimum and maximum expected components that should be present in the identifier.
-///     To allow an optional identifier, pass a range starting with `0`.
+///   To allow an optional identifier, pass a range starting with `0`.
 ///
 /// returns: ()
 ///
@@ -129,19 +138,21 @@ fn parse_ident(p: &mut Parser, expected_components: Range<u8>) {
     assert!(expected_components.end > 0);
     assert!(expected_components.start <= expected_components.end);
 
-    if expected_components.start == 0 && !p.current().is_ident() {
+    if expected_components.start == 0 && !p.at_soft_ident() {
         return;
     }
 
     p.start(SyntaxKind::IdentGroup);
 
-    parse_single_ident(p);
+    if p.at_correlation_name() {
+        p.bump_any_map(SyntaxKind::BindVar);
+    } else {
+        parse_single_ident(p);
+    }
 
     let mut i: u8 = 1;
     while i < expected_components.end {
-        if i < expected_components.start {
-            p.expect(T![.]);
-        } else if p.nth(0) != Some(T![.]) {
+        if i >= expected_components.start && p.nth(0) != Some(T![.]) {
             break;
         }
 
@@ -153,15 +164,63 @@ fn parse_ident(p: &mut Parser, expected_components: Range<u8>) {
     p.finish();
 }
 
+/// Parses the optional label or name after `END`. As only the terminating
+/// semicolon may follow, any keyword is accepted here.
+fn opt_end_label(p: &mut Parser) {
+    if p.current().is_ident() {
+        parse_ident(p, 1..1);
+    }
+}
+
+/// Checks whether the current token is an identifier with the given text,
+/// for words which are no keywords of their own.
+fn at_word(p: &mut Parser, word: &str) -> bool {
+    p.current().is_ident() && p.current_text().eq_ignore_ascii_case(word)
+}
+
+fn eat_word(p: &mut Parser, word: &str) -> bool {
+    let found = at_word(p, word);
+    if found {
+        p.bump_any_map(SyntaxKind::Keyword);
+    }
+    found
+}
+
+fn expect_word(p: &mut Parser, word: &str) {
+    if !eat_word(p, word) {
+        p.error(ParseErrorType::ExpectedIdent);
+    }
+}
+
 /// Helper function for [`parse_ident`]
 fn parse_single_ident(p: &mut Parser) {
-    if p.current().is_ident() {
-        if !p.eat(T![bind_var]) {
-            p.bump_any_map(SyntaxKind::Ident)
-        }
-    } else {
-        p.error(ParseErrorType::ExpectedIdent)
+    if !p.current().is_ident() {
+        p.error(ParseErrorType::ExpectedIdent);
+        return;
     }
+    if p.eat(T![bind_var]) {
+        return;
+    }
+
+    // SQL*Plus replaces substitution variables textually and ends their
+    // names at a `.`, which it drops. Thus `stage_&run_id.items` is a single
+    // identifier, whereas `&owner..orders` is a qualified one.
+    safe_loop!(p, {
+        let is_terminated = if p.at(T![substitution_var]) {
+            let is_terminated = p.current_text().ends_with('.');
+            p.bump(T![substitution_var]);
+            is_terminated
+        } else {
+            p.bump_any_map(SyntaxKind::Ident);
+            false
+        };
+
+        match p.adjacent() {
+            Some(T![substitution_var]) => {}
+            Some(kind) if is_terminated && kind.is_ident() => {}
+            _ => break,
+        }
+    });
 }
 
 /// Parses a column list, e.g. `(col1, col2)`
@@ -176,10 +235,28 @@ fn parse_column_list(p: &mut Parser) {
     p.expect(T![")"]);
 }
 
+/// Looks ahead for the unqualified name following `keyword`, e.g. the name of
+/// a procedure before its header is parsed.
+fn peek_name_after(p: &mut Parser, keyword: TokenKind) -> Option<String> {
+    let n = (0..8).find(|&n| p.nth(n) == Some(keyword))? + 1;
+    let n = if p.nth(n + 1) == Some(T![.]) {
+        n + 2
+    } else {
+        n
+    };
+
+    p.nth(n)
+        .filter(|t| t.is_ident())
+        .and_then(|_| p.nth_text(n))
+        .map(str::to_owned)
+}
+
 fn parse_ident_or_function_invocation(p: &mut Parser) {
-    if !opt_function_invocation(p) {
+    let checkpoint = p.checkpoint();
+    if !(opt_method_invocation(p) || opt_function_invocation(p)) {
         parse_ident(p, 1..3);
     }
+    parse_member_accesses(p, checkpoint);
 }
 
 #[cfg(test)]
@@ -318,9 +395,9 @@ Root@0..26
     IdentGroup@0..2
       Ident@0..2 "p2"
     Whitespace@2..3 " "
-    Datatype@3..12
+    Datatype@3..11
       Keyword@3..11 "VARCHAR2"
-      Whitespace@11..12 " "
+    Whitespace@11..12 " "
     Assign@12..14 ":="
     Whitespace@14..15 " "
     Expression@15..26
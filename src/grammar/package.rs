@@ -3,14 +3,18 @@
 // <office@cybertec.at>
 
 use crate::grammar::declare_section::parse_declare_section;
+use crate::grammar::udt::{
+    parse_accessible_by_clause, parse_invoker_rights_clause, parse_sharing_clause,
+};
 use crate::parser::Parser;
 use source_gen::syntax::SyntaxKind;
 use source_gen::T;
 
 use super::*;
 
-/// Parses a complete package.
-/// Refer to https://docs.oracle.com/en/database/oracle/oracle-database/23/lnpls/CREATE-PACKAGE-BODY-statement.html#GUID-68526FF2-96A1-4F14-A10B-4DD3E1CD80BE
+/// Parses a complete package specification or body.
+/// Refer to https://docs.oracle.com/en/database/oracle/oracle-database/23/lnpls/CREATE-PACKAGE-statement.html
+/// and https://docs.oracle.com/en/database/oracle/oracle-database/23/lnpls/CREATE-PACKAGE-BODY-statement.html#GUID-68526FF2-96A1-4F14-A10B-4DD3E1CD80BE
 pub(crate) fn parse_package(p: &mut Parser) {
     p.start(SyntaxKind::Package);
     parse_header(p);
@@ -18,7 +22,7 @@ pub(crate) fn parse_package(p: &mut Parser) {
     p.finish();
 }
 
-/// Parses the header of a package.
+/// Parses the header of a package specification or body.
 fn parse_header(p: &mut Parser) {
     p.expect(T![create]);
     if p.eat(T![or]) {
@@ -28,7 +32,7 @@ fn parse_header(p: &mut Parser) {
     p.eat_one_of(&[T![editionable], T![noneditionable]]);
 
     p.expect(T![package]);
-    p.expect(T![body]);
+    let is_body = p.eat(T![body]);
 
     if p.eat(T![if]) {
         p.expect(T![not]);
@@ -37,15 +41,25 @@ fn parse_header(p: &mut Parser) {
 
     parse_ident(p, 1..2);
 
-    if p.eat(T![sharing]) {
-        p.expect(T![=]);
-        p.expect_one_of(&[T![metadata], T![none]]);
+    if p.at(T![sharing]) {
+        parse_sharing_clause(p);
+    }
+
+    if !is_body {
+        safe_loop!(p, {
+            match p.current() {
+                T![accessible] => parse_accessible_by_clause(p),
+                T![authid] => parse_invoker_rights_clause(p),
+                _ => break,
+            }
+        });
     }
 
     p.expect_one_of(&[T![as], T![is]]);
 }
 
-/// Parses the body of a package.
+/// Parses the declarations and, for package bodies, the initialization
+/// section of a package.
 fn parse_body(p: &mut Parser) {
     parse_declare_section(p, None);
 
@@ -58,28 +72,13 @@ fn parse_body(p: &mut Parser) {
             }
         });
 
-        if p.eat(T![exception]) {
-            p.expect(T![when]);
-            if !p.eat(T![others]) {
-                safe_loop!(p, {
-                    parse_ident(p, 1..1);
-                    if !p.eat(T![or]) {
-                        break;
-                    }
-                });
-            }
-            p.expect(T![then]);
-            safe_loop!(p, {
-                parse_stmt(p);
-                if p.at(T![end]) {
-                    break;
-                }
-            });
+        if p.at(T![exception]) {
+            parse_exception_section(p);
         }
     }
 
     p.expect(T![end]);
-    parse_ident(p, 0..1);
+    opt_end_label(p);
     p.expect(T![;]);
 }
 
@@ -122,11 +121,12 @@ Root@0..99
     Dot@66..67 "."
     Ident@67..77 "my_package"
   Whitespace@77..78 " "
-  Keyword@78..85 "SHARING"
-  Whitespace@85..86 " "
-  ComparisonOp@86..87 "="
-  Whitespace@87..88 " "
-  Keyword@88..96 "METADATA"
+  SharingClause@78..96
+    Keyword@78..85 "SHARING"
+    Whitespace@85..86 " "
+    ComparisonOp@86..87 "="
+    Whitespace@87..88 " "
+    Keyword@88..96 "METADATA"
   Whitespace@96..97 " "
   Keyword@97..99 "AS"
 "#]],
@@ -155,9 +155,9 @@ Root@0..153
     Whitespace@34..35 " "
     Keyword@35..37 "AS"
     Whitespace@37..42 "\n    "
-    DeclareSection@42..143
-      Procedure@42..143
-        ProcedureHeader@42..72
+    DeclareSection@42..142
+      Procedure@42..142
+        ProcedureHeader@42..71
           Keyword@42..51 "PROCEDURE"
           Whitespace@51..52 " "
           IdentGroup@52..57
@@ -171,7 +171,7 @@ Root@0..153
               Datatype@62..70
                 Keyword@62..70 "varchar2"
             RParen@70..71 ")"
-          Whitespace@71..72 " "
+        Whitespace@71..72 " "
         Keyword@72..74 "IS"
         Whitespace@74..79 "\n    "
         Block@79..142
@@ -198,13 +198,66 @@ Root@0..153
           Whitespace@133..138 "\n    "
           Keyword@138..141 "END"
           Semicolon@141..142 ";"
-        Whitespace@142..143 "\n"
+    Whitespace@142..143 "\n"
     Keyword@143..146 "END"
     Whitespace@146..147 " "
     IdentGroup@147..151
       Ident@147..151 "util"
     Semicolon@151..152 ";"
   Whitespace@152..153 "\n"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn parse_util_package_spec() {
+        const INPUT: &str = include_str!("../../tests/package/util_spec.ora.sql");
+        check(
+            parse(INPUT, parse_package),
+            expect![[r#"
+Root@0..93
+  Package@0..92
+    Keyword@0..6 "CREATE"
+    Whitespace@6..7 " "
+    Keyword@7..14 "PACKAGE"
+    Whitespace@14..15 " "
+    IdentGroup@15..29
+      Ident@15..24 "northwind"
+      Dot@24..25 "."
+      Ident@25..29 "util"
+    Whitespace@29..30 " "
+    InvokerRightsClause@30..44
+      Keyword@30..36 "AUTHID"
+      Whitespace@36..37 " "
+      Keyword@37..44 "DEFINER"
+    Whitespace@44..45 " "
+    Keyword@45..47 "AS"
+    Whitespace@47..52 "\n    "
+    DeclareSection@52..82
+      Procedure@52..82
+        ProcedureHeader@52..81
+          Keyword@52..61 "PROCEDURE"
+          Whitespace@61..62 " "
+          IdentGroup@62..67
+            Ident@62..67 "print"
+          ParamList@67..81
+            LParen@67..68 "("
+            Param@68..80
+              IdentGroup@68..71
+                Ident@68..71 "str"
+              Whitespace@71..72 " "
+              Datatype@72..80
+                Keyword@72..80 "varchar2"
+            RParen@80..81 ")"
+        Semicolon@81..82 ";"
+    Whitespace@82..83 "\n"
+    Keyword@83..86 "END"
+    Whitespace@86..87 " "
+    IdentGroup@87..91
+      Ident@87..91 "util"
+    Semicolon@91..92 ";"
+  Whitespace@92..93 "\n"
 "#]],
             vec![],
         );
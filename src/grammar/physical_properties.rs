@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements parsing of physical properties, which only affect the storage
+//! in Oracle and have no meaning for PostgreSQL.
+
+use crate::parser::Parser;
+use source_gen::lexer::TokenKind;
+use source_gen::syntax::SyntaxKind;
+use source_gen::T;
+
+use super::{at_word, eat_word, expect_word, parse_ident};
+
+/// Physical properties which are no keywords of their own.
+const PROPERTY_WORDS: &[&str] = &[
+    "compress",
+    "initrans",
+    "logging",
+    "maxtrans",
+    "nocompress",
+    "nologging",
+    "noparallel",
+    "parallel",
+    "pctfree",
+    "pctused",
+    "segment",
+    "storage",
+    "tablespace",
+];
+
+/// Checks whether a physical property starts at the current token.
+pub(crate) fn at_ignored_clause(p: &mut Parser) -> bool {
+    matches!(p.current(), T![cache] | T![nocache]) || PROPERTY_WORDS.iter().any(|w| at_word(p, w))
+}
+
+/// Parses a single physical property, e.g. `PARALLEL 4`, `NOLOGGING` or
+/// `STORAGE (INITIAL 64K)`, which is skipped for PostgreSQL.
+pub(crate) fn parse_ignored_clause(p: &mut Parser) {
+    p.start(SyntaxKind::IgnoredClause);
+
+    match p.current() {
+        T![cache] | T![nocache] => p.bump_any(),
+        _ if eat_word(p, "parallel") => {
+            p.eat(T![int_literal]);
+        }
+        _ if eat_word(p, "tablespace") => parse_ident(p, 1..1),
+        _ if eat_word(p, "storage") => skip_parenthesized(p),
+        _ if ["initrans", "maxtrans", "pctfree", "pctused"]
+            .iter()
+            .any(|w| eat_word(p, w)) =>
+        {
+            p.expect(T![int_literal]);
+        }
+        _ if eat_word(p, "compress") => parse_compression(p),
+        _ if eat_word(p, "segment") => {
+            expect_word(p, "creation");
+            p.expect_one_of(&[T![immediate], T![deferred]]);
+        }
+        _ => p.bump_any_map(SyntaxKind::Keyword),
+    }
+
+    p.finish();
+}
+
+/// Parses the options after `COMPRESS`, e.g. `FOR QUERY HIGH` or `BASIC`.
+fn parse_compression(p: &mut Parser) {
+    if p.eat(T![int_literal]) {
+        return;
+    }
+
+    if p.eat(T![for]) {
+        if !p.eat(T![all]) {
+            p.bump_any_map(SyntaxKind::Keyword);
+        }
+        let _ = eat_word(p, "operations") || eat_word(p, "low") || eat_word(p, "high");
+    } else {
+        let _ = eat_word(p, "basic") || eat_word(p, "advanced");
+    }
+}
+
+/// Skips a parenthesized list of options, including nested parentheses.
+pub(crate) fn skip_parenthesized(p: &mut Parser) {
+    p.expect(T!["("]);
+
+    let mut depth = 0;
+    loop {
+        match p.current() {
+            T![EOF] => break,
+            T![")"] if depth == 0 => break,
+            T!["("] => depth += 1,
+            T![")"] => depth -= 1,
+            _ => {}
+        }
+        p.bump_any();
+    }
+
+    p.expect(T![")"]);
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements parsing of PL/SQL pragmas from a token tree.
+
+use crate::grammar::{parse_argument_list, parse_ident};
+use crate::parser::Parser;
+use source_gen::lexer::TokenKind;
+use source_gen::syntax::SyntaxKind;
+use source_gen::T;
+
+/// Parses any pragma, e.g. `PRAGMA AUTONOMOUS_TRANSACTION` or
+/// `PRAGMA INLINE(calc, 'YES')`, in a declare section or before a statement.
+/// Refer to https://docs.oracle.com/en/database/oracle/oracle-database/23/lnpls/INLINE-pragma.html
+pub(crate) fn parse_pragma(p: &mut Parser) {
+    p.start(SyntaxKind::PragmaStmt);
+    p.expect(T![pragma]);
+    parse_ident(p, 1..1);
+    if p.at(T!["("]) {
+        parse_argument_list(p);
+    }
+    p.expect(T![;]);
+    p.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use super::super::tests::{check, parse};
+    use super::*;
+
+    #[test]
+    fn test_parse_inline_pragma() {
+        check(
+            parse("PRAGMA INLINE(calc_bonus, 'YES');", parse_pragma),
+            expect![[r#"
+Root@0..33
+  PragmaStmt@0..33
+    Keyword@0..6 "PRAGMA"
+    Whitespace@6..7 " "
+    IdentGroup@7..13
+      Ident@7..13 "INLINE"
+    LParen@13..14 "("
+    ArgumentList@14..31
+      Argument@14..24
+        Expression@14..24
+          IdentGroup@14..24
+            Ident@14..24 "calc_bonus"
+      Comma@24..25 ","
+      Whitespace@25..26 " "
+      Argument@26..31
+        QuotedLiteral@26..31 "'YES'"
+    RParen@31..32 ")"
+    Semicolon@32..33 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_deprecate_pragma() {
+        check(
+            parse(
+                "PRAGMA DEPRECATE(old_api, 'Use new_api instead');",
+                parse_pragma,
+            ),
+            expect![[r#"
+Root@0..49
+  PragmaStmt@0..49
+    Keyword@0..6 "PRAGMA"
+    Whitespace@6..7 " "
+    IdentGroup@7..16
+      Ident@7..16 "DEPRECATE"
+    LParen@16..17 "("
+    ArgumentList@17..47
+      Argument@17..24
+        Expression@17..24
+          IdentGroup@17..24
+            Ident@17..24 "old_api"
+      Comma@24..25 ","
+      Whitespace@25..26 " "
+      Argument@26..47
+        QuotedLiteral@26..47 "'Use new_api instead'"
+    RParen@47..48 ")"
+    Semicolon@48..49 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_pragma_without_arguments() {
+        check(
+            parse("PRAGMA AUTONOMOUS_TRANSACTION;", parse_pragma),
+            expect![[r#"
+Root@0..30
+  PragmaStmt@0..30
+    Keyword@0..6 "PRAGMA"
+    Whitespace@6..7 " "
+    IdentGroup@7..29
+      Ident@7..29 "AUTONOMOUS_TRANSACTION"
+    Semicolon@29..30 ";"
+"#]],
+            vec![],
+        );
+    }
+}
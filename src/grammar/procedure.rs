@@ -6,7 +6,10 @@
 //! Implements parsing of procedures from a token tree.
 
 use crate::grammar::call_spec::opt_call_spec;
-use crate::parser::Parser;
+use crate::grammar::udt::{
+    parse_accessible_by_clause, parse_invoker_rights_clause, parse_sharing_clause,
+};
+use crate::parser::{safe_loop, Parser};
 use source_gen::lexer::TokenKind;
 use source_gen::syntax::SyntaxKind;
 
@@ -15,8 +18,14 @@ use super::*;
 /// Parses a complete procedure.
 pub(crate) fn parse_procedure(p: &mut Parser, is_nested: bool) {
     p.start(SyntaxKind::Procedure);
+    let name = peek_name_after(p, T![procedure]);
     parse_header(p, is_nested);
-    parse_body(p);
+
+    // Nested subprograms may be forward-declared without a body
+    if !(is_nested && p.eat(T![;])) {
+        parse_body(p, name.as_deref());
+    }
+
     p.finish();
 }
 
@@ -36,17 +45,33 @@ fn parse_header(p: &mut Parser, is_nested: bool) {
     p.expect(T![procedure]);
 
     parse_ident(p, 1..2);
+    if p.at(T![sharing]) {
+        parse_sharing_clause(p);
+    }
     parse_param_list(p);
+    parse_attributes(p);
     p.finish();
 }
 
+/// Parses the properties between the parameters and the body, see
+/// <https://docs.oracle.com/en/database/oracle/oracle-database/21/lnpls/CREATE-PROCEDURE-statement.html>.
+fn parse_attributes(p: &mut Parser) {
+    safe_loop!(p, {
+        match p.current() {
+            T![accessible] => parse_accessible_by_clause(p),
+            T![authid] => parse_invoker_rights_clause(p),
+            _ => break,
+        }
+    });
+}
+
 /// Parses the body of a procedure.
-fn parse_body(p: &mut Parser) {
+fn parse_body(p: &mut Parser, name: Option<&str>) {
     p.expect_one_of(&[T![is], T![as]]);
     p.eat(T!["$$"]);
 
     if !opt_call_spec(p) {
-        parse_block(p);
+        parse_named_block(p, name);
     }
 
     p.eat(T!["$$"]);
@@ -61,7 +86,8 @@ mod tests {
     use expect_test::expect;
 
     use crate::ParseError;
-    use crate::ParseErrorType::ExpectedToken;
+    use crate::ParseErrorType::{ExpectedToken, MismatchedEndLabel, UnterminatedLiteral};
+    use crate::{parse_procedure_with_options, ParseOptions};
     use source_gen::lexer::TokenKind::ProcedureKw;
 
     use super::super::tests::{check, parse};
@@ -147,11 +173,11 @@ Root@0..146
     ParamList@38..146
       LParen@38..39 "("
       Whitespace@39..41 "  "
-      Param@41..93
+      Param@41..87
         IdentGroup@41..49
           Ident@41..49 "p_emp_id"
         Whitespace@49..59 "          "
-        Datatype@59..93
+        Datatype@59..87
           IdentGroup@59..82
             Ident@59..70 "job_history"
             Dot@70..71 "."
@@ -159,14 +185,14 @@ Root@0..146
           TypeAttribute@82..87
             Percentage@82..83 "%"
             Keyword@83..87 "type"
-          Whitespace@87..93 "\n     "
+      Whitespace@87..93 "\n     "
       Comma@93..94 ","
       Whitespace@94..95 " "
-      Param@95..145
+      Param@95..140
         IdentGroup@95..107
           Ident@95..107 "p_start_date"
         Whitespace@107..113 "      "
-        Datatype@113..145
+        Datatype@113..140
           IdentGroup@113..135
             Ident@113..124 "job_history"
             Dot@124..125 "."
@@ -174,13 +200,60 @@ Root@0..146
           TypeAttribute@135..140
             Percentage@135..136 "%"
             Keyword@136..140 "type"
-          Whitespace@140..145 "\n    "
+      Whitespace@140..145 "\n    "
       RParen@145..146 ")"
 "#]],
             vec![],
         );
     }
 
+    #[test]
+    fn test_parse_header_with_attributes() {
+        const INPUT: &str =
+            "CREATE PROCEDURE purge_logs (days NUMBER) AUTHID CURRENT_USER ACCESSIBLE BY (PROCEDURE maintenance)";
+        check(
+            parse(INPUT, |p| parse_header(p, false)),
+            expect![[r#"
+Root@0..99
+  ProcedureHeader@0..99
+    Keyword@0..6 "CREATE"
+    Whitespace@6..7 " "
+    Keyword@7..16 "PROCEDURE"
+    Whitespace@16..17 " "
+    IdentGroup@17..27
+      Ident@17..27 "purge_logs"
+    Whitespace@27..28 " "
+    ParamList@28..41
+      LParen@28..29 "("
+      Param@29..40
+        IdentGroup@29..33
+          Ident@29..33 "days"
+        Whitespace@33..34 " "
+        Datatype@34..40
+          Keyword@34..40 "NUMBER"
+      RParen@40..41 ")"
+    Whitespace@41..42 " "
+    InvokerRightsClause@42..61
+      Keyword@42..48 "AUTHID"
+      Whitespace@48..49 " "
+      Keyword@49..61 "CURRENT_USER"
+    Whitespace@61..62 " "
+    AccessibleByClause@62..99
+      Keyword@62..72 "ACCESSIBLE"
+      Whitespace@72..73 " "
+      Keyword@73..75 "BY"
+      Whitespace@75..76 " "
+      LParen@76..77 "("
+      Keyword@77..86 "PROCEDURE"
+      Whitespace@86..87 " "
+      IdentGroup@87..98
+        Ident@87..98 "maintenance"
+      RParen@98..99 ")"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn test_parse_body() {
         const INPUT: &str = r#"
@@ -190,7 +263,7 @@ BEGIN
 END hello;
 "#;
         check(
-            parse(INPUT, parse_body),
+            parse(INPUT, |p| parse_body(p, None)),
             expect![[r#"
 Root@0..31
   Whitespace@0..1 "\n"
@@ -221,15 +294,15 @@ Root@0..31
             parse(INPUT, |p| parse_procedure(p, false)),
             expect![[r#"
 Root@0..98
-  Procedure@0..98
-    ProcedureHeader@0..41
+  Procedure@0..97
+    ProcedureHeader@0..40
       Keyword@0..6 "CREATE"
       Whitespace@6..7 " "
       Keyword@7..16 "PROCEDURE"
       Whitespace@16..17 " "
       IdentGroup@17..40
         Ident@17..40 "\"读文👩🏼\u{200d}🔬\""
-      Whitespace@40..41 "\n"
+    Whitespace@40..41 "\n"
     Keyword@41..43 "IS"
     Whitespace@43..44 " "
     Block@44..97
@@ -246,7 +319,7 @@ Root@0..98
       IdentGroup@73..96
         Ident@73..96 "\"读文👩🏼\u{200d}🔬\""
       Semicolon@96..97 ";"
-    Whitespace@97..98 "\n"
+  Whitespace@97..98 "\n"
 "#]],
             vec![],
         );
@@ -261,8 +334,8 @@ Root@0..98
 Root@0..124
   InlineComment@0..58 "-- test: Qualify the  ..."
   Whitespace@58..59 "\n"
-  Procedure@59..124
-    ProcedureHeader@59..100
+  Procedure@59..123
+    ProcedureHeader@59..99
       Keyword@59..65 "CREATE"
       Whitespace@65..66 " "
       Keyword@66..75 "PROCEDURE"
@@ -271,7 +344,7 @@ Root@0..124
         Ident@76..94 "\"alternate_SCHEMA\""
         Dot@94..95 "."
         Ident@95..99 "proc"
-      Whitespace@99..100 "\n"
+    Whitespace@99..100 "\n"
     Keyword@100..102 "IS"
     Whitespace@102..103 "\n"
     Block@103..123
@@ -283,12 +356,65 @@ Root@0..124
       Whitespace@118..119 "\n"
       Keyword@119..122 "END"
       Semicolon@122..123 ";"
-    Whitespace@123..124 "\n"
+  Whitespace@123..124 "\n"
 "#]],
             vec![],
         );
     }
 
+    #[test]
+    fn test_parse_procedure_with_mismatched_end_label() {
+        const INPUT: &str = "CREATE PROCEDURE hr.add_job IS BEGIN NULL; END add_jobs;";
+        let result = parse(INPUT, |p| parse_procedure(p, false));
+        assert!(result.ok());
+        assert_eq!(
+            result.errors,
+            vec![ParseError::new(
+                MismatchedEndLabel("add_job".to_owned(), "add_jobs".to_owned()),
+                47..55,
+            )]
+        );
+
+        let result = parse(
+            "CREATE PROCEDURE hr.add_job IS BEGIN NULL; END ADD_JOB;",
+            |p| parse_procedure(p, false),
+        );
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+    }
+
+    #[test]
+    fn test_parse_procedure_with_unterminated_literal() {
+        const INPUT: &str = r#"CREATE PROCEDURE purge IS
+BEGIN
+    log('purging);
+    DELETE FROM orders;
+    log('done');
+END purge;"#;
+        let has_delete = |result: &crate::Parse| {
+            result
+                .syntax()
+                .descendants()
+                .any(|n| n.kind() == SyntaxKind::DeleteStmt)
+        };
+
+        // The literal ends with the next quote, swallowing the `DELETE`
+        let result = parse_procedure_with_options(INPUT, ParseOptions::default()).unwrap();
+        assert!(!has_delete(&result));
+
+        let options = ParseOptions {
+            single_line_literals: true,
+        };
+        let result = parse_procedure_with_options(INPUT, options).unwrap();
+        assert!(has_delete(&result));
+        assert_eq!(
+            result.errors,
+            vec![ParseError::new(
+                UnterminatedLiteral("'purging);".to_owned()),
+                40..50,
+            )]
+        );
+    }
+
     #[test]
     fn test_parse_pg_procedure() {
         const INPUT: &str = include_str!("../../tests/fixtures/secure_dml.pg.sql");
@@ -297,8 +423,8 @@ Root@0..124
             parse(INPUT, |p| parse_procedure(p, false)),
             expect![[r#"
 Root@0..304
-  Procedure@0..304
-    ProcedureHeader@0..30
+  Procedure@0..303
+    ProcedureHeader@0..29
       Keyword@0..6 "CREATE"
       Whitespace@6..7 " "
       Keyword@7..16 "PROCEDURE"
@@ -308,7 +434,7 @@ Root@0..304
       ParamList@27..29
         LParen@27..28 "("
         RParen@28..29 ")"
-      Whitespace@29..30 "\n"
+    Whitespace@29..30 "\n"
     Keyword@30..32 "AS"
     Whitespace@32..33 " "
     DollarQuote@33..35 "$$"
@@ -408,7 +534,7 @@ Root@0..304
     Whitespace@294..295 " "
     Keyword@295..302 "plpgsql"
     Semicolon@302..303 ";"
-    Whitespace@303..304 "\n"
+  Whitespace@303..304 "\n"
 "#]],
             vec![],
         );
@@ -425,8 +551,8 @@ Root@0..304
 Root@0..176
   InlineComment@0..73 "-- test: ignore EDITI ..."
   Whitespace@73..74 "\n"
-  Procedure@74..176
-    ProcedureHeader@74..133
+  Procedure@74..175
+    ProcedureHeader@74..132
       Keyword@74..80 "CREATE"
       Whitespace@80..81 " "
       Keyword@81..83 "OR"
@@ -439,7 +565,7 @@ Root@0..176
       Whitespace@113..114 " "
       IdentGroup@114..132
         Ident@114..132 "ignore_editionable"
-      Whitespace@132..133 "\n"
+    Whitespace@132..133 "\n"
     Keyword@133..135 "IS"
     Whitespace@135..136 "\n"
     Block@136..175
@@ -454,7 +580,7 @@ Root@0..176
       IdentGroup@156..174
         Ident@156..174 "ignore_editionable"
       Semicolon@174..175 ";"
-    Whitespace@175..176 "\n"
+  Whitespace@175..176 "\n"
 "#]],
             vec![],
         );
@@ -471,8 +597,8 @@ Root@0..176
 Root@0..193
   InlineComment@0..81 "-- test: ignore NONED ..."
   Whitespace@81..82 "\n"
-  Procedure@82..193
-    ProcedureHeader@82..147
+  Procedure@82..192
+    ProcedureHeader@82..146
       Keyword@82..88 "CREATE"
       Whitespace@88..89 " "
       Keyword@89..91 "OR"
@@ -485,7 +611,7 @@ Root@0..193
       Whitespace@124..125 " "
       IdentGroup@125..146
         Ident@125..146 "ignore_noneditionable"
-      Whitespace@146..147 "\n"
+    Whitespace@146..147 "\n"
     Keyword@147..149 "IS"
     Whitespace@149..150 "\n"
     Block@150..192
@@ -500,7 +626,93 @@ Root@0..193
       IdentGroup@170..191
         Ident@170..191 "ignore_noneditionable"
       Semicolon@191..192 ";"
-    Whitespace@192..193 "\n"
+  Whitespace@192..193 "\n"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_sharing_and_accessible_by_procedure() {
+        const INPUT: &str =
+            include_str!("../../tests/procedure/heading/ignore_sharing_accessible_by.ora.sql");
+
+        check(
+            parse(INPUT, |p| parse_procedure(p, false)),
+            expect![[r#"
+Root@0..297
+  InlineComment@0..87 "-- test: ignore SHARI ..."
+  Whitespace@87..88 "\n"
+  Procedure@88..296
+    ProcedureHeader@88..243
+      Keyword@88..94 "CREATE"
+      Whitespace@94..95 " "
+      Keyword@95..97 "OR"
+      Whitespace@97..98 " "
+      Keyword@98..105 "REPLACE"
+      Whitespace@105..106 " "
+      Keyword@106..115 "PROCEDURE"
+      Whitespace@115..116 " "
+      IdentGroup@116..144
+        Ident@116..144 "ignore_sharing_access ..."
+      Whitespace@144..145 " "
+      SharingClause@145..163
+        Keyword@145..152 "SHARING"
+        Whitespace@152..153 " "
+        ComparisonOp@153..154 "="
+        Whitespace@154..155 " "
+        Keyword@155..163 "METADATA"
+      Whitespace@163..164 " "
+      ParamList@164..177
+        LParen@164..165 "("
+        Param@165..176
+          IdentGroup@165..169
+            Ident@165..169 "p_id"
+          Whitespace@169..170 " "
+          Datatype@170..176
+            Keyword@170..176 "NUMBER"
+        RParen@176..177 ")"
+      Whitespace@177..178 "\n"
+      AccessibleByClause@178..228
+        Keyword@178..188 "ACCESSIBLE"
+        Whitespace@188..189 " "
+        Keyword@189..191 "BY"
+        Whitespace@191..192 " "
+        LParen@192..193 "("
+        Keyword@193..200 "PACKAGE"
+        Whitespace@200..201 " "
+        IdentGroup@201..211
+          Ident@201..203 "hr"
+          Dot@203..204 "."
+          Ident@204..211 "emp_api"
+        Comma@211..212 ","
+        Whitespace@212..213 " "
+        Keyword@213..222 "PROCEDURE"
+        Whitespace@222..223 " "
+        IdentGroup@223..227
+          Ident@223..227 "hire"
+        RParen@227..228 ")"
+      Whitespace@228..229 "\n"
+      InvokerRightsClause@229..243
+        Keyword@229..235 "AUTHID"
+        Whitespace@235..236 " "
+        Keyword@236..243 "DEFINER"
+    Whitespace@243..244 "\n"
+    Keyword@244..246 "IS"
+    Whitespace@246..247 "\n"
+    Block@247..296
+      Keyword@247..252 "BEGIN"
+      Whitespace@252..257 "\n    "
+      BlockStatement@257..262
+        Keyword@257..261 "NULL"
+        Semicolon@261..262 ";"
+      Whitespace@262..263 "\n"
+      Keyword@263..266 "END"
+      Whitespace@266..267 " "
+      IdentGroup@267..295
+        Ident@267..295 "ignore_sharing_access ..."
+      Semicolon@295..296 ";"
+  Whitespace@296..297 "\n"
 "#]],
             vec![],
         );
@@ -4,20 +4,63 @@
 
 //! Implements parsing of procedures from a token tree.
 
-use crate::grammar::{opt_expr, parse_expr, parse_function, parse_ident, parse_procedure};
+use crate::grammar::{
+    at_word, eat_word, opt_expr, opt_function_invocation, opt_returning_clause,
+    parse_bulk_into_clause, parse_expr, parse_function, parse_ident, parse_procedure,
+};
 use crate::parser::{safe_loop, Parser};
+use crate::ParseErrorType;
 use source_gen::lexer::TokenKind;
 use source_gen::syntax::SyntaxKind;
 use source_gen::T;
 
 pub(crate) fn parse_query(p: &mut Parser, expect_into_clause: bool) {
     p.start(SyntaxKind::SelectStmt);
+    p.enter_sql();
+    parse_query_block(p, expect_into_clause);
+
+    // Each further query becomes a child of the first one, while `ORDER BY`
+    // applies to all of them
+    while at_word(p, "union") || at_word(p, "intersect") || at_word(p, "minus") {
+        p.bump_any_map(SyntaxKind::Keyword);
+        p.eat(T![all]);
+        if p.eat(T!["("]) {
+            parse_query(p, false);
+            if !p.expect(T![")"]) {
+                p.error(ParseErrorType::UnbalancedParens);
+            }
+        } else {
+            p.start(SyntaxKind::SelectStmt);
+            parse_query_block(p, false);
+            p.finish();
+        }
+    }
+
+    if p.at(T![order]) {
+        parse_order_by_clause(p);
+    }
+
+    p.eat(T![;]);
+    p.leave_sql();
+    p.finish();
+}
+
+/// Parses a single `SELECT .. FROM ..` up to an `ORDER BY` or a set operator.
+fn parse_query_block(p: &mut Parser, expect_into_clause: bool) {
     p.expect(T![select]);
     parse_column_expr(p);
-    parse_into_clause(p, expect_into_clause);
+    if p.at(T![bulk]) {
+        parse_bulk_into_clause(p);
+    } else {
+        parse_into_clause(p, expect_into_clause);
+    }
     p.expect(T![from]);
     parse_from_list(p);
 
+    if p.at(T![pivot]) || p.at(T![unpivot]) {
+        parse_unsupported_clause(p);
+    }
+
     if p.at(T![where]) {
         parse_where_clause(p);
     }
@@ -32,14 +75,110 @@ pub(crate) fn parse_query(p: &mut Parser, expect_into_clause: bool) {
         parse_group_by_clause(p);
     }
 
-    if p.at(T![order]) {
-        parse_order_by_clause(p);
+    if p.at(T![model]) {
+        parse_unsupported_clause(p);
+    }
+}
+
+/// Returns whether the parser is at a `SAMPLE`, `PARTITION` or `AS OF` clause
+/// following a table in the `FROM` list.
+fn at_table_clause(p: &mut Parser) -> bool {
+    (at_word(p, "sample") && matches!(p.nth(1), Some(T!["("]) | Some(T![unquoted_ident])))
+        || (p.at(T![as]) && p.nth(1) == Some(T![of]))
+        || ((p.at(T![partition]) || at_word(p, "subpartition"))
+            && matches!(p.nth(1), Some(T!["("] | T![for])))
+}
+
+/// Skips a `PIVOT`, `UNPIVOT`, `MODEL`, `SAMPLE`, partition extension
+/// (`PARTITION (p1)`) or flashback query (`AS OF`) clause as a single node and
+/// flags it as unimplemented, so the rest of the query can still be analyzed.
+fn parse_unsupported_clause(p: &mut Parser) {
+    p.start(SyntaxKind::UnsupportedClause);
+
+    let construct = match p.current() {
+        T![pivot] => "PIVOT clause",
+        T![unpivot] => "UNPIVOT clause",
+        T![model] => "MODEL clause",
+        T![as] => "flashback query clause",
+        T![partition] => "partition extension clause",
+        _ if at_word(p, "subpartition") => "partition extension clause",
+        _ => "SAMPLE clause",
+    };
+    p.error(ParseErrorType::Unimplemented(construct.to_string()));
+
+    match p.current() {
+        T![as] => {
+            p.bump_any();
+            p.expect(T![of]);
+            if !(p.eat(T![timestamp]) || eat_word(p, "scn")) {
+                p.expect(T![timestamp]);
+            }
+            parse_expr(p);
+        }
+        T![partition] => {
+            p.bump_any();
+            p.eat(T![for]);
+            skip_parenthesized(p);
+        }
+        _ if eat_word(p, "subpartition") => {
+            p.eat(T![for]);
+            skip_parenthesized(p);
+        }
+        _ if eat_word(p, "sample") => {
+            eat_word(p, "block");
+            skip_parenthesized(p);
+            if eat_word(p, "seed") {
+                skip_parenthesized(p);
+            }
+        }
+        T![pivot] => {
+            p.bump_any();
+            p.eat(T![xml]);
+            skip_parenthesized(p);
+        }
+        T![unpivot] => {
+            p.bump_any();
+            if p.eat_one_of(&[T![include], T![exclude]]) {
+                p.expect(T![nulls]);
+            }
+            skip_parenthesized(p);
+        }
+        _ => {
+            p.expect(T![model]);
+            while !matches!(p.current(), T![order] | T![;] | T![")"] | T![EOF]) {
+                if p.at(T!["("]) {
+                    skip_parenthesized(p);
+                } else {
+                    p.bump_any();
+                }
+            }
+        }
+    }
+
+    if matches!(p.current(), T![unquoted_ident] | T![quoted_ident]) {
+        parse_alias(p);
     }
 
-    p.eat(T![;]);
     p.finish();
 }
 
+/// Consumes a balanced pair of parentheses, including everything in between.
+fn skip_parenthesized(p: &mut Parser) {
+    if !p.expect(T!["("]) {
+        return;
+    }
+
+    let mut depth = 1;
+    while depth > 0 && !p.at(T![EOF]) {
+        match p.current() {
+            T!["("] => depth += 1,
+            T![")"] => depth -= 1,
+            _ => {}
+        }
+        p.bump_any();
+    }
+}
+
 pub(crate) fn parse_cte(p: &mut Parser) {
     p.start(SyntaxKind::WithClause);
     p.expect(T![with]);
@@ -207,6 +346,11 @@ pub(crate) fn parse_subquery_factoring_clause(p: &mut Parser) {
         p.eat(T![")"]);
     }
     p.expect(T![as]);
+    if p.eat(T![not]) {
+        p.expect(T![materialized]);
+    } else {
+        p.eat(T![materialized]);
+    }
     p.expect(T!["("]);
     if p.nth(1) != Some(T![values]) {
         parse_query(p, false);
@@ -304,7 +448,7 @@ pub(crate) fn parse_cycle_clause(p: &mut Parser) {
     });
     p.expect(T![set]);
     parse_ident(p, 1..1);
-    p.expect(T![set]);
+    p.expect(T![to]);
     parse_expr(p);
     p.expect(T![default]);
     parse_expr(p);
@@ -338,6 +482,7 @@ pub(crate) fn parse_starts_with(p: &mut Parser) {
 
 pub(crate) fn parse_insert(p: &mut Parser) {
     p.start(SyntaxKind::InsertStmt);
+    p.enter_sql();
     p.expect(T![insert]);
     p.expect(T![into]);
     parse_ident(p, 1..2);
@@ -353,37 +498,39 @@ pub(crate) fn parse_insert(p: &mut Parser) {
         p.expect(T![")"]);
     }
 
-    p.expect(T![values]);
-    p.expect(T!["("]);
+    if p.at(T![select]) {
+        parse_query(p, false);
+    } else {
+        parse_insert_values(p);
+    }
 
-    safe_loop!(p, {
-        if !opt_expr(p) {
-            p.expect(T![default]);
-        }
-        if !p.eat(T![,]) {
-            break;
-        }
-    });
-    p.expect(T![")"]);
+    p.leave_sql();
+    p.finish();
+}
 
-    if p.eat_one_of(&[T![return], T![returning]]) {
+/// Parses the `VALUES` of an `INSERT` and what follows.
+fn parse_insert_values(p: &mut Parser) {
+    p.expect(T![values]);
+    if p.at(T!["("]) {
+        p.bump(T!["("]);
         safe_loop!(p, {
-            parse_expr(p);
-            if !p.eat(T![,]) {
-                break;
+            if !opt_expr(p) {
+                p.expect(T![default]);
             }
-        });
-        p.expect(T![into]);
-        safe_loop!(p, {
-            parse_ident(p, 1..1);
             if !p.eat(T![,]) {
                 break;
             }
         });
+        p.expect(T![")"]);
+    } else {
+        // PL/SQL allows inserting a whole record, e.g. `VALUES l_emp`
+        p.start(SyntaxKind::RecordRow);
+        parse_expr(p);
+        p.finish();
     }
 
+    opt_returning_clause(p);
     p.eat(T![;]);
-    p.finish();
 }
 
 fn parse_column_expr(p: &mut Parser) {
@@ -393,11 +540,16 @@ fn parse_column_expr(p: &mut Parser) {
 
     p.start(SyntaxKind::SelectClause);
 
+    if p.eat_one_of(&[T![distinct], T![unique], T![all]]) && p.eat(T![*]) {
+        p.finish();
+        return;
+    }
+
     safe_loop!(p, {
         p.start(SyntaxKind::ColumnExpr);
 
         parse_expr(p);
-        if [T![as], T![quoted_ident], T![unquoted_ident]].contains(&p.current()) {
+        if p.at(T![as]) || p.at_soft_ident() {
             parse_alias(p);
         }
 
@@ -405,7 +557,7 @@ fn parse_column_expr(p: &mut Parser) {
 
         p.eat(T![,]);
 
-        if [T![into], T![from], T![EOF], T![;]].contains(&p.current()) {
+        if [T![into], T![bulk], T![from], T![EOF], T![;]].contains(&p.current()) {
             break;
         }
     });
@@ -416,7 +568,11 @@ fn parse_column_expr(p: &mut Parser) {
 fn parse_alias(p: &mut Parser) {
     p.start(SyntaxKind::Alias);
     p.eat(T![as]);
-    p.expect_one_of(&[T![quoted_ident], T![unquoted_ident]]);
+    if p.current().is_ident() {
+        p.bump_any_map(SyntaxKind::Ident);
+    } else {
+        p.error(ParseErrorType::ExpectedIdent);
+    }
     p.finish()
 }
 
@@ -458,11 +614,11 @@ fn parse_from_list(p: &mut Parser) {
 
     safe_loop!(p, {
         if !expect_join {
-            parse_ident(p, 1..1);
-        }
-        if let Some(x) = p.nth(1) {
-            if JOIN_TOKENS.contains(&x) && !JOIN_TOKENS.contains(&p.current()) {
-                parse_ident(p, 1..1);
+            parse_table_reference(p);
+            if at_table_clause(p) {
+                parse_unsupported_clause(p);
+            } else {
+                opt_table_alias(p);
             }
         }
         if JOIN_TOKENS.contains(&p.current()) {
@@ -480,6 +636,68 @@ fn parse_from_list(p: &mut Parser) {
     });
 }
 
+/// Parses a single table of a `FROM` list or join: a named table, a subquery,
+/// optionally `LATERAL`, or a `TABLE(..)` collection expression.
+fn parse_table_reference(p: &mut Parser) {
+    match (p.current(), p.nth(1)) {
+        (T!["("], Some(T![select] | T![with])) | (T![lateral], Some(T!["("])) => {
+            parse_inline_view(p)
+        }
+        (T![table], Some(T!["("])) => parse_table_collection_expr(p),
+        _ => parse_ident(p, 1..2),
+    }
+}
+
+/// Parses the optional alias of a table reference, e.g. `e` in `emp e` or
+/// `x (a, b)` in `(SELECT ..) x (a, b)`, renaming the columns as well.
+fn opt_table_alias(p: &mut Parser) {
+    if p.eat(T![as]) {
+        parse_ident(p, 1..1);
+    } else if p.at_soft_ident() {
+        parse_ident(p, 0..1);
+    } else {
+        return;
+    }
+
+    let is_column_list = p.at(T!["("])
+        && p.nth(1).is_some_and(|t| t.is_ident())
+        && matches!(p.nth(2), Some(T![,] | T![")"]));
+    if is_column_list {
+        p.start(SyntaxKind::ColumnAliasList);
+        p.expect(T!["("]);
+        safe_loop!(p, {
+            parse_ident(p, 1..1);
+            if !p.eat(T![,]) {
+                break;
+            }
+        });
+        p.expect(T![")"]);
+        p.finish();
+    }
+}
+
+fn parse_inline_view(p: &mut Parser) {
+    p.start(SyntaxKind::InlineView);
+    p.eat(T![lateral]);
+    p.expect(T!["("]);
+    if p.at(T![with]) {
+        parse_cte(p);
+    } else {
+        parse_query(p, false);
+    }
+    p.expect(T![")"]);
+    p.finish();
+}
+
+fn parse_table_collection_expr(p: &mut Parser) {
+    p.start(SyntaxKind::TableCollectionExpr);
+    p.expect(T![table]);
+    p.expect(T!["("]);
+    parse_expr(p);
+    p.expect(T![")"]);
+    p.finish();
+}
+
 fn parse_join_clause(p: &mut Parser) {
     p.start(SyntaxKind::JoinClause);
     match p.current() {
@@ -516,10 +734,8 @@ fn parse_inner_join_clause(p: &mut Parser) {
     p.start(SyntaxKind::InnerJoinClause);
     p.eat(T![inner]);
     p.expect(T![join]);
-    parse_ident(p, 1..2);
-    if !p.at(T![on]) || p.at(T![using]) {
-        parse_ident(p, 1..1);
-    }
+    parse_table_reference(p);
+    opt_table_alias(p);
     match p.current() {
         T![on] => {
             p.expect(T![on]);
@@ -546,7 +762,8 @@ fn parse_cross_join_clause(p: &mut Parser) {
     p.start(SyntaxKind::CrossJoinClause);
     p.expect(T![cross]);
     p.expect(T![join]);
-    parse_ident(p, 1..2);
+    parse_table_reference(p);
+    opt_table_alias(p);
     p.finish();
 }
 
@@ -554,7 +771,11 @@ fn parse_cross_outer_apply_clause(p: &mut Parser) {
     p.start(SyntaxKind::CrossOuterApplyClause);
     p.expect_one_of(&[T![cross], T![outer]]);
     p.expect(T![apply]);
-    parse_ident(p, 1..2);
+    // A collection expression may be given without `TABLE(..)`
+    if p.at(T![table]) || !opt_function_invocation(p) {
+        parse_table_reference(p);
+    }
+    opt_table_alias(p);
     p.finish();
 }
 
@@ -567,7 +788,8 @@ fn parse_outer_join_clause(p: &mut Parser) {
     p.expect_one_of(&[T![full], T![left], T![right]]);
     p.eat(T![outer]);
     p.expect(T![join]);
-    parse_ident(p, 1..2);
+    parse_table_reference(p);
+    opt_table_alias(p);
     if p.at(T![partition]) {
         parse_partition_by_clause(p);
     }
@@ -598,7 +820,8 @@ fn parse_natural_join_clause(p: &mut Parser) {
     p.expect(T![natural]);
     p.eat(T![inner]);
     p.expect(T![join]);
-    parse_ident(p, 1..2);
+    parse_table_reference(p);
+    opt_table_alias(p);
     p.finish()
 }
 
@@ -724,6 +947,8 @@ pub(crate) fn parse_group_expression_list(p: &mut Parser) {
 mod tests {
     use expect_test::expect;
 
+    use crate::ParseError;
+
     use super::super::tests::{check, parse};
     use super::*;
 
@@ -758,14 +983,14 @@ Root@0..29
   SelectStmt@0..29
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..19
-      ColumnExpr@7..19
+    SelectClause@7..18
+      ColumnExpr@7..18
         IdentGroup@7..11
           Ident@7..11 "name"
         Whitespace@11..12 " "
         Alias@12..18
           Ident@12..18 "\"Name\""
-        Whitespace@18..19 " "
+    Whitespace@18..19 " "
     Keyword@19..23 "FROM"
     Whitespace@23..24 " "
     IdentGroup@24..29
@@ -786,8 +1011,8 @@ Root@0..32
   SelectStmt@0..32
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..22
-      ColumnExpr@7..22
+    SelectClause@7..21
+      ColumnExpr@7..21
         IdentGroup@7..11
           Ident@7..11 "name"
         Whitespace@11..12 " "
@@ -795,7 +1020,7 @@ Root@0..32
           Keyword@12..14 "as"
           Whitespace@14..15 " "
           Ident@15..21 "\"Name\""
-        Whitespace@21..22 " "
+    Whitespace@21..22 " "
     Keyword@22..26 "FROM"
     Whitespace@26..27 " "
     IdentGroup@27..32
@@ -805,6 +1030,99 @@ Root@0..32
         );
     }
 
+    #[test]
+    fn test_parse_select_distinct() {
+        check(
+            parse("SELECT /*+ FULL(e) */ DISTINCT dept_id FROM emp e", |p| {
+                parse_query(p, false)
+            }),
+            expect![[r#"
+Root@0..49
+  SelectStmt@0..49
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    Hint@7..21 "/*+ FULL(e) */"
+    Whitespace@21..22 " "
+    SelectClause@22..38
+      Keyword@22..30 "DISTINCT"
+      Whitespace@30..31 " "
+      ColumnExpr@31..38
+        IdentGroup@31..38
+          Ident@31..38 "dept_id"
+    Whitespace@38..39 " "
+    Keyword@39..43 "FROM"
+    Whitespace@43..44 " "
+    IdentGroup@44..47
+      Ident@44..47 "emp"
+    Whitespace@47..48 " "
+    IdentGroup@48..49
+      Ident@48..49 "e"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_select_unique_asterisk() {
+        check(
+            parse("SELECT UNIQUE * FROM emp", |p| parse_query(p, false)),
+            expect![[r#"
+Root@0..24
+  SelectStmt@0..24
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    SelectClause@7..15
+      Keyword@7..13 "UNIQUE"
+      Whitespace@13..14 " "
+      Asterisk@14..15 "*"
+    Whitespace@15..16 " "
+    Keyword@16..20 "FROM"
+    Whitespace@20..21 " "
+    IdentGroup@21..24
+      Ident@21..24 "emp"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_substitution_variables() {
+        check(
+            parse("SELECT &&col FROM &owner.emp WHERE id = &id", |p| {
+                parse_query(p, false)
+            }),
+            expect![[r#"
+Root@0..43
+  SelectStmt@0..43
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    SelectClause@7..12
+      ColumnExpr@7..12
+        IdentGroup@7..12
+          SubstitutionVar@7..12 "&&col"
+    Whitespace@12..13 " "
+    Keyword@13..17 "FROM"
+    Whitespace@17..18 " "
+    IdentGroup@18..28
+      SubstitutionVar@18..25 "&owner."
+      Ident@25..28 "emp"
+    Whitespace@28..29 " "
+    WhereClause@29..43
+      Keyword@29..34 "WHERE"
+      Whitespace@34..35 " "
+      Expression@35..43
+        IdentGroup@35..37
+          Ident@35..37 "id"
+        Whitespace@37..38 " "
+        ComparisonOp@38..39 "="
+        Whitespace@39..40 " "
+        IdentGroup@40..43
+          SubstitutionVar@40..43 "&id"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn test_select_into_clause() {
         check(
@@ -814,16 +1132,16 @@ Root@0..26
   SelectStmt@0..26
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..9
-      ColumnExpr@7..9
+    SelectClause@7..8
+      ColumnExpr@7..8
         Integer@7..8 "1"
-        Whitespace@8..9 " "
-    IntoClause@9..16
+    Whitespace@8..9 " "
+    IntoClause@9..15
       Keyword@9..13 "INTO"
       Whitespace@13..14 " "
       IdentGroup@14..15
         Ident@14..15 "x"
-      Whitespace@15..16 " "
+    Whitespace@15..16 " "
     Keyword@16..20 "FROM"
     Whitespace@20..21 " "
     IdentGroup@21..26
@@ -844,8 +1162,8 @@ Root@0..38
   SelectStmt@0..38
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..28
-      ColumnExpr@7..28
+    SelectClause@7..27
+      ColumnExpr@7..27
         FunctionInvocation@7..27
           IdentGroup@7..12
             Ident@7..12 "trunc"
@@ -860,7 +1178,7 @@ Root@0..38
             Argument@22..26
               QuotedLiteral@22..26 "'MM'"
           RParen@26..27 ")"
-        Whitespace@27..28 " "
+    Whitespace@27..28 " "
     Keyword@28..32 "FROM"
     Whitespace@32..33 " "
     IdentGroup@33..37
@@ -956,30 +1274,30 @@ Root@0..72
     IdentGroup@20..21
       Ident@20..21 "c"
     Whitespace@21..22 "\n"
-    WhereClause@22..70
+    WhereClause@22..69
       Keyword@22..27 "WHERE"
       Whitespace@27..28 " "
-      Expression@28..70
-        Expression@28..38
+      Expression@28..69
+        Expression@28..35
           Integer@28..31 "100"
           Whitespace@31..32 " "
           ComparisonOp@32..33 "<"
           Whitespace@33..34 " "
           IdentGroup@34..35
             Ident@34..35 "a"
-          Whitespace@35..38 "\n  "
+        Whitespace@35..38 "\n  "
         LogicOp@38..41 "AND"
         Whitespace@41..42 " "
         LParen@42..43 "("
         Expression@43..68
-          Expression@43..51
+          Expression@43..50
             IdentGroup@43..44
               Ident@43..44 "b"
             Whitespace@44..45 " "
             ComparisonOp@45..47 "<="
             Whitespace@47..48 " "
             Integer@48..50 "50"
-            Whitespace@50..51 " "
+          Whitespace@50..51 " "
           LogicOp@51..53 "OR"
           Whitespace@53..54 " "
           Expression@54..68
@@ -990,7 +1308,7 @@ Root@0..72
             Whitespace@60..61 " "
             QuotedLiteral@61..68 "'%foo%'"
         RParen@68..69 ")"
-        Whitespace@69..70 "\n"
+    Whitespace@69..70 "\n"
     Semicolon@70..71 ";"
   Whitespace@71..72 "\n"
 "#]],
@@ -1041,27 +1359,29 @@ Root@0..148
       Ident@75..82 "DEFAULT"
     RParen@82..83 ")"
     Whitespace@83..104 "\n                    "
-    Keyword@104..113 "RETURNING"
-    Whitespace@113..114 " "
-    Expression@114..126
-      IdentGroup@114..122
-        Ident@114..122 "p_emp_id"
-      Whitespace@122..123 " "
-      ArithmeticOp@123..124 "+"
-      Whitespace@124..125 " "
-      Integer@125..126 "1"
-    Comma@126..127 ","
-    Whitespace@127..128 " "
-    QuotedLiteral@128..133 "'abc'"
-    Whitespace@133..134 " "
-    Keyword@134..138 "INTO"
-    Whitespace@138..139 " "
-    IdentGroup@139..141
-      Ident@139..141 "id"
-    Comma@141..142 ","
-    Whitespace@142..143 " "
-    IdentGroup@143..147
-      Ident@143..147 "name"
+    ReturningClause@104..147
+      Keyword@104..113 "RETURNING"
+      Whitespace@113..114 " "
+      Expression@114..126
+        IdentGroup@114..122
+          Ident@114..122 "p_emp_id"
+        Whitespace@122..123 " "
+        ArithmeticOp@123..124 "+"
+        Whitespace@124..125 " "
+        Integer@125..126 "1"
+      Comma@126..127 ","
+      Whitespace@127..128 " "
+      QuotedLiteral@128..133 "'abc'"
+      Whitespace@133..134 " "
+      IntoClause@134..147
+        Keyword@134..138 "INTO"
+        Whitespace@138..139 " "
+        IdentGroup@139..141
+          Ident@139..141 "id"
+        Comma@141..142 ","
+        Whitespace@142..143 " "
+        IdentGroup@143..147
+          Ident@143..147 "name"
     Semicolon@147..148 ";"
 "#]],
             vec![],
@@ -1080,7 +1400,7 @@ Root@0..106
   SelectStmt@0..106
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..49
+    SelectClause@7..48
       ColumnExpr@7..18
         Expression@7..18
           IdentGroup@7..18
@@ -1099,10 +1419,10 @@ Root@0..106
             Ident@31..41 "manager_id"
       Comma@41..42 ","
       Whitespace@42..43 " "
-      ColumnExpr@43..49
+      ColumnExpr@43..48
         IdentGroup@43..48
           Ident@43..48 "LEVEL"
-        Whitespace@48..49 " "
+    Whitespace@48..49 " "
     Keyword@49..53 "FROM"
     Whitespace@53..54 " "
     IdentGroup@54..63
@@ -1114,12 +1434,12 @@ Root@0..106
       Keyword@72..74 "BY"
       Whitespace@74..75 " "
       Expression@75..105
-        Expression@75..93
+        Expression@75..92
           HierarchicalOp@75..80 "PRIOR"
           Whitespace@80..81 " "
           IdentGroup@81..92
             Ident@81..92 "employee_id"
-          Whitespace@92..93 " "
+        Whitespace@92..93 " "
         ComparisonOp@93..94 "="
         Whitespace@94..95 " "
         IdentGroup@95..105
@@ -1146,7 +1466,7 @@ Root@0..221
   SelectStmt@0..221
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..118
+    SelectClause@7..113
       ColumnExpr@7..27
         IdentGroup@7..16
           Ident@7..16 "last_name"
@@ -1156,25 +1476,25 @@ Root@0..221
       Comma@27..28 ","
       Whitespace@28..29 " "
       ColumnExpr@29..64
-        Expression@29..55
+        Expression@29..54
           HierarchicalOp@29..44 "CONNECT_BY_ROOT"
           Whitespace@44..45 " "
           IdentGroup@45..54
             Ident@45..54 "last_name"
-          Whitespace@54..55 " "
+        Whitespace@54..55 " "
         Alias@55..64
           Ident@55..64 "\"Manager\""
       Comma@64..65 ","
       Whitespace@65..69 "\n   "
-      ColumnExpr@69..74
-        IdentGroup@69..74
-          Ident@69..74 "LEVEL"
-      ColumnExpr@74..76
-        Expression@74..76
-          Integer@74..76 "-1"
+      ColumnExpr@69..76
+        Expression@69..76
+          IdentGroup@69..74
+            Ident@69..74 "LEVEL"
+          ArithmeticOp@74..75 "-"
+          Integer@75..76 "1"
       Comma@76..77 ","
       Whitespace@77..78 " "
-      ColumnExpr@78..118
+      ColumnExpr@78..113
         FunctionInvocation@78..113
           IdentGroup@78..97
             Ident@78..97 "SYS_CONNECT_BY_PATH"
@@ -1189,46 +1509,46 @@ Root@0..221
             Argument@109..112
               QuotedLiteral@109..112 "'/'"
           RParen@112..113 ")"
-        Whitespace@113..118 " \n   "
+    Whitespace@113..118 " \n   "
     Keyword@118..122 "FROM"
     Whitespace@122..123 " "
     IdentGroup@123..132
       Ident@123..132 "employees"
     Whitespace@132..136 "\n   "
-    WhereClause@136..179
+    WhereClause@136..175
       Keyword@136..141 "WHERE"
       Whitespace@141..142 " "
-      Expression@142..179
-        Expression@142..152
+      Expression@142..175
+        Expression@142..151
           IdentGroup@142..147
             Ident@142..147 "LEVEL"
           Whitespace@147..148 " "
           ComparisonOp@148..149 ">"
           Whitespace@149..150 " "
           Integer@150..151 "1"
-          Whitespace@151..152 " "
+        Whitespace@151..152 " "
         LogicOp@152..155 "and"
         Whitespace@155..156 " "
-        Expression@156..179
+        Expression@156..175
           IdentGroup@156..169
             Ident@156..169 "department_id"
           Whitespace@169..170 " "
           ComparisonOp@170..171 "="
           Whitespace@171..172 " "
           Integer@172..175 "110"
-          Whitespace@175..179 "\n   "
+    Whitespace@175..179 "\n   "
     Connect@179..220
       Keyword@179..186 "CONNECT"
       Whitespace@186..187 " "
       Keyword@187..189 "BY"
       Whitespace@189..190 " "
       Expression@190..220
-        Expression@190..208
+        Expression@190..207
           HierarchicalOp@190..195 "PRIOR"
           Whitespace@195..196 " "
           IdentGroup@196..207
             Ident@196..207 "employee_id"
-          Whitespace@207..208 " "
+        Whitespace@207..208 " "
         ComparisonOp@208..209 "="
         Whitespace@209..210 " "
         IdentGroup@210..220
@@ -1356,7 +1676,7 @@ SELECT name, vehicle_name FROM CTE;",
             ),
             expect![[r#"
 Root@0..145
-  WithClause@0..110
+  WithClause@0..109
     Keyword@0..4 "WITH"
     Whitespace@4..5 " "
     SubqueryFactoringClause@5..52
@@ -1369,17 +1689,17 @@ Root@0..145
       SelectStmt@13..51
         Keyword@13..19 "SELECT"
         Whitespace@19..20 " "
-        SelectClause@20..38
+        SelectClause@20..37
           ColumnExpr@20..24
             Expression@20..24
               IdentGroup@20..24
                 Ident@20..24 "name"
           Comma@24..25 ","
           Whitespace@25..26 " "
-          ColumnExpr@26..38
+          ColumnExpr@26..37
             IdentGroup@26..37
               Ident@26..37 "employee_id"
-            Whitespace@37..38 " "
+        Whitespace@37..38 " "
         Keyword@38..42 "FROM"
         Whitespace@42..43 " "
         IdentGroup@43..51
@@ -1387,7 +1707,7 @@ Root@0..145
       RParen@51..52 ")"
     Comma@52..53 ","
     Whitespace@53..54 "\n"
-    SubqueryFactoringClause@54..110
+    SubqueryFactoringClause@54..109
       IdentGroup@54..58
         Ident@54..58 "CTE1"
       Whitespace@58..59 " "
@@ -1397,37 +1717,37 @@ Root@0..145
       SelectStmt@63..108
         Keyword@63..69 "SELECT"
         Whitespace@69..70 " "
-        SelectClause@70..96
+        SelectClause@70..95
           ColumnExpr@70..81
             Expression@70..81
               IdentGroup@70..81
                 Ident@70..81 "employee_id"
           Comma@81..82 ","
           Whitespace@82..83 " "
-          ColumnExpr@83..96
+          ColumnExpr@83..95
             IdentGroup@83..95
               Ident@83..95 "vehicle_name"
-            Whitespace@95..96 " "
+        Whitespace@95..96 " "
         Keyword@96..100 "FROM"
         Whitespace@100..101 " "
         IdentGroup@101..108
           Ident@101..108 "vehicle"
       RParen@108..109 ")"
-      Whitespace@109..110 "\n"
+  Whitespace@109..110 "\n"
   SelectStmt@110..145
     Keyword@110..116 "SELECT"
     Whitespace@116..117 " "
-    SelectClause@117..136
+    SelectClause@117..135
       ColumnExpr@117..121
         Expression@117..121
           IdentGroup@117..121
             Ident@117..121 "name"
       Comma@121..122 ","
       Whitespace@122..123 " "
-      ColumnExpr@123..136
+      ColumnExpr@123..135
         IdentGroup@123..135
           Ident@123..135 "vehicle_name"
-        Whitespace@135..136 " "
+    Whitespace@135..136 " "
     Keyword@136..140 "FROM"
     Whitespace@140..141 " "
     IdentGroup@141..144
@@ -1438,6 +1758,89 @@ Root@0..145
         );
     }
 
+    #[test]
+    fn test_cte_with_hint_and_cycle() {
+        check(
+            parse(
+                "WITH t (id) AS NOT MATERIALIZED (SELECT /*+ inline */ id FROM tree)
+CYCLE id SET is_cycle TO 'Y' DEFAULT 'N'
+SELECT id FROM t;",
+                parse_cte,
+            ),
+            expect![[r#"
+Root@0..126
+  WithClause@0..108
+    Keyword@0..4 "WITH"
+    Whitespace@4..5 " "
+    SubqueryFactoringClause@5..108
+      IdentGroup@5..6
+        Ident@5..6 "t"
+      Whitespace@6..7 " "
+      LParen@7..8 "("
+      IdentGroup@8..10
+        Ident@8..10 "id"
+      RParen@10..11 ")"
+      Whitespace@11..12 " "
+      Keyword@12..14 "AS"
+      Whitespace@14..15 " "
+      Keyword@15..18 "NOT"
+      Whitespace@18..19 " "
+      Keyword@19..31 "MATERIALIZED"
+      Whitespace@31..32 " "
+      LParen@32..33 "("
+      SelectStmt@33..66
+        Keyword@33..39 "SELECT"
+        Whitespace@39..40 " "
+        Hint@40..53 "/*+ inline */"
+        Whitespace@53..54 " "
+        SelectClause@54..56
+          ColumnExpr@54..56
+            IdentGroup@54..56
+              Ident@54..56 "id"
+        Whitespace@56..57 " "
+        Keyword@57..61 "FROM"
+        Whitespace@61..62 " "
+        IdentGroup@62..66
+          Ident@62..66 "tree"
+      RParen@66..67 ")"
+      Whitespace@67..68 "\n"
+      CycleClause@68..108
+        Keyword@68..73 "CYCLE"
+        Whitespace@73..74 " "
+        IdentGroup@74..76
+          Ident@74..76 "id"
+        Whitespace@76..77 " "
+        Keyword@77..80 "SET"
+        Whitespace@80..81 " "
+        IdentGroup@81..89
+          Ident@81..89 "is_cycle"
+        Whitespace@89..90 " "
+        Keyword@90..92 "TO"
+        Whitespace@92..93 " "
+        QuotedLiteral@93..96 "'Y'"
+        Whitespace@96..97 " "
+        Keyword@97..104 "DEFAULT"
+        Whitespace@104..105 " "
+        QuotedLiteral@105..108 "'N'"
+  Whitespace@108..109 "\n"
+  SelectStmt@109..126
+    Keyword@109..115 "SELECT"
+    Whitespace@115..116 " "
+    SelectClause@116..118
+      ColumnExpr@116..118
+        IdentGroup@116..118
+          Ident@116..118 "id"
+    Whitespace@118..119 " "
+    Keyword@119..123 "FROM"
+    Whitespace@123..124 " "
+    IdentGroup@124..125
+      Ident@124..125 "t"
+    Semicolon@125..126 ";"
+"#]],
+            vec![],
+        );
+    }
+
     #[test]
     fn test_group_by() {
         check(
@@ -1449,11 +1852,11 @@ Root@0..44
   SelectStmt@0..44
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..19
-      ColumnExpr@7..19
+    SelectClause@7..18
+      ColumnExpr@7..18
         IdentGroup@7..18
           Ident@7..18 "column_list"
-        Whitespace@18..19 " "
+    Whitespace@18..19 " "
     Keyword@19..23 "FROM"
     Whitespace@23..24 " "
     IdentGroup@24..25
@@ -1495,11 +1898,11 @@ SELECT text_length('hans') FROM DUAL;",
             ),
             expect![[r#"
 Root@0..150
-  WithClause@0..113
+  WithClause@0..112
     Keyword@0..4 "WITH"
     Whitespace@4..5 " "
-    Function@5..113
-      FunctionHeader@5..66
+    Function@5..112
+      FunctionHeader@5..65
         Keyword@5..13 "FUNCTION"
         Whitespace@13..14 " "
         IdentGroup@14..25
@@ -1516,11 +1919,11 @@ Root@0..150
         Whitespace@33..38 " \n   "
         Keyword@38..44 "RETURN"
         Whitespace@44..45 " "
-        Datatype@45..52
+        Datatype@45..51
           Keyword@45..51 "NUMBER"
-          Whitespace@51..52 " "
+        Whitespace@51..52 " "
         Keyword@52..65 "DETERMINISTIC"
-        Whitespace@65..66 " "
+      Whitespace@65..66 " "
       Keyword@66..68 "IS"
       Whitespace@68..69 "\n"
       Block@69..112
@@ -1545,12 +1948,12 @@ Root@0..150
         Whitespace@107..108 "\n"
         Keyword@108..111 "END"
         Semicolon@111..112 ";"
-      Whitespace@112..113 "\n"
+  Whitespace@112..113 "\n"
   SelectStmt@113..150
     Keyword@113..119 "SELECT"
     Whitespace@119..120 " "
-    SelectClause@120..140
-      ColumnExpr@120..140
+    SelectClause@120..139
+      ColumnExpr@120..139
         FunctionInvocation@120..139
           IdentGroup@120..131
             Ident@120..131 "text_length"
@@ -1559,7 +1962,7 @@ Root@0..150
             Argument@132..138
               QuotedLiteral@132..138 "'hans'"
           RParen@138..139 ")"
-        Whitespace@139..140 " "
+    Whitespace@139..140 " "
     Keyword@140..144 "FROM"
     Whitespace@144..145 " "
     IdentGroup@145..149
@@ -1582,17 +1985,17 @@ Root@0..78
   SelectStmt@0..78
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..27
+    SelectClause@7..26
       ColumnExpr@7..11
         Expression@7..11
           IdentGroup@7..11
             Ident@7..11 "name"
       Comma@11..12 ","
       Whitespace@12..13 " "
-      ColumnExpr@13..27
+      ColumnExpr@13..26
         IdentGroup@13..26
           Ident@13..26 "license_plate"
-        Whitespace@26..27 " "
+    Whitespace@26..27 " "
     Keyword@27..31 "FROM"
     Whitespace@31..32 " "
     IdentGroup@32..40
@@ -1635,17 +2038,17 @@ Root@0..84
   SelectStmt@0..84
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..27
+    SelectClause@7..26
       ColumnExpr@7..11
         Expression@7..11
           IdentGroup@7..11
             Ident@7..11 "name"
       Comma@11..12 ","
       Whitespace@12..13 " "
-      ColumnExpr@13..27
+      ColumnExpr@13..26
         IdentGroup@13..26
           Ident@13..26 "license_plate"
-        Whitespace@26..27 " "
+    Whitespace@26..27 " "
     Keyword@27..31 "FROM"
     Whitespace@31..32 " "
     IdentGroup@32..40
@@ -1722,11 +2125,11 @@ Root@0..61
   SelectStmt@0..61
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..19
-      ColumnExpr@7..19
+    SelectClause@7..18
+      ColumnExpr@7..18
         IdentGroup@7..18
           Ident@7..18 "column_list"
-        Whitespace@18..19 " "
+    Whitespace@18..19 " "
     Keyword@19..23 "FROM"
     Whitespace@23..24 " "
     IdentGroup@24..25
@@ -1767,11 +2170,11 @@ SELECT * from employee;",
             ),
             expect![[r#"
 Root@0..105
-  WithClause@0..82
+  WithClause@0..80
     Keyword@0..4 "WITH"
     Whitespace@4..5 " "
-    Procedure@5..82
-      ProcedureHeader@5..59
+    Procedure@5..80
+      ProcedureHeader@5..58
         Keyword@5..14 "PROCEDURE"
         Whitespace@14..15 " "
         IdentGroup@15..28
@@ -1779,15 +2182,15 @@ Root@0..105
         ParamList@28..58
           LParen@28..29 "("
           Whitespace@29..34 "\n    "
-          Param@34..57
+          Param@34..55
             IdentGroup@34..48
               Ident@34..48 "in_customer_id"
             Whitespace@48..49 " "
-            Datatype@49..57
+            Datatype@49..55
               Keyword@49..55 "NUMBER"
-              Whitespace@55..57 " \n"
+          Whitespace@55..57 " \n"
           RParen@57..58 ")"
-        Whitespace@58..59 "\n"
+      Whitespace@58..59 "\n"
       Keyword@59..61 "IS"
       Whitespace@61..62 "\n"
       Block@62..80
@@ -1799,7 +2202,7 @@ Root@0..105
         Whitespace@75..76 "\n"
         Keyword@76..79 "END"
         Semicolon@79..80 ";"
-      Whitespace@80..82 " \n"
+  Whitespace@80..82 " \n"
   SelectStmt@82..105
     Keyword@82..88 "SELECT"
     Whitespace@88..89 " "
@@ -1827,11 +2230,11 @@ Root@0..52
   SelectStmt@0..52
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..19
-      ColumnExpr@7..19
+    SelectClause@7..18
+      ColumnExpr@7..18
         IdentGroup@7..18
           Ident@7..18 "column_list"
-        Whitespace@18..19 " "
+    Whitespace@18..19 " "
     Keyword@19..23 "FROM"
     Whitespace@23..24 " "
     IdentGroup@24..25
@@ -1875,7 +2278,7 @@ Root@0..73
   SelectStmt@0..73
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..33
+    SelectClause@7..32
       ColumnExpr@7..9
         Expression@7..9
           IdentGroup@7..9
@@ -1894,7 +2297,7 @@ Root@0..73
             Ident@15..17 "c3"
       Comma@17..18 ","
       Whitespace@18..19 " "
-      ColumnExpr@19..33
+      ColumnExpr@19..32
         FunctionInvocation@19..32
           IdentGroup@19..28
             Ident@19..28 "aggregate"
@@ -1904,7 +2307,7 @@ Root@0..73
               IdentGroup@29..31
                 Ident@29..31 "c4"
           RParen@31..32 ")"
-        Whitespace@32..33 " "
+    Whitespace@32..33 " "
     Keyword@33..37 "FROM"
     Whitespace@37..38 " "
     IdentGroup@38..48
@@ -1944,94 +2347,94 @@ Root@0..73
                 |p| parse_query(p, false),
             ),
             expect![[r#"
-Root@0..174
-  SelectStmt@0..174
-    Keyword@0..6 "SELECT"
-    Whitespace@6..7 " "
-    SelectClause@7..45
-      ColumnExpr@7..15
-        Expression@7..15
-          IdentGroup@7..15
-            Ident@7..15 "customer"
-      Comma@15..16 ","
-      Whitespace@16..17 " "
-      ColumnExpr@17..25
-        Expression@17..25
-          IdentGroup@17..25
-            Ident@17..25 "category"
-      Comma@25..26 ","
-      Whitespace@26..27 " "
-      ColumnExpr@27..45
-        FunctionInvocation@27..44
-          IdentGroup@27..30
-            Ident@27..30 "SUM"
-          LParen@30..31 "("
-          ArgumentList@31..43
-            Argument@31..43
-              IdentGroup@31..43
-                Ident@31..43 "sales_amount"
-          RParen@43..44 ")"
-        Whitespace@44..45 " "
-    Keyword@45..49 "FROM"
-    Whitespace@49..50 " "
-    IdentGroup@50..73
-      Ident@50..73 "customer_category_sales"
-    Whitespace@73..74 " "
-    GroupByClause@74..146
-      Keyword@74..79 "GROUP"
-      Whitespace@79..80 " "
-      Keyword@80..82 "BY"
-      Whitespace@82..83 " "
-      GroupingSetsClause@83..145
-        Keyword@83..91 "GROUPING"
-        Whitespace@91..92 " "
-        Keyword@92..96 "SETS"
-        LParen@96..97 "("
-        GroupingExpressionList@97..116
-          LParen@97..98 "("
-          Expression@98..106
-            IdentGroup@98..106
-              Ident@98..106 "customer"
-          Comma@106..107 ","
-          IdentGroup@107..115
-            Ident@107..115 "category"
-          RParen@115..116 ")"
-        Comma@116..117 ","
-        Whitespace@117..118 " "
-        GroupingExpressionList@118..128
-          LParen@118..119 "("
-          IdentGroup@119..127
-            Ident@119..127 "customer"
-          RParen@127..128 ")"
-        Comma@128..129 ","
-        Whitespace@129..130 " "
-        GroupingExpressionList@130..140
-          LParen@130..131 "("
-          IdentGroup@131..139
-            Ident@131..139 "category"
-          RParen@139..140 ")"
-        Comma@140..141 ","
-        Whitespace@141..142 " "
-        GroupingExpressionList@142..144
-          LParen@142..143 "("
-          RParen@143..144 ")"
-        RParen@144..145 ")"
-      Whitespace@145..146 " "
-    OrderByClause@146..173
-      Keyword@146..151 "ORDER"
-      Whitespace@151..152 " "
-      Keyword@152..154 "BY"
-      Whitespace@154..155 " "
-      Expression@155..163
-        IdentGroup@155..163
-          Ident@155..163 "customer"
-      Comma@163..164 ","
-      Whitespace@164..165 " "
-      Expression@165..173
-        IdentGroup@165..173
-          Ident@165..173 "category"
-    Semicolon@173..174 ";"
-"#]], vec![]);
+                Root@0..174
+                  SelectStmt@0..174
+                    Keyword@0..6 "SELECT"
+                    Whitespace@6..7 " "
+                    SelectClause@7..44
+                      ColumnExpr@7..15
+                        Expression@7..15
+                          IdentGroup@7..15
+                            Ident@7..15 "customer"
+                      Comma@15..16 ","
+                      Whitespace@16..17 " "
+                      ColumnExpr@17..25
+                        Expression@17..25
+                          IdentGroup@17..25
+                            Ident@17..25 "category"
+                      Comma@25..26 ","
+                      Whitespace@26..27 " "
+                      ColumnExpr@27..44
+                        FunctionInvocation@27..44
+                          IdentGroup@27..30
+                            Ident@27..30 "SUM"
+                          LParen@30..31 "("
+                          ArgumentList@31..43
+                            Argument@31..43
+                              IdentGroup@31..43
+                                Ident@31..43 "sales_amount"
+                          RParen@43..44 ")"
+                    Whitespace@44..45 " "
+                    Keyword@45..49 "FROM"
+                    Whitespace@49..50 " "
+                    IdentGroup@50..73
+                      Ident@50..73 "customer_category_sales"
+                    Whitespace@73..74 " "
+                    GroupByClause@74..145
+                      Keyword@74..79 "GROUP"
+                      Whitespace@79..80 " "
+                      Keyword@80..82 "BY"
+                      Whitespace@82..83 " "
+                      GroupingSetsClause@83..145
+                        Keyword@83..91 "GROUPING"
+                        Whitespace@91..92 " "
+                        Keyword@92..96 "SETS"
+                        LParen@96..97 "("
+                        GroupingExpressionList@97..116
+                          LParen@97..98 "("
+                          Expression@98..106
+                            IdentGroup@98..106
+                              Ident@98..106 "customer"
+                          Comma@106..107 ","
+                          IdentGroup@107..115
+                            Ident@107..115 "category"
+                          RParen@115..116 ")"
+                        Comma@116..117 ","
+                        Whitespace@117..118 " "
+                        GroupingExpressionList@118..128
+                          LParen@118..119 "("
+                          IdentGroup@119..127
+                            Ident@119..127 "customer"
+                          RParen@127..128 ")"
+                        Comma@128..129 ","
+                        Whitespace@129..130 " "
+                        GroupingExpressionList@130..140
+                          LParen@130..131 "("
+                          IdentGroup@131..139
+                            Ident@131..139 "category"
+                          RParen@139..140 ")"
+                        Comma@140..141 ","
+                        Whitespace@141..142 " "
+                        GroupingExpressionList@142..144
+                          LParen@142..143 "("
+                          RParen@143..144 ")"
+                        RParen@144..145 ")"
+                    Whitespace@145..146 " "
+                    OrderByClause@146..173
+                      Keyword@146..151 "ORDER"
+                      Whitespace@151..152 " "
+                      Keyword@152..154 "BY"
+                      Whitespace@154..155 " "
+                      Expression@155..163
+                        IdentGroup@155..163
+                          Ident@155..163 "customer"
+                      Comma@163..164 ","
+                      Whitespace@164..165 " "
+                      Expression@165..173
+                        IdentGroup@165..173
+                          Ident@165..173 "category"
+                    Semicolon@173..174 ";"
+            "#]], vec![]);
     }
 
     #[test]
@@ -2079,7 +2482,7 @@ Root@0..133
   SelectStmt@0..133
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..47
+    SelectClause@7..46
       ColumnExpr@7..15
         Expression@7..15
           IdentGroup@7..15
@@ -2098,17 +2501,17 @@ Root@0..133
             Ident@25..35 "first_name"
       Comma@35..36 ","
       Whitespace@36..37 " "
-      ColumnExpr@37..47
+      ColumnExpr@37..46
         IdentGroup@37..46
           Ident@37..46 "last_name"
-        Whitespace@46..47 " "
+    Whitespace@46..47 " "
     Keyword@47..51 "FROM"
     Whitespace@51..52 " "
     IdentGroup@52..58
       Ident@52..58 "orders"
     Whitespace@58..59 "\n"
-    JoinClause@59..108
-      OuterJoinClause@59..108
+    JoinClause@59..107
+      OuterJoinClause@59..107
         Keyword@59..63 "LEFT"
         Whitespace@63..64 " "
         Keyword@64..68 "JOIN"
@@ -2118,7 +2521,7 @@ Root@0..133
         Whitespace@78..79 " "
         Keyword@79..81 "ON"
         Whitespace@81..82 " "
-        Expression@82..108
+        Expression@82..107
           IdentGroup@82..93
             Ident@82..93 "employee_id"
           Whitespace@93..94 " "
@@ -2126,7 +2529,7 @@ Root@0..133
           Whitespace@95..96 " "
           IdentGroup@96..107
             Ident@96..107 "salesman_id"
-          Whitespace@107..108 " "
+    Whitespace@107..108 " "
     OrderByClause@108..132
       Keyword@108..113 "ORDER"
       Whitespace@113..114 " "
@@ -2156,7 +2559,7 @@ Root@0..183
   SelectStmt@0..183
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..47
+    SelectClause@7..46
       ColumnExpr@7..17
         Expression@7..17
           IdentGroup@7..17
@@ -2175,17 +2578,17 @@ Root@0..183
             Ident@30..38 "order_id"
       Comma@38..39 ","
       Whitespace@39..40 " "
-      ColumnExpr@40..47
+      ColumnExpr@40..46
         IdentGroup@40..46
           Ident@40..46 "status"
-        Whitespace@46..47 " "
+    Whitespace@46..47 " "
     Keyword@47..51 "FROM"
     Whitespace@51..52 " "
     IdentGroup@52..58
       Ident@52..58 "orders"
     Whitespace@58..59 " "
-    JoinClause@59..110
-      OuterJoinClause@59..110
+    JoinClause@59..109
+      OuterJoinClause@59..109
         Keyword@59..64 "RIGHT"
         Whitespace@64..65 " "
         Keyword@65..69 "JOIN"
@@ -2195,7 +2598,7 @@ Root@0..183
         Whitespace@80..81 " "
         Keyword@81..83 "ON"
         Whitespace@83..84 " "
-        Expression@84..110
+        Expression@84..109
           IdentGroup@84..95
             Ident@84..95 "employee_id"
           Whitespace@95..96 " "
@@ -2203,18 +2606,18 @@ Root@0..183
           Whitespace@97..98 " "
           IdentGroup@98..109
             Ident@98..109 "salesman_id"
-          Whitespace@109..110 " "
-    WhereClause@110..152
+    Whitespace@109..110 " "
+    WhereClause@110..150
       Keyword@110..115 "WHERE"
       Whitespace@115..116 " "
-      Expression@116..152
+      Expression@116..150
         IdentGroup@116..125
           Ident@116..125 "job_title"
         Whitespace@125..126 " "
         ComparisonOp@126..127 "="
         Whitespace@127..128 " "
         QuotedLiteral@128..150 "'Sales Representative'"
-        Whitespace@150..152 " \n"
+    Whitespace@150..152 " \n"
     OrderByClause@152..182
       Keyword@152..157 "ORDER"
       Whitespace@157..158 " "
@@ -2247,24 +2650,24 @@ Root@0..137
   SelectStmt@0..137
     Keyword@0..6 "SELECT"
     Whitespace@6..7 " "
-    SelectClause@7..33
+    SelectClause@7..32
       ColumnExpr@7..18
         Expression@7..18
           IdentGroup@7..18
             Ident@7..18 "member_name"
       Comma@18..19 ","
       Whitespace@19..20 " "
-      ColumnExpr@20..33
+      ColumnExpr@20..32
         IdentGroup@20..32
           Ident@20..32 "project_name"
-        Whitespace@32..33 " "
+    Whitespace@32..33 " "
     Keyword@33..37 "FROM"
     Whitespace@37..38 " "
     IdentGroup@38..45
       Ident@38..45 "members"
     Whitespace@45..46 " "
-    JoinClause@46..116
-      OuterJoinClause@46..116
+    JoinClause@46..115
+      OuterJoinClause@46..115
         Keyword@46..50 "FULL"
         Whitespace@50..51 " "
         Keyword@51..56 "OUTER"
@@ -2276,7 +2679,7 @@ Root@0..137
         Whitespace@70..72 " \n"
         Keyword@72..74 "ON"
         Whitespace@74..75 " "
-        Expression@75..116
+        Expression@75..115
           IdentGroup@75..94
             Ident@75..83 "projects"
             Dot@83..84 "."
@@ -2288,7 +2691,7 @@ Root@0..137
             Ident@97..104 "members"
             Dot@104..105 "."
             Ident@105..115 "project_id"
-          Whitespace@115..116 " "
+    Whitespace@115..116 " "
     OrderByClause@116..136
       Keyword@116..121 "ORDER"
       Whitespace@121..122 " "
@@ -2386,8 +2789,8 @@ Root@0..56
     IdentGroup@14..15
       Ident@14..15 "a"
     Whitespace@15..16 " "
-    JoinClause@16..36
-      InnerJoinClause@16..36
+    JoinClause@16..35
+      InnerJoinClause@16..35
         Keyword@16..20 "JOIN"
         Whitespace@20..21 " "
         IdentGroup@21..22
@@ -2395,7 +2798,7 @@ Root@0..56
         Whitespace@22..23 " "
         Keyword@23..25 "ON"
         Whitespace@25..26 " "
-        Expression@26..36
+        Expression@26..35
           IdentGroup@26..30
             Ident@26..27 "a"
             Dot@27..28 "."
@@ -2405,7 +2808,7 @@ Root@0..56
             Ident@31..32 "b"
             Dot@32..33 "."
             Ident@33..35 "id"
-          Whitespace@35..36 " "
+    Whitespace@35..36 " "
     JoinClause@36..55
       InnerJoinClause@36..55
         Keyword@36..40 "JOIN"
@@ -2474,6 +2877,1029 @@ Root@0..43
             Ident@39..40 "d"
             Dot@40..41 "."
             Ident@41..43 "id"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_pivot_is_unsupported() {
+        check(
+            parse(
+                "SELECT * FROM sales PIVOT (SUM(amount) FOR quarter IN ('Q1', 'Q2')) p WHERE year = 2023;",
+                |p| parse_query(p, false),
+            ),
+            expect![[r#"
+Root@0..88
+  SelectStmt@0..88
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    Asterisk@7..8 "*"
+    Whitespace@8..9 " "
+    Keyword@9..13 "FROM"
+    Whitespace@13..14 " "
+    IdentGroup@14..19
+      Ident@14..19 "sales"
+    Whitespace@19..20 " "
+    UnsupportedClause@20..69
+      Keyword@20..25 "PIVOT"
+      Whitespace@25..26 " "
+      LParen@26..27 "("
+      Ident@27..30 "SUM"
+      LParen@30..31 "("
+      Ident@31..37 "amount"
+      RParen@37..38 ")"
+      Whitespace@38..39 " "
+      Keyword@39..42 "FOR"
+      Whitespace@42..43 " "
+      Ident@43..50 "quarter"
+      Whitespace@50..51 " "
+      Keyword@51..53 "IN"
+      Whitespace@53..54 " "
+      LParen@54..55 "("
+      QuotedLiteral@55..59 "'Q1'"
+      Comma@59..60 ","
+      Whitespace@60..61 " "
+      QuotedLiteral@61..65 "'Q2'"
+      RParen@65..66 ")"
+      RParen@66..67 ")"
+      Whitespace@67..68 " "
+      Alias@68..69
+        Ident@68..69 "p"
+    Whitespace@69..70 " "
+    WhereClause@70..87
+      Keyword@70..75 "WHERE"
+      Whitespace@75..76 " "
+      Expression@76..87
+        IdentGroup@76..80
+          Ident@76..80 "year"
+        Whitespace@80..81 " "
+        ComparisonOp@81..82 "="
+        Whitespace@82..83 " "
+        Integer@83..87 "2023"
+    Semicolon@87..88 ";"
+"#]],
+            vec![ParseError::new(
+                ParseErrorType::Unimplemented("PIVOT clause".to_string()),
+                20..25,
+            )],
+        );
+    }
+
+    #[test]
+    fn test_unpivot_is_unsupported() {
+        check(
+            parse(
+                "SELECT * FROM sales UNPIVOT INCLUDE NULLS (amount FOR quarter IN (q1, q2));",
+                |p| parse_query(p, false),
+            ),
+            expect![[r#"
+Root@0..75
+  SelectStmt@0..75
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    Asterisk@7..8 "*"
+    Whitespace@8..9 " "
+    Keyword@9..13 "FROM"
+    Whitespace@13..14 " "
+    IdentGroup@14..19
+      Ident@14..19 "sales"
+    Whitespace@19..20 " "
+    UnsupportedClause@20..74
+      Keyword@20..27 "UNPIVOT"
+      Whitespace@27..28 " "
+      Keyword@28..35 "INCLUDE"
+      Whitespace@35..36 " "
+      Keyword@36..41 "NULLS"
+      Whitespace@41..42 " "
+      LParen@42..43 "("
+      Ident@43..49 "amount"
+      Whitespace@49..50 " "
+      Keyword@50..53 "FOR"
+      Whitespace@53..54 " "
+      Ident@54..61 "quarter"
+      Whitespace@61..62 " "
+      Keyword@62..64 "IN"
+      Whitespace@64..65 " "
+      LParen@65..66 "("
+      Ident@66..68 "q1"
+      Comma@68..69 ","
+      Whitespace@69..70 " "
+      Ident@70..72 "q2"
+      RParen@72..73 ")"
+      RParen@73..74 ")"
+    Semicolon@74..75 ";"
+"#]],
+            vec![ParseError::new(
+                ParseErrorType::Unimplemented("UNPIVOT clause".to_string()),
+                20..27,
+            )],
+        );
+    }
+
+    #[test]
+    fn test_model_is_unsupported() {
+        check(
+            parse(
+                "SELECT country, year, s FROM sales MODEL DIMENSION BY (country, year) MEASURES (sale s) RULES (s = s * 2) ORDER BY country;",
+                |p| parse_query(p, false),
+            ),
+            expect![[r#"
+Root@0..123
+  SelectStmt@0..123
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    SelectClause@7..23
+      ColumnExpr@7..14
+        Expression@7..14
+          IdentGroup@7..14
+            Ident@7..14 "country"
+      Comma@14..15 ","
+      Whitespace@15..16 " "
+      ColumnExpr@16..20
+        Expression@16..20
+          IdentGroup@16..20
+            Ident@16..20 "year"
+      Comma@20..21 ","
+      Whitespace@21..22 " "
+      ColumnExpr@22..23
+        IdentGroup@22..23
+          Ident@22..23 "s"
+    Whitespace@23..24 " "
+    Keyword@24..28 "FROM"
+    Whitespace@28..29 " "
+    IdentGroup@29..34
+      Ident@29..34 "sales"
+    Whitespace@34..35 " "
+    UnsupportedClause@35..105
+      Keyword@35..40 "MODEL"
+      Whitespace@40..41 " "
+      Ident@41..50 "DIMENSION"
+      Whitespace@50..51 " "
+      Keyword@51..53 "BY"
+      Whitespace@53..54 " "
+      LParen@54..55 "("
+      Ident@55..62 "country"
+      Comma@62..63 ","
+      Whitespace@63..64 " "
+      Keyword@64..68 "year"
+      RParen@68..69 ")"
+      Whitespace@69..70 " "
+      Keyword@70..78 "MEASURES"
+      Whitespace@78..79 " "
+      LParen@79..80 "("
+      Ident@80..84 "sale"
+      Whitespace@84..85 " "
+      Ident@85..86 "s"
+      RParen@86..87 ")"
+      Whitespace@87..88 " "
+      Ident@88..93 "RULES"
+      Whitespace@93..94 " "
+      LParen@94..95 "("
+      Ident@95..96 "s"
+      Whitespace@96..97 " "
+      ComparisonOp@97..98 "="
+      Whitespace@98..99 " "
+      Ident@99..100 "s"
+      Whitespace@100..101 " "
+      Asterisk@101..102 "*"
+      Whitespace@102..103 " "
+      Integer@103..104 "2"
+      RParen@104..105 ")"
+    Whitespace@105..106 " "
+    OrderByClause@106..122
+      Keyword@106..111 "ORDER"
+      Whitespace@111..112 " "
+      Keyword@112..114 "BY"
+      Whitespace@114..115 " "
+      Expression@115..122
+        IdentGroup@115..122
+          Ident@115..122 "country"
+    Semicolon@122..123 ";"
+"#]],
+            vec![ParseError::new(
+                ParseErrorType::Unimplemented("MODEL clause".to_string()),
+                35..40,
+            )],
+        );
+    }
+
+    #[test]
+    fn test_sample_is_unsupported() {
+        check(
+            parse(
+                "SELECT * FROM emp SAMPLE BLOCK (10) SEED (1) e WHERE e.sal > 1000;",
+                |p| parse_query(p, false),
+            ),
+            expect![[r#"
+Root@0..66
+  SelectStmt@0..66
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    Asterisk@7..8 "*"
+    Whitespace@8..9 " "
+    Keyword@9..13 "FROM"
+    Whitespace@13..14 " "
+    IdentGroup@14..17
+      Ident@14..17 "emp"
+    Whitespace@17..18 " "
+    UnsupportedClause@18..46
+      Keyword@18..24 "SAMPLE"
+      Whitespace@24..25 " "
+      Keyword@25..30 "BLOCK"
+      Whitespace@30..31 " "
+      LParen@31..32 "("
+      Integer@32..34 "10"
+      RParen@34..35 ")"
+      Whitespace@35..36 " "
+      Keyword@36..40 "SEED"
+      Whitespace@40..41 " "
+      LParen@41..42 "("
+      Integer@42..43 "1"
+      RParen@43..44 ")"
+      Whitespace@44..45 " "
+      Alias@45..46
+        Ident@45..46 "e"
+    Whitespace@46..47 " "
+    WhereClause@47..65
+      Keyword@47..52 "WHERE"
+      Whitespace@52..53 " "
+      Expression@53..65
+        IdentGroup@53..58
+          Ident@53..54 "e"
+          Dot@54..55 "."
+          Ident@55..58 "sal"
+        Whitespace@58..59 " "
+        ComparisonOp@59..60 ">"
+        Whitespace@60..61 " "
+        Integer@61..65 "1000"
+    Semicolon@65..66 ";"
+"#]],
+            vec![ParseError::new(
+                ParseErrorType::Unimplemented("SAMPLE clause".to_string()),
+                18..24,
+            )],
+        );
+    }
+
+    #[test]
+    fn test_partition_extension_is_unsupported() {
+        check(
+            parse("SELECT * FROM sales PARTITION (q1) s", |p| {
+                parse_query(p, false)
+            }),
+            expect![[r#"
+Root@0..36
+  SelectStmt@0..36
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    Asterisk@7..8 "*"
+    Whitespace@8..9 " "
+    Keyword@9..13 "FROM"
+    Whitespace@13..14 " "
+    IdentGroup@14..19
+      Ident@14..19 "sales"
+    Whitespace@19..20 " "
+    UnsupportedClause@20..36
+      Keyword@20..29 "PARTITION"
+      Whitespace@29..30 " "
+      LParen@30..31 "("
+      Ident@31..33 "q1"
+      RParen@33..34 ")"
+      Whitespace@34..35 " "
+      Alias@35..36
+        Ident@35..36 "s"
+"#]],
+            vec![ParseError::new(
+                ParseErrorType::Unimplemented("partition extension clause".to_string()),
+                20..29,
+            )],
+        );
+    }
+
+    #[test]
+    fn test_clause_words_are_no_alias() {
+        for word in ["UNION", "MINUS", "INTERSECT", "FETCH", "OFFSET"] {
+            let input = format!("SELECT a FROM t {word}");
+            let parse = parse(&input, |p| parse_query(p, false));
+            assert!(
+                !parse
+                    .syntax()
+                    .descendants()
+                    .any(|n| n.kind() == SyntaxKind::Alias),
+                "{word} taken as alias"
+            );
+        }
+    }
+
+    #[test]
+    fn test_flashback_query_is_unsupported() {
+        check(
+            parse(
+                "SELECT * FROM emp AS OF TIMESTAMP SYSTIMESTAMP - INTERVAL '1' HOUR WHERE id = 1;",
+                |p| parse_query(p, false),
+            ),
+            expect![[r#"
+Root@0..80
+  SelectStmt@0..80
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    Asterisk@7..8 "*"
+    Whitespace@8..9 " "
+    Keyword@9..13 "FROM"
+    Whitespace@13..14 " "
+    IdentGroup@14..17
+      Ident@14..17 "emp"
+    Whitespace@17..18 " "
+    UnsupportedClause@18..66
+      Keyword@18..20 "AS"
+      Whitespace@20..21 " "
+      Keyword@21..23 "OF"
+      Whitespace@23..24 " "
+      Keyword@24..33 "TIMESTAMP"
+      Whitespace@33..34 " "
+      Expression@34..66
+        IdentGroup@34..46
+          Ident@34..46 "SYSTIMESTAMP"
+        Whitespace@46..47 " "
+        ArithmeticOp@47..48 "-"
+        Whitespace@48..49 " "
+        TypedLiteral@49..66
+          Keyword@49..57 "INTERVAL"
+          Whitespace@57..58 " "
+          QuotedLiteral@58..61 "'1'"
+          Whitespace@61..62 " "
+          Keyword@62..66 "HOUR"
+    Whitespace@66..67 " "
+    WhereClause@67..79
+      Keyword@67..72 "WHERE"
+      Whitespace@72..73 " "
+      Expression@73..79
+        IdentGroup@73..75
+          Ident@73..75 "id"
+        Whitespace@75..76 " "
+        ComparisonOp@76..77 "="
+        Whitespace@77..78 " "
+        Integer@78..79 "1"
+    Semicolon@79..80 ";"
+"#]],
+            vec![ParseError::new(
+                ParseErrorType::Unimplemented("flashback query clause".to_string()),
+                18..20,
+            )],
+        );
+    }
+
+    #[test]
+    fn test_scalar_subquery_in_select_list() {
+        check(
+            parse("SELECT name, (SELECT max(x) FROM t) AS mx FROM emp;", |p| {
+                parse_query(p, false)
+            }),
+            expect![[r#"
+Root@0..51
+  SelectStmt@0..51
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    SelectClause@7..41
+      ColumnExpr@7..11
+        Expression@7..11
+          IdentGroup@7..11
+            Ident@7..11 "name"
+      Comma@11..12 ","
+      Whitespace@12..13 " "
+      ColumnExpr@13..41
+        LParen@13..14 "("
+        SelectStmt@14..34
+          Keyword@14..20 "SELECT"
+          Whitespace@20..21 " "
+          SelectClause@21..27
+            ColumnExpr@21..27
+              FunctionInvocation@21..27
+                IdentGroup@21..24
+                  Ident@21..24 "max"
+                LParen@24..25 "("
+                ArgumentList@25..26
+                  Argument@25..26
+                    IdentGroup@25..26
+                      Ident@25..26 "x"
+                RParen@26..27 ")"
+          Whitespace@27..28 " "
+          Keyword@28..32 "FROM"
+          Whitespace@32..33 " "
+          IdentGroup@33..34
+            Ident@33..34 "t"
+        RParen@34..35 ")"
+        Whitespace@35..36 " "
+        Alias@36..41
+          Keyword@36..38 "AS"
+          Whitespace@38..39 " "
+          Ident@39..41 "mx"
+    Whitespace@41..42 " "
+    Keyword@42..46 "FROM"
+    Whitespace@46..47 " "
+    IdentGroup@47..50
+      Ident@47..50 "emp"
+    Semicolon@50..51 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_case_in_group_by_and_order_by() {
+        check(
+            parse(
+                "SELECT name FROM emp GROUP BY CASE WHEN a > 1 THEN 'x' ELSE 'y' END ORDER BY CASE dept WHEN 1 THEN 0 ELSE 1 END DESC, name;",
+                |p| parse_query(p, false),
+            ),
+            expect![[r#"
+Root@0..123
+  SelectStmt@0..123
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    SelectClause@7..11
+      ColumnExpr@7..11
+        IdentGroup@7..11
+          Ident@7..11 "name"
+    Whitespace@11..12 " "
+    Keyword@12..16 "FROM"
+    Whitespace@16..17 " "
+    IdentGroup@17..20
+      Ident@17..20 "emp"
+    Whitespace@20..21 " "
+    GroupByClause@21..67
+      Keyword@21..26 "GROUP"
+      Whitespace@26..27 " "
+      Keyword@27..29 "BY"
+      Whitespace@29..30 " "
+      CaseStmt@30..67
+        Keyword@30..34 "CASE"
+        Whitespace@34..35 " "
+        SearchedCaseExpression@35..54
+          Keyword@35..39 "WHEN"
+          Whitespace@39..40 " "
+          Expression@40..45
+            IdentGroup@40..41
+              Ident@40..41 "a"
+            Whitespace@41..42 " "
+            ComparisonOp@42..43 ">"
+            Whitespace@43..44 " "
+            Integer@44..45 "1"
+          Whitespace@45..46 " "
+          Keyword@46..50 "THEN"
+          Whitespace@50..51 " "
+          QuotedLiteral@51..54 "'x'"
+        Whitespace@54..55 " "
+        ElseExpression@55..63
+          Keyword@55..59 "ELSE"
+          Whitespace@59..60 " "
+          QuotedLiteral@60..63 "'y'"
+        Whitespace@63..64 " "
+        Keyword@64..67 "END"
+    Whitespace@67..68 " "
+    OrderByClause@68..122
+      Keyword@68..73 "ORDER"
+      Whitespace@73..74 " "
+      Keyword@74..76 "BY"
+      Whitespace@76..77 " "
+      CaseStmt@77..111
+        Keyword@77..81 "CASE"
+        Whitespace@81..82 " "
+        SimpleCaseExpression@82..100
+          IdentGroup@82..86
+            Ident@82..86 "dept"
+          Whitespace@86..87 " "
+          Keyword@87..91 "WHEN"
+          Whitespace@91..92 " "
+          ComparissonExpression@92..93
+            Integer@92..93 "1"
+          Whitespace@93..94 " "
+          Keyword@94..98 "THEN"
+          Whitespace@98..99 " "
+          Integer@99..100 "0"
+        Whitespace@100..101 " "
+        ElseExpression@101..107
+          Keyword@101..105 "ELSE"
+          Whitespace@105..106 " "
+          Integer@106..107 "1"
+        Whitespace@107..108 " "
+        Keyword@108..111 "END"
+      Whitespace@111..112 " "
+      Keyword@112..116 "DESC"
+      Comma@116..117 ","
+      Whitespace@117..118 " "
+      Expression@118..122
+        IdentGroup@118..122
+          Ident@118..122 "name"
+    Semicolon@122..123 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_select_bulk_collect_into() {
+        check(
+            parse(
+                "SELECT id, name BULK COLLECT INTO l_ids, l_names FROM emp;",
+                |p| parse_query(p, true),
+            ),
+            expect![[r#"
+Root@0..58
+  SelectStmt@0..58
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    SelectClause@7..15
+      ColumnExpr@7..9
+        Expression@7..9
+          IdentGroup@7..9
+            Ident@7..9 "id"
+      Comma@9..10 ","
+      Whitespace@10..11 " "
+      ColumnExpr@11..15
+        IdentGroup@11..15
+          Ident@11..15 "name"
+    Whitespace@15..16 " "
+    BulkIntoClause@16..48
+      Keyword@16..20 "BULK"
+      Whitespace@20..21 " "
+      Keyword@21..28 "COLLECT"
+      Whitespace@28..29 " "
+      Keyword@29..33 "INTO"
+      Whitespace@33..34 " "
+      IdentGroup@34..39
+        Ident@34..39 "l_ids"
+      Comma@39..40 ","
+      Whitespace@40..41 " "
+      IdentGroup@41..48
+        Ident@41..48 "l_names"
+    Whitespace@48..49 " "
+    Keyword@49..53 "FROM"
+    Whitespace@53..54 " "
+    IdentGroup@54..57
+      Ident@54..57 "emp"
+    Semicolon@57..58 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_insert_without_column_list() {
+        check(
+            parse("INSERT INTO t VALUES (1, 2);", parse_insert),
+            expect![[r#"
+Root@0..28
+  InsertStmt@0..28
+    Keyword@0..6 "INSERT"
+    Whitespace@6..7 " "
+    Keyword@7..11 "INTO"
+    Whitespace@11..12 " "
+    IdentGroup@12..13
+      Ident@12..13 "t"
+    Whitespace@13..14 " "
+    Keyword@14..20 "VALUES"
+    Whitespace@20..21 " "
+    LParen@21..22 "("
+    Expression@22..23
+      Integer@22..23 "1"
+    Comma@23..24 ","
+    Whitespace@24..25 " "
+    Integer@25..26 "2"
+    RParen@26..27 ")"
+    Semicolon@27..28 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_select_with_keyword_columns_and_aliases() {
+        check(
+            parse("SELECT a || b name, c type, end FROM t", |p| {
+                parse_query(p, false)
+            }),
+            expect![[r#"
+Root@0..38
+  SelectStmt@0..38
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    SelectClause@7..31
+      ColumnExpr@7..18
+        Expression@7..13
+          IdentGroup@7..8
+            Ident@7..8 "a"
+          Whitespace@8..9 " "
+          Concat@9..11 "||"
+          Whitespace@11..12 " "
+          IdentGroup@12..13
+            Ident@12..13 "b"
+        Whitespace@13..14 " "
+        Alias@14..18
+          Ident@14..18 "name"
+      Comma@18..19 ","
+      Whitespace@19..20 " "
+      ColumnExpr@20..26
+        IdentGroup@20..21
+          Ident@20..21 "c"
+        Whitespace@21..22 " "
+        Alias@22..26
+          Ident@22..26 "type"
+      Comma@26..27 ","
+      Whitespace@27..28 " "
+      ColumnExpr@28..31
+        IdentGroup@28..31
+          Ident@28..31 "end"
+    Whitespace@31..32 " "
+    Keyword@32..36 "FROM"
+    Whitespace@36..37 " "
+    IdentGroup@37..38
+      Ident@37..38 "t"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_set_operators() {
+        check(
+            parse(
+                "SELECT a FROM x UNION ALL (SELECT b FROM y) MINUS SELECT c FROM z ORDER BY 1",
+                |p| parse_query(p, false),
+            ),
+            expect![[r#"
+Root@0..76
+  SelectStmt@0..76
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    SelectClause@7..8
+      ColumnExpr@7..8
+        IdentGroup@7..8
+          Ident@7..8 "a"
+    Whitespace@8..9 " "
+    Keyword@9..13 "FROM"
+    Whitespace@13..14 " "
+    IdentGroup@14..15
+      Ident@14..15 "x"
+    Whitespace@15..16 " "
+    Keyword@16..21 "UNION"
+    Whitespace@21..22 " "
+    Keyword@22..25 "ALL"
+    Whitespace@25..26 " "
+    LParen@26..27 "("
+    SelectStmt@27..42
+      Keyword@27..33 "SELECT"
+      Whitespace@33..34 " "
+      SelectClause@34..35
+        ColumnExpr@34..35
+          IdentGroup@34..35
+            Ident@34..35 "b"
+      Whitespace@35..36 " "
+      Keyword@36..40 "FROM"
+      Whitespace@40..41 " "
+      IdentGroup@41..42
+        Ident@41..42 "y"
+    RParen@42..43 ")"
+    Whitespace@43..44 " "
+    Keyword@44..49 "MINUS"
+    Whitespace@49..50 " "
+    SelectStmt@50..65
+      Keyword@50..56 "SELECT"
+      Whitespace@56..57 " "
+      SelectClause@57..58
+        ColumnExpr@57..58
+          IdentGroup@57..58
+            Ident@57..58 "c"
+      Whitespace@58..59 " "
+      Keyword@59..63 "FROM"
+      Whitespace@63..64 " "
+      IdentGroup@64..65
+        Ident@64..65 "z"
+    Whitespace@65..66 " "
+    OrderByClause@66..76
+      Keyword@66..71 "ORDER"
+      Whitespace@71..72 " "
+      Keyword@72..74 "BY"
+      Whitespace@74..75 " "
+      Integer@75..76 "1"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_insert_subquery() {
+        check(
+            parse("INSERT INTO t (a) SELECT b FROM u;", parse_insert),
+            expect![[r#"
+Root@0..34
+  InsertStmt@0..34
+    Keyword@0..6 "INSERT"
+    Whitespace@6..7 " "
+    Keyword@7..11 "INTO"
+    Whitespace@11..12 " "
+    IdentGroup@12..13
+      Ident@12..13 "t"
+    Whitespace@13..14 " "
+    LParen@14..15 "("
+    IdentGroup@15..16
+      Ident@15..16 "a"
+    RParen@16..17 ")"
+    Whitespace@17..18 " "
+    SelectStmt@18..34
+      Keyword@18..24 "SELECT"
+      Whitespace@24..25 " "
+      SelectClause@25..26
+        ColumnExpr@25..26
+          IdentGroup@25..26
+            Ident@25..26 "b"
+      Whitespace@26..27 " "
+      Keyword@27..31 "FROM"
+      Whitespace@31..32 " "
+      IdentGroup@32..33
+        Ident@32..33 "u"
+      Semicolon@33..34 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_insert_record() {
+        check(
+            parse("INSERT INTO emp VALUES l_emps(i);", parse_insert),
+            expect![[r#"
+Root@0..33
+  InsertStmt@0..33
+    Keyword@0..6 "INSERT"
+    Whitespace@6..7 " "
+    Keyword@7..11 "INTO"
+    Whitespace@11..12 " "
+    IdentGroup@12..15
+      Ident@12..15 "emp"
+    Whitespace@15..16 " "
+    Keyword@16..22 "VALUES"
+    Whitespace@22..23 " "
+    RecordRow@23..32
+      Expression@23..32
+        FunctionInvocation@23..32
+          IdentGroup@23..29
+            Ident@23..29 "l_emps"
+          LParen@29..30 "("
+          ArgumentList@30..31
+            Argument@30..31
+              IdentGroup@30..31
+                Ident@30..31 "i"
+          RParen@31..32 ")"
+    Semicolon@32..33 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_cross_apply_lateral_and_table_collection() {
+        check(
+            parse(
+                "SELECT * FROM emp e CROSS APPLY (SELECT * FROM dept d WHERE d.id = e.dept_id) d, LATERAL (SELECT 1 FROM dual) x, TABLE(get_ids(e.id)) t;",
+                |p| parse_query(p, false),
+            ),
+            expect![[r#"
+Root@0..136
+  SelectStmt@0..136
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    Asterisk@7..8 "*"
+    Whitespace@8..9 " "
+    Keyword@9..13 "FROM"
+    Whitespace@13..14 " "
+    IdentGroup@14..17
+      Ident@14..17 "emp"
+    Whitespace@17..18 " "
+    IdentGroup@18..19
+      Ident@18..19 "e"
+    Whitespace@19..20 " "
+    JoinClause@20..79
+      CrossOuterApplyClause@20..79
+        Keyword@20..25 "CROSS"
+        Whitespace@25..26 " "
+        Keyword@26..31 "APPLY"
+        Whitespace@31..32 " "
+        InlineView@32..77
+          LParen@32..33 "("
+          SelectStmt@33..76
+            Keyword@33..39 "SELECT"
+            Whitespace@39..40 " "
+            Asterisk@40..41 "*"
+            Whitespace@41..42 " "
+            Keyword@42..46 "FROM"
+            Whitespace@46..47 " "
+            IdentGroup@47..51
+              Ident@47..51 "dept"
+            Whitespace@51..52 " "
+            IdentGroup@52..53
+              Ident@52..53 "d"
+            Whitespace@53..54 " "
+            WhereClause@54..76
+              Keyword@54..59 "WHERE"
+              Whitespace@59..60 " "
+              Expression@60..76
+                IdentGroup@60..64
+                  Ident@60..61 "d"
+                  Dot@61..62 "."
+                  Ident@62..64 "id"
+                Whitespace@64..65 " "
+                ComparisonOp@65..66 "="
+                Whitespace@66..67 " "
+                IdentGroup@67..76
+                  Ident@67..68 "e"
+                  Dot@68..69 "."
+                  Ident@69..76 "dept_id"
+          RParen@76..77 ")"
+        Whitespace@77..78 " "
+        IdentGroup@78..79
+          Ident@78..79 "d"
+    Comma@79..80 ","
+    Whitespace@80..81 " "
+    InlineView@81..109
+      Keyword@81..88 "LATERAL"
+      Whitespace@88..89 " "
+      LParen@89..90 "("
+      SelectStmt@90..108
+        Keyword@90..96 "SELECT"
+        Whitespace@96..97 " "
+        SelectClause@97..98
+          ColumnExpr@97..98
+            Integer@97..98 "1"
+        Whitespace@98..99 " "
+        Keyword@99..103 "FROM"
+        Whitespace@103..104 " "
+        IdentGroup@104..108
+          Ident@104..108 "dual"
+      RParen@108..109 ")"
+    Whitespace@109..110 " "
+    IdentGroup@110..111
+      Ident@110..111 "x"
+    Comma@111..112 ","
+    Whitespace@112..113 " "
+    TableCollectionExpr@113..133
+      Keyword@113..118 "TABLE"
+      LParen@118..119 "("
+      FunctionInvocation@119..132
+        IdentGroup@119..126
+          Ident@119..126 "get_ids"
+        LParen@126..127 "("
+        ArgumentList@127..131
+          Argument@127..131
+            IdentGroup@127..131
+              Ident@127..128 "e"
+              Dot@128..129 "."
+              Ident@129..131 "id"
+        RParen@131..132 ")"
+      RParen@132..133 ")"
+    Whitespace@133..134 " "
+    IdentGroup@134..135
+      Ident@134..135 "t"
+    Semicolon@135..136 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_outer_apply_function() {
+        check(
+            parse("SELECT * FROM emp e OUTER APPLY get_depts(e.id) d;", |p| {
+                parse_query(p, false)
+            }),
+            expect![[r#"
+Root@0..50
+  SelectStmt@0..50
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    Asterisk@7..8 "*"
+    Whitespace@8..9 " "
+    Keyword@9..13 "FROM"
+    Whitespace@13..14 " "
+    IdentGroup@14..17
+      Ident@14..17 "emp"
+    Whitespace@17..18 " "
+    IdentGroup@18..19
+      Ident@18..19 "e"
+    Whitespace@19..20 " "
+    JoinClause@20..49
+      CrossOuterApplyClause@20..49
+        Keyword@20..25 "OUTER"
+        Whitespace@25..26 " "
+        Keyword@26..31 "APPLY"
+        Whitespace@31..32 " "
+        FunctionInvocation@32..47
+          IdentGroup@32..41
+            Ident@32..41 "get_depts"
+          LParen@41..42 "("
+          ArgumentList@42..46
+            Argument@42..46
+              IdentGroup@42..46
+                Ident@42..43 "e"
+                Dot@43..44 "."
+                Ident@44..46 "id"
+          RParen@46..47 ")"
+        Whitespace@47..48 " "
+        IdentGroup@48..49
+          Ident@48..49 "d"
+    Semicolon@49..50 ";"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_from_list_aliases_with_column_lists() {
+        check(
+            parse(
+                "SELECT x.a FROM emp AS e, (SELECT 1, 2 FROM dual) x (a, b) JOIN dept d (id) USING (id);",
+                |p| parse_query(p, false),
+            ),
+            expect![[r#"
+Root@0..87
+  SelectStmt@0..87
+    Keyword@0..6 "SELECT"
+    Whitespace@6..7 " "
+    SelectClause@7..10
+      ColumnExpr@7..10
+        IdentGroup@7..10
+          Ident@7..8 "x"
+          Dot@8..9 "."
+          Ident@9..10 "a"
+    Whitespace@10..11 " "
+    Keyword@11..15 "FROM"
+    Whitespace@15..16 " "
+    IdentGroup@16..19
+      Ident@16..19 "emp"
+    Whitespace@19..20 " "
+    Keyword@20..22 "AS"
+    Whitespace@22..23 " "
+    IdentGroup@23..24
+      Ident@23..24 "e"
+    Comma@24..25 ","
+    Whitespace@25..26 " "
+    InlineView@26..49
+      LParen@26..27 "("
+      SelectStmt@27..48
+        Keyword@27..33 "SELECT"
+        Whitespace@33..34 " "
+        SelectClause@34..38
+          ColumnExpr@34..35
+            Expression@34..35
+              Integer@34..35 "1"
+          Comma@35..36 ","
+          Whitespace@36..37 " "
+          ColumnExpr@37..38
+            Integer@37..38 "2"
+        Whitespace@38..39 " "
+        Keyword@39..43 "FROM"
+        Whitespace@43..44 " "
+        IdentGroup@44..48
+          Ident@44..48 "dual"
+      RParen@48..49 ")"
+    Whitespace@49..50 " "
+    IdentGroup@50..51
+      Ident@50..51 "x"
+    Whitespace@51..52 " "
+    ColumnAliasList@52..58
+      LParen@52..53 "("
+      IdentGroup@53..54
+        Ident@53..54 "a"
+      Comma@54..55 ","
+      Whitespace@55..56 " "
+      IdentGroup@56..57
+        Ident@56..57 "b"
+      RParen@57..58 ")"
+    Whitespace@58..59 " "
+    JoinClause@59..86
+      InnerJoinClause@59..86
+        Keyword@59..63 "JOIN"
+        Whitespace@63..64 " "
+        IdentGroup@64..68
+          Ident@64..68 "dept"
+        Whitespace@68..69 " "
+        IdentGroup@69..70
+          Ident@69..70 "d"
+        Whitespace@70..71 " "
+        ColumnAliasList@71..75
+          LParen@71..72 "("
+          IdentGroup@72..74
+            Ident@72..74 "id"
+          RParen@74..75 ")"
+        Whitespace@75..76 " "
+        Keyword@76..81 "USING"
+        Whitespace@81..82 " "
+        LParen@82..83 "("
+        IdentGroup@83..85
+          Ident@83..85 "id"
+        RParen@85..86 ")"
+    Semicolon@86..87 ";"
 "#]],
             vec![],
         );
@@ -2,6 +2,7 @@ use crate::{safe_loop, Parser};
 use source_gen::{lexer::TokenKind, syntax::SyntaxKind, T};
 
 use super::parse_ident;
+use super::udt::parse_sharing_clause;
 
 #[allow(unused)]
 pub(crate) fn parse_sequence(p: &mut Parser) {
@@ -13,9 +14,8 @@ pub(crate) fn parse_sequence(p: &mut Parser) {
         p.expect(T![exists]);
     }
     parse_ident(p, 1..2);
-    if p.eat(T![sharing]) {
-        p.expect(T![=]);
-        p.expect_one_of(&[T![metadata], T![data], T![none]]);
+    if p.at(T![sharing]) {
+        parse_sharing_clause(p);
     }
     parse_sequence_params(p);
     p.eat(T![;]);
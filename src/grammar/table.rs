@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH
+// <office@cybertec.at>
+
+//! Implements parsing of tables created from a query from a token tree.
+
+use crate::grammar::physical_properties::{at_ignored_clause, parse_ignored_clause};
+use crate::parser::Parser;
+use source_gen::lexer::TokenKind;
+use source_gen::syntax::SyntaxKind;
+
+use super::*;
+
+/// Parses a complete `CREATE TABLE .. AS SELECT` statement. Physical
+/// properties, e.g. `NOLOGGING` or `PARALLEL`, are skipped.
+/// Refer to https://docs.oracle.com/en/database/oracle/oracle-database/23/sqlrf/CREATE-TABLE.html
+pub(crate) fn parse_table(p: &mut Parser) {
+    p.start(SyntaxKind::Table);
+
+    p.expect(T![create]);
+    p.expect(T![table]);
+
+    if p.eat(T![if]) {
+        p.expect(T![not]);
+        p.expect(T![exists]);
+    }
+
+    parse_ident(p, 1..2);
+
+    if p.at(T!["("]) {
+        parse_column_list(p);
+    }
+
+    while at_ignored_clause(p) {
+        parse_ignored_clause(p);
+    }
+
+    p.expect(T![as]);
+    if p.at(T![with]) {
+        parse_cte(p);
+    } else {
+        parse_query(p, false);
+    }
+
+    p.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use super::super::tests::{check, parse};
+    use super::*;
+
+    #[test]
+    fn test_parse_table_as_select() {
+        check(
+            parse(
+                "CREATE TABLE stage.orders_tmp (id, total) NOLOGGING PARALLEL 8 COMPRESS AS SELECT id, amount FROM orders WHERE 1 = 0;",
+                parse_table,
+            ),
+            expect![[r#"
+Root@0..117
+  Table@0..117
+    Keyword@0..6 "CREATE"
+    Whitespace@6..7 " "
+    Keyword@7..12 "TABLE"
+    Whitespace@12..13 " "
+    IdentGroup@13..29
+      Ident@13..18 "stage"
+      Dot@18..19 "."
+      Ident@19..29 "orders_tmp"
+    Whitespace@29..30 " "
+    LParen@30..31 "("
+    IdentGroup@31..33
+      Ident@31..33 "id"
+    Comma@33..34 ","
+    Whitespace@34..35 " "
+    IdentGroup@35..40
+      Ident@35..40 "total"
+    RParen@40..41 ")"
+    Whitespace@41..42 " "
+    IgnoredClause@42..51
+      Keyword@42..51 "NOLOGGING"
+    Whitespace@51..52 " "
+    IgnoredClause@52..62
+      Keyword@52..60 "PARALLEL"
+      Whitespace@60..61 " "
+      Integer@61..62 "8"
+    Whitespace@62..63 " "
+    IgnoredClause@63..71
+      Keyword@63..71 "COMPRESS"
+    Whitespace@71..72 " "
+    Keyword@72..74 "AS"
+    Whitespace@74..75 " "
+    SelectStmt@75..117
+      Keyword@75..81 "SELECT"
+      Whitespace@81..82 " "
+      SelectClause@82..92
+        ColumnExpr@82..84
+          Expression@82..84
+            IdentGroup@82..84
+              Ident@82..84 "id"
+        Comma@84..85 ","
+        Whitespace@85..86 " "
+        ColumnExpr@86..92
+          IdentGroup@86..92
+            Ident@86..92 "amount"
+      Whitespace@92..93 " "
+      Keyword@93..97 "FROM"
+      Whitespace@97..98 " "
+      IdentGroup@98..104
+        Ident@98..104 "orders"
+      Whitespace@104..105 " "
+      WhereClause@105..116
+        Keyword@105..110 "WHERE"
+        Whitespace@110..111 " "
+        Expression@111..116
+          Integer@111..112 "1"
+          Whitespace@112..113 " "
+          ComparisonOp@113..114 "="
+          Whitespace@114..115 " "
+          Integer@115..116 "0"
+      Semicolon@116..117 ";"
+"#]],
+            vec![],
+        );
+    }
+}
@@ -57,7 +57,7 @@ fn parse_header(p: &mut Parser) {
 
 fn parse_simple_dml_trigger(p: &mut Parser) {
     parse_dml_event_clause(p);
-    parse_referencing_clause(p);
+    let correlation_names = parse_referencing_clause(p);
 
     if p.eat(T![for]) {
         p.expect(T![each]);
@@ -69,9 +69,13 @@ fn parse_simple_dml_trigger(p: &mut Parser) {
 
     p.eat_one_of(&[T![enable], T![disable]]);
 
+    // Other than in the body, `NEW` and `OLD` are not prefixed with a colon
+    // here, but still refer to the rows
     if p.eat(T![when]) {
         p.expect(T!["("]);
+        p.set_correlation_names(correlation_names);
         parse_expr(p);
+        p.set_correlation_names(Vec::new());
         p.expect(T![")"]);
     }
 }
@@ -166,13 +170,23 @@ fn parse_dml_event_clause(p: &mut Parser) {
 }
 
 const REFERENCING_TOKENS: &[TokenKind] = &[T![old], T![new], T![parent]];
-fn parse_referencing_clause(p: &mut Parser) {
+
+/// Parses the optional `REFERENCING` clause and returns the lowercased
+/// correlation names of the rows, i.e. `new`, `old` and `parent` unless
+/// renamed.
+fn parse_referencing_clause(p: &mut Parser) -> Vec<String> {
+    let mut names = ["old", "new", "parent"].map(str::to_owned).to_vec();
+
     if p.eat(T![referencing]) {
         safe_loop!(p, {
+            let index = REFERENCING_TOKENS.iter().position(|t| p.at(*t));
             if !p.expect_one_of(REFERENCING_TOKENS) {
                 break;
             }
             p.eat(T![as]);
+            if let Some(index) = index {
+                names[index] = p.current_text().to_lowercase();
+            }
             parse_ident(p, 1..1);
 
             if !REFERENCING_TOKENS.contains(&p.current()) {
@@ -180,6 +194,8 @@ fn parse_referencing_clause(p: &mut Parser) {
             }
         });
     }
+
+    names
 }
 
 fn parse_trigger_edition_clause(p: &mut Parser) {
@@ -345,8 +361,8 @@ Root@0..228
             parse(INPUT, parse_trigger),
             expect![[r#"
 Root@0..237
-  Trigger@0..237
-    TriggerHeader@0..123
+  Trigger@0..236
+    TriggerHeader@0..122
       Keyword@0..6 "CREATE"
       Whitespace@6..7 " "
       Keyword@7..9 "OR"
@@ -385,7 +401,7 @@ Root@0..237
       Keyword@114..118 "EACH"
       Whitespace@118..119 " "
       Keyword@119..122 "ROW"
-      Whitespace@122..123 "\n"
+    Whitespace@122..123 "\n"
     Block@123..236
       Keyword@123..128 "BEGIN"
       Whitespace@128..131 "\n  "
@@ -435,7 +451,7 @@ Root@0..237
       Whitespace@231..232 "\n"
       Keyword@232..235 "END"
       Semicolon@235..236 ";"
-    Whitespace@236..237 "\n"
+  Whitespace@236..237 "\n"
 "#]],
             vec![],
         );
@@ -448,8 +464,8 @@ Root@0..237
             parse(INPUT, parse_trigger),
             expect![[r#"
 Root@0..518
-  Trigger@0..518
-    TriggerHeader@0..95
+  Trigger@0..516
+    TriggerHeader@0..94
       Keyword@0..6 "CREATE"
       Whitespace@6..7 " "
       Keyword@7..9 "OR"
@@ -477,9 +493,9 @@ Root@0..518
       Keyword@86..90 "EACH"
       Whitespace@90..91 " "
       Keyword@91..94 "ROW"
-      Whitespace@94..95 "\n"
+    Whitespace@94..95 "\n"
     Block@95..516
-      DeclareSection@95..118
+      DeclareSection@95..117
         Keyword@95..102 "DECLARE"
         Whitespace@102..107 "\n    "
         IdentGroup@107..109
@@ -488,7 +504,7 @@ Root@0..518
         Datatype@110..116
           Keyword@110..116 "NUMBER"
         Semicolon@116..117 ";"
-        Whitespace@117..118 "\n"
+      Whitespace@117..118 "\n"
       Keyword@118..123 "BEGIN"
       Whitespace@123..128 "\n    "
       BlockStatement@128..133
@@ -497,7 +513,7 @@ Root@0..518
       Whitespace@133..138 "\n    "
       InlineComment@138..168 "-- insert a new custo ..."
       Whitespace@168..173 "\n    "
-      BlockStatement@173..368
+      BlockStatement@173..336
         InsertStmt@173..336
           Keyword@173..179 "INSERT"
           Whitespace@179..180 " "
@@ -551,20 +567,22 @@ Root@0..518
             Ident@288..300 "credit_limit"
           RParen@300..301 ")"
           Whitespace@301..306 "\n    "
-          Keyword@306..315 "RETURNING"
-          Whitespace@315..316 " "
-          IdentGroup@316..327
-            Ident@316..327 "customer_id"
-          Whitespace@327..328 " "
-          Keyword@328..332 "INTO"
-          Whitespace@332..333 " "
-          IdentGroup@333..335
-            Ident@333..335 "id"
+          ReturningClause@306..335
+            Keyword@306..315 "RETURNING"
+            Whitespace@315..316 " "
+            IdentGroup@316..327
+              Ident@316..327 "customer_id"
+            Whitespace@327..328 " "
+            IntoClause@328..335
+              Keyword@328..332 "INTO"
+              Whitespace@332..333 " "
+              IdentGroup@333..335
+                Ident@333..335 "id"
           Semicolon@335..336 ";"
-        Whitespace@336..342 "\n\n    "
-        InlineComment@342..363 "-- insert the contact"
-        Whitespace@363..368 "\n    "
-      BlockStatement@368..512
+      Whitespace@336..342 "\n\n    "
+      InlineComment@342..363 "-- insert the contact"
+      Whitespace@363..368 "\n    "
+      BlockStatement@368..511
         InsertStmt@368..511
           Keyword@368..374 "INSERT"
           Whitespace@374..375 " "
@@ -627,10 +645,91 @@ Root@0..518
             Ident@507..509 "id"
           RParen@509..510 ")"
           Semicolon@510..511 ";"
-        Whitespace@511..512 "\n"
+      Whitespace@511..512 "\n"
       Keyword@512..515 "END"
       Semicolon@515..516 ";"
-    Whitespace@516..518 "\n\n"
+  Whitespace@516..518 "\n\n"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn parse_header_with_when_clause() {
+        check(
+            parse(
+                r#"CREATE TRIGGER my_trigger
+                    BEFORE UPDATE ON my_table
+                    REFERENCING OLD AS prev
+                    FOR EACH ROW
+                    WHEN (NEW.sal > prev.sal AND old.sal IS NOT NULL)"#,
+                parse_header,
+            ),
+            expect![[r#"
+Root@0..218
+  TriggerHeader@0..218
+    Keyword@0..6 "CREATE"
+    Whitespace@6..7 " "
+    Keyword@7..14 "TRIGGER"
+    Whitespace@14..15 " "
+    IdentGroup@15..25
+      Ident@15..25 "my_trigger"
+    Whitespace@25..46 "\n                    "
+    Keyword@46..52 "BEFORE"
+    Whitespace@52..53 " "
+    Keyword@53..59 "UPDATE"
+    Whitespace@59..60 " "
+    Keyword@60..62 "ON"
+    Whitespace@62..63 " "
+    IdentGroup@63..71
+      Ident@63..71 "my_table"
+    Whitespace@71..92 "\n                    "
+    Keyword@92..103 "REFERENCING"
+    Whitespace@103..104 " "
+    Keyword@104..107 "OLD"
+    Whitespace@107..108 " "
+    Keyword@108..110 "AS"
+    Whitespace@110..111 " "
+    IdentGroup@111..115
+      Ident@111..115 "prev"
+    Whitespace@115..136 "\n                    "
+    Keyword@136..139 "FOR"
+    Whitespace@139..140 " "
+    Keyword@140..144 "EACH"
+    Whitespace@144..145 " "
+    Keyword@145..148 "ROW"
+    Whitespace@148..169 "\n                    "
+    Keyword@169..173 "WHEN"
+    Whitespace@173..174 " "
+    LParen@174..175 "("
+    Expression@175..217
+      Expression@175..193
+        IdentGroup@175..182
+          BindVar@175..178 "NEW"
+          Dot@178..179 "."
+          Ident@179..182 "sal"
+        Whitespace@182..183 " "
+        ComparisonOp@183..184 ">"
+        Whitespace@184..185 " "
+        IdentGroup@185..193
+          BindVar@185..189 "prev"
+          Dot@189..190 "."
+          Ident@190..193 "sal"
+      Whitespace@193..194 " "
+      LogicOp@194..197 "AND"
+      Whitespace@197..198 " "
+      Expression@198..217
+        IdentGroup@198..205
+          Ident@198..201 "old"
+          Dot@201..202 "."
+          Ident@202..205 "sal"
+        Whitespace@205..206 " "
+        Keyword@206..208 "IS"
+        Whitespace@208..209 " "
+        Keyword@209..212 "NOT"
+        Whitespace@212..213 " "
+        Keyword@213..217 "NULL"
+    RParen@217..218 ")"
 "#]],
             vec![],
         );
@@ -643,8 +742,8 @@ Root@0..518
             parse(INPUT, parse_trigger),
             expect![[r#"
 Root@0..84
-  Trigger@0..84
-    TriggerHeader@0..63
+  Trigger@0..83
+    TriggerHeader@0..62
       Keyword@0..6 "CREATE"
       Whitespace@6..7 " "
       Keyword@7..14 "TRIGGER"
@@ -662,7 +761,7 @@ Root@0..84
         Ident@46..55 "my_schema"
       Dot@55..56 "."
       Keyword@56..62 "SCHEMA"
-      Whitespace@62..63 "\n"
+    Whitespace@62..63 "\n"
     Block@63..83
       Keyword@63..68 "BEGIN"
       Whitespace@68..73 "\n    "
@@ -672,7 +771,7 @@ Root@0..84
       Whitespace@78..79 "\n"
       Keyword@79..82 "END"
       Semicolon@82..83 ";"
-    Whitespace@83..84 "\n"
+  Whitespace@83..84 "\n"
 "#]],
             vec![],
         );
@@ -179,7 +179,7 @@ fn parse_constructor_declaration(p: &mut Parser) {
     p.finish();
 }
 
-fn parse_result_cache_clause(p: &mut Parser) {
+pub(super) fn parse_result_cache_clause(p: &mut Parser) {
     p.start(SyntaxKind::ResultCacheClause);
     p.expect(T![result_cache]);
     if p.eat(T![relies_on]) {
@@ -195,7 +195,7 @@ fn parse_result_cache_clause(p: &mut Parser) {
     p.finish();
 }
 
-fn parse_parallel_enable_clause(p: &mut Parser) {
+pub(super) fn parse_parallel_enable_clause(p: &mut Parser) {
     p.start(SyntaxKind::ParallelEnableClause);
     p.expect(T![parallel_enable]);
     if p.eat(T!["("]) {
@@ -249,11 +249,18 @@ fn parse_streaming_clause(p: &mut Parser) {
     p.finish();
 }
 
-fn parse_sharing_clause(p: &mut Parser) {
+/// Parses the `SHARING` clause of objects in application containers. All
+/// sharing modes are accepted, although `DATA` and `EXTENDED DATA` are only
+/// valid for tables, views and sequences.
+pub(super) fn parse_sharing_clause(p: &mut Parser) {
     p.start(SyntaxKind::SharingClause);
     p.expect(T![sharing]);
     p.expect(T![=]);
-    p.expect_one_of(&[T![metadata], T![none]]);
+    if p.eat(T![extended]) {
+        p.expect(T![data]);
+    } else {
+        p.expect_one_of(&[T![metadata], T![data], T![none]]);
+    }
     p.finish();
 }
 
@@ -265,7 +272,7 @@ fn parse_default_collation_clause(p: &mut Parser) {
     p.finish();
 }
 
-fn parse_accessible_by_clause(p: &mut Parser) {
+pub(super) fn parse_accessible_by_clause(p: &mut Parser) {
     p.start(SyntaxKind::AccessibleByClause);
     p.expect(T![accessible]);
     p.expect(T![by]);
@@ -279,7 +286,7 @@ fn parse_accessible_by_clause(p: &mut Parser) {
             T![type],
         ]);
         parse_ident(p, 1..2);
-        if !p.at(T![,]) || p.at(T![")"]) {
+        if !p.eat(T![,]) {
             break;
         }
     });
@@ -287,7 +294,7 @@ fn parse_accessible_by_clause(p: &mut Parser) {
     p.finish();
 }
 
-fn parse_invoker_rights_clause(p: &mut Parser) {
+pub(super) fn parse_invoker_rights_clause(p: &mut Parser) {
     p.start(SyntaxKind::InvokerRightsClause);
     p.expect(T![authid]);
     p.expect_one_of(&[T![current_user], T![definer]]);
@@ -477,14 +484,14 @@ Root@0..113
     Whitespace@6..7 " "
     Keyword@7..11 "TYPE"
     Whitespace@11..12 " "
-    PlsqlTypeSource@12..112
+    PlsqlTypeSource@12..111
       IdentGroup@12..29
         Ident@12..29 "customer_typ_demo"
       Whitespace@29..30 " "
-      ObjectBaseTypeDef@30..112
+      ObjectBaseTypeDef@30..111
         Keyword@30..32 "AS"
         Whitespace@32..33 " "
-        ObjectTypeDef@33..112
+        ObjectTypeDef@33..111
           Keyword@33..39 "OBJECT"
           Whitespace@39..40 " "
           LParen@40..41 "("
@@ -492,12 +499,12 @@ Root@0..113
           IdentGroup@42..53
             Ident@42..53 "customer_id"
           Whitespace@53..54 " "
-          Datatype@54..68
+          Datatype@54..63
             Keyword@54..60 "NUMBER"
             LParen@60..61 "("
             Integer@61..62 "6"
             RParen@62..63 ")"
-            Whitespace@63..68 "\n    "
+          Whitespace@63..68 "\n    "
           Comma@68..69 ","
           Whitespace@69..70 " "
           IdentGroup@70..82
@@ -508,7 +515,7 @@ Root@0..113
               Ident@89..105 "CUST_ADDRESS_TYP"
             Whitespace@105..110 "\n    "
           RParen@110..111 ")"
-          Whitespace@111..112 " "
+    Whitespace@111..112 " "
     Semicolon@112..113 ";"
 "#]],
             vec![],
@@ -591,10 +598,10 @@ Root@0..113
             Keyword@43..49 "NUMBER"
           Comma@49..50 ","
           Whitespace@50..57 " \n     "
-          ElementSpec@57..111
+          ElementSpec@57..106
             Keyword@57..63 "MEMBER"
             Whitespace@63..64 " "
-            FunctionSpec@64..111
+            FunctionSpec@64..106
               Keyword@64..72 "FUNCTION"
               Whitespace@72..73 " "
               IdentGroup@73..77
@@ -609,9 +616,9 @@ Root@0..113
               Whitespace@92..93 " "
               Keyword@93..99 "RETURN"
               Whitespace@99..100 " "
-              Datatype@100..111
+              Datatype@100..106
                 Keyword@100..106 "NUMBER"
-                Whitespace@106..111 " \n   "
+          Whitespace@106..111 " \n   "
           RParen@111..112 ")"
     Semicolon@112..113 ";"
 "#]],
@@ -774,8 +781,8 @@ Root@0..207
       Whitespace@38..39 " "
       Keyword@39..41 "IS"
       Whitespace@41..45 "\n   "
-      SubprogDeclInType@45..203
-        FuncDeclInType@45..203
+      SubprogDeclInType@45..202
+        FuncDeclInType@45..202
           Keyword@45..53 "FUNCTION"
           Whitespace@53..54 " "
           IdentGroup@54..67
@@ -841,7 +848,7 @@ Root@0..207
             Whitespace@191..198 "\n      "
             Keyword@198..201 "END"
             Semicolon@201..202 ";"
-          Whitespace@202..203 "\n"
+      Whitespace@202..203 "\n"
       Keyword@203..206 "END"
     Semicolon@206..207 ";"
 "#]],
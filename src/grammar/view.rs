@@ -4,6 +4,8 @@
 
 //! Implements parsing of views from a token tree.
 
+use crate::grammar::physical_properties::{at_ignored_clause, parse_ignored_clause};
+use crate::grammar::udt::parse_sharing_clause;
 use crate::parser::Parser;
 use source_gen::lexer::TokenKind;
 use source_gen::syntax::SyntaxKind;
@@ -35,10 +37,8 @@ pub(crate) fn parse_view(p: &mut Parser) {
 
     parse_ident(p, 1..2);
 
-    if p.eat(T![sharing]) {
-        p.expect(T![=]);
-        p.expect_one_of(&[T![metadata], T![data], T![extended], T![none]]);
-        p.eat(T![data]);
+    if p.at(T![sharing]) {
+        parse_sharing_clause(p);
     }
 
     match p.current() {
@@ -84,6 +84,10 @@ pub(crate) fn parse_view(p: &mut Parser) {
         p.expect_one_of(&[T![current_user], T![definer]]);
     }
 
+    while at_ignored_clause(p) {
+        parse_ignored_clause(p);
+    }
+
     p.expect(T![as]);
 
     parse_query(p, false);
@@ -288,15 +292,62 @@ Root@0..49
     SelectStmt@26..49
       Keyword@26..32 "SELECT"
       Whitespace@32..33 " "
-      SelectClause@33..38
-        ColumnExpr@33..38
+      SelectClause@33..37
+        ColumnExpr@33..37
           IdentGroup@33..37
             Ident@33..37 "name"
-          Whitespace@37..38 " "
+      Whitespace@37..38 " "
       Keyword@38..42 "FROM"
       Whitespace@42..43 " "
       IdentGroup@43..49
         Ident@43..49 "stores"
+"#]],
+            vec![],
+        );
+    }
+
+    #[test]
+    fn test_parse_view_with_physical_properties() {
+        check(
+            parse(
+                "CREATE VIEW big_sales NOLOGGING PARALLEL AS SELECT /*+ PARALLEL(s 8) */ id FROM sales s",
+                parse_view,
+            ),
+            expect![[r#"
+Root@0..87
+  View@0..87
+    Keyword@0..6 "CREATE"
+    Whitespace@6..7 " "
+    Keyword@7..11 "VIEW"
+    Whitespace@11..12 " "
+    IdentGroup@12..21
+      Ident@12..21 "big_sales"
+    Whitespace@21..22 " "
+    IgnoredClause@22..31
+      Keyword@22..31 "NOLOGGING"
+    Whitespace@31..32 " "
+    IgnoredClause@32..40
+      Keyword@32..40 "PARALLEL"
+    Whitespace@40..41 " "
+    Keyword@41..43 "AS"
+    Whitespace@43..44 " "
+    SelectStmt@44..87
+      Keyword@44..50 "SELECT"
+      Whitespace@50..51 " "
+      Hint@51..71 "/*+ PARALLEL(s 8) */"
+      Whitespace@71..72 " "
+      SelectClause@72..74
+        ColumnExpr@72..74
+          IdentGroup@72..74
+            Ident@72..74 "id"
+      Whitespace@74..75 " "
+      Keyword@75..79 "FROM"
+      Whitespace@79..80 " "
+      IdentGroup@80..85
+        Ident@80..85 "sales"
+      Whitespace@85..86 " "
+      IdentGroup@86..87
+        Ident@86..87 "s"
 "#]],
             vec![],
         );
@@ -10,7 +10,9 @@
 pub use analyzer::*;
 pub use ast::*;
 pub use parser::*;
-pub use util::SqlIdent;
+pub use util::{
+    InvalidSqlIdent, KeywordCase, LineCol, LineIndex, SourceExcerpt, SourceLocation, SqlIdent,
+};
 
 mod analyzer;
 mod ast;
@@ -7,13 +7,64 @@
 
 use std::ops::Range;
 
-use rowan::{Checkpoint, GreenNode, GreenNodeBuilder};
+use rowan::{Checkpoint, GreenNode, GreenNodeBuilder, TextRange, TextSize};
 
 use crate::grammar;
+use crate::util::KeywordCase;
 use source_gen::lexer::{Lexer, Token, TokenKind};
 use source_gen::syntax::{SyntaxKind, SyntaxNode};
 use source_gen::T;
 
+/// Keywords which may start the next clause or terminate a statement after an
+/// optional identifier, e.g. `VALUES` in `INSERT INTO t VALUES (..)`. All
+/// other keywords are soft and accepted as identifiers in these positions.
+const CLAUSE_KEYWORDS: &[TokenKind] = &[
+    T![as],
+    T![begin],
+    T![bulk],
+    T![connect],
+    T![cross],
+    T![end],
+    T![exception],
+    T![fetch],
+    T![for],
+    T![from],
+    T![full],
+    T![group],
+    T![having],
+    T![inner],
+    T![into],
+    T![is],
+    T![join],
+    T![left],
+    T![loop],
+    T![model],
+    T![natural],
+    T![on],
+    T![order],
+    T![outer],
+    T![partition],
+    T![pivot],
+    T![return],
+    T![returning],
+    T![right],
+    T![schema],
+    T![set],
+    T![start],
+    T![starts],
+    T![then],
+    T![unpivot],
+    T![using],
+    T![values],
+    T![when],
+    T![where],
+    T![with],
+];
+
+/// Like [`CLAUSE_KEYWORDS`], but for contextual keywords without a token of
+/// their own, e.g. `UNION` in `SELECT .. FROM t UNION SELECT ..`.
+const CLAUSE_WORDS: &[&str] = &["intersect", "minus", "offset", "sample", "union"];
+
 /// Error type describing all possible parser failures.
 #[derive(Debug, Eq, thiserror::Error, PartialEq)]
 pub enum ParseErrorType {
@@ -23,6 +74,10 @@ pub enum ParseErrorType {
     /// A token could not be parsed by the lexer
     #[error("Unknown token found: {0}")]
     UnknownToken(String),
+    /// A string literal is missing its closing quote. Parsing resumes on the
+    /// next line, see [`Lexer`].
+    #[error("Unterminated string literal: {0}")]
+    UnterminatedLiteral(String),
     /// The parser expected a DDL or database event
     #[error("Expected DDL or database event")]
     ExpectedDdlOrDatabaseEvent,
@@ -50,6 +105,14 @@ pub enum ParseErrorType {
     /// The parser stumbled upon the end of input, but expecting further input.
     #[error("Unexpected end of input found")]
     Eof,
+    /// The input contains further tokens after a complete object. This is only
+    /// a warning, the tokens are kept in a [`SyntaxKind::TrailingContent`] node.
+    #[error("Trailing content after object: {0}")]
+    TrailingContent(String),
+    /// The label after `END` differs from the block label or the name of the
+    /// subprogram. This is only a warning, as the block itself is complete.
+    #[error("END label '{1}' does not match '{0}'")]
+    MismatchedEndLabel(String, String),
     /// The parser encountered a construct that has not yet been implemented
     #[error("Unimplemented construct: {0}")]
     Unimplemented(String),
@@ -58,6 +121,17 @@ pub enum ParseErrorType {
     Unhandled(String, String),
 }
 
+impl ParseErrorType {
+    /// Returns whether this error is only a warning, i.e. the parsed object is
+    /// still complete.
+    pub fn is_warning(&self) -> bool {
+        matches!(
+            self,
+            Self::TrailingContent(_) | Self::MismatchedEndLabel(_, _)
+        )
+    }
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct ParseError {
     pub(crate) typ: ParseErrorType,
@@ -80,6 +154,13 @@ impl std::fmt::Display for ParseError {
     }
 }
 
+/// Options for lexing the input of a parser.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ParseOptions {
+    /// See [`Lexer::single_line_literals()`].
+    pub single_line_literals: bool,
+}
+
 /// Tries to parse any string of SQL tokens.
 pub fn parse_any(input: &str) -> Result<Parse, ParseError> {
     let mut parser = Parser::new(input);
@@ -92,31 +173,91 @@ pub fn parse_any(input: &str) -> Result<Parse, ParseError> {
     Ok(parser.build())
 }
 
+/// Tries to parse an anonymous block from a string.
+pub fn parse_block(input: &str) -> Result<Parse, ParseError> {
+    parse_block_with_options(input, ParseOptions::default())
+}
+
+/// Same as [`parse_block()`], lexing the input according to `options`.
+pub fn parse_block_with_options(input: &str, options: ParseOptions) -> Result<Parse, ParseError> {
+    let mut parser = Parser::with_options(input, options);
+
+    grammar::parse_block(&mut parser);
+    parser.eat_trailing_content();
+
+    Ok(parser.build())
+}
+
+/// Tries to parse a `COMMENT ON` statement from a string.
+pub fn parse_comment(input: &str) -> Result<Parse, ParseError> {
+    let mut parser = Parser::new(input);
+
+    grammar::parse_comment_stmt(&mut parser);
+    parser.eat_trailing_content();
+
+    Ok(parser.build())
+}
+
 /// Tries to parse a DML statement from a string.
 pub fn parse_dml(input: &str) -> Result<Parse, ParseError> {
     let mut parser = Parser::new(input);
+
     grammar::parse_dml(&mut parser);
+    parser.eat_trailing_content();
 
     Ok(parser.build())
 }
 
 /// Tries to parse a function from a string.
 pub fn parse_function(input: &str) -> Result<Parse, ParseError> {
-    let mut parser = Parser::new(input);
+    parse_function_with_options(input, ParseOptions::default())
+}
+
+/// Same as [`parse_function()`], lexing the input according to `options`.
+pub fn parse_function_with_options(
+    input: &str,
+    options: ParseOptions,
+) -> Result<Parse, ParseError> {
+    let mut parser = Parser::with_options(input, options);
 
     // Expect a function
     grammar::parse_function(&mut parser, false);
+    parser.eat_trailing_content();
 
     // TODO handle any errors here
     Ok(parser.build())
 }
 
+/// Tries to parse a materialized view from a string.
+pub fn parse_materialized_view(input: &str) -> Result<Parse, ParseError> {
+    parse_materialized_view_with_options(input, ParseOptions::default())
+}
+
+/// Same as [`parse_materialized_view()`], lexing the input according to `options`.
+pub fn parse_materialized_view_with_options(
+    input: &str,
+    options: ParseOptions,
+) -> Result<Parse, ParseError> {
+    let mut parser = Parser::with_options(input, options);
+
+    grammar::parse_materialized_view(&mut parser);
+    parser.eat_trailing_content();
+
+    Ok(parser.build())
+}
+
 /// Tries to parse a package from a string.
 pub fn parse_package(input: &str) -> Result<Parse, ParseError> {
-    let mut parser = Parser::new(input);
+    parse_package_with_options(input, ParseOptions::default())
+}
+
+/// Same as [`parse_package()`], lexing the input according to `options`.
+pub fn parse_package_with_options(input: &str, options: ParseOptions) -> Result<Parse, ParseError> {
+    let mut parser = Parser::with_options(input, options);
 
     // Expect a package
     grammar::parse_package(&mut parser);
+    parser.eat_trailing_content();
 
     // TODO handle any errors here
     Ok(parser.build())
@@ -124,39 +265,80 @@ pub fn parse_package(input: &str) -> Result<Parse, ParseError> {
 
 /// Tries to parse a procedure from a string.
 pub fn parse_procedure(input: &str) -> Result<Parse, ParseError> {
-    let mut parser = Parser::new(input);
+    parse_procedure_with_options(input, ParseOptions::default())
+}
+
+/// Same as [`parse_procedure()`], lexing the input according to `options`.
+pub fn parse_procedure_with_options(
+    input: &str,
+    options: ParseOptions,
+) -> Result<Parse, ParseError> {
+    let mut parser = Parser::with_options(input, options);
 
     // Expect a procedure
     grammar::parse_procedure(&mut parser, false);
+    parser.eat_trailing_content();
 
     // TODO handle any errors here
     Ok(parser.build())
 }
 
 pub fn parse_query(input: &str) -> Result<Parse, ParseError> {
-    let mut parser = Parser::new(input);
+    parse_query_with_options(input, ParseOptions::default())
+}
+
+/// Same as [`parse_query()`], lexing the input according to `options`.
+pub fn parse_query_with_options(input: &str, options: ParseOptions) -> Result<Parse, ParseError> {
+    let mut parser = Parser::with_options(input, options);
 
     // Expect a query `SELECT`
     grammar::parse_query(&mut parser, false);
+    parser.eat_trailing_content();
+
+    Ok(parser.build())
+}
+
+/// Tries to parse a `CREATE TABLE .. AS SELECT` statement from a string.
+pub fn parse_table(input: &str) -> Result<Parse, ParseError> {
+    parse_table_with_options(input, ParseOptions::default())
+}
+
+/// Same as [`parse_table()`], lexing the input according to `options`.
+pub fn parse_table_with_options(input: &str, options: ParseOptions) -> Result<Parse, ParseError> {
+    let mut parser = Parser::with_options(input, options);
+
+    grammar::parse_table(&mut parser);
+    parser.eat_trailing_content();
 
-    // TODO handle any errors here
     Ok(parser.build())
 }
 
 pub fn parse_trigger(input: &str) -> Result<Parse, ParseError> {
-    let mut parser = Parser::new(input);
+    parse_trigger_with_options(input, ParseOptions::default())
+}
+
+/// Same as [`parse_trigger()`], lexing the input according to `options`.
+pub fn parse_trigger_with_options(input: &str, options: ParseOptions) -> Result<Parse, ParseError> {
+    let mut parser = Parser::with_options(input, options);
 
     // Expect a query `SELECT`
     grammar::parse_trigger(&mut parser);
+    parser.eat_trailing_content();
 
     // TODO handle any errors here
     Ok(parser.build())
 }
 
 pub fn parse_view(input: &str) -> Result<Parse, ParseError> {
-    let mut parser = Parser::new(input);
+    parse_view_with_options(input, ParseOptions::default())
+}
+
+/// Same as [`parse_view()`], lexing the input according to `options`.
+pub fn parse_view_with_options(input: &str, options: ParseOptions) -> Result<Parse, ParseError> {
+    let mut parser = Parser::with_options(input, options);
 
     grammar::parse_view(&mut parser);
+    parser.eat_trailing_content();
 
     // TODO handle any errors here
     Ok(parser.build())
@@ -175,8 +357,25 @@ impl Parse {
         SyntaxNode::new_root(self.green_node.clone())
     }
 
+    /// Returns whether the input was parsed without any errors, ignoring
+    /// warnings.
     pub fn ok(&self) -> bool {
-        self.errors.is_empty()
+        self.errors.iter().all(|e| e.typ.is_warning())
+    }
+
+    /// Returns the case most keywords of the input are written in, so that
+    /// generated snippets can follow the style of the file, see
+    /// [`KeywordCase::apply_to_sql()`]. Only tokens parsed as keywords are
+    /// taken into account, not keywords used as identifiers.
+    pub fn keyword_case(&self) -> KeywordCase {
+        let syntax = self.syntax();
+        let keywords = syntax
+            .descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|token| matches!(token.kind(), SyntaxKind::Keyword | SyntaxKind::LogicOp))
+            .collect::<Vec<_>>();
+
+        KeywordCase::dominant(keywords.iter().map(|token| token.text()))
     }
 }
 
@@ -189,11 +388,30 @@ pub struct Parser<'a> {
     builder: GreenNodeBuilder<'static>,
     /// The list of all found errors.
     errors: Vec<ParseError>,
+    /// Whitespace and comments consumed by a lookahead, but not yet added to
+    /// the tree, see [`Parser::eat_ws()`].
+    trivia: Vec<Token<'a>>,
+    /// Lowercased names taken as bind variables when qualifying an
+    /// identifier, e.g. `NEW` in the `WHEN` clause of a trigger.
+    correlation_names: Vec<String>,
+    /// The number of SQL statements being parsed, e.g. `2` within a subquery
+    /// of an `UPDATE`. Collection methods cannot be used in SQL, so `t.count`
+    /// is a column there.
+    sql_depth: usize,
+    /// Lowercased names of the collection types and variables declared so
+    /// far.
+    collections: Vec<String>,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(input: &'a str) -> Self {
-        let tokens = Lexer::new(input).collect::<Vec<_>>();
+        Self::with_options(input, ParseOptions::default())
+    }
+
+    pub fn with_options(input: &'a str, options: ParseOptions) -> Self {
+        let tokens = Lexer::new(input)
+            .single_line_literals(options.single_line_literals)
+            .collect::<Vec<_>>();
         Self::from_tokens(tokens)
     }
 
@@ -203,6 +421,10 @@ impl<'a> Parser<'a> {
             tokens,
             builder: GreenNodeBuilder::new(),
             errors: Vec::new(),
+            trivia: Vec::new(),
+            correlation_names: Vec::new(),
+            sql_depth: 0,
+            collections: Vec::new(),
         };
         parser.builder.start_node(SyntaxKind::Root.into());
         parser
@@ -215,18 +437,51 @@ impl<'a> Parser<'a> {
             self.error(ParseErrorType::Incomplete(remaining_tokens));
         }
 
-        self.finish();
+        self.flush_trivia();
+        self.builder.finish_node();
         Parse {
             green_node: self.builder.finish(),
             errors: self.errors,
         }
     }
 
+    /// Wraps all remaining tokens after a successfully parsed object into a
+    /// [`SyntaxKind::TrailingContent`] node, emitting a warning instead of
+    /// failing with [`ParseErrorType::Incomplete`].
+    pub(crate) fn eat_trailing_content(&mut self) {
+        if self.errors.iter().any(|e| !e.typ.is_warning()) || self.at(T![EOF]) {
+            return;
+        }
+
+        let content = self.tokens.iter().rev().map(|t| t.text).collect::<String>();
+        self.error(ParseErrorType::TrailingContent(
+            content.trim_end().to_string(),
+        ));
+
+        self.start(SyntaxKind::TrailingContent);
+        while !self.at(T![EOF]) {
+            self.bump_any();
+        }
+        self.builder.finish_node();
+    }
+
     /// Checks if the current token is `kind`.
     pub fn at(&mut self, kind: TokenKind) -> bool {
         self.current() == kind
     }
 
+    /// Returns whether the current token may be taken as an optional
+    /// identifier, e.g. an alias. Other than for mandatory identifiers,
+    /// keywords starting the next clause are not accepted, see
+    /// `CLAUSE_KEYWORDS` and `CLAUSE_WORDS`.
+    pub fn at_soft_ident(&mut self) -> bool {
+        let current = self.current();
+        current.is_ident()
+            && !CLAUSE_KEYWORDS.contains(&current)
+            && !(current == T![unquoted_ident]
+                && CLAUSE_WORDS.contains(&self.current_text().to_lowercase().as_str()))
+    }
+
     /// Lookahead operation: returns the kind of the next nth token.
     pub fn nth(&mut self, mut n: usize) -> Option<TokenKind> {
         let mut i = 0;
@@ -248,6 +503,17 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Lookahead operation: returns the text of the next nth token. Used for
+    /// contextual keywords, see [`Parser::current_text()`].
+    pub fn nth_text(&self, n: usize) -> Option<&str> {
+        self.tokens
+            .iter()
+            .rev()
+            .filter(|token| !token.kind.is_trivia())
+            .nth(n)
+            .map(|token| token.text)
+    }
+
     /// Lookahead operation, returns `count` items if present.
     pub fn lookahead(&mut self, count: usize) -> Vec<TokenKind> {
         (1..=count)
@@ -264,6 +530,64 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Returns the text of the current token, or an empty string at the end
+    /// of input. Used for contextual keywords which are not worth a token.
+    pub fn current_text(&mut self) -> &str {
+        self.eat_ws();
+        self.tokens.last().map(|t| t.text).unwrap_or_default()
+    }
+
+    /// Returns the kind of the next token if it directly follows the last one
+    /// consumed, i.e. without whitespace or comments in between.
+    pub(crate) fn adjacent(&self) -> Option<TokenKind> {
+        self.tokens
+            .last()
+            .map(|t| t.kind)
+            .filter(|kind| !kind.is_trivia())
+    }
+
+    /// Sets the names which are parsed as [`SyntaxKind::BindVar`] when
+    /// qualifying an identifier, e.g. `NEW.sal`. Pass an empty list to reset
+    /// them.
+    pub(crate) fn set_correlation_names(&mut self, names: Vec<String>) {
+        self.correlation_names = names;
+    }
+
+    /// Checks if the current token is one of the correlation names, followed
+    /// by a dot.
+    pub(crate) fn at_correlation_name(&mut self) -> bool {
+        if self.correlation_names.is_empty() || self.nth(1) != Some(T![.]) {
+            return false;
+        }
+        let text = self.current_text().to_lowercase();
+        self.correlation_names.contains(&text)
+    }
+
+    /// Marks the start of a SQL statement, which must be followed by a call
+    /// to [`Parser::leave_sql()`] at its end.
+    pub(crate) fn enter_sql(&mut self) {
+        self.sql_depth += 1;
+    }
+
+    pub(crate) fn leave_sql(&mut self) {
+        self.sql_depth = self.sql_depth.saturating_sub(1);
+    }
+
+    /// Checks if a SQL statement is being parsed, rather than PL/SQL.
+    pub(crate) fn in_sql(&self) -> bool {
+        self.sql_depth > 0
+    }
+
+    /// Registers a declared collection type or variable.
+    pub(crate) fn add_collection(&mut self, name: &str) {
+        self.collections.push(name.to_lowercase());
+    }
+
+    /// Checks if `name` is a collection type or variable declared so far.
+    pub(crate) fn is_collection(&self, name: &str) -> bool {
+        self.collections.contains(&name.to_lowercase())
+    }
+
     /// Consumes the next token if `kind` matches.
     pub fn eat(&mut self, kind: TokenKind) -> bool {
         if !self.at(kind) {
@@ -293,6 +617,32 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Splits the sign off the current token if it is a negative number. The
+    /// lexer takes `-1` as one literal, which is wrong after an operand, e.g.
+    /// in `a -1` or a `-1` continuing an expression on the next line.
+    pub(crate) fn split_sign(&mut self) {
+        if !matches!(self.current(), T![int_literal] | T![decimal_literal]) {
+            return;
+        }
+        let token = self.tokens.pop().unwrap();
+        let Some(number) = token.text.strip_prefix('-') else {
+            self.tokens.push(token);
+            return;
+        };
+
+        let sign_end = token.range.start() + TextSize::of('-');
+        self.tokens.push(Token {
+            kind: token.kind,
+            text: number,
+            range: TextRange::new(sign_end, token.range.end()),
+        });
+        self.tokens.push(Token {
+            kind: T![-],
+            text: &token.text[..1],
+            range: TextRange::new(token.range.start(), sign_end),
+        });
+    }
+
     /// Consumes the current token as it is
     pub fn bump(&mut self, kind: TokenKind) {
         assert!(self.eat(kind));
@@ -312,6 +662,29 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Consumes a statement containing an unterminated literal, which spans
+    /// the rest of its line. The statement is taken to end there, so that
+    /// the following ones still parse. Only looks up to the next `;` or
+    /// nested statement list.
+    pub(crate) fn eat_unterminated_stmt(&mut self) -> bool {
+        const STOP: &[TokenKind] = &[T![;], T![begin], T![declare], T![else], T![loop], T![then]];
+
+        let Some(index) = self
+            .tokens
+            .iter()
+            .rev()
+            .take_while(|token| !STOP.contains(&token.kind))
+            .position(Token::is_unterminated_literal)
+        else {
+            return false;
+        };
+
+        for _ in 0..=index {
+            self.do_bump();
+        }
+        true
+    }
+
     /// Consumes all tokens until the last searched token is found.
     pub fn until_last(&mut self, token_kind: TokenKind) {
         // The tokens list is reversed, therefore the search is done from front.
@@ -348,24 +721,34 @@ impl<'a> Parser<'a> {
         false
     }
 
-    /// Consume all whitespaces / comments & attach
-    /// them to the current node to preserve them.
+    /// Consume all whitespaces / comments up to the next token.
+    ///
+    /// They are only added to the tree once it is known where they belong,
+    /// i.e. right before the next token or node is added. Thus nodes never
+    /// start or end with trivia: comments and whitespace between two nodes
+    /// are attached to their common parent, while trivia between the tokens
+    /// of a node stays inside of it. Editing or replacing a node therefore
+    /// neither drops nor duplicates the comments around it, e.g. a comment
+    /// after an `END` spanning multiple lines.
     fn eat_ws(&mut self) {
-        loop {
-            match self.tokens.last() {
-                Some(token) if token.kind.is_trivia() => {
-                    let token = self.tokens.pop().unwrap();
-                    let syntax_kind: SyntaxKind = token.kind.into();
-                    self.builder.token(syntax_kind.into(), token.text);
-                }
-                _ => break,
-            }
+        while self.tokens.last().is_some_and(|t| t.kind.is_trivia()) {
+            let token = self.tokens.pop().unwrap();
+            self.trivia.push(token);
+        }
+    }
+
+    /// Adds the pending trivia to the current node.
+    fn flush_trivia(&mut self) {
+        self.eat_ws();
+        for token in self.trivia.drain(..) {
+            let syntax_kind: SyntaxKind = token.kind.into();
+            self.builder.token(syntax_kind.into(), token.text);
         }
     }
 
     /// Start a new (nested) node
     pub(crate) fn start(&mut self, kind: SyntaxKind) {
-        self.eat_ws();
+        self.flush_trivia();
         self.builder.start_node(kind.into());
     }
 
@@ -375,7 +758,7 @@ impl<'a> Parser<'a> {
     }
 
     pub(crate) fn checkpoint(&mut self) -> Checkpoint {
-        self.eat_ws();
+        self.flush_trivia();
         self.builder.checkpoint()
     }
 
@@ -398,11 +781,10 @@ impl<'a> Parser<'a> {
 
     /// Function to consume the next token, regardless of any [`TokenKind`]
     fn do_bump(&mut self) {
+        self.flush_trivia();
         assert!(!self.tokens.is_empty());
         let token = self.tokens.pop().unwrap();
-        if token.kind == TokenKind::Error {
-            self.error(ParseErrorType::UnknownToken(token.text.to_string()));
-        }
+        self.check_token(&token);
         let syntax_kind: SyntaxKind = token.kind.into();
         self.builder.token(syntax_kind.into(), token.text);
     }
@@ -410,14 +792,28 @@ impl<'a> Parser<'a> {
     /// Function to consume the next token, regardless of any [`TokenKind`], and
     /// add it as `target` `[SyntaxKind]` node to the tree
     fn do_bump_map(&mut self, target: SyntaxKind) {
+        self.flush_trivia();
         assert!(!self.tokens.is_empty());
         let token = self.tokens.pop().unwrap();
-        if token.kind == TokenKind::Error {
-            self.error(ParseErrorType::UnknownToken(token.text.to_string()));
-        }
+        self.check_token(&token);
         self.builder.token(target.into(), token.text);
     }
 
+    /// Reports an error for a token the lexer could not make sense of.
+    fn check_token(&mut self, token: &Token) {
+        if token.kind != TokenKind::Error {
+            return;
+        }
+
+        let typ = if token.is_unterminated_literal() {
+            ParseErrorType::UnterminatedLiteral(token.text.to_string())
+        } else {
+            ParseErrorType::UnknownToken(token.text.to_string())
+        };
+        self.errors
+            .push(ParseError::new(typ, Range::from(token.range)));
+    }
+
     pub fn token_len(&mut self) -> usize {
         self.tokens.len()
     }
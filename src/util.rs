@@ -6,81 +6,181 @@
 
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::ops::Range;
 
+use rowan::{TextRange, TextSize};
 use serde::de::{self, Visitor};
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use source_gen::lexer::{Lexer, TokenKind};
+use source_gen::T;
 use tsify::Tsify;
 
-#[derive(Tsify, Clone, Debug, Eq, Serialize)]
-#[tsify(into_wasm_abi, from_wasm_abi)]
+/// An SQL identifier, optionally qualified by a schema.
+///
+/// Identifiers compare like in Oracle: unquoted identifiers are folded to
+/// uppercase, quoted ones are taken as written. Thus `Emp`, `emp` and `"EMP"`
+/// are equal, while `"Emp"` is not. The schema is part of the identity, see
+/// [`SqlIdent::unqualified()`] for lookups ignoring it.
+///
+/// Identifiers are (de)serialized as strings, e.g. `hr."Emp"`.
+#[derive(Clone, Debug, Eq)]
 pub struct SqlIdent {
+    schema: Option<Box<SqlIdent>>,
+    /// The name without any quotes.
     name: String,
     is_quoted: bool,
 }
 
+/// The error returned when parsing a [`SqlIdent`] fails.
+#[derive(Debug, Eq, thiserror::Error, PartialEq)]
+#[error("Invalid SQL identifier: {0}")]
+pub struct InvalidSqlIdent(String);
+
 impl SqlIdent {
+    /// Creates an unqualified identifier. `name` is taken without quotes.
     pub fn new<S>(name: S, is_quoted: bool) -> Self
     where
         S: Into<String>,
     {
         Self {
+            schema: None,
             name: name.into(),
             is_quoted,
         }
     }
+
+    /// Qualifies the identifier with `schema`, replacing any existing one.
+    pub fn with_schema(self, schema: SqlIdent) -> Self {
+        Self {
+            schema: Some(Box::new(schema.unqualified())),
+            ..self
+        }
+    }
+
+    /// Returns the name as written, without quotes.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn is_quoted(&self) -> bool {
+        self.is_quoted
+    }
+
+    pub fn schema(&self) -> Option<&SqlIdent> {
+        self.schema.as_deref()
+    }
+
+    /// Returns the identifier without its schema.
+    pub fn unqualified(&self) -> Self {
+        Self::new(self.name.clone(), self.is_quoted)
+    }
+
+    /// Returns the name as Oracle sees it, i.e. uppercased unless quoted.
+    fn folded(&self) -> String {
+        if self.is_quoted {
+            self.name.clone()
+        } else {
+            self.name.to_uppercase()
+        }
+    }
+
+    /// Parses a single, possibly quoted part of an identifier.
+    fn parse_part(s: &str) -> Option<Self> {
+        match s.strip_prefix('"') {
+            Some(rest) => {
+                let name = rest.strip_suffix('"')?;
+                if name.is_empty() || name.replace("\"\"", "").contains('"') {
+                    return None;
+                }
+                Some(Self::new(name.replace("\"\"", "\""), true))
+            }
+            None if s.is_empty() || s.contains(['"', '.']) || s.contains(char::is_whitespace) => {
+                None
+            }
+            None => Some(Self::new(s, false)),
+        }
+    }
+}
+
+impl std::str::FromStr for SqlIdent {
+    type Err = InvalidSqlIdent;
+
+    /// Parses an identifier like `emp`, `"Emp"` or `hr.emp`. Dots inside
+    /// quotes are part of the name.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut in_quotes = false;
+        let separator = s.char_indices().find_map(|(i, c)| {
+            if c == '"' {
+                in_quotes = !in_quotes;
+            }
+            (c == '.' && !in_quotes).then_some(i)
+        });
+
+        match separator {
+            Some(i) => Self::parse_part(&s[i + 1..])
+                .zip(Self::parse_part(&s[..i]))
+                .map(|(name, schema)| name.with_schema(schema)),
+            None => Self::parse_part(s),
+        }
+        .ok_or_else(|| InvalidSqlIdent(s.to_owned()))
+    }
 }
 
 impl From<&str> for SqlIdent {
+    /// Parses `s` like [`str::parse()`], but takes it as an unquoted name if
+    /// it is not a valid identifier.
     fn from(s: &str) -> Self {
-        Self::new(s, s.starts_with('"') && s.ends_with('"'))
+        s.parse().unwrap_or_else(|_| Self::new(s, false))
     }
 }
 
 impl From<String> for SqlIdent {
     fn from(s: String) -> Self {
-        let is_quoted = s.starts_with('"') && s.ends_with('"');
-        Self::new(s, is_quoted)
+        s.as_str().into()
     }
 }
 
 impl fmt::Display for SqlIdent {
+    /// Writes unquoted identifiers as they are. Quoted ones are only quoted
+    /// again if they differ from the same name without quotes.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let escape = |s: &str| s.replace('\"', "\"\"");
+        if let Some(schema) = &self.schema {
+            write!(f, "{schema}.")?;
+        }
 
-        if self.is_quoted {
-            write!(f, "\"{}\"", escape(&self.name))
+        let is_plain = self.name.starts_with(|c: char| c.is_ascii_uppercase())
+            && self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || "_$#".contains(c));
+
+        if !self.is_quoted || is_plain {
+            f.write_str(&self.name)
         } else {
-            write!(f, "{}", self.name)
+            write!(f, "\"{}\"", self.name.replace('"', "\"\""))
         }
     }
 }
 
 impl PartialEq for SqlIdent {
     fn eq(&self, other: &Self) -> bool {
-        // If the quote-status is different, don't even try further.
-        if self.is_quoted != other.is_quoted {
-            return false;
-        }
-
-        if self.is_quoted {
-            // And if the are indeed quoted, the must be *exactly* equal.
-            self.name == other.name
-        } else {
-            // Otherwise, do a case-insenstive compare.
-            self.name.to_lowercase() == other.name.to_lowercase()
-        }
+        self.schema == other.schema && self.folded() == other.folded()
     }
 }
 
 impl Hash for SqlIdent {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.is_quoted.hash(state);
+        self.schema.hash(state);
+        self.folded().hash(state);
+    }
+}
 
-        if self.is_quoted {
-            self.name.hash(state);
-        } else {
-            self.name.to_lowercase().hash(state);
-        }
+impl Serialize for SqlIdent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
     }
 }
 
@@ -109,3 +209,444 @@ impl<'de> Deserialize<'de> for SqlIdent {
         deserializer.deserialize_str(SqlIdentVisitor)
     }
 }
+
+/// A zero-based line and column position within a source text.
+#[derive(Tsify, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct LineCol {
+    pub line: u32,
+    /// Column in bytes from the start of the line.
+    pub col: u32,
+    /// Column in UTF-16 code units, as expected by editors like VS Code.
+    pub col_utf16: u32,
+}
+
+/// The lines of a [`SourceLocation`] along with some context lines, such that
+/// reports are readable without the source text.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceExcerpt {
+    /// The zero-based line the excerpt starts at.
+    pub start_line: u32,
+    /// The lines, separated by `\n` and without a trailing line break.
+    pub text: String,
+}
+
+/// A range within a source text, both as byte offsets and line/column pairs.
+#[derive(Tsify, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceLocation {
+    pub start_offset: u32,
+    pub end_offset: u32,
+    pub start: LineCol,
+    pub end: LineCol,
+    /// Only set if the [`LineIndex`] was created with excerpt lines, see
+    /// [`LineIndex::with_excerpt_lines()`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excerpt: Option<SourceExcerpt>,
+}
+
+impl SourceLocation {
+    pub fn new(range: TextRange, index: &LineIndex) -> Self {
+        Self {
+            start_offset: range.start().into(),
+            end_offset: range.end().into(),
+            start: index.line_col(range.start()),
+            end: index.line_col(range.end()),
+            excerpt: index
+                .excerpt_lines
+                .map(|context_lines| index.excerpt(range, context_lines)),
+        }
+    }
+
+    /// Returns the byte offsets, e.g. for slicing the source text.
+    pub fn offset_range(&self) -> Range<usize> {
+        self.start_offset as usize..self.end_offset as usize
+    }
+
+    /// Returns the byte offsets as [`TextRange`], e.g. for looking up the
+    /// covered nodes in the syntax tree.
+    pub fn text_range(&self) -> TextRange {
+        TextRange::new(self.start_offset.into(), self.end_offset.into())
+    }
+}
+
+impl From<SourceLocation> for TextRange {
+    fn from(location: SourceLocation) -> Self {
+        location.text_range()
+    }
+}
+
+/// Maps byte offsets of a source text to [`LineCol`] positions. Lines end at
+/// `\n`, so `\r\n` works as well. A byte order mark is not counted in the
+/// columns of the first line, as editors hide it.
+#[derive(Clone, Debug)]
+pub struct LineIndex {
+    text: String,
+    /// Byte offsets at which each line starts, the first one after a byte
+    /// order mark.
+    line_starts: Vec<TextSize>,
+    /// The context lines of the excerpts attached to every [`SourceLocation`]
+    /// created from this index, if any.
+    excerpt_lines: Option<u32>,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let bom_len = text
+            .strip_prefix('\u{feff}')
+            .map_or(0, |rest| text.len() - rest.len());
+        let line_starts = std::iter::once(TextSize::try_from(bom_len).unwrap())
+            .chain(
+                text.match_indices('\n')
+                    .map(|(i, _)| TextSize::try_from(i + 1).unwrap()),
+            )
+            .collect();
+
+        Self {
+            text: text.to_owned(),
+            line_starts,
+            excerpt_lines: None,
+        }
+    }
+
+    /// Attaches a [`SourceExcerpt`] with `context_lines` before and after to
+    /// every [`SourceLocation`] created from this index. `None` attaches none.
+    pub fn with_excerpt_lines(self, context_lines: Option<u32>) -> Self {
+        Self {
+            excerpt_lines: context_lines,
+            ..self
+        }
+    }
+
+    /// Returns the lines covered by `range`, along with `context_lines` before
+    /// and after, as far as there are any.
+    pub fn excerpt(&self, range: TextRange, context_lines: u32) -> SourceExcerpt {
+        let start = self.line_col(range.start()).line;
+        let end = self.line_col(range.end());
+        // A range ending right after a line break does not cover the next line
+        let end = if end.col == 0 && end.line > start {
+            end.line - 1
+        } else {
+            end.line
+        };
+
+        let first = start.saturating_sub(context_lines) as usize;
+        let last = (end as usize + context_lines as usize).min(self.line_starts.len() - 1);
+        let text_end = self
+            .line_starts
+            .get(last + 1)
+            .map_or(self.text.len(), |&next| usize::from(next));
+        let text = &self.text[usize::from(self.line_starts[first])..text_end];
+
+        SourceExcerpt {
+            start_line: first as u32,
+            text: text.trim_end_matches(['\n', '\r']).replace("\r\n", "\n"),
+        }
+    }
+
+    /// Returns the position of `offset`, which must lie on a char boundary.
+    pub fn line_col(&self, offset: TextSize) -> LineCol {
+        let line = self
+            .line_starts
+            .partition_point(|&start| start <= offset)
+            .saturating_sub(1);
+        let line_start = self.line_starts[line].min(offset);
+        let prefix = &self.text[TextRange::new(line_start, offset)];
+
+        LineCol {
+            line: line as u32,
+            col: (offset - line_start).into(),
+            col_utf16: prefix.encode_utf16().count() as u32,
+        }
+    }
+}
+
+/// The letter case keywords are written in, see [`Parse::keyword_case()`].
+///
+/// [`Parse::keyword_case()`]: crate::Parse::keyword_case
+#[derive(Tsify, Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[tsify(into_wasm_abi, from_wasm_abi)]
+#[serde(rename_all = "camelCase")]
+pub enum KeywordCase {
+    /// `BEGIN`, the default as used throughout the Oracle documentation.
+    #[default]
+    Upper,
+    /// `begin`
+    Lower,
+    /// `Begin`
+    Capitalized,
+}
+
+impl KeywordCase {
+    /// Returns the case `keyword` is written in, or `None` if it is mixed,
+    /// e.g. `BeGiN`, or has no letters at all.
+    pub fn of(keyword: &str) -> Option<Self> {
+        let mut letters = keyword.chars().filter(|c| c.is_alphabetic());
+        let first = letters.next()?;
+        let rest = letters.collect::<Vec<_>>();
+
+        if first.is_uppercase() && rest.iter().all(|c| c.is_uppercase()) {
+            Some(Self::Upper)
+        } else if first.is_lowercase() && rest.iter().all(|c| c.is_lowercase()) {
+            Some(Self::Lower)
+        } else if first.is_uppercase() && rest.iter().all(|c| c.is_lowercase()) {
+            Some(Self::Capitalized)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the most common case of `keywords`, ignoring mixed ones.
+    /// Ties are resolved in declaration order, e.g. to uppercase.
+    pub fn dominant<'a>(keywords: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut counts = [0usize; 3];
+        for case in keywords.into_iter().filter_map(Self::of) {
+            counts[case as usize] += 1;
+        }
+
+        [Self::Upper, Self::Lower, Self::Capitalized]
+            .into_iter()
+            .rev()
+            .max_by_key(|case| counts[*case as usize])
+            .unwrap_or_default()
+    }
+
+    /// Writes a single keyword in this case, e.g. `end loop` as `END LOOP`.
+    pub fn apply(&self, keyword: &str) -> String {
+        match self {
+            Self::Upper => keyword.to_uppercase(),
+            Self::Lower => keyword.to_lowercase(),
+            Self::Capitalized => keyword
+                .split_inclusive(|c: char| !c.is_alphanumeric() && c != '_')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    chars.next().map_or_else(String::new, |first| {
+                        first
+                            .to_uppercase()
+                            .chain(chars.flat_map(char::to_lowercase))
+                            .collect()
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Writes all keywords of an SQL snippet in this case, e.g. for generated
+    /// replacement text. Identifiers, literals and comments are kept as they
+    /// are, but identifiers which are keywords as well, e.g. `name`, are
+    /// changed too, as the lexer cannot tell them apart.
+    pub fn apply_to_sql(&self, sql: &str) -> String {
+        Lexer::new(sql)
+            .map(|token| {
+                let is_keyword = token.kind.is_ident()
+                    && !matches!(
+                        token.kind,
+                        T![unquoted_ident] | T![quoted_ident] | T![bind_var]
+                    );
+                if is_keyword {
+                    self.apply(token.text)
+                } else {
+                    token.text.to_owned()
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use source_gen::syntax::SyntaxKind;
+
+    use super::*;
+
+    #[test]
+    fn test_line_col_with_unicode() {
+        const INPUT: &str = include_str!("../tests/fixtures/unicode_characters.ora.sql");
+        let index = LineIndex::new(INPUT);
+
+        assert_eq!(
+            index.line_col(0.into()),
+            LineCol {
+                line: 0,
+                col: 0,
+                col_utf16: 0
+            }
+        );
+
+        // The semicolon after `END "读文👩🏼‍🔬"`
+        let offset = INPUT.rfind(';').unwrap();
+        assert_eq!(
+            index.line_col(TextSize::try_from(offset).unwrap()),
+            LineCol {
+                line: 3,
+                col: 27,
+                col_utf16: 15
+            }
+        );
+    }
+
+    #[test]
+    fn test_source_location_ranges() {
+        const INPUT: &str = "BEGIN\n  NULL;\nEND;";
+        let index = LineIndex::new(INPUT);
+        let range = TextRange::new(8.into(), 13.into());
+        let location = SourceLocation::new(range, &index);
+
+        assert_eq!(&INPUT[location.offset_range()], "NULL;");
+        assert_eq!(location.text_range(), range);
+        assert_eq!(location.start.line, 1);
+        assert_eq!(location.end.col, 7);
+        assert_eq!(location.excerpt, None);
+        assert_eq!(TextRange::from(location), range);
+    }
+
+    #[test]
+    fn test_source_excerpt() {
+        const INPUT: &str = "BEGIN\n  a := 1;\n  b := 2;\n  c := 3;\nEND;\n";
+        let excerpt = |start: u32, end: u32, context_lines| {
+            let index = LineIndex::new(INPUT).with_excerpt_lines(Some(context_lines));
+            SourceLocation::new(TextRange::new(start.into(), end.into()), &index)
+                .excerpt
+                .unwrap()
+        };
+
+        assert_eq!(
+            excerpt(18, 25, 0),
+            SourceExcerpt {
+                start_line: 2,
+                text: "  b := 2;".to_string(),
+            }
+        );
+        assert_eq!(
+            excerpt(18, 25, 1),
+            SourceExcerpt {
+                start_line: 1,
+                text: "  a := 1;\n  b := 2;\n  c := 3;".to_string(),
+            }
+        );
+        // A range ending after a line break ends on that line, context lines
+        // stop at the ends of the text
+        assert_eq!(
+            excerpt(0, 17, 5),
+            SourceExcerpt {
+                start_line: 0,
+                text: INPUT.trim_end().to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_line_col_with_bom_and_crlf() {
+        const INPUT: &str =
+            "\u{feff}CREATE PROCEDURE p IS\r\nBEGIN -- empty\r\n  NULL;\r\nEND p;\r\n";
+        let parse = crate::parse_procedure(INPUT).unwrap();
+        assert!(parse.ok(), "{:#?}", parse.errors);
+
+        let index = LineIndex::new(INPUT).with_excerpt_lines(Some(0));
+        let create = TextRange::new(3.into(), 9.into());
+        let location = SourceLocation::new(create, &index);
+        assert_eq!(&INPUT[location.offset_range()], "CREATE");
+        assert_eq!(
+            location.start,
+            LineCol {
+                line: 0,
+                col: 0,
+                col_utf16: 0
+            }
+        );
+        assert_eq!(location.excerpt.unwrap().text, "CREATE PROCEDURE p IS");
+
+        let null = INPUT.find("NULL").unwrap() as u32;
+        let location = SourceLocation::new(TextRange::new(null.into(), (null + 5).into()), &index);
+        assert_eq!((location.start.line, location.start.col), (2, 2));
+        assert_eq!((location.end.line, location.end.col), (2, 7));
+
+        let comment = parse
+            .syntax()
+            .descendants_with_tokens()
+            .find(|it| it.kind() == SyntaxKind::InlineComment)
+            .unwrap();
+        assert_eq!(comment.to_string(), "-- empty");
+    }
+
+    #[test]
+    fn test_sql_ident_equality() {
+        let ident = |s: &str| s.parse::<SqlIdent>().unwrap();
+
+        assert_eq!(ident("Emp"), ident("emp"));
+        assert_eq!(ident("emp"), ident("\"EMP\""));
+        assert_ne!(ident("emp"), ident("\"Emp\""));
+        assert_ne!(ident("emp"), ident("\"emp\""));
+        assert_eq!(ident("hr.emp"), ident("\"HR\".Emp"));
+        assert_ne!(ident("hr.emp"), ident("emp"));
+        assert_eq!(ident("hr.emp").unqualified(), ident("emp"));
+
+        let tables = std::collections::HashSet::from([ident("\"Emp\""), ident("hr.dept")]);
+        assert!(tables.contains(&ident("\"Emp\"")));
+        assert!(tables.contains(&ident("HR.DEPT")));
+        assert!(!tables.contains(&ident("emp")));
+    }
+
+    #[test]
+    fn test_sql_ident_parse_and_display() {
+        let ident = "\"My Schema\".\"a.\"\"b\"\"\"".parse::<SqlIdent>().unwrap();
+        assert_eq!(ident.schema().map(SqlIdent::name), Some("My Schema"));
+        assert_eq!(ident.name(), "a.\"b\"");
+        assert!(ident.is_quoted());
+
+        assert_eq!(ident.to_string(), "\"My Schema\".\"a.\"\"b\"\"\"");
+        assert_eq!(SqlIdent::from("hr.Emp").to_string(), "hr.Emp");
+        assert_eq!(SqlIdent::from("\"EMP\"").to_string(), "EMP");
+        assert_eq!(SqlIdent::from("\"Emp\"").to_string(), "\"Emp\"");
+
+        for invalid in ["", "a.b.c", "\"emp", "hr.", "my table"] {
+            assert_eq!(
+                invalid.parse::<SqlIdent>(),
+                Err(InvalidSqlIdent(invalid.to_owned())),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_keyword_case() {
+        assert_eq!(KeywordCase::of("BEGIN"), Some(KeywordCase::Upper));
+        assert_eq!(KeywordCase::of("end_loop"), Some(KeywordCase::Lower));
+        assert_eq!(KeywordCase::of("Begin"), Some(KeywordCase::Capitalized));
+        assert_eq!(KeywordCase::of("BeGiN"), None);
+        assert_eq!(KeywordCase::of("(+)"), None);
+
+        assert_eq!(
+            KeywordCase::dominant(["begin", "END", "loop", "Exit"]),
+            KeywordCase::Lower
+        );
+        assert_eq!(KeywordCase::dominant(["begin", "END"]), KeywordCase::Upper);
+        assert_eq!(KeywordCase::dominant([]), KeywordCase::Upper);
+
+        assert_eq!(KeywordCase::Capitalized.apply("END LOOP"), "End Loop");
+        assert_eq!(
+            KeywordCase::Lower.apply_to_sql("UPDATE Emp SET sal = 'ABC' -- KEEP\n"),
+            "update Emp set sal = 'ABC' -- KEEP\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_keyword_case() {
+        const INPUT: &str = r#"
+create or replace procedure Purge IS
+begin
+    delete from orders where id = 1 AND name IS NULL;
+end;
+"#;
+        let parse = crate::parse_procedure(INPUT).unwrap();
+        assert_eq!(parse.keyword_case(), KeywordCase::Lower);
+        assert_eq!(
+            parse.keyword_case().apply_to_sql("WHERE NOT EXISTS"),
+            "where not exists"
+        );
+    }
+}
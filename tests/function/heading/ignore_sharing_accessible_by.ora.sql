@@ -0,0 +1,8 @@
+-- test: ignore SHARING and ACCESSIBLE BY clauses, there is no equivalent in PostgreSQL
+CREATE OR REPLACE FUNCTION ignore_sharing_accessible_by SHARING = NONE
+RETURN NUMBER
+ACCESSIBLE BY (FUNCTION util.caller)
+IS
+BEGIN
+    RETURN 1;
+END ignore_sharing_accessible_by;
@@ -2,7 +2,11 @@
 // SPDX-FileCopyrightText: 2022 CYBERTEC PostgreSQL International GmbH
 // <office@cybertec.at>
 
-use poc_plpgsql_analyzer::parse_procedure;
+use poc_plpgsql_analyzer::{
+    analyze, analyze_batch, analyze_batch_with_progress, parse_dml, parse_procedure, parse_query,
+    AnalyzeError, AstNode, DboAnalyzeBatch, DboAnalyzeBatchResult, DboAnalyzeContext,
+    DboAnalyzeItem, DboBatchProgress, DboType, ParseErrorType, Root,
+};
 
 const ADD_JOB_HISTORY: &str = include_str!("fixtures/add_job_history.sql");
 
@@ -12,3 +16,125 @@ fn check_parse_procedure() {
     assert!(result.is_ok(), "{result:#?}");
     assert!(result.unwrap().errors.is_empty());
 }
+
+#[test]
+fn check_parse_procedure_with_trailing_content() {
+    let input = format!("{ADD_JOB_HISTORY}\n/\nGRANT EXECUTE ON add_job_history TO hr_app;\n");
+    let parse = parse_procedure(&input).unwrap();
+    assert!(parse.ok(), "{:?}", parse.errors);
+    assert_eq!(parse.errors.len(), 1);
+
+    let root = Root::cast(parse.syntax()).unwrap();
+    assert!(root.procedure().is_some());
+    assert_eq!(
+        root.trailing_content().unwrap().text().trim(),
+        "GRANT EXECUTE ON add_job_history TO hr_app;"
+    );
+
+    let result = analyze(DboType::Procedure, &input, &DboAnalyzeContext::default());
+    assert!(result.is_ok(), "{result:#?}");
+}
+
+#[test]
+fn check_parse_query_and_dml_with_trailing_content() {
+    for parse in [
+        parse_query("SELECT 1 FROM dual;\n/\n"),
+        parse_dml("DELETE FROM job_history WHERE employee_id = 100;\n/\n"),
+    ] {
+        let parse = parse.unwrap();
+        assert!(parse.ok(), "{:?}", parse.errors);
+        assert_eq!(parse.errors.len(), 1);
+        assert!(parse.errors[0]
+            .to_string()
+            .starts_with(&ParseErrorType::TrailingContent("/".to_string()).to_string()));
+
+        let root = Root::cast(parse.syntax()).unwrap();
+        assert_eq!(root.objects().count(), 1);
+        assert_eq!(root.trailing_content().unwrap().text().trim(), "/");
+    }
+
+    let result = analyze(
+        DboType::Query,
+        "SELECT 1 FROM dual;\n/",
+        &DboAnalyzeContext::default(),
+    );
+    assert!(result.is_ok(), "{result:#?}");
+}
+
+#[test]
+fn check_analyze_batch() {
+    let batch = DboAnalyzeBatch(vec![
+        DboAnalyzeItem {
+            typ: DboType::Procedure,
+            sql: ADD_JOB_HISTORY.to_owned(),
+        },
+        DboAnalyzeItem {
+            typ: DboType::CheckConstraint,
+            sql: "CHECK (salary > 0)".to_owned(),
+        },
+        DboAnalyzeItem {
+            typ: DboType::View,
+            sql: "CREATE VIEW store_view AS SELECT name FROM stores".to_owned(),
+        },
+    ]);
+
+    let DboAnalyzeBatchResult(results) = analyze_batch(&batch, &DboAnalyzeContext::default());
+    assert_eq!(results.len(), 3);
+
+    assert!(results[0].error.is_none(), "{:#?}", results[0]);
+    assert_eq!(
+        results[0]
+            .meta_data
+            .as_ref()
+            .unwrap()
+            .procedure
+            .as_ref()
+            .unwrap()
+            .name,
+        "add_job_history"
+    );
+    assert!(results[1].meta_data.is_none());
+    assert_eq!(
+        results[1].error,
+        Some(AnalyzeError::Unsupported(DboType::CheckConstraint))
+    );
+    assert_eq!(
+        results[2]
+            .meta_data
+            .as_ref()
+            .unwrap()
+            .view
+            .as_ref()
+            .unwrap()
+            .name,
+        "store_view"
+    );
+}
+
+#[test]
+fn check_analyze_batch_progress() {
+    let item = |typ, sql: &str| DboAnalyzeItem {
+        typ,
+        sql: sql.to_owned(),
+    };
+    let batch = DboAnalyzeBatch(vec![
+        item(DboType::Procedure, ADD_JOB_HISTORY),
+        item(DboType::CheckConstraint, "CHECK (salary > 0)"),
+        item(DboType::View, "CREATE VIEW v AS SELECT name FROM stores"),
+    ]);
+
+    let mut reports = Vec::new();
+    let DboAnalyzeBatchResult(results) =
+        analyze_batch_with_progress(&batch, &DboAnalyzeContext::default(), 2, |progress| {
+            reports.push(progress)
+        });
+    assert_eq!(results.len(), 3);
+
+    let progress = |processed, succeeded, failed| DboBatchProgress {
+        total: 3,
+        processed,
+        succeeded,
+        failed,
+    };
+    assert_eq!(reports, vec![progress(2, 1, 1), progress(3, 2, 1)]);
+}
@@ -0,0 +1,3 @@
+CREATE PACKAGE northwind.util AUTHID DEFINER AS
+    PROCEDURE print(str varchar2);
+END util;
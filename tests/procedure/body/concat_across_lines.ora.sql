@@ -0,0 +1,25 @@
+-- test: long concatenations and arithmetic spanning multiple lines with comments
+CREATE OR REPLACE PROCEDURE report_failure(p_order_id NUMBER, p_reason VARCHAR2)
+IS
+    l_retries NUMBER := 3;
+    l_message VARCHAR2(4000);
+BEGIN
+    l_message := 'Order ' || p_order_id -- the failing order
+        || ' failed: ' || p_reason
+        /* keep the retry count for support */
+        || ' (retries left: ' || (l_retries
+            -1) || ')';
+
+    DBMS_OUTPUT.PUT_LINE(
+        'Report for ' || -- header
+        TO_CHAR(SYSDATE, 'YYYY-MM-DD') || ': '
+        -- body
+        || l_message
+    );
+
+    RAISE_APPLICATION_ERROR(
+        -20001, -- custom error code
+        'Order processing failed: ' || /* details */ l_message
+            || ' at step ' || l_retries -1
+    );
+END report_failure;
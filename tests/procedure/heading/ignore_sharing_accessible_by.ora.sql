@@ -0,0 +1,8 @@
+-- test: ignore SHARING and ACCESSIBLE BY clauses, there is no equivalent in PostgreSQL
+CREATE OR REPLACE PROCEDURE ignore_sharing_accessible_by SHARING = METADATA (p_id NUMBER)
+ACCESSIBLE BY (PACKAGE hr.emp_api, PROCEDURE hire)
+AUTHID DEFINER
+IS
+BEGIN
+    NULL;
+END ignore_sharing_accessible_by;
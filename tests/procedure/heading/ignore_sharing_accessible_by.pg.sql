@@ -0,0 +1,8 @@
+-- test: ignore SHARING and ACCESSIBLE BY clauses, there is no equivalent in PostgreSQL
+CREATE OR REPLACE PROCEDURE ignore_sharing_accessible_by(p_id NUMBER)
+SECURITY DEFINER
+AS $body$
+BEGIN
+    NULL;
+END;
+$body$ LANGUAGE plpgsql;
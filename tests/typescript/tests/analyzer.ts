@@ -3,7 +3,15 @@
 
 import fs from 'node:fs';
 import path from 'node:path';
-import { analyze, DboAnalyzeContext } from 'poc-plpgsql-analyzer';
+import {
+  analyze,
+  Analyzer,
+  analyzeBatch,
+  analyzeSummary,
+  DboAnalyzeContext,
+  dependencyGraph,
+  dependencyGraphToDot,
+} from 'poc-plpgsql-analyzer';
 
 const FUNCTION_HEADINGS_DIR = '../function/heading';
 const PROCEDURE_HEADINGS_DIR = '../procedure/heading';
@@ -43,6 +51,21 @@ describe('try to parse and analyze Oracle function', () => {
     expect(metaData.procedure).toBeUndefined();
     expect(metaData.query).toBeUndefined();
   });
+
+  it('should classify the function volatility', () => {
+    const content = fs.readFileSync('../function/heading/function_heading_example.ora.sql', 'utf8');
+    const metaData = analyze('function', content, { tables: {} });
+
+    expect(metaData.function.volatility).toEqual('immutable');
+  });
+
+  it('should map parameter and return types', () => {
+    const content = fs.readFileSync('../function/heading/function_heading_example.ora.sql', 'utf8');
+    const metaData = analyze('function', content, { tables: {} });
+
+    expect(metaData.function.parameters.map((p) => p.mappedType)).toEqual(['text', 'numeric', 'boolean', 'timestamp', null]);
+    expect(metaData.function.returnType).toEqual('numeric');
+  });
 });
 
 describe('try to parse and analyze Oracle procedures', () => {
@@ -77,6 +100,21 @@ describe('try to parse and analyze Oracle procedures', () => {
     expect(metaData.function).toBeUndefined();
     expect(metaData.query).toBeUndefined();
   });
+
+  it('should count the lines of code as configured', () => {
+    const content = fs.readFileSync('../fixtures/add_job_history.sql', 'utf8');
+    const metaData = analyze('procedure', content, {
+      tables: {},
+      options: { linesOfCode: 'logical' },
+    });
+
+    expect(metaData.procedure.linesOfCode).toEqual(1);
+    expect(metaData.lineCounts).toEqual({
+      physical: 5,
+      nonComment: 5,
+      logical: 1,
+    });
+  });
 });
 
 describe('try to parse and analyze Oracle `SELECT` querys', () => {
@@ -175,3 +213,89 @@ describe('passing type context information into analyzer', () => {
     expect(metaData.query).toBeUndefined();
   });
 });
+
+describe('analyzing multiple objects in one call', () => {
+  it('should return a result for each item in order', () => {
+    const results = analyzeBatch(
+      [
+        { typ: 'view', sql: 'CREATE VIEW store_view AS SELECT name FROM stores' },
+        { typ: 'package', sql: 'CREATE PACKAGE p AS END p;' },
+      ],
+      { tables: {} },
+    );
+
+    expect(results).toHaveLength(2);
+    expect(results[0].metaData.view.name).toEqual('store_view');
+    expect(results[0].error).toBeUndefined();
+    expect(results[1].metaData).toBeUndefined();
+    expect(results[1].error).toEqual({ unsupported: 'package' });
+  });
+
+  it('should report the progress', () => {
+    const progress = [];
+    analyzeBatch(
+      [
+        { typ: 'view', sql: 'CREATE VIEW store_view AS SELECT name FROM stores' },
+        { typ: 'package', sql: 'CREATE PACKAGE p AS END p;' },
+      ],
+      { tables: {} },
+      p => progress.push(p),
+    );
+
+    expect(progress).toEqual([
+      { total: 2, processed: 1, succeeded: 1, failed: 0 },
+      { total: 2, processed: 2, succeeded: 1, failed: 1 },
+    ]);
+  });
+});
+
+describe('summarizing an object', () => {
+  it('should only return counts', () => {
+    const content = fs.readFileSync('../fixtures/add_job_history.sql', 'utf8');
+    const summary = analyzeSummary('procedure', content, { tables: {} });
+
+    expect(summary).toEqual({
+      linesOfCode: 5,
+      statements: 1,
+      errors: 0,
+      warnings: 0,
+      unsupportedFeatures: 0,
+    });
+  });
+});
+
+describe('building the dependency graph of many objects', () => {
+  it('should return the adjacency and render it as DOT', () => {
+    const graph = dependencyGraph(
+      [{ typ: 'view', sql: 'CREATE VIEW store_view AS SELECT name FROM stores' }],
+      { tables: {} },
+    );
+
+    expect(graph).toEqual({
+      adjacency: {
+        store_view: [{ target: 'stores', kind: 'table', isRecursive: false }],
+      },
+    });
+    expect(dependencyGraphToDot(graph)).toEqual(
+      'digraph dependencies {\n    "stores" [shape=box];\n    "store_view" -> "stores" [style=dashed];\n}\n',
+    );
+  });
+});
+
+describe('analyzing with a stateful analyzer', () => {
+  it('should reuse the context for every call', () => {
+    const analyzer = new Analyzer({ tables: {} });
+    const metaData = analyzer.analyze(
+      'view',
+      'CREATE VIEW store_view AS SELECT name FROM stores',
+    );
+    expect(metaData.view.name).toEqual('store_view');
+
+    const results = analyzer.analyzeBatch([
+      { typ: 'package', sql: 'CREATE PACKAGE p AS END p;' },
+    ]);
+    expect(results[0].error).toEqual({ unsupported: 'package' });
+
+    analyzer.free();
+  });
+});
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: SEE LICENSE IN LICENSE.md
+// SPDX-FileCopyrightText: 2023 CYBERTEC PostgreSQL International GmbH <office@cybertec.at>
+
+// Consumes every exported function and class with explicit type annotations,
+// such that `ts-jest` fails on any mismatch with the generated declarations.
+
+import {
+  analyze,
+  Analyzer,
+  analyzeBatch,
+  analyzeSummary,
+  DboAnalyzeBatchResult,
+  DboAnalyzeContext,
+  DboBatchProgress,
+  DboDependency,
+  DboDependencyGraph,
+  DboMetaData,
+  DboPackageValidation,
+  DboSummary,
+  DboType,
+  dependencyGraph,
+  dependencyGraphToDot,
+  validatePackage,
+} from 'poc-plpgsql-analyzer';
+
+const CONTEXT: DboAnalyzeContext = {
+  tables: {
+    orders: { columns: { id: { typ: 'integer' } } },
+  },
+  options: { linesOfCode: 'physical', singleLineLiterals: false },
+  renames: { orders: 'sales_orders' },
+};
+
+const PROCEDURE = `
+CREATE OR REPLACE PROCEDURE process_orders IS
+    CURSOR c IS SELECT id FROM orders;
+    l_id NUMBER;
+BEGIN
+    LOOP
+        FETCH c INTO l_id;
+        EXIT WHEN c%NOTFOUND;
+        CASE l_id
+            WHEN 1 THEN l_id := MOD(l_id, l_id);
+        END CASE;
+    END LOOP;
+END process_orders;
+`;
+
+describe('typing the exported API', () => {
+  it('should type the metadata of an object', () => {
+    const typ: DboType = 'procedure';
+    const metaData: DboMetaData = analyze(typ, PROCEDURE, CONTEXT);
+
+    const renames: [string, string][] = (metaData.renames ?? []).map(r => [r.from, r.to]);
+    expect(renames).toContainEqual(['orders', 'sales_orders']);
+
+    const cases: number[] = (metaData.casesWithoutElse ?? []).map(c => c.location.start.line);
+    expect(cases).toEqual([8]);
+
+    const patterns: string[] = (metaData.fetchLoops ?? []).map(l => l.pattern);
+    expect(patterns).toHaveLength(1);
+
+    const functions: string[] = (metaData.numericFunctions ?? []).map(f => f.function);
+    expect(functions).toEqual(['MOD']);
+
+    const returnings: string[] = (metaData.bulkReturnings ?? []).map(r => r.table);
+    expect(returnings).toEqual([]);
+  });
+
+  it('should type the metadata of a table', () => {
+    const metaData = analyze('table', 'CREATE TABLE t NOLOGGING AS SELECT id FROM orders', CONTEXT);
+
+    const name: string = metaData.table.name;
+    const options: string[] = metaData.table.droppedOptions.map(o => o.option);
+    expect(name).toEqual('t');
+    expect(options).toEqual(['NOLOGGING']);
+  });
+
+  it('should type summaries, batches and their progress', () => {
+    const summary: DboSummary = analyzeSummary('procedure', PROCEDURE, CONTEXT);
+    expect(summary.statements).toBeGreaterThan(0);
+
+    const progress: DboBatchProgress[] = [];
+    const results: DboAnalyzeBatchResult = analyzeBatch(
+      [{ typ: 'procedure', sql: PROCEDURE }],
+      CONTEXT,
+      (p: DboBatchProgress) => {
+        progress.push(p);
+      },
+      1,
+    );
+    expect(results[0].metaData).toEqual(expect.anything());
+    expect(progress.map(p => p.processed)).toEqual([1]);
+  });
+
+  it('should type the dependency graph', () => {
+    const graph: DboDependencyGraph = dependencyGraph([{ typ: 'procedure', sql: PROCEDURE }], CONTEXT);
+    const dependencies: DboDependency[] = graph.adjacency['process_orders'];
+    expect(dependencies.map(d => d.target)).toEqual(['orders']);
+
+    const dot: string = dependencyGraphToDot(graph);
+    expect(dot).toContain('"process_orders" -> "orders"');
+  });
+
+  it('should type the package validation', () => {
+    const validation: DboPackageValidation = validatePackage(
+      'CREATE PACKAGE p AS PROCEDURE a; END p;',
+      'CREATE PACKAGE BODY p AS PROCEDURE a IS BEGIN NULL; END a; END p;',
+    );
+    expect(validation.mismatches).toEqual([]);
+  });
+
+  it('should type the stateful analyzer', () => {
+    const analyzer = new Analyzer({ tables: {} });
+    analyzer.setContext(CONTEXT);
+
+    const metaData: DboMetaData = analyzer.analyze('procedure', PROCEDURE);
+    const summary: DboSummary = analyzer.analyzeSummary('procedure', PROCEDURE);
+    const results: DboAnalyzeBatchResult = analyzer.analyzeBatch(
+      [{ typ: 'procedure', sql: PROCEDURE }],
+      (p: DboBatchProgress) => expect(p.total).toEqual(1),
+    );
+    expect(metaData.procedure.name).toEqual('process_orders');
+    expect(summary.linesOfCode).toBeGreaterThan(0);
+    expect(results).toHaveLength(1);
+
+    analyzer.free();
+  });
+});